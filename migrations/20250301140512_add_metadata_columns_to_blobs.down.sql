@@ -0,0 +1 @@
+ALTER TABLE blobs DROP COLUMN alt_text, DROP COLUMN caption, DROP COLUMN focal_point_x, DROP COLUMN focal_point_y;
@@ -0,0 +1,4 @@
+ALTER TABLE blobs ADD COLUMN alt_text varchar NOT NULL DEFAULT '', ADD COLUMN caption text NOT NULL DEFAULT '',
+ADD COLUMN focal_point_x real NOT NULL DEFAULT 0.5, ADD COLUMN focal_point_y real NOT NULL DEFAULT 0.5,
+ADD CONSTRAINT check_blobs_focal_point_x CHECK (focal_point_x >= 0 AND focal_point_x <= 1),
+ADD CONSTRAINT check_blobs_focal_point_y CHECK (focal_point_y >= 0 AND focal_point_y <= 1);
@@ -0,0 +1,3 @@
+ALTER TABLE websites DROP COLUMN storage_quota;
+
+ALTER TABLE users DROP COLUMN storage_quota;
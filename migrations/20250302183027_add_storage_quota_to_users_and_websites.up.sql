@@ -0,0 +1,5 @@
+ALTER TABLE users ADD COLUMN storage_quota bigint,
+ADD CONSTRAINT check_users_storage_quota CHECK (storage_quota IS NULL OR storage_quota >= 0);
+
+ALTER TABLE websites ADD COLUMN storage_quota bigint,
+ADD CONSTRAINT check_websites_storage_quota CHECK (storage_quota IS NULL OR storage_quota >= 0);
@@ -0,0 +1,17 @@
+CREATE TABLE blob_uploads (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    website_id uuid NULL,
+    user_id uuid NOT NULL,
+    file_name varchar NOT NULL,
+    content_type varchar NOT NULL,
+    upload_length bigint NOT NULL,
+    upload_offset bigint NOT NULL DEFAULT 0,
+    created_at timestamptz NOT NULL DEFAULT current_timestamp,
+    updated_at timestamptz NULL,
+    CONSTRAINT pkey_blob_uploads PRIMARY KEY (id),
+    CONSTRAINT fkey_blob_uploads_to_websites FOREIGN KEY (website_id) REFERENCES websites (id) ON DELETE CASCADE,
+    CONSTRAINT fkey_blob_uploads_to_users FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    CONSTRAINT check_blob_uploads_upload_offset CHECK (upload_offset >= 0 AND upload_offset <= upload_length)
+);
+
+SELECT manage_updated_at('blob_uploads');
@@ -0,0 +1,3 @@
+ALTER TABLE post_comments DROP COLUMN mentioned_user_ids;
+
+ALTER TABLE posts DROP COLUMN mentioned_user_ids;
@@ -0,0 +1,3 @@
+ALTER TABLE posts ADD COLUMN mentioned_user_ids uuid [] NOT NULL DEFAULT ARRAY[]::uuid [];
+
+ALTER TABLE post_comments ADD COLUMN mentioned_user_ids uuid [] NOT NULL DEFAULT ARRAY[]::uuid [];
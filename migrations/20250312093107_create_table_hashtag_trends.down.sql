@@ -0,0 +1 @@
+DROP TABLE hashtag_trends;
@@ -0,0 +1,16 @@
+CREATE TABLE hashtag_trends (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    hashtag_id uuid NOT NULL,
+    posts_count bigint NOT NULL DEFAULT 0,
+    views_count bigint NOT NULL DEFAULT 0,
+    score double precision NOT NULL DEFAULT 0,
+    created_at timestamptz NOT NULL DEFAULT current_timestamp,
+    updated_at timestamptz NULL,
+    CONSTRAINT pkey_hashtag_trends PRIMARY KEY (id),
+    CONSTRAINT fkey_hashtag_trends_to_hashtags FOREIGN KEY (hashtag_id) REFERENCES hashtags (id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX index_hashtag_trends_on_hashtag_id ON hashtag_trends USING btree (hashtag_id);
+CREATE INDEX index_hashtag_trends_on_score ON hashtag_trends USING btree (score DESC);
+
+SELECT manage_updated_at('hashtag_trends');
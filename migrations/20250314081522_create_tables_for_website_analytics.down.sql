@@ -0,0 +1,11 @@
+DROP TABLE IF EXISTS website_daily_countries;
+
+DROP TABLE IF EXISTS website_daily_referrers;
+
+DROP TABLE IF EXISTS post_daily_stats;
+
+DROP TABLE IF EXISTS website_daily_stats;
+
+DROP TABLE IF EXISTS post_view_events;
+
+DROP TYPE IF EXISTS user_agent_class;
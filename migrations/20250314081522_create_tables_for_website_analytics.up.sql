@@ -0,0 +1,97 @@
+CREATE TYPE user_agent_class AS ENUM ('desktop', 'mobile', 'tablet', 'bot', 'unknown');
+
+CREATE TABLE IF NOT EXISTS post_view_events (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    website_id uuid NOT NULL,
+    post_id uuid NOT NULL,
+    user_id uuid NULL,
+    ip_address_hash varchar NOT NULL,
+    referrer_host varchar NULL,
+    user_agent_class user_agent_class NOT NULL DEFAULT 'unknown',
+    country_alpha2 varchar(2) NULL,
+    created_at timestamptz NOT NULL DEFAULT current_timestamp,
+    updated_at timestamptz NULL,
+    CONSTRAINT pkey_post_view_events PRIMARY KEY (id),
+    CONSTRAINT fkey_post_view_events_to_websites FOREIGN KEY (website_id) REFERENCES websites (id) ON DELETE CASCADE,
+    CONSTRAINT fkey_post_view_events_to_posts FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE,
+    CONSTRAINT fkey_post_view_events_to_users FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS index_post_view_events_on_created_at ON post_view_events USING btree (created_at);
+
+SELECT manage_updated_at('post_view_events');
+
+CREATE TABLE IF NOT EXISTS website_daily_stats (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    website_id uuid NOT NULL,
+    day date NOT NULL,
+    views_count bigint NOT NULL DEFAULT 0,
+    visitors_count bigint NOT NULL DEFAULT 0,
+    reactions_count bigint NOT NULL DEFAULT 0,
+    comments_count bigint NOT NULL DEFAULT 0,
+    created_at timestamptz NOT NULL DEFAULT current_timestamp,
+    updated_at timestamptz NULL,
+    CONSTRAINT pkey_website_daily_stats PRIMARY KEY (id),
+    CONSTRAINT fkey_website_daily_stats_to_websites FOREIGN KEY (website_id) REFERENCES websites (id)
+    ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS index_website_daily_stats_on_website_id_day ON website_daily_stats
+USING btree (website_id, day);
+
+SELECT manage_updated_at('website_daily_stats');
+
+CREATE TABLE IF NOT EXISTS post_daily_stats (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    website_id uuid NOT NULL,
+    post_id uuid NOT NULL,
+    day date NOT NULL,
+    views_count bigint NOT NULL DEFAULT 0,
+    visitors_count bigint NOT NULL DEFAULT 0,
+    created_at timestamptz NOT NULL DEFAULT current_timestamp,
+    updated_at timestamptz NULL,
+    CONSTRAINT pkey_post_daily_stats PRIMARY KEY (id),
+    CONSTRAINT fkey_post_daily_stats_to_websites FOREIGN KEY (website_id) REFERENCES websites (id) ON DELETE CASCADE,
+    CONSTRAINT fkey_post_daily_stats_to_posts FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS index_post_daily_stats_on_post_id_day ON post_daily_stats USING btree (post_id, day);
+CREATE INDEX IF NOT EXISTS index_post_daily_stats_on_website_id_day ON post_daily_stats USING btree (website_id, day);
+
+SELECT manage_updated_at('post_daily_stats');
+
+CREATE TABLE IF NOT EXISTS website_daily_referrers (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    website_id uuid NOT NULL,
+    day date NOT NULL,
+    referrer_host varchar NOT NULL,
+    views_count bigint NOT NULL DEFAULT 0,
+    created_at timestamptz NOT NULL DEFAULT current_timestamp,
+    updated_at timestamptz NULL,
+    CONSTRAINT pkey_website_daily_referrers PRIMARY KEY (id),
+    CONSTRAINT fkey_website_daily_referrers_to_websites FOREIGN KEY (website_id) REFERENCES websites (id)
+    ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS index_website_daily_referrers_on_website_id_day_referrer_host
+ON website_daily_referrers USING btree (website_id, day, referrer_host);
+
+SELECT manage_updated_at('website_daily_referrers');
+
+CREATE TABLE IF NOT EXISTS website_daily_countries (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    website_id uuid NOT NULL,
+    day date NOT NULL,
+    country_alpha2 varchar(2) NOT NULL,
+    views_count bigint NOT NULL DEFAULT 0,
+    created_at timestamptz NOT NULL DEFAULT current_timestamp,
+    updated_at timestamptz NULL,
+    CONSTRAINT pkey_website_daily_countries PRIMARY KEY (id),
+    CONSTRAINT fkey_website_daily_countries_to_websites FOREIGN KEY (website_id) REFERENCES websites (id)
+    ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS index_website_daily_countries_on_website_id_day_country_alpha2
+ON website_daily_countries USING btree (website_id, day, country_alpha2);
+
+SELECT manage_updated_at('website_daily_countries');
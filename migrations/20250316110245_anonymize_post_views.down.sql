@@ -0,0 +1,15 @@
+DROP TABLE IF EXISTS archived_post_views;
+
+ALTER TABLE post_view_events RENAME COLUMN visitor_hash TO ip_address_hash;
+
+DROP INDEX IF EXISTS index_post_views_on_created_at;
+DROP INDEX IF EXISTS index_post_views_on_post_id_visitor_hash;
+
+DELETE FROM post_views WHERE ip_address IS NULL;
+
+ALTER TABLE post_views DROP COLUMN visitor_hash, ALTER COLUMN ip_address SET NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS index_post_views_on_post_id_ip_address ON post_views
+USING btree (post_id, ip_address) WHERE user_id IS NULL;
+
+DROP TABLE IF EXISTS visitor_hash_salts;
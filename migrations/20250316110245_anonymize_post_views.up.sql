@@ -0,0 +1,36 @@
+CREATE TABLE IF NOT EXISTS visitor_hash_salts (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    day date NOT NULL,
+    salt varchar NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT current_timestamp,
+    updated_at timestamptz NULL,
+    CONSTRAINT pkey_visitor_hash_salts PRIMARY KEY (id)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS index_visitor_hash_salts_on_day ON visitor_hash_salts USING btree (day);
+
+SELECT manage_updated_at('visitor_hash_salts');
+
+ALTER TABLE post_views ALTER COLUMN ip_address DROP NOT NULL, ADD COLUMN visitor_hash varchar NULL;
+
+DROP INDEX IF EXISTS index_post_views_on_post_id_ip_address;
+
+CREATE UNIQUE INDEX IF NOT EXISTS index_post_views_on_post_id_visitor_hash ON post_views
+USING btree (post_id, visitor_hash) WHERE user_id IS NULL AND visitor_hash IS NOT NULL;
+CREATE INDEX IF NOT EXISTS index_post_views_on_created_at ON post_views USING btree (created_at);
+
+ALTER TABLE post_view_events RENAME COLUMN ip_address_hash TO visitor_hash;
+
+CREATE TABLE IF NOT EXISTS archived_post_views (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    post_id uuid NOT NULL,
+    views_count bigint NOT NULL DEFAULT 0,
+    created_at timestamptz NOT NULL DEFAULT current_timestamp,
+    updated_at timestamptz NULL,
+    CONSTRAINT pkey_archived_post_views PRIMARY KEY (id),
+    CONSTRAINT fkey_archived_post_views_to_posts FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS index_archived_post_views_on_post_id ON archived_post_views USING btree (post_id);
+
+SELECT manage_updated_at('archived_post_views');
@@ -0,0 +1,11 @@
+DROP INDEX IF EXISTS index_websites_on_name_trgm;
+DROP INDEX IF EXISTS index_posts_on_title_trgm;
+DROP INDEX IF EXISTS index_post_comments_on_content_trgm;
+DROP INDEX IF EXISTS index_hashtags_on_name_trgm;
+DROP INDEX IF EXISTS index_users_on_display_name_trgm;
+DROP INDEX IF EXISTS index_users_on_username_trgm;
+DROP INDEX IF EXISTS index_users_on_search;
+
+ALTER TABLE users DROP COLUMN search;
+
+DROP EXTENSION IF EXISTS pg_trgm;
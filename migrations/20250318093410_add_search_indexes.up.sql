@@ -0,0 +1,11 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE users ADD COLUMN search tsvector GENERATED ALWAYS AS (to_tsvector('simple', display_name || ' ' || bio)) STORED;
+
+CREATE INDEX IF NOT EXISTS index_users_on_search ON users USING gin (search);
+CREATE INDEX IF NOT EXISTS index_users_on_username_trgm ON users USING gin ((username::text) gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS index_users_on_display_name_trgm ON users USING gin (display_name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS index_hashtags_on_name_trgm ON hashtags USING gin ((name::text) gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS index_post_comments_on_content_trgm ON post_comments USING gin (content gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS index_posts_on_title_trgm ON posts USING gin (title gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS index_websites_on_name_trgm ON websites USING gin ((name::text) gin_trgm_ops);
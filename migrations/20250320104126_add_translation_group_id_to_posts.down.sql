@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS index_posts_on_translation_group_id_language;
+
+ALTER TABLE posts DROP COLUMN translation_group_id;
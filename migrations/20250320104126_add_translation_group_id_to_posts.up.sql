@@ -0,0 +1,5 @@
+ALTER TABLE posts ADD COLUMN translation_group_id uuid NOT NULL DEFAULT gen_random_uuid();
+
+CREATE UNIQUE INDEX IF NOT EXISTS index_posts_on_translation_group_id_language ON posts USING btree (
+    translation_group_id, language
+);
@@ -0,0 +1,5 @@
+ALTER TABLE posts DROP COLUMN moderation_reason;
+ALTER TABLE posts DROP COLUMN suspended_at;
+
+ALTER TABLE websites DROP COLUMN moderation_reason;
+ALTER TABLE websites DROP COLUMN suspended_at;
@@ -0,0 +1,5 @@
+ALTER TABLE websites ADD COLUMN suspended_at timestamptz NULL;
+ALTER TABLE websites ADD COLUMN moderation_reason text NULL;
+
+ALTER TABLE posts ADD COLUMN suspended_at timestamptz NULL;
+ALTER TABLE posts ADD COLUMN moderation_reason text NULL;
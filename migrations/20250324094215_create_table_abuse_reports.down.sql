@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS abuse_reports;
+
+DROP TYPE IF EXISTS abuse_report_target_type;
+
+DROP TYPE IF EXISTS abuse_report_category;
@@ -0,0 +1,28 @@
+CREATE TYPE abuse_report_category AS ENUM ('spam', 'harassment', 'hate_speech', 'violence', 'illegal_content', 'other');
+
+CREATE TYPE abuse_report_target_type AS ENUM ('post', 'post_comment', 'user', 'website');
+
+CREATE TABLE IF NOT EXISTS abuse_reports (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    user_id uuid NOT NULL,
+    target_type abuse_report_target_type NOT NULL,
+    target_id uuid NOT NULL,
+    category abuse_report_category NOT NULL,
+    note text NOT NULL DEFAULT '',
+    resolved_by_user_id uuid NULL,
+    resolved_at timestamptz NULL,
+    created_at timestamptz NOT NULL DEFAULT current_timestamp,
+    updated_at timestamptz NULL,
+    CONSTRAINT pkey_abuse_reports PRIMARY KEY (id),
+    CONSTRAINT fkey_abuse_reports_to_users FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    CONSTRAINT fkey_abuse_reports_to_resolved_by_users FOREIGN KEY (resolved_by_user_id) REFERENCES users (id)
+    ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS index_abuse_reports_on_user_id_created_at ON abuse_reports
+USING btree (user_id, created_at);
+CREATE INDEX IF NOT EXISTS index_abuse_reports_on_target_type_target_id ON abuse_reports
+USING btree (target_type, target_id);
+
+SELECT manage_updated_at('abuse_reports');
+SELECT manage_versions('abuse_reports');
@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS audit_logs;
+
+DROP TYPE IF EXISTS audit_log_target_type;
+
+DROP TYPE IF EXISTS audit_log_action;
@@ -0,0 +1,32 @@
+CREATE TYPE audit_log_action AS ENUM (
+    'disable_user',
+    'enable_user',
+    'insert_invitation_code',
+    'reset_user_password',
+    'update_user_email',
+    'update_user_password',
+    'update_user_role'
+);
+
+CREATE TYPE audit_log_target_type AS ENUM ('invitation_code', 'user');
+
+CREATE TABLE IF NOT EXISTS audit_logs (
+    id uuid NOT NULL DEFAULT gen_random_uuid(),
+    actor_user_id uuid NULL,
+    ip_address inet NULL,
+    action audit_log_action NOT NULL,
+    target_type audit_log_target_type NOT NULL,
+    target_id uuid NOT NULL,
+    before_value jsonb NULL,
+    after_value jsonb NULL,
+    created_at timestamptz NOT NULL DEFAULT current_timestamp,
+    CONSTRAINT pkey_audit_logs PRIMARY KEY (id),
+    CONSTRAINT fkey_audit_logs_to_users FOREIGN KEY (actor_user_id) REFERENCES users (id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS index_audit_logs_on_created_at ON audit_logs USING btree (created_at);
+CREATE INDEX IF NOT EXISTS index_audit_logs_on_actor_user_id_created_at ON audit_logs
+USING btree (actor_user_id, created_at);
+CREATE INDEX IF NOT EXISTS index_audit_logs_on_action_created_at ON audit_logs USING btree (action, created_at);
+CREATE INDEX IF NOT EXISTS index_audit_logs_on_target_type_target_id ON audit_logs
+USING btree (target_type, target_id);
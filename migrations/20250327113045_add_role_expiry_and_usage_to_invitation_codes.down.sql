@@ -0,0 +1,16 @@
+-- Values can't be removed from an enum type, so `delete_invitation_code` and `update_user_invitation_allowance` stay
+-- in `audit_log_action`.
+DELETE FROM audit_logs WHERE action IN ('delete_invitation_code', 'update_user_invitation_allowance');
+
+ALTER TABLE users DROP COLUMN IF EXISTS invitation_allowance;
+
+DROP INDEX IF EXISTS index_invitation_codes_on_used_at_created_at;
+DROP INDEX IF EXISTS index_invitation_codes_on_invited_by_user_id;
+
+DELETE FROM invitation_codes WHERE used_at IS NOT NULL;
+
+ALTER TABLE invitation_codes DROP COLUMN IF EXISTS used_at;
+ALTER TABLE invitation_codes DROP COLUMN IF EXISTS used_by_user_id;
+ALTER TABLE invitation_codes DROP COLUMN IF EXISTS invited_by_user_id;
+ALTER TABLE invitation_codes DROP COLUMN IF EXISTS expires_at;
+ALTER TABLE invitation_codes DROP COLUMN IF EXISTS role;
@@ -0,0 +1,20 @@
+ALTER TABLE invitation_codes ADD COLUMN role user_role NOT NULL DEFAULT 'user';
+ALTER TABLE invitation_codes ADD COLUMN expires_at timestamptz NULL;
+ALTER TABLE invitation_codes ADD COLUMN invited_by_user_id uuid NULL;
+ALTER TABLE invitation_codes ADD COLUMN used_by_user_id uuid NULL;
+ALTER TABLE invitation_codes ADD COLUMN used_at timestamptz NULL;
+
+ALTER TABLE invitation_codes ADD CONSTRAINT fkey_invitation_codes_to_invited_by_users
+FOREIGN KEY (invited_by_user_id) REFERENCES users (id) ON DELETE SET NULL;
+ALTER TABLE invitation_codes ADD CONSTRAINT fkey_invitation_codes_to_used_by_users
+FOREIGN KEY (used_by_user_id) REFERENCES users (id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS index_invitation_codes_on_invited_by_user_id ON invitation_codes
+USING btree (invited_by_user_id);
+CREATE INDEX IF NOT EXISTS index_invitation_codes_on_used_at_created_at ON invitation_codes
+USING btree (used_at, created_at);
+
+ALTER TABLE users ADD COLUMN invitation_allowance integer NOT NULL DEFAULT 0;
+
+ALTER TYPE audit_log_action ADD VALUE IF NOT EXISTS 'delete_invitation_code';
+ALTER TYPE audit_log_action ADD VALUE IF NOT EXISTS 'update_user_invitation_allowance';
@@ -0,0 +1 @@
+DROP INDEX IF EXISTS index_hashtags_on_lower_name;
@@ -0,0 +1,2 @@
+-- Lets `search_hashtags` find hashtags by the prefix of their name without a sequential scan.
+CREATE INDEX IF NOT EXISTS index_hashtags_on_lower_name ON hashtags USING btree (LOWER(name::text) text_pattern_ops);
@@ -0,0 +1,3 @@
+-- Values can't be removed from an enum type, so the moderation actions stay in `audit_log_action` and the `post` and
+-- `website` target types stay in `audit_log_target_type`.
+DELETE FROM audit_logs WHERE action IN ('moderate_post', 'moderate_website', 'restore_post', 'restore_website');
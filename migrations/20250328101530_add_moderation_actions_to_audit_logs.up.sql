@@ -0,0 +1,7 @@
+ALTER TYPE audit_log_action ADD VALUE IF NOT EXISTS 'moderate_post';
+ALTER TYPE audit_log_action ADD VALUE IF NOT EXISTS 'moderate_website';
+ALTER TYPE audit_log_action ADD VALUE IF NOT EXISTS 'restore_post';
+ALTER TYPE audit_log_action ADD VALUE IF NOT EXISTS 'restore_website';
+
+ALTER TYPE audit_log_target_type ADD VALUE IF NOT EXISTS 'post';
+ALTER TYPE audit_log_target_type ADD VALUE IF NOT EXISTS 'website';
@@ -0,0 +1,4 @@
+-- Values can't be removed from an enum type, so the new actions stay in `audit_log_action` and the `abuse_report`
+-- target type stays in `audit_log_target_type`.
+DELETE FROM audit_logs
+WHERE action IN ('resolve_abuse_report', 'update_user_storage_quota', 'update_website_storage_quota');
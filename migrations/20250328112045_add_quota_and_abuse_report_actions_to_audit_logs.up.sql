@@ -0,0 +1,5 @@
+ALTER TYPE audit_log_action ADD VALUE IF NOT EXISTS 'resolve_abuse_report';
+ALTER TYPE audit_log_action ADD VALUE IF NOT EXISTS 'update_user_storage_quota';
+ALTER TYPE audit_log_action ADD VALUE IF NOT EXISTS 'update_website_storage_quota';
+
+ALTER TYPE audit_log_target_type ADD VALUE IF NOT EXISTS 'abuse_report';
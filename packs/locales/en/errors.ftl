@@ -19,3 +19,4 @@ invalid-length = Invalid length (must be {$min ->
     *[zero] {""}
 })
 is-invalid = Is invalid
+too-many-requests = Too many requests, please try again later
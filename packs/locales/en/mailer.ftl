@@ -1,3 +1,4 @@
+author-mentioned-you-you-can-see-it-at-the-following-url = @{$author} mentioned you, you can see it at the following URL
 by-default-all-user-accounts-are-disabled-but-we-will-let-you-know-when-your-account-is-enabled = By default, all user accounts are disabled, but we will let you know when your account is enabled
 confirm-your-email = Confirm your email
 confirm-your-login = Confirm your login
@@ -7,16 +8,38 @@ if-you-recognize-this-action-you-can-ignore-this-message = If you recognize this
 invitation-code = Invitation code
 confirmation-code = Confirmation code
 hello = Hello
+new-abuse-report-received = New abuse report received
 new-user-account-created = New user account created
 new-user-session-started = New user session started
+please-free-up-some-space-or-contact-us-to-increase-your-quota-at-the-following-email-address = Please free up some space or contact us to increase your quota at the following email address
 reset-your-password = Reset your password
 someone-has-created-a-new-user-account-with-the-following-username = Someone has created a new user account with the following username
+someone-has-reported-target-for-the-following-reason = Someone has reported {$target} for the following reason
 someone-has-started-a-user-session-with-your-account = Someone has started a user session with your account
+the-export-of-target-is-ready-you-can-download-it-from-the-following-url = The export of {$target} is ready, you can download it from the following URL
+the-following-items-were-skipped = The following items were skipped
+this-file-will-be-available-for-seven-days = This file will be available for seven days
 this-user-account-is-disabled-by-default = This user account is disabled by default
 use-this-code-to-action = Use this code to {$action}
 use-this-code-to-create-your-account = Use this code to create your account
 we-are-glad-to-inform-you-that-we-have-enabled-your-user-account = We are glad to inform you that we have enabled your user account
+we-are-glad-to-inform-you-that-we-have-restored-target = We are glad to inform you that we have restored {$target}
+we-could-not-import-the-file-uploaded-to-target = We could not import the file uploaded to {$target}, please check that it is a WordPress export or a Markdown archive
+we-have-imported-count-posts-into-target = We have imported {$count} posts into {$target}
+we-have-suspended-target-for-the-following-reason = We have suspended {$target} for the following reason
+we-have-unpublished-target-for-the-following-reason = We have unpublished {$target} for the following reason
 we-regret-to-inform-you-that-we-have-disabled-your-user-account = We regret to inform you that we have disabled your user account
 welcome-to-title = Welcome to {$title}!
+you-can-review-it-at-the-following-url = You can review it at the following URL
+you-have-been-mentioned = You have been mentioned
+you-have-used-used-of-the-max-available-in-target = You have used {$used} of the {$max} available in {$target}
+your-content-has-been-restored = Your content has been restored
+your-content-has-been-suspended = Your content has been suspended
+your-content-has-been-unpublished = Your content has been unpublished
+your-personal-files = your personal files
+your-posts-could-not-be-imported = Your posts could not be imported
+your-posts-have-been-imported = Your posts have been imported
+your-storage-is-almost-full = Your storage is almost full
 your-user-account-has-been-disabled = Your user account has been disabled
 your-user-account-has-been-enabled = Your user account has been enabled
+your-website-export-is-ready = Your website export is ready
@@ -1,10 +1,16 @@
 failed-to-create-post = Failed to create post
 failed-to-create-website = Failed to create website
+failed-to-export-website = Failed to export website
+failed-to-import-posts = Failed to import posts
 failed-to-save-navigation = Failed to save navigation
+failed-to-update-file = Failed to update file
 failed-to-update-post = Failed to update post
 failed-to-update-website = Failed to update website
+file-updated-successfully = File updated successfully
 navigation-saved-successfully = Navigation saved successfully
 post-created-successfully = Post created successfully
+post-import-started-successfully = Post import started successfully, you will receive an email when it is finished
 post-updated-successfully = Post updated successfully
 website-created-successfully = Website created successfully
+website-export-started-successfully = Website export started successfully, you will receive an email when it is ready
 website-updated-successfully = Website updated successfully
@@ -19,3 +19,4 @@ invalid-length = Longitud inválida (debe tener {$min ->
     *[zero] {""}
 })
 is-invalid = Es inválido
+too-many-requests = Demasiadas solicitudes, por favor inténtalo de nuevo más tarde
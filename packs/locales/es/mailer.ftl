@@ -1,3 +1,4 @@
+author-mentioned-you-you-can-see-it-at-the-following-url = @{$author} te mencionó, puedes verlo en la siguiente URL
 by-default-all-user-accounts-are-disabled-but-we-will-let-you-know-when-your-account-is-enabled = Por defecto, todas las cuentas de usuario están deshabilitadas, pero te informaremos cuando tu cuenta esté habilitada
 confirm-your-email = Confirmar tu correo electrónico
 confirm-your-login = Confirmar tu inicio de sesión
@@ -7,16 +8,38 @@ if-you-recognize-this-action-you-can-ignore-this-message = Si reconoces esta acc
 invitation-code = Código de invitación
 confirmation-code = Código de confirmación
 hello = Hola
+new-abuse-report-received = Nuevo reporte de abuso recibido
 new-user-account-created = Nueva cuenta de usuario creada
 new-user-session-started = Nueva sesión de usuario iniciada
+please-free-up-some-space-or-contact-us-to-increase-your-quota-at-the-following-email-address = Por favor libera algo de espacio o contáctanos para aumentar tu cuota en la siguiente dirección de correo electrónico
 reset-your-password = Reiniciar tu contraseña
 someone-has-created-a-new-user-account-with-the-following-username = Alguien ha creado una nueva cuenta de usuario con el siguiente nombre de usuario
+someone-has-reported-target-for-the-following-reason = Alguien ha reportado {$target} por el siguiente motivo
 someone-has-started-a-user-session-with-your-account = Alguien ha iniciado una sesión de usuario con tu cuenta
+the-export-of-target-is-ready-you-can-download-it-from-the-following-url = La exportación de {$target} está lista, puedes descargarla desde la siguiente URL
+the-following-items-were-skipped = Los siguientes elementos fueron omitidos
+this-file-will-be-available-for-seven-days = Este archivo estará disponible por siete días
 this-user-account-is-disabled-by-default = Esta cuenta de usuario está deshabilitada por defecto
 use-this-code-to-action = Use este código para {$action}
 use-this-code-to-create-your-account = Use este código para crear tu cuenta
 we-are-glad-to-inform-you-that-we-have-enabled-your-user-account = Estamos encantados de informarte que hemos habilitado tu cuenta de usuario
+we-are-glad-to-inform-you-that-we-have-restored-target = Nos complace informarte que hemos restaurado {$target}
+we-could-not-import-the-file-uploaded-to-target = No pudimos importar el archivo subido a {$target}, por favor verifica que sea una exportación de WordPress o un archivo Markdown
+we-have-imported-count-posts-into-target = Hemos importado {$count} posts en {$target}
+we-have-suspended-target-for-the-following-reason = Hemos suspendido {$target} por el siguiente motivo
+we-have-unpublished-target-for-the-following-reason = Hemos despublicado {$target} por el siguiente motivo
 we-regret-to-inform-you-that-we-have-disabled-your-user-account = Lamentamos informarle que hemos deshabilitado tu cuenta de usuario
 welcome-to-title = ¡Bienvenido a {$title}!
+you-can-review-it-at-the-following-url = Puedes revisarlo en la siguiente URL
+you-have-been-mentioned = Te han mencionado
+you-have-used-used-of-the-max-available-in-target = Has usado {$used} de los {$max} disponibles en {$target}
+your-content-has-been-restored = Tu contenido ha sido restaurado
+your-content-has-been-suspended = Tu contenido ha sido suspendido
+your-content-has-been-unpublished = Tu contenido ha sido despublicado
+your-personal-files = tus archivos personales
+your-posts-could-not-be-imported = Tus posts no pudieron ser importados
+your-posts-have-been-imported = Tus posts han sido importados
+your-storage-is-almost-full = Tu almacenamiento está casi lleno
 your-user-account-has-been-disabled = Tu cuenta de usuario ha sido deshabilitada
 your-user-account-has-been-enabled = Tu cuenta de usuario ha sido habilitada
+your-website-export-is-ready = La exportación de tu website está lista
@@ -1,10 +1,16 @@
 failed-to-create-post = Error al crear post
 failed-to-create-website = Error al crear el website
+failed-to-export-website = No se pudo exportar el sitio web
+failed-to-import-posts = Error al importar posts
 failed-to-save-navigation = Error al guardar navegación
+failed-to-update-file = Error al actualizar archivo
 failed-to-update-post = Error al actualizar post
 failed-to-update-website = Error al actualizar el website
+file-updated-successfully = Archivo actualizado exitosamente
 navigation-saved-successfully = Navegación guardada exitosamente
 post-created-successfully = Post creado exitosamente
+post-import-started-successfully = La importación de posts se inició exitosamente, recibirás un correo electrónico cuando termine
 post-updated-successfully = Post actualizado exitosamente
 website-created-successfully = Website creado exitosamente
+website-export-started-successfully = La exportación del sitio web se inició correctamente, recibirás un correo electrónico cuando esté lista
 website-updated-successfully = Website actualizado exitosamente
@@ -19,3 +19,4 @@ invalid-length = Comprimento inválido (deve ter {$min ->
     *[zero] {""}
 })
 is-invalid = É inválido
+too-many-requests = Muitas solicitações, por favor tente novamente mais tarde
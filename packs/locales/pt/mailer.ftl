@@ -1,3 +1,4 @@
+author-mentioned-you-you-can-see-it-at-the-following-url = @{$author} mencionou você, você pode ver na seguinte URL
 by-default-all-user-accounts-are-disabled-but-we-will-let-you-know-when-your-account-is-enabled = Por padrão, todas as contas de usuário estão desativadas, mas avisaremos quando sua conta for ativada
 confirm-your-email = Confirme seu e-mail
 confirm-your-login = Confirme seu login
@@ -7,16 +8,38 @@ if-you-recognize-this-action-you-can-ignore-this-message = Se você reconhece es
 invitation-code = Código de convite
 confirmation-code = Código de confirmação
 hello = Olá
+new-abuse-report-received = Nova denúncia de abuso recebida
 new-user-account-created = Nova conta de usuário criada
 new-user-session-started = Nova sessão de usuário iniciada
+please-free-up-some-space-or-contact-us-to-increase-your-quota-at-the-following-email-address = Por favor libere algum espaço ou entre em contato conosco para aumentar sua cota no seguinte endereço de e-mail
 reset-your-password = Redefinir sua senha
 someone-has-created-a-new-user-account-with-the-following-username = Alguém criou uma nova conta de usuário com o seguinte nome de usuário
+someone-has-reported-target-for-the-following-reason = Alguém denunciou {$target} pelo seguinte motivo
 someone-has-started-a-user-session-with-your-account = Alguém iniciou uma sessão de usuário com sua conta
+the-export-of-target-is-ready-you-can-download-it-from-the-following-url = A exportação de {$target} está pronta, você pode baixá-la na seguinte URL
+the-following-items-were-skipped = Os seguintes itens foram ignorados
+this-file-will-be-available-for-seven-days = Este arquivo estará disponível por sete dias
 this-user-account-is-disabled-by-default = Esta conta de usuário está desativada por padrão
 use-this-code-to-action = Use este código para {$action}
 use-this-code-to-create-your-account = Use este código para criar sua conta
 we-are-glad-to-inform-you-that-we-have-enabled-your-user-account = Temos o prazer de informar que ativamos sua conta de usuário
+we-are-glad-to-inform-you-that-we-have-restored-target = Temos o prazer de informar que restauramos {$target}
+we-could-not-import-the-file-uploaded-to-target = Não foi possível importar o arquivo enviado para {$target}, verifique se é uma exportação do WordPress ou um arquivo Markdown
+we-have-imported-count-posts-into-target = Importamos {$count} posts em {$target}
+we-have-suspended-target-for-the-following-reason = Suspendemos {$target} pelo seguinte motivo
+we-have-unpublished-target-for-the-following-reason = Despublicamos {$target} pelo seguinte motivo
 we-regret-to-inform-you-that-we-have-disabled-your-user-account = Lamentamos informar que desativamos sua conta de usuário
 welcome-to-title = Bem-vindo ao {$title}!
+you-can-review-it-at-the-following-url = Você pode revisá-la na seguinte URL
+you-have-been-mentioned = Você foi mencionado
+you-have-used-used-of-the-max-available-in-target = Você usou {$used} dos {$max} disponíveis em {$target}
+your-content-has-been-restored = Seu conteúdo foi restaurado
+your-content-has-been-suspended = Seu conteúdo foi suspenso
+your-content-has-been-unpublished = Seu conteúdo foi despublicado
+your-personal-files = seus arquivos pessoais
+your-posts-could-not-be-imported = Seus posts não puderam ser importados
+your-posts-have-been-imported = Seus posts foram importados
+your-storage-is-almost-full = Seu armazenamento está quase cheio
 your-user-account-has-been-disabled = Sua conta de usuário foi desativada
 your-user-account-has-been-enabled = Sua conta de usuário foi ativada
+your-website-export-is-ready = A exportação do seu website está pronta
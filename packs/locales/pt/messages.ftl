@@ -1,10 +1,16 @@
 failed-to-create-post = Falha ao criar postagem
 failed-to-create-website = Falha ao criar website
+failed-to-export-website = Falha ao exportar o site
+failed-to-import-posts = Falha ao importar postagens
 failed-to-save-navigation = Falha ao salvar navegação
+failed-to-update-file = Falha ao atualizar arquivo
 failed-to-update-post = Falha ao atualizar postagem
 failed-to-update-website = Falha ao atualizar website
+file-updated-successfully = Arquivo atualizado com sucesso
 navigation-saved-successfully = Navegação salva com sucesso
 post-created-successfully = Postagem criada com sucesso
+post-import-started-successfully = A importação de postagens foi iniciada com sucesso, você receberá um e-mail quando terminar
 post-updated-successfully = Postagem atualizada com sucesso
 website-created-successfully = Website criado com sucesso
+website-export-started-successfully = A exportação do site foi iniciada com sucesso, você receberá um e-mail quando estiver pronta
 website-updated-successfully = Website atualizado com sucesso
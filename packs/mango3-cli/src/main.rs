@@ -1,13 +1,35 @@
-use clap::{value_parser, Arg, Command};
+use std::str::FromStr;
 
-use mango3_core::commands::{disable_user, get_user_by_username, insert_invitation_code, update_user_role};
-use mango3_core::config::load_config;
+use chrono::{Duration, Utc};
+use clap::{value_parser, Arg, ArgAction, Command};
+
+use mango3_core::commands::{
+    anonymize_post_views, collect_blob_garbage, disable_user, enqueue_website_export, generate_static_website,
+    get_user_by_username, get_website_by_subdomain, import_posts, insert_invitation_code, paginate_audit_logs,
+    update_user_role,
+};
+use mango3_core::config::{load_config, MISC_CONFIG};
+use mango3_core::enums::{AuditLogAction, UserRole};
+use mango3_core::models::AuditActor;
+use mango3_core::utils::CursorPageParams;
 use mango3_core::CoreContext;
 
+const ARG_ACTION: &str = "action";
+const ARG_DAYS: &str = "days";
+const ARG_DRY_RUN: &str = "dry-run";
 const ARG_EMAIL: &str = "Email";
+const ARG_FILE: &str = "file";
+const ARG_FORCE: &str = "force";
 const ARG_ROLE: &str = "role";
+const ARG_SUBDOMAIN: &str = "Subdomain";
 const ARG_USERNAME: &str = "Username";
 
+const COMMAND_ANONYMIZE_POST_VIEWS: &str = "anonymize-post-views";
+const COMMAND_COLLECT_BLOB_GARBAGE: &str = "collect-blob-garbage";
+const COMMAND_EXPORT_AUDIT_LOGS: &str = "export-audit-logs";
+const COMMAND_EXPORT_WEBSITE: &str = "export-website";
+const COMMAND_GENERATE_STATIC_WEBSITE: &str = "generate-static-website";
+const COMMAND_IMPORT_POSTS: &str = "import-posts";
 const COMMAND_LOCK_USER: &str = "lock-user";
 const COMMAND_NEW_INVITATION_CODE: &str = "new-invitation-code";
 const COMMAND_UPDATE_USER_ROLE: &str = "update-user-role";
@@ -18,11 +40,26 @@ async fn main() {
 
     let core_context = CoreContext::setup().await;
 
+    let arg_action = Arg::new(ARG_ACTION)
+        .short('a')
+        .long("action")
+        .value_parser(value_parser!(String));
+    let arg_days = Arg::new(ARG_DAYS)
+        .short('d')
+        .long("days")
+        .value_parser(value_parser!(i64));
+    let arg_dry_run = Arg::new(ARG_DRY_RUN).long("dry-run").action(ArgAction::SetTrue);
     let arg_email = Arg::new(ARG_EMAIL)
         .short('e')
         .long("email")
         .value_parser(value_parser!(String));
+    let arg_file = Arg::new(ARG_FILE).value_parser(value_parser!(String));
+    let arg_force = Arg::new(ARG_FORCE).long("force").action(ArgAction::SetTrue);
     let arg_role = Arg::new(ARG_ROLE).value_parser(value_parser!(String));
+    let arg_subdomain = Arg::new(ARG_SUBDOMAIN)
+        .short('s')
+        .long("subdomain")
+        .value_parser(value_parser!(String));
     let arg_username = Arg::new(ARG_USERNAME)
         .short('u')
         .long("username")
@@ -30,6 +67,37 @@ async fn main() {
     let version = env!("CARGO_PKG_VERSION");
     let command_matches = Command::new("Mango³ CLI")
         .version(version)
+        .subcommand(Command::new(COMMAND_ANONYMIZE_POST_VIEWS).version(version))
+        .subcommand(
+            Command::new(COMMAND_COLLECT_BLOB_GARBAGE)
+                .version(version)
+                .arg(arg_dry_run),
+        )
+        .subcommand(
+            Command::new(COMMAND_EXPORT_AUDIT_LOGS)
+                .version(version)
+                .arg(arg_action)
+                .arg(arg_username.clone()),
+        )
+        .subcommand(
+            Command::new(COMMAND_EXPORT_WEBSITE)
+                .version(version)
+                .arg(arg_username.clone())
+                .arg(arg_subdomain.clone()),
+        )
+        .subcommand(
+            Command::new(COMMAND_GENERATE_STATIC_WEBSITE)
+                .version(version)
+                .arg(arg_subdomain.clone())
+                .arg(arg_force),
+        )
+        .subcommand(
+            Command::new(COMMAND_IMPORT_POSTS)
+                .version(version)
+                .arg(arg_username.clone())
+                .arg(arg_subdomain)
+                .arg(arg_file),
+        )
         .subcommand(
             Command::new(COMMAND_LOCK_USER)
                 .version(version)
@@ -38,7 +106,9 @@ async fn main() {
         .subcommand(
             Command::new(COMMAND_NEW_INVITATION_CODE)
                 .version(version)
-                .arg(arg_email.clone()),
+                .arg(arg_email.clone())
+                .arg(arg_days)
+                .arg(arg_role.clone()),
         )
         .subcommand(
             Command::new(COMMAND_UPDATE_USER_ROLE)
@@ -49,6 +119,136 @@ async fn main() {
         .get_matches();
 
     match command_matches.subcommand() {
+        Some((COMMAND_ANONYMIZE_POST_VIEWS, _)) => {
+            let result = anonymize_post_views().await;
+
+            match result {
+                Ok(success) => {
+                    println!("Post views anonymized: {}", success.data)
+                }
+                _ => println!("Failed to anonymize post views."),
+            }
+        }
+        Some((COMMAND_COLLECT_BLOB_GARBAGE, matches)) => {
+            let dry_run = matches.get_flag(ARG_DRY_RUN);
+            let summary = collect_blob_garbage(MISC_CONFIG.blob_gc_grace_period(), dry_run).await;
+
+            println!("Blob garbage collected: {summary}")
+        }
+        Some((COMMAND_EXPORT_AUDIT_LOGS, matches)) => {
+            let action = matches
+                .get_one::<String>(ARG_ACTION)
+                .map(|action| AuditLogAction::from_str(action).expect("invalid action"));
+            let actor_user_id = if let Some(username) = matches.get_one::<String>(ARG_USERNAME) {
+                let user = get_user_by_username(&core_context, username)
+                    .await
+                    .expect("could not get user");
+
+                Some(user.id)
+            } else {
+                None
+            };
+            let mut page_params = CursorPageParams {
+                after: None,
+                first: 100,
+            };
+
+            loop {
+                let page = paginate_audit_logs(&core_context, &page_params, action, actor_user_id, None).await;
+
+                for audit_log in &page.nodes {
+                    println!(
+                        "{}",
+                        serde_json::to_string(audit_log).expect("could not serialize audit log")
+                    );
+                }
+
+                if !page.has_next_page {
+                    break;
+                }
+
+                page_params.after = page.end_cursor;
+            }
+        }
+        Some((COMMAND_EXPORT_WEBSITE, matches)) => {
+            let username = matches
+                .get_one::<String>(ARG_USERNAME)
+                .expect("argument username is missing");
+            let subdomain = matches
+                .get_one::<String>(ARG_SUBDOMAIN)
+                .expect("argument subdomain is missing");
+            let user = get_user_by_username(&core_context, username)
+                .await
+                .expect("could not get user");
+            let website = get_website_by_subdomain(&core_context, subdomain)
+                .await
+                .expect("could not get website");
+
+            if website.user_id != user.id {
+                println!("Website does not belong to user.");
+                return;
+            }
+
+            let result = enqueue_website_export(&core_context, &website, &user).await;
+
+            match result {
+                Ok(_) => {
+                    println!("Website export started, the user will receive an email when it is ready.")
+                }
+                _ => println!("Failed to export website."),
+            }
+        }
+        Some((COMMAND_GENERATE_STATIC_WEBSITE, matches)) => {
+            let subdomain = matches
+                .get_one::<String>(ARG_SUBDOMAIN)
+                .expect("argument subdomain is missing");
+            let force = matches.get_flag(ARG_FORCE);
+            let website = get_website_by_subdomain(&core_context, subdomain)
+                .await
+                .expect("could not get website");
+            let result = generate_static_website(&core_context, &website, force).await;
+
+            match result {
+                Ok(success) => {
+                    println!(
+                        "Static website generated in {}: {}",
+                        website.static_path().display(),
+                        success.data
+                    )
+                }
+                _ => println!("Failed to generate static website."),
+            }
+        }
+        Some((COMMAND_IMPORT_POSTS, matches)) => {
+            let username = matches
+                .get_one::<String>(ARG_USERNAME)
+                .expect("argument username is missing");
+            let subdomain = matches
+                .get_one::<String>(ARG_SUBDOMAIN)
+                .expect("argument subdomain is missing");
+            let file = matches.get_one::<String>(ARG_FILE).expect("argument file is missing");
+            let user = get_user_by_username(&core_context, username)
+                .await
+                .expect("could not get user");
+            let website = get_website_by_subdomain(&core_context, subdomain)
+                .await
+                .expect("could not get website");
+
+            if website.user_id != user.id {
+                println!("Website does not belong to user.");
+                return;
+            }
+
+            let data = std::fs::read(file).expect("could not read file");
+            let result = import_posts(&core_context, &website, &user, &data).await;
+
+            match result {
+                Ok(success) => {
+                    println!("{}", success.data)
+                }
+                _ => println!("Failed to import posts."),
+            }
+        }
         Some((COMMAND_LOCK_USER, matches)) => {
             let username = matches
                 .get_one::<String>(ARG_USERNAME)
@@ -56,7 +256,7 @@ async fn main() {
             let user = get_user_by_username(&core_context, username)
                 .await
                 .expect("could not get user");
-            let result = disable_user(&core_context, &user).await;
+            let result = disable_user(&core_context, &user, &AuditActor::default()).await;
 
             match result {
                 Ok(_) => {
@@ -67,7 +267,14 @@ async fn main() {
         }
         Some((COMMAND_NEW_INVITATION_CODE, matches)) => {
             let email = matches.get_one::<String>(ARG_EMAIL).expect("argument email is missing");
-            let result = insert_invitation_code(&core_context, email).await;
+            let role = matches
+                .get_one::<String>(ARG_ROLE)
+                .map(|role| role.into())
+                .unwrap_or(UserRole::User);
+            let expires_at = matches
+                .get_one::<i64>(ARG_DAYS)
+                .map(|days| Utc::now() + Duration::days(*days));
+            let result = insert_invitation_code(&core_context, email, role, expires_at, &AuditActor::default()).await;
 
             match result {
                 Ok(_) => {
@@ -84,7 +291,7 @@ async fn main() {
             let user = get_user_by_username(&core_context, username)
                 .await
                 .expect("Could not get user");
-            let result = update_user_role(&core_context, &user, role).await;
+            let result = update_user_role(&core_context, &user, role, &AuditActor::default()).await;
 
             match result {
                 Ok(_) => {
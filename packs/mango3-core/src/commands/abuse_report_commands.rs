@@ -0,0 +1,446 @@
+use uuid::Uuid;
+
+use crate::enums::{AbuseReportCategory, AbuseReportTargetType};
+use crate::models::{AbuseReport, User};
+use crate::utils::*;
+use crate::CoreContext;
+
+#[cfg(feature = "resolve-abuse-report")]
+use crate::enums::{AuditLogAction, AuditLogTargetType};
+#[cfg(feature = "resolve-abuse-report")]
+use crate::models::AuditActor;
+
+#[cfg(feature = "get-abuse-report-by-id")]
+pub async fn get_abuse_report_by_id(core_context: &CoreContext, id: Uuid) -> sqlx::Result<AbuseReport> {
+    sqlx::query_as!(
+        AbuseReport,
+        r#"SELECT
+            id,
+            user_id,
+            target_type as "target_type!: AbuseReportTargetType",
+            target_id,
+            category as "category!: AbuseReportCategory",
+            note,
+            resolved_by_user_id,
+            resolved_at,
+            created_at,
+            updated_at
+        FROM abuse_reports WHERE id = $1 LIMIT 1"#,
+        id, // $1
+    )
+    .fetch_one(&core_context.db_pool)
+    .await
+}
+
+#[cfg(feature = "insert-abuse-report")]
+pub async fn insert_abuse_report(
+    core_context: &CoreContext,
+    user: &User,
+    target_type: AbuseReportTargetType,
+    target_id: Uuid,
+    category: &str,
+    note: &str,
+) -> MutResult<AbuseReport> {
+    use std::str::FromStr;
+
+    use crate::enums::{AdminMailerJobCommand, Input, InputError};
+
+    let mut validator = crate::validator!();
+    let category = AbuseReportCategory::from_str(category).ok();
+    let note = note.trim();
+
+    validator.custom_validation(Input::Category, InputError::IsInvalid, || category.is_some());
+    validator.validate_length(Input::Note, note, None, Some(1024));
+
+    if !validator.is_valid {
+        return crate::mut_error!(validator.errors);
+    }
+
+    if !abuse_report_target_exists(core_context, target_type, target_id).await {
+        return crate::mut_error!();
+    }
+
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    // Locks the reporter, so concurrent reports are counted one after the other against the hourly limit.
+    sqlx::query!(
+        "SELECT id FROM users WHERE id = $1 FOR UPDATE",
+        user.id // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    let reports_count = sqlx::query!(
+        "SELECT COUNT(*) FROM abuse_reports
+        WHERE user_id = $1 AND created_at > current_timestamp - make_interval(hours => 1)",
+        user.id, // $1
+    )
+    .fetch_one(&mut *transaction)
+    .await?
+    .count
+    .unwrap_or_default();
+
+    if !validator.custom_validation(Input::Category, InputError::TooManyRequests, || {
+        reports_count < i64::from(crate::config::MISC_CONFIG.max_abuse_reports_per_hour)
+    }) {
+        return crate::mut_error!(validator.errors);
+    }
+
+    let abuse_report = sqlx::query_as!(
+        AbuseReport,
+        r#"INSERT INTO abuse_reports (user_id, target_type, target_id, category, note)
+        VALUES ($1, $2, $3, $4, $5) RETURNING
+            id,
+            user_id,
+            target_type as "target_type!: AbuseReportTargetType",
+            target_id,
+            category as "category!: AbuseReportCategory",
+            note,
+            resolved_by_user_id,
+            resolved_at,
+            created_at,
+            updated_at"#,
+        user.id,                                  // $1
+        target_type as AbuseReportTargetType,     // $2
+        target_id,                                // $3
+        category.unwrap() as AbuseReportCategory, // $4
+        note,                                     // $5
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    core_context
+        .jobs
+        .admin_mailer(AdminMailerJobCommand::NewAbuseReport {
+            category: abuse_report.category,
+            target_type: abuse_report.target_type,
+            target_id: abuse_report.target_id,
+            note: abuse_report.note.clone(),
+        })
+        .await;
+
+    crate::mut_success!(abuse_report)
+}
+
+#[cfg(feature = "insert-abuse-report")]
+async fn abuse_report_target_exists(
+    core_context: &CoreContext,
+    target_type: AbuseReportTargetType,
+    target_id: Uuid,
+) -> bool {
+    let db_pool = &core_context.db_pool;
+
+    match target_type {
+        AbuseReportTargetType::Post => sqlx::query!("SELECT id FROM posts WHERE id = $1 LIMIT 1", target_id)
+            .fetch_one(db_pool)
+            .await
+            .is_ok(),
+        AbuseReportTargetType::PostComment => {
+            sqlx::query!("SELECT id FROM post_comments WHERE id = $1 LIMIT 1", target_id)
+                .fetch_one(db_pool)
+                .await
+                .is_ok()
+        }
+        AbuseReportTargetType::User => sqlx::query!("SELECT id FROM users WHERE id = $1 LIMIT 1", target_id)
+            .fetch_one(db_pool)
+            .await
+            .is_ok(),
+        AbuseReportTargetType::Website => sqlx::query!("SELECT id FROM websites WHERE id = $1 LIMIT 1", target_id)
+            .fetch_one(db_pool)
+            .await
+            .is_ok(),
+    }
+}
+
+#[cfg(feature = "paginate-abuse-reports")]
+pub async fn paginate_abuse_reports(
+    core_context: &CoreContext,
+    page_params: &CursorPageParams,
+    is_resolved: bool,
+) -> CursorPage<AbuseReport> {
+    crate::cursor_page!(
+        core_context,
+        page_params,
+        |node: AbuseReport| node.id,
+        move |core_context, after| async move { get_abuse_report_by_id(core_context, after).await.ok() },
+        move |core_context, cursor_resource, limit| async move {
+            let (cursor_id, cursor_created_at) = cursor_resource
+                .map(|c| (Some(c.id), Some(c.created_at)))
+                .unwrap_or_default();
+
+            sqlx::query_as!(
+                AbuseReport,
+                r#"SELECT
+                    id,
+                    user_id,
+                    target_type as "target_type!: AbuseReportTargetType",
+                    target_id,
+                    category as "category!: AbuseReportCategory",
+                    note,
+                    resolved_by_user_id,
+                    resolved_at,
+                    created_at,
+                    updated_at
+                FROM abuse_reports
+                WHERE (resolved_at IS NOT NULL) = $1
+                    AND ($3::timestamptz IS NULL OR created_at < $3 OR (created_at = $3 AND id < $2))
+                ORDER BY created_at DESC, id DESC LIMIT $4"#,
+                is_resolved,       // $1
+                cursor_id,         // $2
+                cursor_created_at, // $3
+                limit,             // $4
+            )
+            .fetch_all(&core_context.db_pool)
+            .await
+            .unwrap_or_default()
+        },
+    )
+    .await
+}
+
+/// Resolves the abuse report along with any other open report about the same target, so staff don't have to go
+/// through duplicates one by one.
+#[cfg(feature = "resolve-abuse-report")]
+pub async fn resolve_abuse_report(
+    core_context: &CoreContext,
+    abuse_report: &AbuseReport,
+    actor: &AuditActor,
+) -> MutResult<AbuseReport> {
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    let result = sqlx::query!(
+        "UPDATE abuse_reports SET resolved_by_user_id = $3, resolved_at = current_timestamp
+        WHERE target_type = $1 AND target_id = $2 AND resolved_at IS NULL",
+        abuse_report.target_type as AbuseReportTargetType, // $1
+        abuse_report.target_id,                            // $2
+        actor.user_id,                                     // $3
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return crate::mut_error!();
+    }
+
+    super::insert_audit_log(
+        &mut *transaction,
+        actor,
+        AuditLogAction::ResolveAbuseReport,
+        AuditLogTargetType::AbuseReport,
+        abuse_report.id,
+        None,
+        Some(serde_json::json!({
+            "target_type": abuse_report.target_type.to_string(),
+            "target_id": abuse_report.target_id,
+            "resolved_reports_count": result.rows_affected(),
+        })),
+    )
+    .await?;
+
+    transaction.commit().await?;
+
+    crate::mut_result!(get_abuse_report_by_id(core_context, abuse_report.id).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::paginate_audit_logs;
+    use crate::enums::{AbuseReportTargetType, AuditLogAction, Input, InputError};
+    use crate::models::AuditActor;
+    use crate::test_utils::{fake_sentence, fake_uuid, insert_test_post, insert_test_user, setup_core_context};
+    use crate::utils::CursorPageParams;
+
+    use super::{insert_abuse_report, paginate_abuse_reports, resolve_abuse_report};
+
+    #[tokio::test]
+    async fn should_insert_abuse_report() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let user = insert_test_user(&core_context).await;
+
+        let result = insert_abuse_report(
+            &core_context,
+            &user,
+            AbuseReportTargetType::Post,
+            post.id,
+            "spam",
+            &fake_sentence(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+
+        let abuse_report = result.unwrap().data;
+
+        assert_eq!(abuse_report.target_id, post.id);
+        assert!(!abuse_report.is_resolved());
+    }
+
+    #[tokio::test]
+    async fn should_not_insert_abuse_report_with_invalid_category() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let user = insert_test_user(&core_context).await;
+
+        let result = insert_abuse_report(
+            &core_context,
+            &user,
+            AbuseReportTargetType::Post,
+            post.id,
+            "unknown",
+            "",
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert!(matches!(
+            result.err().unwrap().errors.get(&Input::Category),
+            Some(InputError::IsInvalid)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_not_insert_abuse_report_when_target_does_not_exist() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+
+        let result = insert_abuse_report(
+            &core_context,
+            &user,
+            AbuseReportTargetType::Website,
+            fake_uuid(),
+            "spam",
+            "",
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_not_insert_abuse_report_when_rate_limited() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let user = insert_test_user(&core_context).await;
+
+        for _ in 0..crate::config::MISC_CONFIG.max_abuse_reports_per_hour {
+            let result =
+                insert_abuse_report(&core_context, &user, AbuseReportTargetType::Post, post.id, "spam", "").await;
+
+            assert!(result.is_ok());
+        }
+
+        let result = insert_abuse_report(&core_context, &user, AbuseReportTargetType::Post, post.id, "spam", "").await;
+
+        assert!(result.is_err());
+        assert!(matches!(
+            result.err().unwrap().errors.get(&Input::Category),
+            Some(InputError::TooManyRequests)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_not_go_over_abuse_reports_rate_limit_concurrently() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let user = insert_test_user(&core_context).await;
+
+        for _ in 1..crate::config::MISC_CONFIG.max_abuse_reports_per_hour {
+            let _ = insert_abuse_report(&core_context, &user, AbuseReportTargetType::Post, post.id, "spam", "").await;
+        }
+
+        let (result1, result2) = futures::future::join(
+            insert_abuse_report(&core_context, &user, AbuseReportTargetType::Post, post.id, "spam", ""),
+            insert_abuse_report(&core_context, &user, AbuseReportTargetType::Post, post.id, "spam", ""),
+        )
+        .await;
+
+        assert!(result1.is_ok() != result2.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_resolve_all_open_abuse_reports_for_the_same_target() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let user = insert_test_user(&core_context).await;
+        let other_user = insert_test_user(&core_context).await;
+        let admin_user = insert_test_user(&core_context).await;
+
+        let abuse_report = insert_abuse_report(&core_context, &user, AbuseReportTargetType::Post, post.id, "spam", "")
+            .await
+            .unwrap()
+            .data;
+        insert_abuse_report(
+            &core_context,
+            &other_user,
+            AbuseReportTargetType::Post,
+            post.id,
+            "other",
+            "",
+        )
+        .await
+        .unwrap();
+
+        let result =
+            resolve_abuse_report(&core_context, &abuse_report, &AuditActor::new(Some(&admin_user), None)).await;
+
+        assert!(result.is_ok());
+
+        let abuse_report = result.unwrap().data;
+
+        assert!(abuse_report.is_resolved());
+        assert_eq!(abuse_report.resolved_by_user_id, Some(admin_user.id));
+
+        let cursor_page = paginate_abuse_reports(
+            &core_context,
+            &CursorPageParams {
+                after: None,
+                first: 100,
+            },
+            false,
+        )
+        .await;
+
+        assert!(!cursor_page.nodes.iter().any(|node| node.target_id == post.id));
+
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::ResolveAbuseReport),
+            Some(admin_user.id),
+            Some(abuse_report.id),
+        )
+        .await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+        assert_eq!(
+            cursor_page.nodes[0].after_value,
+            Some(serde_json::json!({
+                "target_type": "post",
+                "target_id": post.id,
+                "resolved_reports_count": 2,
+            }))
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_resolve_abuse_report_when_is_resolved() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let user = insert_test_user(&core_context).await;
+        let actor = AuditActor::new(Some(&user), None);
+
+        let abuse_report = insert_abuse_report(&core_context, &user, AbuseReportTargetType::Post, post.id, "spam", "")
+            .await
+            .unwrap()
+            .data;
+
+        assert!(resolve_abuse_report(&core_context, &abuse_report, &actor).await.is_ok());
+
+        let result = resolve_abuse_report(&core_context, &abuse_report, &actor).await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use uuid::Uuid;
+
+use crate::enums::{AuditLogAction, AuditLogTargetType};
+use crate::models::AuditLog;
+
+#[cfg(feature = "get-audit-log-by-id")]
+use crate::CoreContext;
+
+#[cfg(feature = "paginate-audit-logs")]
+use crate::utils::*;
+
+#[cfg(feature = "get-audit-log-by-id")]
+pub async fn get_audit_log_by_id(core_context: &CoreContext, id: Uuid) -> sqlx::Result<AuditLog> {
+    sqlx::query_as!(
+        AuditLog,
+        r#"SELECT
+            id,
+            actor_user_id,
+            host(ip_address) as ip_address,
+            action as "action!: AuditLogAction",
+            target_type as "target_type!: AuditLogTargetType",
+            target_id,
+            before_value,
+            after_value,
+            created_at
+        FROM audit_logs WHERE id = $1 LIMIT 1"#,
+        id, // $1
+    )
+    .fetch_one(&core_context.db_pool)
+    .await
+}
+
+/// Takes the transaction of the audited action, so the action is rolled back if its audit log can't be written.
+#[cfg(feature = "insert-audit-log")]
+pub(crate) async fn insert_audit_log<'c>(
+    executor: impl sqlx::PgExecutor<'c>,
+    actor: &crate::models::AuditActor,
+    action: AuditLogAction,
+    target_type: AuditLogTargetType,
+    target_id: Uuid,
+    before_value: Option<serde_json::Value>,
+    after_value: Option<serde_json::Value>,
+) -> sqlx::Result<AuditLog> {
+    sqlx::query_as!(
+        AuditLog,
+        r#"INSERT INTO audit_logs
+            (actor_user_id, ip_address, action, target_type, target_id, before_value, after_value)
+        VALUES ($1, $2::text::inet, $3, $4, $5, $6, $7) RETURNING
+            id,
+            actor_user_id,
+            host(ip_address) as ip_address,
+            action as "action!: AuditLogAction",
+            target_type as "target_type!: AuditLogTargetType",
+            target_id,
+            before_value,
+            after_value,
+            created_at"#,
+        actor.user_id,                     // $1
+        actor.ip_address,                  // $2
+        action as AuditLogAction,          // $3
+        target_type as AuditLogTargetType, // $4
+        target_id,                         // $5
+        before_value,                      // $6
+        after_value,                       // $7
+    )
+    .fetch_one(executor)
+    .await
+}
+
+#[cfg(feature = "paginate-audit-logs")]
+pub async fn paginate_audit_logs(
+    core_context: &CoreContext,
+    page_params: &CursorPageParams,
+    action: Option<AuditLogAction>,
+    actor_user_id: Option<Uuid>,
+    target_id: Option<Uuid>,
+) -> CursorPage<AuditLog> {
+    crate::cursor_page!(
+        core_context,
+        page_params,
+        |node: AuditLog| node.id,
+        move |core_context, after| async move { get_audit_log_by_id(core_context, after).await.ok() },
+        move |core_context, cursor_resource, limit| async move {
+            let (cursor_id, cursor_created_at) = cursor_resource
+                .map(|c| (Some(c.id), Some(c.created_at)))
+                .unwrap_or_default();
+
+            sqlx::query_as!(
+                AuditLog,
+                r#"SELECT
+                    id,
+                    actor_user_id,
+                    host(ip_address) as ip_address,
+                    action as "action!: AuditLogAction",
+                    target_type as "target_type!: AuditLogTargetType",
+                    target_id,
+                    before_value,
+                    after_value,
+                    created_at
+                FROM audit_logs
+                WHERE ($1::audit_log_action IS NULL OR action = $1)
+                    AND ($2::uuid IS NULL OR actor_user_id = $2)
+                    AND ($3::uuid IS NULL OR target_id = $3)
+                    AND ($5::timestamptz IS NULL OR created_at < $5 OR (created_at = $5 AND id < $4))
+                ORDER BY created_at DESC, id DESC LIMIT $6"#,
+                action as Option<AuditLogAction>, // $1
+                actor_user_id,                    // $2
+                target_id,                        // $3
+                cursor_id,                        // $4
+                cursor_created_at,                // $5
+                limit,                            // $6
+            )
+            .fetch_all(&core_context.db_pool)
+            .await
+            .unwrap_or_default()
+        },
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::enums::{AuditLogAction, AuditLogTargetType};
+    use crate::models::AuditActor;
+    use crate::test_utils::{insert_test_user, setup_core_context};
+    use crate::utils::CursorPageParams;
+
+    use super::{insert_audit_log, paginate_audit_logs};
+
+    #[tokio::test]
+    async fn should_insert_audit_log() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let actor = AuditActor::new(Some(&user), Some("127.0.0.1"));
+
+        let result = insert_audit_log(
+            &core_context.db_pool,
+            &actor,
+            AuditLogAction::UpdateUserRole,
+            AuditLogTargetType::User,
+            user.id,
+            Some(serde_json::json!({ "role": "user" })),
+            Some(serde_json::json!({ "role": "admin" })),
+        )
+        .await;
+
+        assert!(result.is_ok());
+
+        let audit_log = result.unwrap();
+
+        assert_eq!(audit_log.actor_user_id, Some(user.id));
+        assert_eq!(audit_log.ip_address.as_deref(), Some("127.0.0.1"));
+    }
+
+    #[tokio::test]
+    async fn should_filter_audit_logs() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+
+        insert_audit_log(
+            &core_context.db_pool,
+            &AuditActor::default(),
+            AuditLogAction::DisableUser,
+            AuditLogTargetType::User,
+            user.id,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::DisableUser),
+            None,
+            Some(user.id),
+        )
+        .await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::EnableUser),
+            None,
+            Some(user.id),
+        )
+        .await;
+
+        assert!(cursor_page.nodes.is_empty());
+    }
+}
@@ -5,6 +5,18 @@ use sqlx::types::Uuid;
 use crate::constants::*;
 use crate::models::*;
 
+#[cfg(feature = "update-blob")]
+use crate::enums::{Input, InputError};
+#[cfg(feature = "update-blob")]
+use crate::utils::{Validator, ValidatorTrait};
+
+#[cfg(feature = "update-blob")]
+impl Validator {
+    fn validate_blob_focal_point(&mut self, input: Input, value: f32) -> bool {
+        self.custom_validation(input, InputError::IsInvalid, &|| (0.0..=1.0).contains(&value))
+    }
+}
+
 #[cfg(feature = "all-blobs-by-ids")]
 pub async fn all_blobs_by_ids<'a>(ids: Vec<Uuid>, website: Option<&Website>, user: Option<&User>) -> Vec<Blob<'a>> {
     if ids.is_empty() {
@@ -19,6 +31,257 @@ pub async fn all_blobs_by_ids<'a>(ids: Vec<Uuid>, website: Option<&Website>, use
         .collect()
 }
 
+#[cfg(feature = "paginate-storage-usages")]
+pub async fn paginate_storage_usages(
+    core_context: &crate::CoreContext,
+    page_params: &crate::utils::CursorPageParams,
+) -> crate::utils::CursorPage<StorageUsage> {
+    crate::cursor_page!(
+        core_context,
+        page_params,
+        |node: StorageUsage| node.id,
+        move |core_context, after| async move {
+            sqlx::query_as!(
+                StorageUsage,
+                r#"SELECT id AS "id!", user_id AS "user_id!", website_id, used_bytes AS "used_bytes!"
+                FROM (
+                    SELECT user_id AS id, user_id, NULL::uuid AS website_id, SUM(byte_size)::bigint AS used_bytes
+                    FROM blobs WHERE website_id IS NULL GROUP BY user_id
+                    UNION ALL
+                    SELECT w.id, w.user_id, w.id, SUM(b.byte_size)::bigint
+                    FROM blobs AS b INNER JOIN websites AS w ON w.id = b.website_id GROUP BY w.id
+                ) AS storage_usages
+                WHERE id = $1 LIMIT 1"#,
+                after, // $1
+            )
+            .fetch_one(&core_context.db_pool)
+            .await
+            .ok()
+        },
+        move |core_context, cursor_resource, limit| async move {
+            let (cursor_id, cursor_used_bytes) = cursor_resource
+                .map(|c| (Some(c.id), Some(c.used_bytes)))
+                .unwrap_or_default();
+
+            sqlx::query_as!(
+                StorageUsage,
+                r#"SELECT id AS "id!", user_id AS "user_id!", website_id, used_bytes AS "used_bytes!"
+                FROM (
+                    SELECT user_id AS id, user_id, NULL::uuid AS website_id, SUM(byte_size)::bigint AS used_bytes
+                    FROM blobs WHERE website_id IS NULL GROUP BY user_id
+                    UNION ALL
+                    SELECT w.id, w.user_id, w.id, SUM(b.byte_size)::bigint
+                    FROM blobs AS b INNER JOIN websites AS w ON w.id = b.website_id GROUP BY w.id
+                ) AS storage_usages
+                WHERE $2::bigint IS NULL OR used_bytes < $2 OR (used_bytes = $2 AND id < $1)
+                ORDER BY used_bytes DESC, id DESC LIMIT $3"#,
+                cursor_id,         // $1
+                cursor_used_bytes, // $2
+                limit,             // $3
+            )
+            .fetch_all(&core_context.db_pool)
+            .await
+            .unwrap_or_default()
+        },
+    )
+    .await
+}
+
+#[cfg(feature = "collect-blob-garbage")]
+const BLOB_GARBAGE_BATCH_SIZE: i64 = 100;
+
+#[cfg(feature = "collect-blob-garbage")]
+#[derive(Clone, Debug, Default)]
+pub struct BlobGarbageSummary {
+    pub dry_run: bool,
+    pub orphaned_blobs: u64,
+    pub orphaned_blobs_byte_size: i64,
+    pub orphaned_directories: u64,
+    pub stale_tmp_files: u64,
+}
+
+#[cfg(feature = "collect-blob-garbage")]
+impl std::fmt::Display for BlobGarbageSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{} orphaned blobs ({}), {} orphaned directories, {} stale temporary files",
+            if self.dry_run { "[DRY RUN] " } else { "" },
+            self.orphaned_blobs,
+            size::Size::from_bytes(self.orphaned_blobs_byte_size),
+            self.orphaned_directories,
+            self.stale_tmp_files,
+        )
+    }
+}
+
+#[cfg(feature = "collect-blob-garbage")]
+pub async fn collect_blob_garbage(grace_period: std::time::Duration, dry_run: bool) -> BlobGarbageSummary {
+    let mut summary = BlobGarbageSummary {
+        dry_run,
+        ..Default::default()
+    };
+
+    collect_orphaned_blobs(&mut summary, grace_period).await;
+    collect_orphaned_blob_directories(&mut summary, grace_period).await;
+    collect_stale_tmp_files(&mut summary, grace_period);
+
+    summary
+}
+
+/// Deletes the blobs, personal or of a website, that nothing references anymore once they are older than the grace
+/// period.
+#[cfg(feature = "collect-blob-garbage")]
+async fn collect_orphaned_blobs(summary: &mut BlobGarbageSummary, grace_period: std::time::Duration) {
+    let db_pool = crate::db_pool().await;
+    let created_before = chrono::Utc::now() - chrono::Duration::from_std(grace_period).unwrap_or_default();
+    let mut after_id = None;
+
+    loop {
+        let blobs = sqlx::query_as!(
+            Blob,
+            "SELECT
+                id,
+                website_id,
+                user_id,
+                file_name,
+                content_type,
+                byte_size,
+                md5_checksum,
+                alt_text,
+                caption,
+                focal_point_x,
+                focal_point_y,
+                created_at,
+                updated_at
+            FROM blobs AS b
+            WHERE
+                created_at < $1 AND ($2::uuid IS NULL OR id > $2)
+                AND NOT EXISTS (SELECT 1 FROM users AS u WHERE u.avatar_image_blob_id = b.id)
+                AND NOT EXISTS (
+                    SELECT 1 FROM websites AS w WHERE w.cover_image_blob_id = b.id OR w.icon_image_blob_id = b.id
+                )
+                AND NOT EXISTS (
+                    SELECT 1 FROM posts AS p WHERE p.cover_image_blob_id = b.id OR b.id = ANY(p.blob_ids)
+                )
+                AND NOT EXISTS (
+                    SELECT 1 FROM posts AS p
+                    WHERE (p.user_id = b.user_id OR p.website_id = b.website_id)
+                        AND (strpos(p.content, b.id::text) > 0 OR strpos(p.variables::text, b.id::text) > 0)
+                )
+                AND NOT EXISTS (
+                    SELECT 1 FROM websites AS w
+                    WHERE (w.user_id = b.user_id OR w.id = b.website_id) AND strpos(w.description, b.id::text) > 0
+                )
+                AND NOT EXISTS (
+                    SELECT 1 FROM navigation_items AS ni INNER JOIN websites AS w ON w.id = ni.website_id
+                    WHERE (w.user_id = b.user_id OR w.id = b.website_id) AND strpos(ni.url, b.id::text) > 0
+                )
+                AND NOT EXISTS (SELECT 1 FROM users AS u WHERE u.id = b.user_id AND strpos(u.bio, b.id::text) > 0)
+            ORDER BY id LIMIT $3",
+            created_before,          // $1
+            after_id,                // $2
+            BLOB_GARBAGE_BATCH_SIZE, // $3
+        )
+        .fetch_all(db_pool)
+        .await
+        .unwrap_or_default();
+
+        let Some(last_blob) = blobs.last() else {
+            break;
+        };
+
+        after_id = Some(last_blob.id);
+
+        for blob in &blobs {
+            if !summary.dry_run && delete_blob(blob).await.is_err() {
+                continue;
+            }
+
+            summary.orphaned_blobs += 1;
+            summary.orphaned_blobs_byte_size += blob.byte_size;
+        }
+
+        if (blobs.len() as i64) < BLOB_GARBAGE_BATCH_SIZE {
+            break;
+        }
+    }
+}
+
+#[cfg(feature = "collect-blob-garbage")]
+async fn collect_orphaned_blob_directories(summary: &mut BlobGarbageSummary, grace_period: std::time::Duration) {
+    let db_pool = crate::db_pool().await;
+    let blobs_path = std::path::Path::new(&crate::config::MISC_CONFIG.storage_path).join("blobs");
+
+    let Ok(entries) = std::fs::read_dir(blobs_path) else {
+        return;
+    };
+
+    let directories = entries
+        .flatten()
+        .filter(|entry| is_older_than(entry, grace_period))
+        .filter_map(|entry| {
+            let id = Uuid::try_parse(&entry.file_name().to_string_lossy()).ok()?;
+
+            Some((id, entry.path()))
+        })
+        .collect::<Vec<_>>();
+
+    for batch in directories.chunks(BLOB_GARBAGE_BATCH_SIZE as usize) {
+        let ids = batch.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+
+        let Ok(existing_ids) = sqlx::query_scalar!("SELECT id FROM blobs WHERE id = ANY($1)", &ids)
+            .fetch_all(db_pool)
+            .await
+        else {
+            continue;
+        };
+
+        for (id, path) in batch {
+            if existing_ids.contains(id) {
+                continue;
+            }
+
+            if !summary.dry_run && std::fs::remove_dir_all(path).is_err() {
+                continue;
+            }
+
+            summary.orphaned_directories += 1;
+        }
+    }
+}
+
+#[cfg(feature = "collect-blob-garbage")]
+fn collect_stale_tmp_files(summary: &mut BlobGarbageSummary, grace_period: std::time::Duration) {
+    let Ok(entries) = std::fs::read_dir(crate::config::MISC_CONFIG.storage_tmp_path()) else {
+        return;
+    };
+
+    let max_age = grace_period.max(std::time::Duration::from_secs(24 * 60 * 60));
+
+    for entry in entries.flatten() {
+        if !is_older_than(&entry, max_age) {
+            continue;
+        }
+
+        if !summary.dry_run && std::fs::remove_file(entry.path()).is_err() {
+            continue;
+        }
+
+        summary.stale_tmp_files += 1;
+    }
+}
+
+#[cfg(feature = "collect-blob-garbage")]
+fn is_older_than(entry: &std::fs::DirEntry, duration: std::time::Duration) -> bool {
+    entry
+        .metadata()
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|elapsed| elapsed >= duration)
+}
+
 #[cfg(feature = "delete-blob")]
 pub async fn delete_blob(blob: &Blob<'_>) -> crate::utils::MutResult {
     use cached::IOCachedAsync;
@@ -38,37 +301,6 @@ pub async fn delete_blob(blob: &Blob<'_>) -> crate::utils::MutResult {
     crate::mut_success!()
 }
 
-#[cfg(feature = "delete-orphaned-blobs")]
-pub async fn delete_orphaned_blobs() -> crate::utils::MutResult {
-    let db_pool = crate::db_pool().await;
-
-    let result = sqlx::query_as!(
-        Blob,
-        "SELECT *
-        FROM blobs AS b
-        WHERE
-            website_id IS NULL AND (
-                SELECT id FROM users AS u WHERE u.avatar_image_blob_id = b.id LIMIT 1
-            ) IS NULL AND (
-                SELECT id FROM websites AS w WHERE w.cover_image_blob_id = b.id OR w.icon_image_blob_id = b.id
-                LIMIT 1
-            ) IS NULL AND (
-                SELECT id FROM posts AS p WHERE p.cover_image_blob_id = b.id OR b.id = ANY(p.blob_ids) LIMIT 1
-            ) IS NULL
-        LIMIT 1",
-    )
-    .fetch_all(db_pool)
-    .await;
-
-    if let Ok(blobs) = result {
-        for blob in blobs {
-            let _ = delete_blob(&blob).await;
-        }
-    }
-
-    crate::mut_success!()
-}
-
 #[cfg(feature = "get-blob-by-id")]
 #[io_cached(
     map_error = r##"|_| sqlx::Error::RowNotFound"##,
@@ -81,7 +313,20 @@ async fn get_cached_blob_by_id(id: Uuid) -> sqlx::Result<Blob<'static>> {
 
     sqlx::query_as!(
         Blob,
-        "SELECT id, website_id, user_id, file_name, content_type, byte_size, md5_checksum, created_at, updated_at
+        "SELECT
+            id,
+            website_id,
+            user_id,
+            file_name,
+            content_type,
+            byte_size,
+            md5_checksum,
+            alt_text,
+            caption,
+            focal_point_x,
+            focal_point_y,
+            created_at,
+            updated_at
         FROM blobs WHERE id = $1 LIMIT 1",
         id, // $1
     )
@@ -115,43 +360,60 @@ pub async fn insert_blob<'a>(
 ) -> crate::utils::MutResult<Blob<'a>> {
     use std::io::Write;
 
-    use md5::Digest;
-
-    let db_pool = crate::db_pool().await;
-    let website_id = website.map(|w| w.id);
     let tmp_file_path = crate::config::MISC_CONFIG
         .storage_tmp_path()
         .join(Uuid::new_v4().to_string());
     let mut tmp_file = std::fs::File::create(&tmp_file_path)?;
-    let mut byte_size = 0i64;
-    let mut md5_hasher = md5::Md5::new();
 
     while let Some(chunk) = field.chunk().await.map_err(|_| crate::utils::MutError::default())? {
-        tmp_file.write(&chunk)?;
-        byte_size += chunk.len() as i64;
-        md5_hasher.update(chunk);
+        tmp_file.write_all(&chunk)?;
     }
 
-    #[cfg(feature = "website-storage")]
-    {
-        if let Some(website) = website {
-            if website.available_storage().await.bytes() < byte_size {
-                return crate::mut_error!();
-            }
-        }
-    }
-
-    let md5_checksum = format!("{:x}", md5_hasher.finalize());
+    let file_name = field.file_name().unwrap_or_default().to_owned();
     let content_type = field
         .content_type()
         .unwrap_or(&mime::APPLICATION_OCTET_STREAM)
         .to_string();
 
+    insert_blob_from_tmp_file(user, website, &tmp_file_path, &file_name, &content_type).await
+}
+
+#[cfg(feature = "insert-blob-from-tmp-file")]
+pub(crate) async fn insert_blob_from_tmp_file<'a>(
+    user: &User,
+    website: Option<&Website>,
+    tmp_file_path: &std::path::Path,
+    file_name: &str,
+    content_type: &str,
+) -> crate::utils::MutResult<Blob<'a>> {
+    let db_pool = crate::db_pool().await;
+    let website_id = website.map(|w| w.id);
+
+    let Ok((byte_size, md5_checksum)) = read_byte_size_and_md5_checksum(tmp_file_path) else {
+        let _ = std::fs::remove_file(tmp_file_path);
+
+        return crate::mut_error!();
+    };
+
     let result = sqlx::query_as!(
         Blob,
-        "SELECT id, website_id, user_id, file_name, content_type, byte_size, md5_checksum, created_at, updated_at
+        "SELECT
+            id,
+            website_id,
+            user_id,
+            file_name,
+            content_type,
+            byte_size,
+            md5_checksum,
+            alt_text,
+            caption,
+            focal_point_x,
+            focal_point_y,
+            created_at,
+            updated_at
         FROM blobs
-        WHERE user_id = $1 AND website_id = $2 AND content_type = $3 AND byte_size = $4 AND md5_checksum = $5",
+        WHERE user_id = $1 AND website_id IS NOT DISTINCT FROM $2 AND content_type = $3 AND byte_size = $4
+            AND md5_checksum = $5",
         user.id,      // $1
         website_id,   // $2
         content_type, // $3
@@ -167,19 +429,42 @@ pub async fn insert_blob<'a>(
         return crate::mut_success!(blob.clone());
     }
 
-    if !ALLOWED_FILE_TYPES.contains(&content_type.as_str()) {
+    if !ALLOWED_FILE_TYPES.contains(&content_type) {
         let _ = std::fs::remove_file(tmp_file_path);
 
         return crate::mut_error!();
     }
 
-    let file_name = field.file_name().unwrap_or_default();
+    let (used_storage, max_storage) = if let Some(website) = website {
+        (website.used_storage().await, website.max_storage().await)
+    } else {
+        (user.used_storage().await, user.max_storage())
+    };
+
+    if (max_storage - used_storage).bytes() < byte_size {
+        let _ = std::fs::remove_file(tmp_file_path);
+
+        return crate::mut_error!();
+    }
 
     let result = sqlx::query_as!(
         Blob,
         "INSERT INTO blobs (user_id, website_id, file_name, content_type, byte_size, md5_checksum)
         VALUES ($1, $2, $3, $4, $5, $6)
-        RETURNING id, website_id, user_id, file_name, content_type, byte_size, md5_checksum, created_at, updated_at",
+        RETURNING
+            id,
+            website_id,
+            user_id,
+            file_name,
+            content_type,
+            byte_size,
+            md5_checksum,
+            alt_text,
+            caption,
+            focal_point_x,
+            focal_point_y,
+            created_at,
+            updated_at",
         user.id,      // $1
         website_id,   // $2
         file_name,    // $3
@@ -192,13 +477,67 @@ pub async fn insert_blob<'a>(
 
     if let Ok(ref blob) = result {
         let _ = std::fs::create_dir_all(blob.directory().to_string());
-        let _ = std::fs::rename(&tmp_file_path, blob.default_path().to_string());
+        let _ = std::fs::rename(tmp_file_path, blob.default_path().to_string());
         let _ = std::fs::remove_file(tmp_file_path);
+
+        send_storage_warning(user, website, used_storage.bytes(), byte_size, max_storage).await;
     }
 
     crate::mut_result!(result)
 }
 
+#[cfg(feature = "insert-blob-from-tmp-file")]
+fn read_byte_size_and_md5_checksum(path: &std::path::Path) -> std::io::Result<(i64, String)> {
+    use std::io::Read;
+
+    use md5::Digest;
+
+    let mut file = std::fs::File::open(path)?;
+    let mut buffer = vec![0; 64 * 1024];
+    let mut byte_size = 0i64;
+    let mut md5_hasher = md5::Md5::new();
+
+    loop {
+        let len = file.read(&mut buffer)?;
+
+        if len == 0 {
+            break;
+        }
+
+        byte_size += len as i64;
+        md5_hasher.update(&buffer[..len]);
+    }
+
+    Ok((byte_size, format!("{:x}", md5_hasher.finalize())))
+}
+
+#[cfg(feature = "insert-blob-from-tmp-file")]
+async fn send_storage_warning(
+    user: &User,
+    website: Option<&Website>,
+    used_storage: i64,
+    byte_size: i64,
+    max_storage: size::Size,
+) {
+    let threshold = max_storage.bytes() * crate::config::MISC_CONFIG.storage_warning_threshold as i64 / 100;
+
+    if used_storage >= threshold || used_storage + byte_size < threshold {
+        return;
+    }
+
+    crate::jobs()
+        .await
+        .mailer(
+            user,
+            crate::enums::MailerJobCommand::StorageWarning {
+                website_name: website.map(|w| w.name.clone()),
+                used_storage: size::Size::from_bytes(used_storage + byte_size).to_string(),
+                max_storage: max_storage.to_string(),
+            },
+        )
+        .await;
+}
+
 #[cfg(feature = "paginate-blobs")]
 pub async fn paginate_blobs<'a>(
     core_context: &'a crate::CoreContext,
@@ -221,7 +560,19 @@ pub async fn paginate_blobs<'a>(
             sqlx::query_as!(
                 Blob,
                 r#"SELECT
-                    id, website_id, user_id, file_name, content_type, byte_size, md5_checksum, created_at, updated_at
+                    id,
+                    website_id,
+                    user_id,
+                    file_name,
+                    content_type,
+                    byte_size,
+                    md5_checksum,
+                    alt_text,
+                    caption,
+                    focal_point_x,
+                    focal_point_y,
+                    created_at,
+                    updated_at
                 FROM blobs
                 WHERE ($1::uuid IS NULL OR website_id = $1) AND ($2::uuid IS NULL OR user_id = $2)
                     AND ($4::timestamptz IS NULL OR created_at < $4 OR (created_at = $4 AND id < $3))
@@ -240,14 +591,210 @@ pub async fn paginate_blobs<'a>(
     .await
 }
 
+#[cfg(feature = "update-blob")]
+pub async fn update_blob<'a>(
+    blob: &Blob<'_>,
+    alt_text: &str,
+    caption: &str,
+    focal_point_x: f32,
+    focal_point_y: f32,
+) -> crate::utils::MutResult<Blob<'a>> {
+    use cached::IOCachedAsync;
+
+    let db_pool = crate::db_pool().await;
+    let mut validator = crate::validator!();
+
+    let alt_text = alt_text.trim();
+    let caption = caption.trim();
+
+    validator.validate_length(Input::AltText, alt_text, None, Some(512));
+    validator.validate_length(Input::Caption, caption, None, Some(1024));
+    validator.validate_blob_focal_point(Input::FocalPointX, focal_point_x);
+    validator.validate_blob_focal_point(Input::FocalPointY, focal_point_y);
+
+    if !validator.is_valid {
+        return crate::mut_error!(validator.errors);
+    }
+
+    let result = sqlx::query_as!(
+        Blob,
+        "UPDATE blobs SET alt_text = $2, caption = $3, focal_point_x = $4, focal_point_y = $5 WHERE id = $1
+        RETURNING
+            id,
+            website_id,
+            user_id,
+            file_name,
+            content_type,
+            byte_size,
+            md5_checksum,
+            alt_text,
+            caption,
+            focal_point_x,
+            focal_point_y,
+            created_at,
+            updated_at",
+        blob.id,       // $1
+        alt_text,      // $2
+        caption,       // $3
+        focal_point_x, // $4
+        focal_point_y, // $5
+    )
+    .fetch_one(db_pool)
+    .await;
+
+    match result {
+        Ok(blob1) => {
+            if blob.focal_point_x != blob1.focal_point_x || blob.focal_point_y != blob1.focal_point_y {
+                blob.remove_variants();
+            }
+
+            if let Some(cache) = GET_CACHED_BLOB_BY_ID.get() {
+                let _ = cache.cache_remove(&blob.id).await;
+            }
+
+            crate::mut_success!(blob1)
+        }
+        Err(_) => crate::mut_error!(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::models::Blob;
     use crate::test_utils::{
         insert_test_blob, insert_test_post, insert_test_user, insert_test_website, setup_core_context,
     };
     use crate::utils::CursorPageParams;
+    use crate::CoreContext;
+
+    use super::{
+        collect_blob_garbage, collect_orphaned_blobs, delete_blob, get_blob_by_id, paginate_blobs,
+        paginate_storage_usages, update_blob, BlobGarbageSummary,
+    };
 
-    use super::{delete_blob, get_blob_by_id, paginate_blobs};
+    const GRACE_PERIOD: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+
+    async fn backdate_blob(core_context: &CoreContext, blob: &Blob<'_>) {
+        sqlx::query!(
+            "UPDATE blobs SET created_at = created_at - INTERVAL '2 days' WHERE id = $1",
+            blob.id, // $1
+        )
+        .execute(&core_context.db_pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_collect_blob_garbage_in_dry_run() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let blob = insert_test_blob(&core_context, Some(&user), None).await;
+        let orphaned_directory = std::path::Path::new(&crate::config::MISC_CONFIG.storage_path)
+            .join("blobs")
+            .join(uuid::Uuid::new_v4().to_string());
+
+        std::fs::create_dir_all(&orphaned_directory).unwrap();
+
+        let summary = collect_blob_garbage(std::time::Duration::ZERO, true).await;
+
+        assert!(summary.dry_run);
+        assert!(summary.orphaned_blobs > 0);
+        assert!(summary.orphaned_directories > 0);
+        assert!(get_blob_by_id(blob.id, None, None).await.is_ok());
+        assert!(orphaned_directory.exists());
+
+        let _ = std::fs::remove_dir_all(orphaned_directory);
+    }
+
+    #[tokio::test]
+    async fn should_collect_orphaned_blobs() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let blob = insert_test_blob(&core_context, Some(&user), None).await;
+        let mut summary = BlobGarbageSummary::default();
+
+        backdate_blob(&core_context, &blob).await;
+
+        collect_orphaned_blobs(&mut summary, GRACE_PERIOD).await;
+
+        assert!(get_blob_by_id(blob.id, None, None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_collect_orphaned_website_blobs() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let blob = insert_test_blob(&core_context, Some(&user), Some(&website)).await;
+        let mut summary = BlobGarbageSummary::default();
+
+        backdate_blob(&core_context, &blob).await;
+
+        collect_orphaned_blobs(&mut summary, GRACE_PERIOD).await;
+
+        assert!(get_blob_by_id(blob.id, None, None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_not_collect_recent_blobs() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let blob = insert_test_blob(&core_context, Some(&user), None).await;
+        let mut summary = BlobGarbageSummary::default();
+
+        collect_orphaned_blobs(&mut summary, GRACE_PERIOD).await;
+
+        assert!(get_blob_by_id(blob.id, None, None).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_not_collect_website_blobs_referenced_by_posts() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let post = insert_test_post(&core_context, Some(&website), Some(&user)).await;
+        let blob = insert_test_blob(&core_context, Some(&user), Some(&website)).await;
+        let mut summary = BlobGarbageSummary::default();
+
+        sqlx::query!(
+            "UPDATE posts SET content = $2 WHERE id = $1",
+            post.id,                             // $1
+            format!("![Image]({})", blob.url()), // $2
+        )
+        .execute(&core_context.db_pool)
+        .await
+        .unwrap();
+
+        backdate_blob(&core_context, &blob).await;
+
+        collect_orphaned_blobs(&mut summary, GRACE_PERIOD).await;
+
+        assert!(get_blob_by_id(blob.id, None, None).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_not_collect_blobs_referenced_by_navigation_items() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let blob = insert_test_blob(&core_context, Some(&user), None).await;
+        let mut summary = BlobGarbageSummary::default();
+
+        sqlx::query!(
+            "INSERT INTO navigation_items (website_id, title, url) VALUES ($1, 'File', $2)",
+            website.id,             // $1
+            blob.url().to_string(), // $2
+        )
+        .execute(&core_context.db_pool)
+        .await
+        .unwrap();
+
+        backdate_blob(&core_context, &blob).await;
+
+        collect_orphaned_blobs(&mut summary, GRACE_PERIOD).await;
+
+        assert!(get_blob_by_id(blob.id, None, None).await.is_ok());
+    }
 
     #[tokio::test]
     async fn should_delete_blob() {
@@ -317,4 +864,56 @@ mod tests {
 
         assert_eq!(cursor_page.nodes.len(), 1);
     }
+
+    #[tokio::test]
+    async fn should_paginate_storage_usages() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let _ = insert_test_blob(&core_context, Some(&user), None).await;
+        let _ = insert_test_blob(&core_context, Some(&user), Some(&website)).await;
+
+        let first_page = paginate_storage_usages(&core_context, &CursorPageParams { after: None, first: 1 }).await;
+
+        assert_eq!(first_page.nodes.len(), 1);
+        assert!(first_page.has_next_page);
+
+        let second_page = paginate_storage_usages(
+            &core_context,
+            &CursorPageParams {
+                after: first_page.end_cursor,
+                first: 1,
+            },
+        )
+        .await;
+
+        assert_eq!(second_page.nodes.len(), 1);
+        assert_ne!(first_page.nodes[0].id, second_page.nodes[0].id);
+        assert!(first_page.nodes[0].used_bytes >= second_page.nodes[0].used_bytes);
+    }
+
+    #[tokio::test]
+    async fn should_update_blob() {
+        let core_context = setup_core_context().await;
+        let blob = insert_test_blob(&core_context, None, None).await;
+
+        let result = update_blob(&blob, "A mango", "A mango on a table", 0.25, 0.75).await;
+
+        assert!(result.is_ok());
+
+        let blob = result.ok().unwrap().data;
+
+        assert_eq!(blob.alt_text, "A mango");
+        assert_eq!(blob.focal_point_x, 0.25);
+    }
+
+    #[tokio::test]
+    async fn should_not_update_blob_when_focal_point_is_invalid() {
+        let core_context = setup_core_context().await;
+        let blob = insert_test_blob(&core_context, None, None).await;
+
+        let result = update_blob(&blob, "", "", 1.5, -0.5).await;
+
+        assert!(result.is_err());
+    }
 }
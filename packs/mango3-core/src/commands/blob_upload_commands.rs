@@ -0,0 +1,401 @@
+use sqlx::types::Uuid;
+
+use crate::models::*;
+
+#[cfg(feature = "insert-blob-upload")]
+use crate::constants::ALLOWED_FILE_TYPES;
+
+#[cfg(feature = "append-blob-upload-chunk")]
+pub async fn append_blob_upload_chunk<'a>(
+    blob_upload: &BlobUpload<'_>,
+    upload_offset: i64,
+    chunk: &[u8],
+) -> crate::utils::MutResult<BlobUpload<'a>> {
+    use std::io::{Seek, SeekFrom, Write};
+
+    let db_pool = crate::db_pool().await;
+    let new_upload_offset = upload_offset + chunk.len() as i64;
+
+    if upload_offset != blob_upload.upload_offset || new_upload_offset > blob_upload.upload_length {
+        return crate::mut_error!();
+    }
+
+    let mut transaction = db_pool.begin().await?;
+
+    // The offset is claimed first, so a concurrent request for the same offset waits on the row lock and then fails.
+    let Ok(updated_blob_upload) = sqlx::query_as!(
+        BlobUpload,
+        "UPDATE blob_uploads SET upload_offset = $3 WHERE id = $1 AND upload_offset = $2
+        RETURNING
+            id,
+            website_id,
+            user_id,
+            file_name,
+            content_type,
+            upload_length,
+            upload_offset,
+            created_at,
+            updated_at",
+        blob_upload.id,    // $1
+        upload_offset,     // $2
+        new_upload_offset, // $3
+    )
+    .fetch_one(&mut *transaction)
+    .await
+    else {
+        return crate::mut_error!();
+    };
+
+    let mut tmp_file = std::fs::OpenOptions::new().write(true).open(blob_upload.tmp_path())?;
+
+    tmp_file.seek(SeekFrom::Start(upload_offset as u64))?;
+    tmp_file.write_all(chunk)?;
+    tmp_file.set_len(new_upload_offset as u64)?;
+
+    transaction.commit().await?;
+
+    crate::mut_success!(updated_blob_upload)
+}
+
+/// Deletes the uploads that haven't received a chunk for a day, so slow uploads in progress can still be resumed.
+#[cfg(feature = "delete-all-expired-blob-uploads")]
+pub async fn delete_all_expired_blob_uploads() -> crate::utils::MutResult {
+    let db_pool = crate::db_pool().await;
+
+    let blob_uploads = sqlx::query_as!(
+        BlobUpload,
+        "DELETE FROM blob_uploads WHERE COALESCE(updated_at, created_at) < current_timestamp - INTERVAL '1 day'
+        RETURNING
+            id,
+            website_id,
+            user_id,
+            file_name,
+            content_type,
+            upload_length,
+            upload_offset,
+            created_at,
+            updated_at",
+    )
+    .fetch_all(db_pool)
+    .await?;
+
+    for blob_upload in blob_uploads {
+        let _ = std::fs::remove_file(blob_upload.tmp_path());
+    }
+
+    crate::mut_success!()
+}
+
+#[cfg(feature = "delete-blob-upload")]
+pub async fn delete_blob_upload(blob_upload: &BlobUpload<'_>) -> crate::utils::MutResult {
+    let db_pool = crate::db_pool().await;
+
+    sqlx::query!("DELETE FROM blob_uploads WHERE id = $1", blob_upload.id)
+        .execute(db_pool)
+        .await?;
+
+    let _ = std::fs::remove_file(blob_upload.tmp_path());
+
+    crate::mut_success!()
+}
+
+#[cfg(feature = "finish-blob-upload")]
+pub async fn finish_blob_upload<'a>(
+    blob_upload: &BlobUpload<'_>,
+    user: &User,
+    website: Option<&Website>,
+) -> crate::utils::MutResult<Blob<'a>> {
+    if !blob_upload.is_complete() || blob_upload.user_id != user.id || blob_upload.website_id != website.map(|w| w.id) {
+        return crate::mut_error!();
+    }
+
+    let result = super::blob_commands::insert_blob_from_tmp_file(
+        user,
+        website,
+        &blob_upload.tmp_path(),
+        &blob_upload.file_name,
+        &blob_upload.content_type,
+    )
+    .await;
+
+    // The temporary file is only kept when the blob could be inserted later, so the upload can be finished again.
+    if result.is_ok() || !blob_upload.tmp_path().exists() {
+        let db_pool = crate::db_pool().await;
+
+        sqlx::query!("DELETE FROM blob_uploads WHERE id = $1", blob_upload.id)
+            .execute(db_pool)
+            .await?;
+    }
+
+    result
+}
+
+#[cfg(feature = "get-blob-upload-by-id")]
+pub async fn get_blob_upload_by_id<'a>(id: Uuid, user: &User) -> sqlx::Result<BlobUpload<'a>> {
+    let db_pool = crate::db_pool().await;
+
+    sqlx::query_as!(
+        BlobUpload,
+        "SELECT
+            id,
+            website_id,
+            user_id,
+            file_name,
+            content_type,
+            upload_length,
+            upload_offset,
+            created_at,
+            updated_at
+        FROM blob_uploads WHERE id = $1 AND user_id = $2 LIMIT 1",
+        id,      // $1
+        user.id, // $2
+    )
+    .fetch_one(db_pool)
+    .await
+}
+
+#[cfg(feature = "insert-blob-upload")]
+pub async fn insert_blob_upload<'a>(
+    user: &User,
+    website: Option<&Website>,
+    file_name: &str,
+    content_type: &str,
+    upload_length: i64,
+) -> crate::utils::MutResult<BlobUpload<'a>> {
+    let db_pool = crate::db_pool().await;
+    let website_id = website.map(|w| w.id);
+
+    if upload_length <= 0 || !ALLOWED_FILE_TYPES.contains(&content_type) {
+        return crate::mut_error!();
+    }
+
+    let available_storage = if let Some(website) = website {
+        website.available_storage().await
+    } else {
+        user.available_storage().await
+    };
+
+    if available_storage.bytes() < upload_length {
+        return crate::mut_error!();
+    }
+
+    let result = sqlx::query_as!(
+        BlobUpload,
+        "INSERT INTO blob_uploads (user_id, website_id, file_name, content_type, upload_length)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING
+            id,
+            website_id,
+            user_id,
+            file_name,
+            content_type,
+            upload_length,
+            upload_offset,
+            created_at,
+            updated_at",
+        user.id,       // $1
+        website_id,    // $2
+        file_name,     // $3
+        content_type,  // $4
+        upload_length, // $5
+    )
+    .fetch_one(db_pool)
+    .await;
+
+    if let Ok(ref blob_upload) = result {
+        let _ = std::fs::File::create(blob_upload.tmp_path());
+    }
+
+    crate::mut_result!(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{insert_test_user, insert_test_website, setup_core_context};
+
+    use super::{
+        append_blob_upload_chunk, delete_blob_upload, finish_blob_upload, get_blob_upload_by_id, insert_blob_upload,
+    };
+
+    #[tokio::test]
+    async fn should_insert_blob_upload() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+
+        let result = insert_blob_upload(&user, None, "favicon.png", "image/png", 1024).await;
+
+        assert!(result.is_ok());
+
+        let blob_upload = result.unwrap().data;
+
+        assert_eq!(blob_upload.upload_offset, 0);
+        assert!(blob_upload.tmp_path().exists());
+    }
+
+    #[tokio::test]
+    async fn should_not_insert_blob_upload_with_invalid_content_type() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+
+        let result = insert_blob_upload(&user, None, "script.sh", "text/x-sh", 1024).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_not_insert_blob_upload_when_exceeds_available_storage() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let upload_length = user.available_storage().await.bytes() + 1;
+
+        let result = insert_blob_upload(&user, None, "favicon.png", "image/png", upload_length).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_get_blob_upload_by_id() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let blob_upload = insert_blob_upload(&user, None, "favicon.png", "image/png", 1024)
+            .await
+            .unwrap()
+            .data;
+
+        let result = get_blob_upload_by_id(blob_upload.id, &user).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_not_get_blob_upload_by_id_when_user_is_invalid() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let other_user = insert_test_user(&core_context).await;
+        let blob_upload = insert_blob_upload(&user, None, "favicon.png", "image/png", 1024)
+            .await
+            .unwrap()
+            .data;
+
+        let result = get_blob_upload_by_id(blob_upload.id, &other_user).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_append_blob_upload_chunks() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let blob_upload = insert_blob_upload(&user, None, "favicon.png", "image/png", 8)
+            .await
+            .unwrap()
+            .data;
+
+        let blob_upload = append_blob_upload_chunk(&blob_upload, 0, &[0; 5]).await.unwrap().data;
+
+        assert_eq!(blob_upload.upload_offset, 5);
+        assert!(!blob_upload.is_complete());
+
+        let blob_upload = append_blob_upload_chunk(&blob_upload, 5, &[0; 3]).await.unwrap().data;
+
+        assert!(blob_upload.is_complete());
+    }
+
+    #[tokio::test]
+    async fn should_not_append_blob_upload_chunk_with_invalid_offset() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let blob_upload = insert_blob_upload(&user, None, "favicon.png", "image/png", 8)
+            .await
+            .unwrap()
+            .data;
+
+        let result = append_blob_upload_chunk(&blob_upload, 2, &[0; 3]).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_not_append_blob_upload_chunk_twice_at_same_offset() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let blob_upload = insert_blob_upload(&user, None, "favicon.png", "image/png", 8)
+            .await
+            .unwrap()
+            .data;
+
+        let _ = append_blob_upload_chunk(&blob_upload, 0, &[1; 5]).await.unwrap();
+
+        let result = append_blob_upload_chunk(&blob_upload, 0, &[2; 5]).await;
+
+        assert!(result.is_err());
+        assert_eq!(std::fs::read(blob_upload.tmp_path()).unwrap(), vec![1; 5]);
+    }
+
+    #[tokio::test]
+    async fn should_not_append_blob_upload_chunk_beyond_upload_length() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let blob_upload = insert_blob_upload(&user, None, "favicon.png", "image/png", 8)
+            .await
+            .unwrap()
+            .data;
+
+        let result = append_blob_upload_chunk(&blob_upload, 0, &[0; 9]).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_finish_blob_upload() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let buffer = std::fs::read("../../assets/favicon.png").unwrap();
+        let blob_upload = insert_blob_upload(&user, Some(&website), "favicon.png", "image/png", buffer.len() as i64)
+            .await
+            .unwrap()
+            .data;
+        let blob_upload = append_blob_upload_chunk(&blob_upload, 0, &buffer).await.unwrap().data;
+
+        let result = finish_blob_upload(&blob_upload, &user, Some(&website)).await;
+
+        assert!(result.is_ok());
+
+        let blob = result.unwrap().data;
+
+        assert_eq!(blob.byte_size, buffer.len() as i64);
+        assert_eq!(blob.website_id, Some(website.id));
+        assert!(get_blob_upload_by_id(blob_upload.id, &user).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_not_finish_incomplete_blob_upload() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let blob_upload = insert_blob_upload(&user, None, "favicon.png", "image/png", 8)
+            .await
+            .unwrap()
+            .data;
+        let blob_upload = append_blob_upload_chunk(&blob_upload, 0, &[0; 5]).await.unwrap().data;
+
+        let result = finish_blob_upload(&blob_upload, &user, None).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_delete_blob_upload() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let blob_upload = insert_blob_upload(&user, None, "favicon.png", "image/png", 8)
+            .await
+            .unwrap()
+            .data;
+
+        let result = delete_blob_upload(&blob_upload).await;
+
+        assert!(result.is_ok());
+        assert!(!blob_upload.tmp_path().exists());
+    }
+}
@@ -34,6 +34,48 @@ pub async fn all_hashtags_by_ids(ids: &Vec<Uuid>) -> Vec<Hashtag<'_>> {
         .unwrap_or_default()
 }
 
+#[cfg(feature = "all-related-hashtags")]
+pub async fn all_related_hashtags<'a>(hashtag: &Hashtag<'_>, limit: i64) -> Vec<Hashtag<'a>> {
+    let db_pool = crate::db_pool().await;
+
+    sqlx::query_as!(
+        Hashtag,
+        r#"SELECT h.id, h.name, h.created_at, h.updated_at
+        FROM hashtags AS h
+        INNER JOIN (
+            SELECT related.id, COUNT(*) AS posts_count
+            FROM posts AS p
+            INNER JOIN websites AS w ON w.id = p.website_id, UNNEST(p.hashtag_ids) AS related(id)
+            WHERE $1 = ANY(p.hashtag_ids) AND p.published_at IS NOT NULL AND w.published_at IS NOT NULL
+                AND p.suspended_at IS NULL AND w.suspended_at IS NULL AND related.id != $1
+            GROUP BY related.id
+        ) AS r ON r.id = h.id
+        ORDER BY r.posts_count DESC, h.name ASC LIMIT $2"#,
+        hashtag.id, // $1
+        limit,      // $2
+    )
+    .fetch_all(db_pool)
+    .await
+    .unwrap_or_default()
+}
+
+#[cfg(feature = "all-trending-hashtags")]
+pub async fn all_trending_hashtags<'a>(limit: i64) -> Vec<Hashtag<'a>> {
+    let db_pool = crate::db_pool().await;
+
+    sqlx::query_as!(
+        Hashtag,
+        r#"SELECT h.id, h.name, h.created_at, h.updated_at
+        FROM hashtags AS h
+        INNER JOIN hashtag_trends AS t ON t.hashtag_id = h.id
+        ORDER BY t.score DESC, h.name ASC LIMIT $1"#,
+        limit, // $1
+    )
+    .fetch_all(db_pool)
+    .await
+    .unwrap_or_default()
+}
+
 #[cfg(feature = "get-hashtag-by-id")]
 #[cached::proc_macro::io_cached(
     map_error = r##"|_| sqlx::Error::RowNotFound"##,
@@ -144,11 +186,96 @@ pub async fn get_or_insert_many_hashtags(content: &str) -> crate::utils::MutResu
     )
 }
 
+/// Hashtags starting with the query, the exact match first and then by how much they are trending.
+#[cfg(feature = "search-hashtags")]
+pub async fn search_hashtags<'a>(query: &str, limit: i64) -> Vec<Hashtag<'a>> {
+    let query = query.trim().trim_start_matches('#');
+
+    if query.is_empty() {
+        return vec![];
+    }
+
+    let db_pool = crate::db_pool().await;
+    let pattern = query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+
+    // The prefix is matched with `LIKE`, so the index on the lowercase name can be used.
+    sqlx::query_as!(
+        Hashtag,
+        r#"SELECT h.id, h.name, h.created_at, h.updated_at
+        FROM hashtags AS h
+        LEFT JOIN hashtag_trends AS t ON t.hashtag_id = h.id
+        WHERE LOWER(h.name::text) LIKE LOWER($2) || '%'
+        ORDER BY LOWER(h.name::text) = LOWER($1) DESC, t.score DESC NULLS LAST, h.name ASC LIMIT $3"#,
+        query,   // $1
+        pattern, // $2
+        limit,   // $3
+    )
+    .fetch_all(db_pool)
+    .await
+    .unwrap_or_default()
+}
+
+/// Replaces the trending hashtags with the ones used by the posts published in the last days, scoring each post like
+/// ten views of the posts with the hashtag.
+#[cfg(feature = "update-hashtag-trends")]
+pub async fn update_hashtag_trends() -> crate::utils::MutResult {
+    let db_pool = crate::db_pool().await;
+    let period_days = i32::from(crate::config::MISC_CONFIG.hashtag_trends_period_days);
+    let mut transaction = db_pool.begin().await?;
+
+    sqlx::query!("DELETE FROM hashtag_trends")
+        .execute(&mut *transaction)
+        .await?;
+
+    sqlx::query!(
+        r#"WITH published_posts AS (
+            SELECT p.id, p.hashtag_ids, p.published_at
+            FROM posts AS p
+            INNER JOIN websites AS w ON w.id = p.website_id
+            WHERE p.published_at IS NOT NULL AND w.published_at IS NOT NULL AND cardinality(p.hashtag_ids) > 0
+                AND p.suspended_at IS NULL AND w.suspended_at IS NULL
+        ), recent_posts AS (
+            SELECT hashtag.id AS hashtag_id, COUNT(*) AS posts_count
+            FROM published_posts AS p, UNNEST(p.hashtag_ids) AS hashtag(id)
+            WHERE p.published_at > current_timestamp - make_interval(days => $1)
+            GROUP BY hashtag.id
+        ), recent_views AS (
+            SELECT hashtag.id AS hashtag_id, COUNT(*) AS views_count
+            FROM post_views AS v
+            INNER JOIN published_posts AS p ON p.id = v.post_id, UNNEST(p.hashtag_ids) AS hashtag(id)
+            WHERE v.created_at > current_timestamp - make_interval(days => $1)
+            GROUP BY hashtag.id
+        )
+        INSERT INTO hashtag_trends (hashtag_id, posts_count, views_count, score)
+        SELECT
+            hashtag_id,
+            COALESCE(rp.posts_count, 0),
+            COALESCE(rv.views_count, 0),
+            COALESCE(rp.posts_count, 0) + COALESCE(rv.views_count, 0) / 10.0
+        FROM recent_posts AS rp
+        FULL OUTER JOIN recent_views AS rv USING (hashtag_id)
+        INNER JOIN hashtags AS h ON h.id = hashtag_id"#,
+        period_days, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    crate::mut_success!()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::test_utils::fake_slug;
+    use uuid::Uuid;
+
+    use crate::test_utils::{fake_slug, insert_test_post, setup_core_context};
+    use crate::CoreContext;
 
-    use super::{all_hashtags_by_ids, get_or_insert_hashtag, get_or_insert_many_hashtags};
+    use super::{
+        all_hashtags_by_ids, all_related_hashtags, all_trending_hashtags, get_or_insert_hashtag,
+        get_or_insert_many_hashtags, search_hashtags, update_hashtag_trends,
+    };
 
     #[tokio::test]
     async fn should_return_all_by_ids() {
@@ -179,4 +306,108 @@ mod tests {
 
         assert_eq!(result.ok().unwrap().data.len(), 4);
     }
+
+    #[tokio::test]
+    async fn should_search_hashtags_by_prefix() {
+        let slug = fake_slug();
+        let hashtag = get_or_insert_hashtag(&slug).await.ok().unwrap().data;
+
+        let result = search_hashtags(&format!("#{}", &slug[..slug.len() - 1]), 10).await;
+
+        assert!(result.iter().any(|item| item.id == hashtag.id));
+
+        let result = search_hashtags("   ", 10).await;
+
+        assert!(result.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_search_hashtags_with_like_characters_literally() {
+        let slug = fake_slug();
+        let hashtag = get_or_insert_hashtag(&format!("{slug}_x")).await.ok().unwrap().data;
+
+        let result = search_hashtags(&format!("{slug}_"), 10).await;
+
+        assert!(result.iter().any(|item| item.id == hashtag.id));
+
+        let result = search_hashtags(&format!("{slug}%"), 10).await;
+
+        assert!(result.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_return_related_hashtags() {
+        let core_context = setup_core_context().await;
+        let hashtag = get_or_insert_hashtag(&fake_slug()).await.ok().unwrap().data;
+        let related_hashtag = get_or_insert_hashtag(&fake_slug()).await.ok().unwrap().data;
+
+        insert_test_post_with_hashtags(&core_context, &[hashtag.id, related_hashtag.id]).await;
+
+        let result = all_related_hashtags(&hashtag, 10).await;
+
+        assert_eq!(
+            result.iter().map(|item| item.id).collect::<Vec<_>>(),
+            vec![related_hashtag.id]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_relate_hashtag_to_itself() {
+        let slug = fake_slug();
+        let hashtag = get_or_insert_hashtag(&slug).await.ok().unwrap().data;
+
+        let result = all_related_hashtags(&hashtag, 10).await;
+
+        assert!(result.iter().all(|item| item.id != hashtag.id));
+    }
+
+    #[tokio::test]
+    async fn should_update_hashtag_trends() {
+        let core_context = setup_core_context().await;
+        let hashtag = get_or_insert_hashtag(&fake_slug()).await.ok().unwrap().data;
+        let unused_hashtag = get_or_insert_hashtag(&fake_slug()).await.ok().unwrap().data;
+
+        insert_test_post_with_hashtags(&core_context, &[hashtag.id]).await;
+
+        let result = update_hashtag_trends().await;
+
+        assert!(result.is_ok());
+
+        let scores = sqlx::query!(
+            "SELECT hashtag_id, score FROM hashtag_trends WHERE hashtag_id = ANY($1)",
+            &[hashtag.id, unused_hashtag.id], // $1
+        )
+        .fetch_all(&core_context.db_pool)
+        .await
+        .unwrap();
+
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0].hashtag_id, hashtag.id);
+        assert!(scores[0].score >= 1.0);
+
+        let trending_hashtags = all_trending_hashtags(10).await;
+
+        assert!(trending_hashtags.len() <= 10);
+    }
+
+    /// Inserts a published post, in a published website, using the given hashtags.
+    async fn insert_test_post_with_hashtags(core_context: &CoreContext, hashtag_ids: &[Uuid]) {
+        let post = insert_test_post(core_context, None, None).await;
+
+        sqlx::query!(
+            "UPDATE websites SET published_at = current_timestamp WHERE id = $1",
+            post.website_id // $1
+        )
+        .execute(&core_context.db_pool)
+        .await
+        .unwrap();
+        sqlx::query!(
+            "UPDATE posts SET hashtag_ids = $2 WHERE id = $1",
+            post.id,     // $1
+            hashtag_ids, // $2
+        )
+        .execute(&core_context.db_pool)
+        .await
+        .unwrap();
+    }
 }
@@ -1,42 +1,145 @@
-use crate::models::InvitationCode;
+use uuid::Uuid;
+
+use crate::enums::UserRole;
+use crate::models::*;
 use crate::utils::*;
 use crate::CoreContext;
 
+#[cfg(feature = "insert-invitation-code")]
+use chrono::{DateTime, Utc};
+
+#[cfg(any(feature = "delete-invitation-code", feature = "insert-invitation-code"))]
+use crate::enums::{AuditLogAction, AuditLogTargetType};
+
 #[cfg(feature = "delete-invitation-code")]
-pub async fn delete_invitation_code(core_context: &CoreContext, invitation_code: &InvitationCode) -> MutResult {
-    sqlx::query!("DELETE FROM invitation_codes WHERE id = $1", invitation_code.id)
-        .execute(&core_context.db_pool)
-        .await?;
+pub async fn delete_invitation_code(
+    core_context: &CoreContext,
+    invitation_code: &InvitationCode,
+    actor: &AuditActor,
+) -> MutResult {
+    if invitation_code.is_used() {
+        return crate::mut_error!();
+    }
+
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    let result = sqlx::query!(
+        "DELETE FROM invitation_codes WHERE id = $1 AND used_at IS NULL",
+        invitation_code.id // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return crate::mut_error!();
+    }
+
+    super::insert_audit_log(
+        &mut *transaction,
+        actor,
+        AuditLogAction::DeleteInvitationCode,
+        AuditLogTargetType::InvitationCode,
+        invitation_code.id,
+        Some(serde_json::json!({ "email": invitation_code.email })),
+        None,
+    )
+    .await?;
+
+    transaction.commit().await?;
 
     crate::mut_success!()
 }
 
+/// Only returns the invitation code if it can still be used to register, i.e. it is neither used nor expired.
 #[cfg(feature = "get-invitation-code")]
 pub async fn get_invitation_code(core_context: &CoreContext, code: &str) -> sqlx::Result<InvitationCode> {
     sqlx::query_as!(
         InvitationCode,
-        "SELECT * FROM invitation_codes WHERE code = $1 LIMIT 1",
-        code
+        r#"SELECT
+            id,
+            email,
+            code,
+            role as "role!: UserRole",
+            expires_at,
+            invited_by_user_id,
+            used_by_user_id,
+            used_at,
+            created_at,
+            updated_at
+        FROM invitation_codes
+        WHERE code = $1 AND used_at IS NULL AND (expires_at IS NULL OR expires_at > current_timestamp)
+        LIMIT 1"#,
+        code // $1
     )
     .fetch_one(&core_context.db_pool)
     .await
 }
 
 #[cfg(feature = "get-invitation-code-by-id")]
-pub async fn get_invitation_code_by_id(core_context: &CoreContext, id: uuid::Uuid) -> sqlx::Result<InvitationCode> {
+pub async fn get_invitation_code_by_id(core_context: &CoreContext, id: Uuid) -> sqlx::Result<InvitationCode> {
     sqlx::query_as!(
         InvitationCode,
-        "SELECT * FROM invitation_codes WHERE id = $1 LIMIT 1",
-        id
+        r#"SELECT
+            id,
+            email,
+            code,
+            role as "role!: UserRole",
+            expires_at,
+            invited_by_user_id,
+            used_by_user_id,
+            used_at,
+            created_at,
+            updated_at
+        FROM invitation_codes WHERE id = $1 LIMIT 1"#,
+        id // $1
     )
     .fetch_one(&core_context.db_pool)
     .await
 }
 
+#[cfg(any(feature = "get-remaining-invitations-count", feature = "insert-invitation-code"))]
+async fn fetch_remaining_invitations_count<'c>(executor: impl sqlx::PgExecutor<'c>, user: &User) -> i64 {
+    sqlx::query!(
+        r#"SELECT
+            (SELECT invitation_allowance FROM users WHERE id = $1)::bigint
+            - (SELECT COUNT(*) FROM invitation_codes WHERE invited_by_user_id = $1) AS "count!""#,
+        user.id // $1
+    )
+    .fetch_one(executor)
+    .await
+    .map(|record| record.count.max(0))
+    .unwrap_or_default()
+}
+
+/// How many more invitations the user can send, according to the allowance given by an admin.
+#[cfg(feature = "get-remaining-invitations-count")]
+pub async fn get_remaining_invitations_count(core_context: &CoreContext, user: &User) -> i64 {
+    fetch_remaining_invitations_count(&core_context.db_pool, user).await
+}
+
 #[cfg(feature = "insert-invitation-code")]
-pub async fn insert_invitation_code(core_context: &CoreContext, email: &str) -> MutResult<InvitationCode> {
+pub async fn insert_invitation_code(
+    core_context: &CoreContext,
+    email: &str,
+    role: UserRole,
+    expires_at: Option<DateTime<Utc>>,
+    actor: &AuditActor,
+) -> MutResult<InvitationCode> {
+    insert_invitation_code_within_allowance(core_context, email, role, expires_at, actor, None).await
+}
+
+/// When an inviter is given, their allowance is checked in the same transaction as the insert, after locking their
+/// row, so concurrent invitations can't go over it.
+#[cfg(feature = "insert-invitation-code")]
+async fn insert_invitation_code_within_allowance(
+    core_context: &CoreContext,
+    email: &str,
+    role: UserRole,
+    expires_at: Option<DateTime<Utc>>,
+    actor: &AuditActor,
+    inviter: Option<&User>,
+) -> MutResult<InvitationCode> {
     use crate::enums::{Input, InputError};
-    use crate::utils::ValidatorTrait;
 
     let mut validator = crate::validator!();
 
@@ -47,7 +150,8 @@ pub async fn insert_invitation_code(core_context: &CoreContext, email: &str) ->
         && validator.validate_format(Input::Email, &email, &crate::constants::REGEX_EMAIL)
     {
         let email_exists = sqlx::query!(
-            "SELECT id FROM users WHERE LOWER(email) = $1 LIMIT 1",
+            "SELECT id FROM users WHERE LOWER(email) = $1
+            UNION ALL SELECT id FROM invitation_codes WHERE email = $1 LIMIT 1",
             email // $1
         )
         .fetch_one(&core_context.db_pool)
@@ -56,23 +160,76 @@ pub async fn insert_invitation_code(core_context: &CoreContext, email: &str) ->
         validator.custom_validation(Input::Email, InputError::AlreadyInUse, || !email_exists);
     }
 
+    validator.custom_validation(Input::ExpiresAt, InputError::IsInvalid, || {
+        !expires_at.is_some_and(|expires_at| expires_at <= Utc::now())
+    });
+
+    // Invitations can't hand out the superuser role, as there can only be one superuser.
+    validator.custom_validation(Input::Role, InputError::IsInvalid, || role != UserRole::Superuser);
+
     if !validator.is_valid {
         return crate::mut_error!(validator.errors);
     }
 
     let code = crate::utils::generate_random_string(crate::config::MISC_CONFIG.invitation_code_length);
 
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    if let Some(inviter) = inviter {
+        sqlx::query!(
+            "SELECT id FROM users WHERE id = $1 FOR UPDATE",
+            inviter.id // $1
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        if fetch_remaining_invitations_count(&mut *transaction, inviter).await < 1 {
+            return crate::mut_error!();
+        }
+    }
+
     let result = sqlx::query_as!(
         InvitationCode,
-        "INSERT INTO invitation_codes (email, code) VALUES ($1, $2) RETURNING *",
-        email, // $1
-        code,  // $2
+        r#"INSERT INTO invitation_codes (email, code, role, expires_at, invited_by_user_id)
+        VALUES ($1, $2, $3, $4, $5) RETURNING
+            id,
+            email,
+            code,
+            role as "role!: UserRole",
+            expires_at,
+            invited_by_user_id,
+            used_by_user_id,
+            used_at,
+            created_at,
+            updated_at"#,
+        email,                                         // $1
+        code,                                          // $2
+        role as UserRole,                              // $3
+        expires_at,                                    // $4
+        inviter.map(|user| user.id).or(actor.user_id), // $5
     )
-    .fetch_one(&core_context.db_pool)
+    .fetch_one(&mut *transaction)
     .await;
 
     match result {
         Ok(invitation_code) => {
+            super::insert_audit_log(
+                &mut *transaction,
+                actor,
+                AuditLogAction::InsertInvitationCode,
+                AuditLogTargetType::InvitationCode,
+                invitation_code.id,
+                None,
+                Some(serde_json::json!({
+                    "email": invitation_code.email,
+                    "role": invitation_code.role.to_string(),
+                    "expires_at": invitation_code.expires_at,
+                })),
+            )
+            .await?;
+
+            transaction.commit().await?;
+
             core_context
                 .jobs
                 .guest_mailer(&email, crate::enums::GuestMailerJobCommand::InvitationCode(code))
@@ -83,3 +240,365 @@ pub async fn insert_invitation_code(core_context: &CoreContext, email: &str) ->
         Err(_) => crate::mut_error!(),
     }
 }
+
+/// Picks the email addresses from a pasted list or a CSV file, ignoring headers and any other column.
+#[cfg(feature = "insert-invitation-codes")]
+fn parse_emails(text: &str) -> Vec<String> {
+    let mut emails: Vec<String> = Vec::new();
+
+    for value in text.split([',', ';', '\t', '\r', '\n', ' ']) {
+        let email = value
+            .trim_matches(|c: char| c == '"' || c == '\'' || c.is_whitespace())
+            .to_lowercase();
+
+        if email.contains('@') && !emails.contains(&email) {
+            emails.push(email);
+        }
+    }
+
+    emails
+}
+
+#[cfg(feature = "insert-invitation-codes")]
+pub async fn insert_invitation_codes(
+    core_context: &CoreContext,
+    text: &str,
+    role: UserRole,
+    expires_at: Option<DateTime<Utc>>,
+    actor: &AuditActor,
+) -> InvitationCodesReport {
+    let mut report = InvitationCodesReport::default();
+
+    for email in parse_emails(text) {
+        let result = insert_invitation_code(core_context, &email, role.clone(), expires_at, actor).await;
+
+        if result.is_ok() {
+            report.invited_emails.push(email);
+        } else {
+            report.failed_emails.push(email);
+        }
+    }
+
+    report
+}
+
+/// Invites someone on behalf of a user, as long as the user still has invitations left.
+#[cfg(feature = "insert-user-invitation-code")]
+pub async fn insert_user_invitation_code(
+    core_context: &CoreContext,
+    user: &User,
+    email: &str,
+    actor: &AuditActor,
+) -> MutResult<InvitationCode> {
+    insert_invitation_code_within_allowance(core_context, email, UserRole::User, None, actor, Some(user)).await
+}
+
+#[cfg(feature = "paginate-invitation-codes")]
+pub async fn paginate_invitation_codes(
+    core_context: &CoreContext,
+    page_params: &CursorPageParams,
+    is_used: Option<bool>,
+    invited_by_user_id: Option<Uuid>,
+) -> CursorPage<InvitationCode> {
+    crate::cursor_page!(
+        core_context,
+        page_params,
+        |node: InvitationCode| node.id,
+        move |core_context, after| async move { get_invitation_code_by_id(core_context, after).await.ok() },
+        move |core_context, cursor_resource, limit| async move {
+            let (cursor_id, cursor_created_at) = cursor_resource
+                .map(|c| (Some(c.id), Some(c.created_at)))
+                .unwrap_or_default();
+
+            sqlx::query_as!(
+                InvitationCode,
+                r#"SELECT
+                    id,
+                    email,
+                    code,
+                    role as "role!: UserRole",
+                    expires_at,
+                    invited_by_user_id,
+                    used_by_user_id,
+                    used_at,
+                    created_at,
+                    updated_at
+                FROM invitation_codes
+                WHERE (
+                        $1::bool IS NULL OR ($1 IS TRUE AND used_at IS NOT NULL) OR ($1 IS FALSE AND used_at IS NULL)
+                    )
+                    AND ($2::uuid IS NULL OR invited_by_user_id = $2)
+                    AND ($4::timestamptz IS NULL OR created_at < $4 OR (created_at = $4 AND id < $3))
+                ORDER BY created_at DESC, id DESC LIMIT $5"#,
+                is_used,            // $1
+                invited_by_user_id, // $2
+                cursor_id,          // $3
+                cursor_created_at,  // $4
+                limit,              // $5
+            )
+            .fetch_all(&core_context.db_pool)
+            .await
+            .unwrap_or_default()
+        },
+    )
+    .await
+}
+
+#[cfg(feature = "resend-invitation-code")]
+pub async fn resend_invitation_code(core_context: &CoreContext, invitation_code: &InvitationCode) -> MutResult {
+    if !invitation_code.is_usable() {
+        return crate::mut_error!();
+    }
+
+    core_context
+        .jobs
+        .guest_mailer(
+            &invitation_code.email,
+            crate::enums::GuestMailerJobCommand::InvitationCode(invitation_code.code.clone()),
+        )
+        .await;
+
+    crate::mut_success!()
+}
+
+/// Marks the invitation code as used by the user being registered with it. Takes the transaction that inserts the
+/// user, and fails if the code was used or expired in the meantime.
+#[cfg(feature = "use-invitation-code")]
+pub(crate) async fn use_invitation_code<'c>(
+    executor: impl sqlx::PgExecutor<'c>,
+    invitation_code: &InvitationCode,
+    user: &User,
+) -> sqlx::Result<()> {
+    sqlx::query!(
+        "UPDATE invitation_codes SET used_at = current_timestamp, used_by_user_id = $2
+        WHERE id = $1 AND used_at IS NULL AND (expires_at IS NULL OR expires_at > current_timestamp)
+        RETURNING id",
+        invitation_code.id, // $1
+        user.id,            // $2
+    )
+    .fetch_one(executor)
+    .await
+    .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use crate::commands::update_user_invitation_allowance;
+    use crate::enums::UserRole;
+    use crate::models::AuditActor;
+    use crate::test_utils::{fake_email, insert_test_user, insert_test_user_with_invitation_code, setup_core_context};
+    use crate::utils::CursorPageParams;
+
+    use super::{
+        delete_invitation_code, get_invitation_code, get_invitation_code_by_id, get_remaining_invitations_count,
+        insert_invitation_code, insert_invitation_codes, insert_user_invitation_code, paginate_invitation_codes,
+        parse_emails,
+    };
+
+    #[tokio::test]
+    async fn should_insert_invitation_code() {
+        let core_context = setup_core_context().await;
+        let email = fake_email();
+
+        let result =
+            insert_invitation_code(&core_context, &email, UserRole::Creator, None, &AuditActor::default()).await;
+
+        assert!(result.is_ok());
+
+        let invitation_code = result.unwrap().data;
+
+        assert_eq!(invitation_code.email, email.to_lowercase());
+        assert_eq!(invitation_code.role, UserRole::Creator);
+        assert!(invitation_code.is_usable());
+
+        let result = insert_invitation_code(&core_context, &email, UserRole::User, None, &AuditActor::default()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_not_insert_invitation_code_already_expired() {
+        let core_context = setup_core_context().await;
+        let expires_at = Utc::now() - Duration::days(1);
+
+        let result = insert_invitation_code(
+            &core_context,
+            &fake_email(),
+            UserRole::User,
+            Some(expires_at),
+            &AuditActor::default(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_insert_invitation_codes() {
+        let core_context = setup_core_context().await;
+        let email1 = fake_email().to_lowercase();
+        let email2 = fake_email().to_lowercase();
+        let text = format!("email,name\n\"{email1}\",Foo\n{email2};{email1}\ninvalid");
+
+        let report = insert_invitation_codes(&core_context, &text, UserRole::User, None, &AuditActor::default()).await;
+
+        assert_eq!(report.invited_emails, vec![email1, email2]);
+        assert!(report.failed_emails.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_not_insert_invitation_code_for_superuser() {
+        let core_context = setup_core_context().await;
+
+        let result = insert_invitation_code(
+            &core_context,
+            &fake_email(),
+            UserRole::Superuser,
+            None,
+            &AuditActor::default(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_use_invitation_code_on_register() {
+        let core_context = setup_core_context().await;
+        let invitation_code = insert_invitation_code(
+            &core_context,
+            &fake_email(),
+            UserRole::Creator,
+            None,
+            &AuditActor::default(),
+        )
+        .await
+        .unwrap()
+        .data;
+
+        let result =
+            insert_test_user_with_invitation_code(&core_context, &invitation_code.email, Some(&invitation_code)).await;
+
+        assert!(result.is_ok());
+
+        let user = result.unwrap().data;
+
+        assert_eq!(user.role, UserRole::Creator);
+
+        let invitation_code = get_invitation_code_by_id(&core_context, invitation_code.id)
+            .await
+            .unwrap();
+
+        assert!(invitation_code.is_used());
+        assert_eq!(invitation_code.used_by_user_id, Some(user.id));
+
+        assert!(get_invitation_code(&core_context, &invitation_code.code).await.is_err());
+        assert!(
+            insert_test_user_with_invitation_code(&core_context, &fake_email(), Some(&invitation_code))
+                .await
+                .is_err()
+        );
+        assert!(
+            delete_invitation_code(&core_context, &invitation_code, &AuditActor::default())
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_use_invitation_role_with_other_email() {
+        let core_context = setup_core_context().await;
+        let invitation_code = insert_invitation_code(
+            &core_context,
+            &fake_email(),
+            UserRole::Admin,
+            None,
+            &AuditActor::default(),
+        )
+        .await
+        .unwrap()
+        .data;
+
+        let result = insert_test_user_with_invitation_code(&core_context, &fake_email(), Some(&invitation_code)).await;
+
+        assert!(result.is_err());
+
+        let invitation_code = get_invitation_code_by_id(&core_context, invitation_code.id)
+            .await
+            .unwrap();
+
+        assert!(!invitation_code.is_used());
+
+        let invitation_code = insert_invitation_code(
+            &core_context,
+            &fake_email(),
+            UserRole::User,
+            None,
+            &AuditActor::default(),
+        )
+        .await
+        .unwrap()
+        .data;
+
+        let result = insert_test_user_with_invitation_code(&core_context, &fake_email(), Some(&invitation_code)).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().data.role, UserRole::User);
+    }
+
+    #[tokio::test]
+    async fn should_respect_user_invitation_allowance() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let actor = AuditActor::new(Some(&user), None);
+
+        assert_eq!(get_remaining_invitations_count(&core_context, &user).await, 0);
+        assert!(insert_user_invitation_code(&core_context, &user, &fake_email(), &actor)
+            .await
+            .is_err());
+
+        let _ = update_user_invitation_allowance(&core_context, &user, "1", &AuditActor::default()).await;
+
+        assert_eq!(get_remaining_invitations_count(&core_context, &user).await, 1);
+
+        let result = insert_user_invitation_code(&core_context, &user, &fake_email(), &actor).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().data.invited_by_user_id, Some(user.id));
+        assert_eq!(get_remaining_invitations_count(&core_context, &user).await, 0);
+
+        let cursor_page =
+            paginate_invitation_codes(&core_context, &CursorPageParams::default(), Some(false), Some(user.id)).await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_go_over_user_invitation_allowance_concurrently() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let actor = AuditActor::new(Some(&user), None);
+
+        let _ = update_user_invitation_allowance(&core_context, &user, "1", &AuditActor::default()).await;
+
+        let (email1, email2) = (fake_email(), fake_email());
+        let (result1, result2) = futures::future::join(
+            insert_user_invitation_code(&core_context, &user, &email1, &actor),
+            insert_user_invitation_code(&core_context, &user, &email2, &actor),
+        )
+        .await;
+
+        assert!(result1.is_ok() != result2.is_ok());
+        assert_eq!(get_remaining_invitations_count(&core_context, &user).await, 0);
+    }
+
+    #[test]
+    fn should_parse_emails() {
+        assert_eq!(
+            parse_emails("Email\n'a@example.com', b@example.com\tc\nA@example.com"),
+            vec!["a@example.com".to_owned(), "b@example.com".to_owned()]
+        );
+    }
+}
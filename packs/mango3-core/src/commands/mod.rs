@@ -1,12 +1,37 @@
+#[cfg(any(
+    feature = "get-abuse-report-by-id",
+    feature = "insert-abuse-report",
+    feature = "paginate-abuse-reports",
+    feature = "resolve-abuse-report",
+))]
+mod abuse_report_commands;
+#[cfg(any(
+    feature = "get-audit-log-by-id",
+    feature = "insert-audit-log",
+    feature = "paginate-audit-logs",
+))]
+mod audit_log_commands;
 #[cfg(any(
     feature = "all-blobs-by-ids",
+    feature = "collect-blob-garbage",
     feature = "delete-blob",
-    feature = "delete-orphaned-blobs",
     feature = "get-blob-by-id",
     feature = "insert-blob",
+    feature = "insert-blob-from-tmp-file",
     feature = "paginate-blobs",
+    feature = "paginate-storage-usages",
+    feature = "update-blob",
 ))]
 mod blob_commands;
+#[cfg(any(
+    feature = "append-blob-upload-chunk",
+    feature = "delete-all-expired-blob-uploads",
+    feature = "delete-blob-upload",
+    feature = "finish-blob-upload",
+    feature = "get-blob-upload-by-id",
+    feature = "insert-blob-upload",
+))]
+mod blob_upload_commands;
 #[cfg(any(
     feature = "confirm-confirmation-code",
     feature = "delete-all-expired-confirmation-codes",
@@ -17,17 +42,27 @@ mod blob_commands;
 mod confirmation_code_commands;
 #[cfg(any(
     feature = "all-hashtags-by-ids",
+    feature = "all-related-hashtags",
+    feature = "all-trending-hashtags",
     feature = "get-hashtag-by-id",
     feature = "get-hashtag-by-name",
     feature = "get-or-insert-hashtag",
-    feature = "get-or-insert-many-hashtags"
+    feature = "get-or-insert-many-hashtags",
+    feature = "search-hashtags",
+    feature = "update-hashtag-trends",
 ))]
 mod hashtag_commands;
 #[cfg(any(
     feature = "delete-invitation-code",
     feature = "get-invitation-code",
     feature = "get-invitation-code-by-id",
+    feature = "get-remaining-invitations-count",
     feature = "insert-invitation-code",
+    feature = "insert-invitation-codes",
+    feature = "insert-user-invitation-code",
+    feature = "paginate-invitation-codes",
+    feature = "resend-invitation-code",
+    feature = "use-invitation-code",
 ))]
 mod invitation_code_commands;
 #[cfg(any(
@@ -38,16 +73,28 @@ mod invitation_code_commands;
 ))]
 mod navigation_item_commands;
 #[cfg(any(
+    feature = "all-job-queue-depths",
+    feature = "all-platform-daily-stats",
+    feature = "get-platform-stats",
+))]
+mod platform_stats_commands;
+#[cfg(any(
+    feature = "all-post-translations",
     feature = "delete-post",
     feature = "get-post-by-id",
     feature = "get-post-by-id-with-search-rank",
     feature = "get-post-by-slug",
     feature = "insert-post",
+    feature = "moderate-post",
     feature = "paginate-posts",
+    feature = "paginate-posts-for-moderation",
+    feature = "restore-post",
     feature = "search-posts",
     feature = "update-post",
 ))]
 mod post_commands;
+#[cfg(any(feature = "enqueue-post-import", feature = "import-posts"))]
+mod post_import_commands;
 #[cfg(any(
     feature = "delete-post-comment",
     feature = "get-post-comments-count",
@@ -63,27 +110,48 @@ mod post_comment_commands;
     feature = "insert-or-update-post-reaction"
 ))]
 mod post_reaction_commands;
-#[cfg(any(feature = "get-post-views-count", feature = "get-or-insert-post-view"))]
+#[cfg(any(
+    feature = "anonymize-post-views",
+    feature = "delete-all-expired-post-views",
+    feature = "get-post-views-count",
+    feature = "get-or-insert-post-view",
+    feature = "insert-post-view-event"
+))]
 mod post_view_commands;
 #[cfg(any(
+    feature = "all-search-suggestions",
+    feature = "all-text-search-languages",
+    feature = "search-all"
+))]
+mod search_commands;
+#[cfg(feature = "generate-static-website")]
+mod static_website_commands;
+#[cfg(any(
+    feature = "all-mentioned-users",
+    feature = "all-mentioned-users-by-ids",
     feature = "authenticate-user",
     feature = "clear-user-cache",
     feature = "confirm-user-email",
     feature = "disable-user",
     feature = "enable-user",
+    feature = "get-used-user-storage",
     feature = "get-user-by-id",
     feature = "get-user-by-username",
     feature = "get-user-by-username-or-email",
+    feature = "get-user-invitation-allowance",
     feature = "insert-user",
+    feature = "notify-mentioned-users",
     feature = "paginate-users",
     feature = "reset-user-password",
     feature = "send-user-email-confirmation-code",
     feature = "send-user-login-confirmation-code",
     feature = "send-user-password-reset-code",
     feature = "update-user-email",
+    feature = "update-user-invitation-allowance",
     feature = "update-user-password",
     feature = "update-user-profile",
     feature = "update-user-role",
+    feature = "update-user-storage-quota",
 ))]
 mod user_commands;
 #[cfg(any(
@@ -101,26 +169,75 @@ mod user_session_commands;
     feature = "get-used-website-storage",
     feature = "get-website-by-id",
     feature = "get-website-by-subdomain",
+    feature = "get-website-max-storage",
     feature = "insert-website",
+    feature = "moderate-website",
     feature = "paginate-websites",
+    feature = "paginate-websites-for-moderation",
     feature = "paginate-websites-sorted-by-name-asc",
+    feature = "restore-website",
     feature = "search-websites",
     feature = "update-website",
+    feature = "update-website-storage-quota",
 ))]
 mod website_commands;
+#[cfg(any(
+    feature = "all-website-countries",
+    feature = "all-website-daily-stats",
+    feature = "all-website-referrers",
+    feature = "all-website-top-posts",
+    feature = "update-website-analytics",
+))]
+mod website_analytics_commands;
+#[cfg(any(
+    feature = "delete-all-expired-website-exports",
+    feature = "enqueue-website-export",
+    feature = "export-website",
+))]
+mod website_export_commands;
 
+#[cfg(feature = "get-abuse-report-by-id")]
+pub use abuse_report_commands::get_abuse_report_by_id;
+#[cfg(feature = "insert-abuse-report")]
+pub use abuse_report_commands::insert_abuse_report;
+#[cfg(feature = "paginate-abuse-reports")]
+pub use abuse_report_commands::paginate_abuse_reports;
+#[cfg(feature = "resolve-abuse-report")]
+pub use abuse_report_commands::resolve_abuse_report;
+#[cfg(feature = "get-audit-log-by-id")]
+pub use audit_log_commands::get_audit_log_by_id;
+#[cfg(feature = "insert-audit-log")]
+pub(crate) use audit_log_commands::insert_audit_log;
+#[cfg(feature = "paginate-audit-logs")]
+pub use audit_log_commands::paginate_audit_logs;
 #[cfg(feature = "all-blobs-by-ids")]
 pub use blob_commands::all_blobs_by_ids;
+#[cfg(feature = "collect-blob-garbage")]
+pub use blob_commands::{collect_blob_garbage, BlobGarbageSummary};
 #[cfg(feature = "delete-blob")]
 pub use blob_commands::delete_blob;
-#[cfg(feature = "delete-orphaned-blobs")]
-pub use blob_commands::delete_orphaned_blobs;
 #[cfg(feature = "get-blob-by-id")]
 pub use blob_commands::get_blob_by_id;
 #[cfg(feature = "insert-blob")]
 pub use blob_commands::insert_blob;
 #[cfg(feature = "paginate-blobs")]
 pub use blob_commands::paginate_blobs;
+#[cfg(feature = "paginate-storage-usages")]
+pub use blob_commands::paginate_storage_usages;
+#[cfg(feature = "update-blob")]
+pub use blob_commands::update_blob;
+#[cfg(feature = "append-blob-upload-chunk")]
+pub use blob_upload_commands::append_blob_upload_chunk;
+#[cfg(feature = "delete-all-expired-blob-uploads")]
+pub use blob_upload_commands::delete_all_expired_blob_uploads;
+#[cfg(feature = "delete-blob-upload")]
+pub use blob_upload_commands::delete_blob_upload;
+#[cfg(feature = "finish-blob-upload")]
+pub use blob_upload_commands::finish_blob_upload;
+#[cfg(feature = "get-blob-upload-by-id")]
+pub use blob_upload_commands::get_blob_upload_by_id;
+#[cfg(feature = "insert-blob-upload")]
+pub use blob_upload_commands::insert_blob_upload;
 #[cfg(feature = "confirm-confirmation-code")]
 pub use confirmation_code_commands::confirm_confirmation_code;
 #[cfg(feature = "delete-all-expired-confirmation-codes")]
@@ -133,6 +250,10 @@ pub use confirmation_code_commands::get_confirmation_code_by_user;
 pub use confirmation_code_commands::insert_confirmation_code;
 #[cfg(feature = "all-hashtags-by-ids")]
 pub use hashtag_commands::all_hashtags_by_ids;
+#[cfg(feature = "all-related-hashtags")]
+pub use hashtag_commands::all_related_hashtags;
+#[cfg(feature = "all-trending-hashtags")]
+pub use hashtag_commands::all_trending_hashtags;
 #[cfg(feature = "get-hashtag-by-id")]
 pub use hashtag_commands::get_hashtag_by_id;
 #[cfg(feature = "get-hashtag-by-name")]
@@ -141,14 +262,30 @@ pub use hashtag_commands::get_hashtag_by_name;
 pub use hashtag_commands::get_or_insert_hashtag;
 #[cfg(feature = "get-or-insert-many-hashtags")]
 pub use hashtag_commands::get_or_insert_many_hashtags;
+#[cfg(feature = "search-hashtags")]
+pub use hashtag_commands::search_hashtags;
+#[cfg(feature = "update-hashtag-trends")]
+pub use hashtag_commands::update_hashtag_trends;
 #[cfg(feature = "delete-invitation-code")]
 pub use invitation_code_commands::delete_invitation_code;
 #[cfg(feature = "get-invitation-code")]
 pub use invitation_code_commands::get_invitation_code;
 #[cfg(feature = "get-invitation-code-by-id")]
 pub use invitation_code_commands::get_invitation_code_by_id;
+#[cfg(feature = "get-remaining-invitations-count")]
+pub use invitation_code_commands::get_remaining_invitations_count;
 #[cfg(feature = "insert-invitation-code")]
 pub use invitation_code_commands::insert_invitation_code;
+#[cfg(feature = "insert-invitation-codes")]
+pub use invitation_code_commands::insert_invitation_codes;
+#[cfg(feature = "insert-user-invitation-code")]
+pub use invitation_code_commands::insert_user_invitation_code;
+#[cfg(feature = "paginate-invitation-codes")]
+pub use invitation_code_commands::paginate_invitation_codes;
+#[cfg(feature = "resend-invitation-code")]
+pub use invitation_code_commands::resend_invitation_code;
+#[cfg(feature = "use-invitation-code")]
+pub(crate) use invitation_code_commands::use_invitation_code;
 #[cfg(feature = "all-navigation-items-by-website")]
 pub use navigation_item_commands::all_navigation_items_by_website;
 #[cfg(feature = "get-navigation-item-by-id")]
@@ -157,6 +294,14 @@ pub use navigation_item_commands::get_navigation_item_by_id;
 pub use navigation_item_commands::insert_navigation_item;
 #[cfg(feature = "insert-or-update-many-navigation-items")]
 pub use navigation_item_commands::insert_or_update_many_navigation_items;
+#[cfg(feature = "all-job-queue-depths")]
+pub use platform_stats_commands::all_job_queue_depths;
+#[cfg(feature = "all-platform-daily-stats")]
+pub use platform_stats_commands::all_platform_daily_stats;
+#[cfg(feature = "get-platform-stats")]
+pub use platform_stats_commands::get_platform_stats;
+#[cfg(feature = "all-post-translations")]
+pub use post_commands::all_post_translations;
 #[cfg(feature = "delete-post")]
 pub use post_commands::delete_post;
 #[cfg(feature = "get-post-by-id")]
@@ -167,12 +312,26 @@ pub use post_commands::get_post_by_id_with_search_rank;
 pub use post_commands::get_post_by_slug;
 #[cfg(feature = "insert-post")]
 pub use post_commands::insert_post;
+#[cfg(any(feature = "insert-post", feature = "update-post"))]
+pub use post_commands::PostInput;
+#[cfg(feature = "moderate-post")]
+pub use post_commands::moderate_post;
 #[cfg(feature = "paginate-posts")]
 pub use post_commands::paginate_posts;
+#[cfg(feature = "paginate-posts-for-moderation")]
+pub use post_commands::paginate_posts_for_moderation;
+#[cfg(feature = "render-post-diagrams")]
+pub use post_commands::render_post_diagrams;
+#[cfg(feature = "restore-post")]
+pub use post_commands::restore_post;
 #[cfg(feature = "search-posts")]
 pub use post_commands::search_posts;
 #[cfg(feature = "update-post")]
 pub use post_commands::update_post;
+#[cfg(feature = "enqueue-post-import")]
+pub use post_import_commands::enqueue_post_import;
+#[cfg(feature = "import-posts")]
+pub use post_import_commands::import_posts;
 #[cfg(feature = "delete-post-comment")]
 pub use post_comment_commands::delete_post_comment;
 #[cfg(feature = "get-post-comments-count")]
@@ -191,12 +350,32 @@ pub use post_reaction_commands::get_post_reaction_emojis_count;
 pub use post_reaction_commands::get_post_reactions_count;
 #[cfg(feature = "insert-or-update-post-reaction")]
 pub use post_reaction_commands::insert_or_update_post_reaction;
+#[cfg(feature = "anonymize-post-views")]
+pub use post_view_commands::anonymize_post_views;
+#[cfg(feature = "delete-all-expired-post-views")]
+pub use post_view_commands::delete_all_expired_post_views;
 #[cfg(feature = "get-or-insert-post-view")]
 pub use post_view_commands::get_or_insert_post_view;
 #[cfg(feature = "get-post-views-count")]
 pub use post_view_commands::get_post_views_count;
+#[cfg(feature = "insert-post-view-event")]
+pub use post_view_commands::insert_post_view_event;
+#[cfg(feature = "all-search-suggestions")]
+pub use search_commands::all_search_suggestions;
+#[cfg(feature = "all-text-search-languages")]
+pub use search_commands::all_text_search_languages;
+#[cfg(feature = "search-all")]
+pub use search_commands::search_all;
+#[cfg(feature = "generate-static-website")]
+pub use static_website_commands::delete_static_website;
+#[cfg(feature = "generate-static-website")]
+pub use static_website_commands::generate_static_website;
 #[cfg(feature = "all-admin-users")]
 pub use user_commands::all_admin_users;
+#[cfg(feature = "all-mentioned-users")]
+pub use user_commands::all_mentioned_users;
+#[cfg(feature = "all-mentioned-users-by-ids")]
+pub use user_commands::all_mentioned_users_by_ids;
 #[cfg(feature = "authenticate-user")]
 pub use user_commands::authenticate_user;
 #[cfg(feature = "clear-user-cache")]
@@ -207,14 +386,20 @@ pub use user_commands::confirm_user_email;
 pub use user_commands::disable_user;
 #[cfg(feature = "enable-user")]
 pub use user_commands::enable_user;
+#[cfg(feature = "get-used-user-storage")]
+pub use user_commands::get_used_user_storage;
 #[cfg(feature = "get-user-by-id")]
 pub use user_commands::get_user_by_id;
 #[cfg(feature = "get-user-by-username")]
 pub use user_commands::get_user_by_username;
 #[cfg(feature = "get-user-by-username-or-email")]
 pub use user_commands::get_user_by_username_or_email;
+#[cfg(feature = "get-user-invitation-allowance")]
+pub use user_commands::get_user_invitation_allowance;
 #[cfg(feature = "insert-user")]
 pub use user_commands::insert_user;
+#[cfg(feature = "notify-mentioned-users")]
+pub use user_commands::notify_mentioned_users;
 #[cfg(feature = "paginate-users")]
 pub use user_commands::paginate_users;
 #[cfg(feature = "reset-user-password")]
@@ -227,12 +412,16 @@ pub use user_commands::send_user_login_confirmation_code;
 pub use user_commands::send_user_password_reset_code;
 #[cfg(feature = "update-user-email")]
 pub use user_commands::update_user_email;
+#[cfg(feature = "update-user-invitation-allowance")]
+pub use user_commands::update_user_invitation_allowance;
 #[cfg(feature = "update-user-password")]
 pub use user_commands::update_user_password;
 #[cfg(feature = "update-user-profile")]
 pub use user_commands::update_user_profile;
 #[cfg(feature = "update-user-role")]
 pub use user_commands::update_user_role;
+#[cfg(feature = "update-user-storage-quota")]
+pub use user_commands::update_user_storage_quota;
 #[cfg(feature = "all-user-sessions-by-user")]
 pub use user_session_commands::all_user_sessions_by_user;
 #[cfg(feature = "delete-all-user-sessions")]
@@ -243,6 +432,16 @@ pub use user_session_commands::delete_user_session;
 pub use user_session_commands::get_user_session_by_id;
 #[cfg(feature = "insert-user-session")]
 pub use user_session_commands::insert_user_session;
+#[cfg(feature = "all-website-countries")]
+pub use website_analytics_commands::all_website_countries;
+#[cfg(feature = "all-website-daily-stats")]
+pub use website_analytics_commands::all_website_daily_stats;
+#[cfg(feature = "all-website-referrers")]
+pub use website_analytics_commands::all_website_referrers;
+#[cfg(feature = "all-website-top-posts")]
+pub use website_analytics_commands::all_website_top_posts;
+#[cfg(feature = "update-website-analytics")]
+pub use website_analytics_commands::update_website_analytics;
 #[cfg(feature = "clear-website-cache")]
 pub use website_commands::clear_website_cache;
 #[cfg(feature = "delete-website")]
@@ -253,13 +452,29 @@ pub use website_commands::get_used_website_storage;
 pub use website_commands::get_website_by_id;
 #[cfg(feature = "get-website-by-subdomain")]
 pub use website_commands::get_website_by_subdomain;
+#[cfg(feature = "get-website-max-storage")]
+pub use website_commands::get_website_max_storage;
 #[cfg(feature = "insert-website")]
 pub use website_commands::insert_website;
+#[cfg(feature = "moderate-website")]
+pub use website_commands::moderate_website;
 #[cfg(feature = "paginate-websites")]
 pub use website_commands::paginate_websites;
+#[cfg(feature = "paginate-websites-for-moderation")]
+pub use website_commands::paginate_websites_for_moderation;
 #[cfg(feature = "paginate-websites-sorted-by-name-asc")]
 pub use website_commands::paginate_websites_sorted_by_name_asc;
+#[cfg(feature = "restore-website")]
+pub use website_commands::restore_website;
 #[cfg(feature = "search-websites")]
 pub use website_commands::search_websites;
 #[cfg(feature = "update-website")]
-pub use website_commands::update_website;
+pub use website_commands::{update_website, WebsiteInput};
+#[cfg(feature = "update-website-storage-quota")]
+pub use website_commands::update_website_storage_quota;
+#[cfg(feature = "delete-all-expired-website-exports")]
+pub use website_export_commands::delete_all_expired_website_exports;
+#[cfg(feature = "enqueue-website-export")]
+pub use website_export_commands::enqueue_website_export;
+#[cfg(feature = "export-website")]
+pub use website_export_commands::export_website;
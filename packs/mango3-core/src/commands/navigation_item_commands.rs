@@ -141,6 +141,9 @@ pub async fn insert_or_update_many_navigation_items(
 
     let _ = delete_all_navigation_items(skip_from_removal, website).await;
 
+    #[cfg(feature = "jobs")]
+    crate::jobs().await.refresh_static_website(website.id).await;
+
     crate::mut_success!()
 }
 
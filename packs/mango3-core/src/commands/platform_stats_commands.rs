@@ -0,0 +1,226 @@
+use crate::models::*;
+use crate::CoreContext;
+
+#[cfg(feature = "all-job-queue-depths")]
+use apalis::prelude::Storage;
+#[cfg(feature = "all-job-queue-depths")]
+use apalis_redis::RedisStorage;
+#[cfg(feature = "all-platform-daily-stats")]
+use chrono::NaiveDate;
+#[cfg(feature = "all-job-queue-depths")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "all-job-queue-depths")]
+async fn job_queue_depth<T>(name: &str, storage: &RedisStorage<T>) -> JobQueueDepth
+where
+    T: Serialize + for<'de> Deserialize<'de> + Send + Sync + Unpin + 'static,
+{
+    JobQueueDepth {
+        name: name.to_owned(),
+        pending_count: storage.clone().len().await.unwrap_or_default(),
+    }
+}
+
+/// How many jobs are waiting to be processed in each queue. These are read straight from Redis, so they are not cached.
+#[cfg(feature = "all-job-queue-depths")]
+pub async fn all_job_queue_depths(core_context: &CoreContext) -> Vec<JobQueueDepth> {
+    let jobs = &core_context.jobs;
+
+    vec![
+        job_queue_depth("admin_mailer", &jobs.storage_admin_mailer).await,
+        job_queue_depth("export_website", &jobs.storage_export_website).await,
+        job_queue_depth("generate_static_website", &jobs.storage_generate_static_website).await,
+        job_queue_depth("guest_mailer", &jobs.storage_guest_mailer).await,
+        job_queue_depth("import_posts", &jobs.storage_import_posts).await,
+        job_queue_depth("mailer", &jobs.storage_mailer).await,
+        job_queue_depth("render_post_diagrams", &jobs.storage_render_post_diagrams).await,
+    ]
+}
+
+#[cfg(feature = "all-platform-daily-stats")]
+#[cached::proc_macro::io_cached(
+    map_error = r##"|_| sqlx::Error::RowNotFound"##,
+    convert = r#"{ since }"#,
+    ty = "cached::AsyncRedisCache<NaiveDate, PlatformDailyStats>",
+    create = r##" {
+        crate::async_redis_cache!(
+            crate::constants::PREFIX_ALL_PLATFORM_DAILY_STATS,
+            crate::config::CACHE_CONFIG.stats_ttl,
+            false
+        ).await
+    } "##
+)]
+async fn all_cached_platform_daily_stats(
+    core_context: &CoreContext,
+    since: NaiveDate,
+) -> sqlx::Result<PlatformDailyStats> {
+    sqlx::query_as!(
+        PlatformDailyStat,
+        r#"WITH users_per_day AS (
+            SELECT created_at::date AS day, COUNT(*) AS users_count
+            FROM users
+            WHERE created_at >= $1::date
+            GROUP BY created_at::date
+        ), websites_per_day AS (
+            SELECT created_at::date AS day, COUNT(*) AS websites_count
+            FROM websites
+            WHERE created_at >= $1::date
+            GROUP BY created_at::date
+        ), posts_per_day AS (
+            SELECT published_at::date AS day, COUNT(*) AS posts_count
+            FROM posts
+            WHERE published_at >= $1::date
+            GROUP BY published_at::date
+        ), comments_per_day AS (
+            SELECT created_at::date AS day, COUNT(*) AS comments_count
+            FROM post_comments
+            WHERE created_at >= $1::date
+            GROUP BY created_at::date
+        )
+        SELECT
+            d.day::date AS "day!",
+            COALESCE(u.users_count, 0) AS "users_count!",
+            COALESCE(w.websites_count, 0) AS "websites_count!",
+            COALESCE(p.posts_count, 0) AS "posts_count!",
+            COALESCE(c.comments_count, 0) AS "comments_count!"
+        FROM generate_series($1::date, current_date, interval '1 day') AS d(day)
+        LEFT JOIN users_per_day AS u ON u.day = d.day::date
+        LEFT JOIN websites_per_day AS w ON w.day = d.day::date
+        LEFT JOIN posts_per_day AS p ON p.day = d.day::date
+        LEFT JOIN comments_per_day AS c ON c.day = d.day::date
+        ORDER BY d.day ASC"#,
+        since, // $1
+    )
+    .fetch_all(&core_context.db_pool)
+    .await
+    .map(|stats| stats.into())
+}
+
+/// One stat per day since the given date until today, including the days without activity.
+#[cfg(feature = "all-platform-daily-stats")]
+pub async fn all_platform_daily_stats(core_context: &CoreContext, since: NaiveDate) -> Vec<PlatformDailyStat> {
+    all_cached_platform_daily_stats(core_context, since)
+        .await
+        .map(|stats| stats.into())
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "get-platform-stats")]
+#[cached::proc_macro::io_cached(
+    map_error = r##"|_| sqlx::Error::RowNotFound"##,
+    convert = r#"{ String::new() }"#,
+    ty = "cached::AsyncRedisCache<String, PlatformStats>",
+    create = r##" {
+        crate::async_redis_cache!(
+            crate::constants::PREFIX_GET_PLATFORM_STATS,
+            crate::config::CACHE_CONFIG.stats_ttl,
+            false
+        ).await
+    } "##
+)]
+pub async fn get_platform_stats(core_context: &CoreContext) -> sqlx::Result<PlatformStats> {
+    use crate::enums::UserRole;
+
+    let users_by_role = sqlx::query_as!(
+        PlatformRoleStat,
+        r#"SELECT role as "role!: UserRole", COUNT(*) AS "users_count!"
+        FROM users GROUP BY role ORDER BY role ASC"#
+    )
+    .fetch_all(&core_context.db_pool)
+    .await?;
+
+    let record = sqlx::query!(
+        r#"SELECT
+            (SELECT COUNT(*) FROM users) AS "users_count!",
+            (SELECT COUNT(*) FROM users WHERE disabled_at IS NOT NULL) AS "disabled_users_count!",
+            (SELECT COUNT(*) FROM users WHERE email_confirmed_at IS NULL) AS "unconfirmed_users_count!",
+            (SELECT COUNT(*) FROM websites) AS "websites_count!",
+            (
+                SELECT COUNT(*) FROM websites WHERE published_at IS NOT NULL AND suspended_at IS NULL
+            ) AS "published_websites_count!",
+            (
+                SELECT COUNT(*) FROM posts WHERE published_at IS NOT NULL AND suspended_at IS NULL
+            ) AS "published_posts_count!",
+            (SELECT COUNT(*) FROM post_comments) AS "comments_count!",
+            (SELECT COALESCE(SUM(byte_size), 0)::bigint FROM blobs) AS "used_bytes!""#
+    )
+    .fetch_one(&core_context.db_pool)
+    .await?;
+
+    Ok(PlatformStats {
+        users_count: record.users_count,
+        disabled_users_count: record.disabled_users_count,
+        unconfirmed_users_count: record.unconfirmed_users_count,
+        users_by_role,
+        websites_count: record.websites_count,
+        published_websites_count: record.published_websites_count,
+        published_posts_count: record.published_posts_count,
+        comments_count: record.comments_count,
+        used_bytes: record.used_bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Days, Utc};
+
+    use crate::models::JobQueueDepth;
+    use crate::test_utils::{fake_uuid, insert_test_post, setup_core_context};
+
+    use super::{all_job_queue_depths, all_platform_daily_stats, get_platform_stats};
+
+    #[tokio::test]
+    async fn should_get_platform_stats() {
+        let core_context = setup_core_context().await;
+        let _ = insert_test_post(&core_context, None, None).await;
+
+        let result = get_platform_stats(&core_context).await;
+
+        assert!(result.is_ok());
+
+        let stats = result.unwrap();
+
+        assert!(stats.users_count > 0);
+        assert!(stats.websites_count > 0);
+        assert!(!stats.users_by_role.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_get_all_platform_daily_stats() {
+        let core_context = setup_core_context().await;
+        let _ = insert_test_post(&core_context, None, None).await;
+        let today = Utc::now().date_naive();
+        let since = today.checked_sub_days(Days::new(6)).unwrap();
+
+        let daily_stats = all_platform_daily_stats(&core_context, since).await;
+
+        assert_eq!(daily_stats.len(), 7);
+        assert_eq!(daily_stats.first().unwrap().day, since);
+        assert_eq!(daily_stats.last().unwrap().day, today);
+    }
+
+    #[tokio::test]
+    async fn should_get_all_job_queue_depths() {
+        let core_context = setup_core_context().await;
+
+        let pending_count = |depths: &[JobQueueDepth]| {
+            depths
+                .iter()
+                .find(|depth| depth.name == "generate_static_website")
+                .unwrap()
+                .pending_count
+        };
+
+        let depths = all_job_queue_depths(&core_context).await;
+
+        assert_eq!(depths.len(), 7);
+
+        let pending_count_before = pending_count(&depths);
+
+        core_context.jobs.generate_static_website(fake_uuid()).await;
+
+        let depths = all_job_queue_depths(&core_context).await;
+
+        assert!(pending_count(&depths) > pending_count_before);
+    }
+}
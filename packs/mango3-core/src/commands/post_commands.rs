@@ -1,12 +1,31 @@
+#[cfg(feature = "insert-post")]
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::models::*;
 use crate::utils::*;
 use crate::CoreContext;
 
+#[cfg(feature = "moderate-post")]
+use crate::enums::ModerationAction;
+#[cfg(any(feature = "moderate-post", feature = "restore-post"))]
+use crate::enums::{AuditLogAction, AuditLogTargetType};
 #[cfg(feature = "insert-post")]
 use crate::enums::{Input, InputError};
 
+/// The fields of a post edited by its author.
+#[cfg(any(feature = "insert-post", feature = "update-post"))]
+pub struct PostInput<'a> {
+    pub title: &'a str,
+    pub slug: &'a str,
+    pub content: &'a str,
+    pub variables: &'a str,
+    pub language: &'a str,
+    pub blobs: Vec<Blob<'a>>,
+    pub cover_image_blob: Option<&'a Blob<'a>>,
+    pub publish: bool,
+}
+
 #[cfg(feature = "insert-post")]
 impl Validator {
     fn validate_post_title(&mut self, value: &str) -> bool {
@@ -46,6 +65,35 @@ impl Validator {
         }
     }
 
+    async fn validate_post_language(
+        &mut self,
+        core_context: &CoreContext,
+        post: Option<&Post>,
+        translation_group_id: Option<Uuid>,
+        language: &str,
+    ) -> bool {
+        if !self.validate_language(language).await {
+            return false;
+        }
+
+        let Some(translation_group_id) = translation_group_id else {
+            return true;
+        };
+
+        let id = post.map(|p| p.id);
+        let language_exists = sqlx::query!(
+            "SELECT id FROM posts WHERE ($1::uuid IS NULL OR id != $1) AND translation_group_id = $2
+            AND language = $3::varchar::regconfig LIMIT 1",
+            id,                   // $1
+            translation_group_id, // $2
+            language,             // $3
+        )
+        .fetch_one(&core_context.db_pool)
+        .await
+        .is_ok();
+        self.custom_validation(Input::Language, InputError::AlreadyInUse, &|| !language_exists)
+    }
+
     fn validate_post_content(&mut self, value: &str) -> bool {
         self.validate_length(
             Input::Content,
@@ -76,7 +124,7 @@ async fn clear_post_cache(core_context: &CoreContext, post: &Post) {
         async {
             let website = post.website(core_context).await.expect("Could not get website");
 
-            GET_POST_BY_SLUG
+            GET_CACHED_POST_BY_SLUG
                 .cache_remove(
                     PREFIX_GET_POST_BY_SLUG,
                     &cache_key_get_post_by_slug(&post.slug, &website),
@@ -87,6 +135,47 @@ async fn clear_post_cache(core_context: &CoreContext, post: &Post) {
     .await;
 }
 
+/// Returns the other posts of the translation set of the given post, sorted by language.
+#[cfg(feature = "all-post-translations")]
+pub async fn all_post_translations(core_context: &CoreContext, post: &Post, is_published: Option<bool>) -> Vec<Post> {
+    sqlx::query_as!(
+        Post,
+        r#"SELECT
+            id,
+            website_id,
+            user_id,
+            translation_group_id,
+            language::varchar as "language!",
+            title,
+            slug,
+            content,
+            variables,
+            hashtag_ids,
+            mentioned_user_ids,
+            cover_image_blob_id,
+            blob_ids,
+            published_at,
+            suspended_at,
+            moderation_reason,
+            modified_at,
+            NULL::real AS search_rank,
+            created_at,
+            updated_at
+        FROM posts
+        WHERE translation_group_id = $1 AND id != $2 AND (
+            $3::bool IS NULL OR ($3 IS TRUE AND published_at IS NOT NULL AND suspended_at IS NULL)
+            OR ($3 IS FALSE AND published_at IS NULL)
+        )
+        ORDER BY language::varchar"#,
+        post.translation_group_id, // $1
+        post.id,                   // $2
+        is_published,              // $3
+    )
+    .fetch_all(&core_context.db_pool)
+    .await
+    .unwrap_or_default()
+}
+
 #[cfg(feature = "delete-post")]
 pub async fn delete_post(core_context: &CoreContext, post: &Post) -> MutResult {
     sqlx::query!("DELETE FROM posts WHERE id = $1", post.id)
@@ -95,6 +184,9 @@ pub async fn delete_post(core_context: &CoreContext, post: &Post) -> MutResult {
 
     clear_post_cache(core_context, post).await;
 
+    #[cfg(feature = "jobs")]
+    core_context.jobs.refresh_static_website(post.website_id).await;
+
     crate::mut_success!()
 }
 
@@ -112,15 +204,19 @@ async fn get_cached_post_by_id(core_context: &CoreContext, id: Uuid) -> sqlx::Re
             id,
             website_id,
             user_id,
+            translation_group_id,
             language::varchar AS "language!",
             title,
             slug,
             content,
             variables,
             hashtag_ids,
+            mentioned_user_ids,
             cover_image_blob_id,
             blob_ids,
             published_at,
+            suspended_at,
+            moderation_reason,
             modified_at,
             NULL::real AS search_rank,
             created_at,
@@ -181,17 +277,21 @@ pub async fn get_post_by_id_with_search_rank(
             id,
             website_id,
             user_id,
+            translation_group_id,
             language::varchar AS "language!",
             title,
             slug,
             content,
             variables,
             hashtag_ids,
+            mentioned_user_ids,
             cover_image_blob_id,
             blob_ids,
             published_at,
+            suspended_at,
+            moderation_reason,
             modified_at,
-            ts_rank(search, websearch_to_tsquery($5)) AS search_rank,
+            ts_rank(search, websearch_to_tsquery(language, $5)) AS search_rank,
             created_at,
             updated_at
         FROM posts
@@ -219,7 +319,7 @@ pub async fn get_post_by_id_with_search_rank(
     ty = "cached::AsyncRedisCache<String, Post>",
     create = r##" { crate::async_redis_cache!(crate::constants::PREFIX_GET_POST_BY_SLUG).await } "##
 )]
-pub async fn get_post_by_slug(core_context: &CoreContext, slug: &str, website: &Website) -> sqlx::Result<Post> {
+async fn get_cached_post_by_slug(core_context: &CoreContext, slug: &str, website: &Website) -> sqlx::Result<Post> {
     if slug.is_empty() {
         return Err(sqlx::Error::RowNotFound);
     }
@@ -230,20 +330,25 @@ pub async fn get_post_by_slug(core_context: &CoreContext, slug: &str, website: &
             id,
             website_id,
             user_id,
+            translation_group_id,
             language::varchar as "language!",
             title,
             slug,
             content,
             variables,
             hashtag_ids,
+            mentioned_user_ids,
             cover_image_blob_id,
             blob_ids,
             published_at,
+            suspended_at,
+            moderation_reason,
             modified_at,
             NULL::real AS search_rank,
             created_at,
             updated_at
-        FROM posts WHERE slug = $1 AND website_id = $2 AND published_at IS NOT NULL LIMIT 1"#,
+        FROM posts WHERE slug = $1 AND website_id = $2 AND published_at IS NOT NULL AND suspended_at IS NULL
+        LIMIT 1"#,
         slug,       // $1
         website.id  // $2
     )
@@ -251,35 +356,70 @@ pub async fn get_post_by_slug(core_context: &CoreContext, slug: &str, website: &
     .await
 }
 
+/// Returns the published post with the given slug. When a language code is given and the post has a published
+/// translation in that language, the translation is returned instead.
+#[cfg(feature = "get-post-by-slug")]
+pub async fn get_post_by_slug(
+    core_context: &CoreContext,
+    slug: &str,
+    website: &Website,
+    language_code: Option<&str>,
+) -> sqlx::Result<Post> {
+    let post = get_cached_post_by_slug(core_context, slug, website).await?;
+
+    let Some(language_code) = language_code.filter(|code| *code != post.language_code()) else {
+        return Ok(post);
+    };
+
+    Ok(super::all_post_translations(core_context, &post, Some(true))
+        .await
+        .into_iter()
+        .find(|translation| translation.language_code() == language_code)
+        .unwrap_or(post))
+}
+
 #[cfg(feature = "insert-post")]
 pub async fn insert_post(
     core_context: &CoreContext,
     website: &Website,
     user: &User,
-    title: &str,
-    slug: &str,
-    content: &str,
-    variables: &str,
-    blobs: Vec<Blob<'_>>,
-    cover_image_blob: Option<&Blob<'_>>,
-    publish: bool,
+    input: PostInput<'_>,
+    published_at: Option<DateTime<Utc>>,
+    translation_of: Option<&Post>,
 ) -> MutResult<Post> {
     let mut validator = crate::validator!();
 
+    let PostInput {
+        title,
+        slug,
+        content,
+        variables,
+        language,
+        blobs,
+        cover_image_blob,
+        publish,
+    } = input;
+
     let title = title.trim();
     let slug = slug.trim().to_lowercase();
     let content = content.trim();
     let variables = variables.parse::<serde_json::Value>().ok();
     let cover_image_blob_id = cover_image_blob.map(|blob| blob.id);
+    let translation_group_id = translation_of.map(|post| post.translation_group_id);
 
     let hashtags = super::get_or_insert_many_hashtags(content).await?;
     let hashtag_ids = hashtags.data.iter().map(|hashtag| hashtag.id).collect::<Vec<Uuid>>();
+    let mentioned_users = super::all_mentioned_users(core_context, content).await;
+    let mentioned_user_ids = mentioned_users.iter().map(|user| user.id).collect::<Vec<Uuid>>();
     let blob_ids = blobs.iter().map(|blob| blob.id).collect::<Vec<Uuid>>();
 
     validator.validate_post_title(title);
     validator.validate_post_slug(core_context, None, website, &slug).await;
     validator.validate_post_content(content);
     validator.validate_post_variables(variables.as_ref());
+    validator
+        .validate_post_language(core_context, None, translation_group_id, language)
+        .await;
 
     if !validator.is_valid {
         return crate::mut_error!(validator.errors);
@@ -290,49 +430,188 @@ pub async fn insert_post(
         r#"INSERT INTO posts (
                 website_id,
                 user_id,
+                translation_group_id,
+                language,
                 title,
                 slug,
                 content,
                 variables,
                 hashtag_ids,
+                mentioned_user_ids,
                 cover_image_blob_id,
                 blob_ids,
-                published_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, CASE WHEN $10 IS TRUE THEN current_timestamp ELSE NULL END)
+                published_at,
+                created_at
+            ) VALUES (
+                $1,
+                $2,
+                COALESCE($14, gen_random_uuid()),
+                $13::varchar::regconfig,
+                $3,
+                $4,
+                $5,
+                $6,
+                $7,
+                $8,
+                $9,
+                $10,
+                CASE WHEN $11 IS TRUE THEN COALESCE($12, current_timestamp) ELSE NULL END,
+                COALESCE($12, current_timestamp)
+            )
             RETURNING
                 id,
                 website_id,
                 user_id,
+                translation_group_id,
                 language::varchar as "language!",
                 title,
                 slug,
                 content,
                 variables,
                 hashtag_ids,
+                mentioned_user_ids,
                 cover_image_blob_id,
                 blob_ids,
                 published_at,
+                suspended_at,
+                moderation_reason,
                 modified_at,
                 NULL::real AS search_rank,
                 created_at,
                 updated_at"#,
-        website.id,          // $1
-        user.id,             // $2
-        title,               // $3
-        slug,                // $4
-        content,             // $5
-        variables.unwrap(),  // $6
-        &hashtag_ids,        // $7
-        cover_image_blob_id, // $8
-        &blob_ids,           // $9
-        publish,             // $10
+        website.id,           // $1
+        user.id,              // $2
+        title,                // $3
+        slug,                 // $4
+        content,              // $5
+        variables.unwrap(),   // $6
+        &hashtag_ids,         // $7
+        &mentioned_user_ids,  // $8
+        cover_image_blob_id,  // $9
+        &blob_ids,            // $10
+        publish,              // $11
+        published_at,         // $12
+        language,             // $13
+        translation_group_id, // $14
     )
     .fetch_one(&core_context.db_pool)
     .await;
 
+    if let Ok(ref post) = result {
+        post.render_diagrams(core_context).await;
+
+        #[cfg(feature = "jobs")]
+        core_context.jobs.refresh_static_website(post.website_id).await;
+
+        if post.is_published(core_context).await {
+            super::notify_mentioned_users(core_context, user, &mentioned_users, &post.url(core_context).await).await;
+        }
+    }
+
     crate::mut_result!(result)
 }
 
+#[cfg(feature = "moderate-post")]
+pub async fn moderate_post(
+    core_context: &CoreContext,
+    post: &Post,
+    action: ModerationAction,
+    reason: &str,
+    actor: &AuditActor,
+) -> MutResult<Post> {
+    let mut validator = crate::validator!();
+
+    let reason = reason.trim();
+
+    validator.validate_moderation_reason(reason);
+
+    if !validator.is_valid {
+        return crate::mut_error!(validator.errors);
+    }
+
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    let result = sqlx::query_as!(
+        Post,
+        r#"UPDATE posts SET
+            published_at = CASE WHEN $2 IS TRUE THEN published_at ELSE NULL END,
+            suspended_at = CASE WHEN $2 IS TRUE THEN current_timestamp ELSE NULL END,
+            moderation_reason = $3
+        WHERE id = $1
+        RETURNING
+            id,
+            website_id,
+            user_id,
+            translation_group_id,
+            language::varchar AS "language!",
+            title,
+            slug,
+            content,
+            variables,
+            hashtag_ids,
+            mentioned_user_ids,
+            cover_image_blob_id,
+            blob_ids,
+            published_at,
+            suspended_at,
+            moderation_reason,
+            modified_at,
+            NULL::real AS search_rank,
+            created_at,
+            updated_at"#,
+        post.id,                             // $1
+        action == ModerationAction::Suspend, // $2
+        reason,                              // $3
+    )
+    .fetch_one(&mut *transaction)
+    .await;
+
+    match result {
+        Ok(post1) => {
+            super::insert_audit_log(
+                &mut *transaction,
+                actor,
+                AuditLogAction::ModeratePost,
+                AuditLogTargetType::Post,
+                post.id,
+                Some(serde_json::json!({
+                    "published_at": post.published_at,
+                    "suspended_at": post.suspended_at,
+                    "moderation_reason": post.moderation_reason,
+                })),
+                Some(serde_json::json!({
+                    "published_at": post1.published_at,
+                    "suspended_at": post1.suspended_at,
+                    "moderation_reason": post1.moderation_reason,
+                })),
+            )
+            .await?;
+
+            transaction.commit().await?;
+
+            clear_post_cache(core_context, post).await;
+            core_context.jobs.refresh_static_website(post.website_id).await;
+
+            if let Ok(user) = post.user(core_context).await {
+                core_context
+                    .jobs
+                    .mailer(
+                        &user,
+                        crate::enums::MailerJobCommand::Moderated {
+                            action,
+                            target_name: post.title.clone(),
+                            reason: reason.to_owned(),
+                        },
+                    )
+                    .await;
+            }
+
+            crate::mut_success!(post1)
+        }
+        Err(_) => crate::mut_error!(),
+    }
+}
+
 #[cfg(feature = "paginate-posts")]
 pub async fn paginate_posts<'a>(
     core_context: &'a CoreContext,
@@ -365,15 +644,19 @@ pub async fn paginate_posts<'a>(
                         id,
                         website_id,
                         user_id,
+                        translation_group_id,
                         language::varchar as "language!",
                         title,
                         slug,
                         content,
                         variables,
                         hashtag_ids,
+                        mentioned_user_ids,
                         cover_image_blob_id,
                         blob_ids,
                         published_at,
+                        suspended_at,
+                        moderation_reason,
                         modified_at,
                         NULL::real AS search_rank,
                         created_at,
@@ -383,6 +666,11 @@ pub async fn paginate_posts<'a>(
                         AND ($3::uuid IS NULL OR $3 = ANY(hashtag_ids)) AND (
                             $4::bool IS NULL OR ($4 IS TRUE AND published_at IS NOT NULL)
                             OR ($4 IS FALSE AND published_at IS NULL)
+                        ) AND (
+                            ($4 IS NOT TRUE AND ($1::uuid IS NOT NULL OR $2::uuid IS NOT NULL)) OR (
+                                suspended_at IS NULL
+                                AND website_id IN (SELECT id FROM websites WHERE suspended_at IS NULL)
+                            )
                         ) AND ($6::timestamptz IS NULL OR created_at < $6 OR (created_at = $6 AND id < $5))
                     ORDER BY created_at DESC, id DESC LIMIT $7"#,
                 website_id,        // $1
@@ -401,6 +689,157 @@ pub async fn paginate_posts<'a>(
     .await
 }
 
+/// Lists all posts, including unpublished and suspended ones, so it must only be used by staff.
+#[cfg(feature = "paginate-posts-for-moderation")]
+pub async fn paginate_posts_for_moderation<'a>(
+    core_context: &'a CoreContext,
+    page_params: &CursorPageParams,
+    query: &'a str,
+) -> CursorPage<Post> {
+    crate::cursor_page!(
+        core_context,
+        page_params,
+        |node: Post| node.id,
+        move |core_context, after| async move { get_post_by_id(core_context, after, None, None, None).await.ok() },
+        move |core_context, cursor_resource, limit| async move {
+            let (cursor_id, cursor_created_at) = cursor_resource
+                .map(|c| (Some(c.id), Some(c.created_at)))
+                .unwrap_or_default();
+
+            sqlx::query_as!(
+                Post,
+                r#"SELECT
+                    id,
+                    website_id,
+                    user_id,
+                    translation_group_id,
+                    language::varchar AS "language!",
+                    title,
+                    slug,
+                    content,
+                    variables,
+                    hashtag_ids,
+                    mentioned_user_ids,
+                    cover_image_blob_id,
+                    blob_ids,
+                    published_at,
+                    suspended_at,
+                    moderation_reason,
+                    modified_at,
+                    NULL::real AS search_rank,
+                    created_at,
+                    updated_at
+                FROM posts
+                WHERE ($1 = '' OR id::text = $1 OR title ILIKE '%' || $1 || '%' OR slug ILIKE '%' || $1 || '%')
+                    AND ($3::timestamptz IS NULL OR created_at < $3 OR (created_at = $3 AND id < $2))
+                ORDER BY created_at DESC, id DESC LIMIT $4"#,
+                query.trim(),      // $1
+                cursor_id,         // $2
+                cursor_created_at, // $3
+                limit,             // $4
+            )
+            .fetch_all(&core_context.db_pool)
+            .await
+            .unwrap_or_default()
+        },
+    )
+    .await
+}
+
+/// Renders again the diagrams of the post that expired from the cache, clearing its content HTML once all of them are
+/// rendered so they are shown instead of code blocks.
+#[cfg(feature = "render-post-diagrams")]
+pub async fn render_post_diagrams(core_context: &CoreContext, post: &Post) -> MutResult {
+    if !post.render_diagrams(core_context).await {
+        return crate::mut_error!();
+    }
+
+    clear_post_cache(core_context, post).await;
+
+    crate::mut_success!()
+}
+
+#[cfg(feature = "restore-post")]
+pub async fn restore_post(core_context: &CoreContext, post: &Post, actor: &AuditActor) -> MutResult<Post> {
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    let result = sqlx::query_as!(
+        Post,
+        r#"UPDATE posts SET
+            published_at = CASE WHEN suspended_at IS NULL THEN current_timestamp ELSE published_at END,
+            suspended_at = NULL,
+            moderation_reason = NULL
+        WHERE id = $1 AND moderation_reason IS NOT NULL
+        RETURNING
+            id,
+            website_id,
+            user_id,
+            translation_group_id,
+            language::varchar AS "language!",
+            title,
+            slug,
+            content,
+            variables,
+            hashtag_ids,
+            mentioned_user_ids,
+            cover_image_blob_id,
+            blob_ids,
+            published_at,
+            suspended_at,
+            moderation_reason,
+            modified_at,
+            NULL::real AS search_rank,
+            created_at,
+            updated_at"#,
+        post.id, // $1
+    )
+    .fetch_one(&mut *transaction)
+    .await;
+
+    match result {
+        Ok(post1) => {
+            super::insert_audit_log(
+                &mut *transaction,
+                actor,
+                AuditLogAction::RestorePost,
+                AuditLogTargetType::Post,
+                post.id,
+                Some(serde_json::json!({
+                    "published_at": post.published_at,
+                    "suspended_at": post.suspended_at,
+                    "moderation_reason": post.moderation_reason,
+                })),
+                Some(serde_json::json!({
+                    "published_at": post1.published_at,
+                    "suspended_at": post1.suspended_at,
+                    "moderation_reason": post1.moderation_reason,
+                })),
+            )
+            .await?;
+
+            transaction.commit().await?;
+
+            clear_post_cache(core_context, post).await;
+            core_context.jobs.refresh_static_website(post.website_id).await;
+
+            if let Ok(user) = post.user(core_context).await {
+                core_context
+                    .jobs
+                    .mailer(
+                        &user,
+                        crate::enums::MailerJobCommand::Restored {
+                            target_name: post.title.clone(),
+                        },
+                    )
+                    .await;
+            }
+
+            crate::mut_success!(post1)
+        }
+        Err(_) => crate::mut_error!(),
+    }
+}
+
 #[cfg(feature = "search-posts")]
 pub async fn search_posts<'a>(
     core_context: &'a CoreContext,
@@ -432,17 +871,21 @@ pub async fn search_posts<'a>(
                     id,
                     website_id,
                     user_id,
+                    translation_group_id,
                     language::varchar as "language!",
                     title,
                     slug,
                     content,
                     variables,
                     hashtag_ids,
+                    mentioned_user_ids,
                     cover_image_blob_id,
                     blob_ids,
                     published_at,
+                    suspended_at,
+                    moderation_reason,
                     modified_at,
-                    ts_rank(search, websearch_to_tsquery($4)) AS search_rank,
+                    ts_rank(search, websearch_to_tsquery(language, $4)) AS search_rank,
                     created_at,
                     updated_at
                 FROM posts
@@ -451,14 +894,19 @@ pub async fn search_posts<'a>(
                         $3::bool IS NULL OR ($3 IS TRUE AND published_at IS NOT NULL)
                         OR ($3 IS FALSE AND published_at IS NULL)
                     ) AND (
-                        search @@ websearch_to_tsquery($4)
+                        ($3 IS NOT TRUE AND ($1::uuid IS NOT NULL OR $2::uuid IS NOT NULL)) OR (
+                            suspended_at IS NULL
+                            AND website_id IN (SELECT id FROM websites WHERE suspended_at IS NULL)
+                        )
+                    ) AND (
+                        search @@ websearch_to_tsquery(language, $4)
                         OR title ILIKE '%' || $4 || '%'
                         OR slug ILIKE '%' || $4 || '%'
                         OR content ILIKE '%' || $4 || '%'
                     ) AND (
                         ($5::uuid IS NULL OR $6::real IS NULL OR $7::timestamptz IS NULL)
-                        OR ts_rank(search, websearch_to_tsquery($4)) < $6 OR (
-                            ts_rank(search, websearch_to_tsquery($4)) = $6 AND (
+                        OR ts_rank(search, websearch_to_tsquery(language, $4)) < $6 OR (
+                            ts_rank(search, websearch_to_tsquery(language, $4)) = $6 AND (
                                 created_at < $7 OR (created_at = $7 AND id < $5)
                             )
                         )
@@ -485,16 +933,21 @@ pub async fn search_posts<'a>(
 pub async fn update_post(
     core_context: &CoreContext,
     post: &Post,
-    title: &str,
-    slug: &str,
-    content: &str,
-    variables: &str,
-    blobs: Vec<Blob<'_>>,
-    cover_image_blob: Option<&Blob<'_>>,
-    publish: bool,
+    input: PostInput<'_>,
 ) -> crate::utils::MutResult<Post> {
     let mut validator = crate::validator!();
 
+    let PostInput {
+        title,
+        slug,
+        content,
+        variables,
+        language,
+        blobs,
+        cover_image_blob,
+        publish,
+    } = input;
+
     let title = title.trim();
     let slug = slug.trim().to_lowercase();
     let content = content.trim();
@@ -503,6 +956,8 @@ pub async fn update_post(
 
     let hashtags = super::get_or_insert_many_hashtags(content).await?;
     let hashtag_ids = hashtags.data.iter().map(|hashtag| hashtag.id).collect::<Vec<Uuid>>();
+    let mentioned_users = super::all_mentioned_users(core_context, content).await;
+    let mentioned_user_ids = mentioned_users.iter().map(|user| user.id).collect::<Vec<Uuid>>();
     let blob_ids = blobs.iter().map(|blob| blob.id).collect::<Vec<Uuid>>();
 
     validator.validate_post_title(title);
@@ -516,11 +971,18 @@ pub async fn update_post(
         .await;
     validator.validate_post_content(content);
     validator.validate_post_variables(variables.as_ref());
+    validator
+        .validate_post_language(core_context, Some(post), Some(post.translation_group_id), language)
+        .await;
 
     if !validator.is_valid {
         return crate::mut_error!(validator.errors);
     }
 
+    let Ok(author) = post.user(core_context).await else {
+        return crate::mut_error!();
+    };
+
     let result = sqlx::query_as!(
         Post,
         r#"UPDATE posts SET
@@ -529,28 +991,35 @@ pub async fn update_post(
             content = $4,
             variables = $5,
             hashtag_ids = $6,
-            cover_image_blob_id = $7,
-            blob_ids = $8,
+            mentioned_user_ids = $7,
+            cover_image_blob_id = $8,
+            blob_ids = $9,
             published_at = CASE
-                WHEN $9 IS TRUE AND published_at IS NOT NULL THEN published_at
-                WHEN $9 IS TRUE THEN current_timestamp
+                WHEN moderation_reason IS NOT NULL THEN published_at
+                WHEN $10 IS TRUE AND published_at IS NOT NULL THEN published_at
+                WHEN $10 IS TRUE THEN current_timestamp
                 ELSE NULL
             END,
-            modified_at = CASE WHEN $9 IS TRUE THEN current_timestamp ELSE NULL END
+            modified_at = CASE WHEN $10 IS TRUE THEN current_timestamp ELSE NULL END,
+            language = $11::varchar::regconfig
         WHERE id = $1
         RETURNING
             id,
             website_id,
             user_id,
+            translation_group_id,
             language::varchar as "language!",
             title,
             slug,
             content,
             variables,
             hashtag_ids,
+            mentioned_user_ids,
             cover_image_blob_id,
             blob_ids,
             published_at,
+            suspended_at,
+            moderation_reason,
             modified_at,
             NULL::real AS search_rank,
             created_at,
@@ -561,17 +1030,41 @@ pub async fn update_post(
         content,             // $4
         variables,           // $5
         &hashtag_ids,        // $6
-        cover_image_blob_id, // $7
-        &blob_ids,           // $8
-        publish,             // $9
+        &mentioned_user_ids, // $7
+        cover_image_blob_id, // $8
+        &blob_ids,           // $9
+        publish,             // $10
+        language,            // $11
     )
     .fetch_one(&core_context.db_pool)
     .await;
 
     match result {
         Ok(post1) => {
+            post1.render_diagrams(core_context).await;
+
             clear_post_cache(core_context, post).await;
 
+            #[cfg(feature = "jobs")]
+            core_context.jobs.refresh_static_website(post.website_id).await;
+
+            // Only users added to the mentions are notified, so editing or publishing the post again doesn't notify
+            // the same users twice.
+            if post1.is_published(core_context).await {
+                let new_mentioned_users = mentioned_users
+                    .into_iter()
+                    .filter(|user| !post.mentioned_user_ids.contains(&user.id))
+                    .collect::<Vec<User>>();
+
+                super::notify_mentioned_users(
+                    core_context,
+                    &author,
+                    &new_mentioned_users,
+                    &post1.url(core_context).await,
+                )
+                .await;
+            }
+
             crate::mut_success!(post1)
         }
         Err(_) => crate::mut_error!(),
@@ -580,11 +1073,16 @@ pub async fn update_post(
 
 #[cfg(test)]
 mod tests {
+    use crate::commands::paginate_audit_logs;
+    use crate::enums::{AuditLogAction, ModerationAction};
+    use crate::models::AuditActor;
     use crate::test_utils::{fake_uuid, insert_test_post, insert_test_user, insert_test_website, setup_core_context};
     use crate::utils::CursorPageParams;
 
     use super::{
-        delete_post, get_post_by_id, get_post_by_id_with_search_rank, get_post_by_slug, paginate_posts, search_posts,
+        all_post_translations, delete_post, get_post_by_id, get_post_by_id_with_search_rank, get_post_by_slug,
+        insert_post, moderate_post, paginate_posts, paginate_posts_for_moderation, render_post_diagrams, restore_post,
+        search_posts, PostInput,
     };
 
     #[tokio::test]
@@ -657,7 +1155,7 @@ mod tests {
         let website = insert_test_website(&core_context, None).await;
         let post = insert_test_post(&core_context, None, None).await;
 
-        let result = get_post_by_slug(&core_context, &post.slug, &website).await;
+        let result = get_post_by_slug(&core_context, &post.slug, &website, None).await;
 
         assert!(result.is_err());
     }
@@ -739,4 +1237,241 @@ mod tests {
 
         assert_eq!(cursor_page.nodes.len(), 1);
     }
+
+    #[tokio::test]
+    async fn should_not_insert_post_when_language_is_invalid() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+
+        let result = insert_post(
+            &core_context,
+            &website,
+            &user,
+            PostInput {
+                title: "Lorem ipsum",
+                slug: "lorem-ipsum",
+                content: "Lorem ipsum dolor sit amet.",
+                variables: "{}",
+                language: "klingon",
+                blobs: vec![],
+                cover_image_blob: None,
+                publish: true,
+            },
+            None,
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_insert_post_translation() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let post = insert_test_post(&core_context, Some(&website), Some(&user)).await;
+
+        let translation = insert_post(
+            &core_context,
+            &website,
+            &user,
+            PostInput {
+                title: &post.title,
+                slug: &format!("{}-es", post.slug),
+                content: &post.content,
+                variables: "{}",
+                language: "spanish",
+                blobs: vec![],
+                cover_image_blob: None,
+                publish: true,
+            },
+            None,
+            Some(&post),
+        )
+        .await
+        .ok()
+        .expect("Could not insert post translation")
+        .data;
+
+        assert_eq!(translation.translation_group_id, post.translation_group_id);
+
+        let translations = all_post_translations(&core_context, &post, Some(true)).await;
+
+        assert_eq!(translations.len(), 1);
+        assert_eq!(translations[0].id, translation.id);
+
+        let result = get_post_by_slug(&core_context, &post.slug, &website, Some("es")).await;
+
+        assert_eq!(result.unwrap().id, translation.id);
+    }
+
+    #[tokio::test]
+    async fn should_not_insert_post_translation_when_language_is_already_in_use() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let post = insert_test_post(&core_context, Some(&website), Some(&user)).await;
+
+        let result = insert_post(
+            &core_context,
+            &website,
+            &user,
+            PostInput {
+                title: &post.title,
+                slug: &format!("{}-copy", post.slug),
+                content: &post.content,
+                variables: "{}",
+                language: &post.language,
+                blobs: vec![],
+                cover_image_blob: None,
+                publish: true,
+            },
+            None,
+            Some(&post),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_suspend_post() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let post = insert_test_post(&core_context, Some(&website), Some(&user)).await;
+
+        let result = moderate_post(
+            &core_context,
+            &post,
+            ModerationAction::Suspend,
+            "Spam",
+            &AuditActor::default(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().data.is_suspended());
+
+        let cursor_page = paginate_posts(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(&website),
+            Some(&user),
+            None,
+            None,
+        )
+        .await;
+
+        assert!(cursor_page
+            .nodes
+            .iter()
+            .any(|node| node.id == post.id && node.is_suspended()));
+
+        let cursor_page = paginate_posts(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(&website),
+            None,
+            None,
+            Some(true),
+        )
+        .await;
+
+        assert!(cursor_page.nodes.is_empty());
+
+        let cursor_page = paginate_posts(&core_context, &CursorPageParams::default(), None, None, None, None).await;
+
+        assert!(!cursor_page.nodes.iter().any(|node| node.id == post.id));
+
+        let cursor_page = paginate_posts_for_moderation(&core_context, &CursorPageParams::default(), &post.slug).await;
+
+        assert!(cursor_page.nodes.iter().any(|node| node.id == post.id));
+
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::ModeratePost),
+            None,
+            Some(post.id),
+        )
+        .await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_moderate_post_when_reason_is_empty() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+
+        let result = moderate_post(
+            &core_context,
+            &post,
+            ModerationAction::Unpublish,
+            " ",
+            &AuditActor::default(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_render_post_diagrams() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+
+        let result = render_post_diagrams(&core_context, &post).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_restore_post() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let post = moderate_post(
+            &core_context,
+            &post,
+            ModerationAction::Unpublish,
+            "Spam",
+            &AuditActor::default(),
+        )
+        .await
+        .unwrap()
+        .data;
+
+        let result = restore_post(&core_context, &post, &AuditActor::default()).await;
+
+        assert!(result.is_ok());
+
+        let post = result.unwrap().data;
+
+        assert!(post.moderation_reason.is_none());
+        assert!(post.published_at.is_some());
+
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::RestorePost),
+            None,
+            Some(post.id),
+        )
+        .await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_restore_post_when_is_not_moderated() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+
+        let result = restore_post(&core_context, &post, &AuditActor::default()).await;
+
+        assert!(result.is_err());
+    }
 }
@@ -24,7 +24,7 @@ pub async fn get_post_comment_by_id(id: uuid::Uuid, user: Option<&User>) -> sqlx
 
     sqlx::query_as!(
         PostComment,
-        "SELECT id, post_id, user_id, content, created_at, updated_at
+        "SELECT id, post_id, user_id, content, mentioned_user_ids, created_at, updated_at
         FROM post_comments WHERE id = $1 AND ($2::uuid IS NULL OR user_id = $2) LIMIT 1",
         id,      // $1
         user_id, // $2
@@ -49,6 +49,7 @@ pub async fn get_post_comments_count(post: &Post) -> i64 {
 
 #[cfg(feature = "insert-post-comment")]
 pub async fn insert_post_comment<'a>(
+    core_context: &crate::CoreContext,
     post: &Post,
     user: &User,
     content: &str,
@@ -56,7 +57,6 @@ pub async fn insert_post_comment<'a>(
     use crate::enums::Input;
     use crate::utils::ValidatorTrait;
 
-    let db_pool = crate::db_pool().await;
     let mut validator = crate::validator!();
     let content = content.trim();
 
@@ -73,17 +73,27 @@ pub async fn insert_post_comment<'a>(
         return crate::mut_error!(validator.errors);
     }
 
+    let mentioned_users = super::all_mentioned_users(core_context, content).await;
+    let mentioned_user_ids = mentioned_users.iter().map(|user| user.id).collect::<Vec<uuid::Uuid>>();
+
     let result = sqlx::query_as!(
         PostComment,
-        "INSERT INTO post_comments (post_id, user_id, content) VALUES ($1, $2, $3) RETURNING
-            id, post_id, user_id, content, created_at, updated_at",
-        post.id, // $1
-        user.id, // $2
-        content, // $3
+        "INSERT INTO post_comments (post_id, user_id, content, mentioned_user_ids, language)
+        VALUES ($1, $2, $3, $4, $5::varchar::regconfig) RETURNING
+            id, post_id, user_id, content, mentioned_user_ids, created_at, updated_at",
+        post.id,             // $1
+        user.id,             // $2
+        content,             // $3
+        &mentioned_user_ids, // $4
+        post.language,       // $5
     )
-    .fetch_one(db_pool)
+    .fetch_one(&core_context.db_pool)
     .await;
 
+    if result.is_ok() {
+        super::notify_mentioned_users(core_context, user, &mentioned_users, &post.url(core_context).await).await;
+    }
+
     crate::mut_result!(result)
 }
 
@@ -108,7 +118,7 @@ pub async fn paginate_post_comments<'a>(
 
             sqlx::query_as!(
                 PostComment,
-                r#"SELECT id, post_id, user_id, content, created_at, updated_at
+                r#"SELECT id, post_id, user_id, content, mentioned_user_ids, created_at, updated_at
                 FROM post_comments
                 WHERE ($1::uuid IS NULL OR post_id = $1) AND ($2::uuid IS NULL OR user_id = $2)
                     AND ($4::timestamptz IS NULL OR created_at < $4 OR (created_at = $4 AND id < $3))
@@ -130,7 +140,7 @@ pub async fn paginate_post_comments<'a>(
 #[cfg(test)]
 mod tests {
     use crate::test_utils::{
-        fake_paragraph, insert_test_post, insert_test_post_comment, insert_test_user, setup_core_context,
+        fake_paragraph, fake_uuid, insert_test_post, insert_test_post_comment, insert_test_user, setup_core_context,
     };
     use crate::utils::CursorPageParams;
 
@@ -153,9 +163,30 @@ mod tests {
         let user = insert_test_user(&core_context).await;
         let content = fake_paragraph();
 
-        let result = insert_post_comment(&post, &user, &content).await;
+        let result = insert_post_comment(&core_context, &post, &user, &content).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_insert_post_comment_with_mentions() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let user = insert_test_user(&core_context).await;
+        let mentioned_user = insert_test_user(&core_context).await;
+        let content = format!("Hi @{} and @unknown-{}", mentioned_user.username, fake_uuid());
+
+        let result = insert_post_comment(&core_context, &post, &user, &content).await;
 
         assert!(result.is_ok());
+
+        let post_comment = result.unwrap().data;
+
+        assert_eq!(post_comment.mentioned_user_ids, vec![mentioned_user.id]);
+        assert!(post_comment
+            .content_html(&core_context)
+            .await
+            .contains(&format!(r#"<a href="{}">"#, mentioned_user.url())));
     }
 
     #[tokio::test]
@@ -0,0 +1,424 @@
+#[cfg(feature = "import-posts")]
+use std::collections::HashMap;
+#[cfg(feature = "import-posts")]
+use std::time::Duration;
+
+#[cfg(feature = "import-posts")]
+use crate::config::MISC_CONFIG;
+use crate::models::*;
+use crate::utils::MutResult;
+#[cfg(feature = "import-posts")]
+use crate::utils::{
+    parse_post_import, tag_to_hashtag, HttpClient, HttpRules, ImportableImage, ImportablePost, ImportableWebsite,
+    PostImportReport, ARCHIVE_NAVIGATION_PATH, ARCHIVE_WEBSITE_PATH,
+};
+use crate::CoreContext;
+
+#[cfg(feature = "import-posts")]
+const IMAGE_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(30);
+#[cfg(feature = "import-posts")]
+const MAX_IMAGE_DOWNLOAD_SIZE: u64 = 32 * 1024 * 1024;
+#[cfg(feature = "enqueue-post-import")]
+const MAX_POST_IMPORT_FILE_SIZE: usize = 256 * 1024 * 1024;
+
+#[cfg(feature = "import-posts")]
+fn guess_content_type(file_name: &str) -> &'static str {
+    let extension = file_name.rsplit('.').next().unwrap_or_default().to_lowercase();
+
+    match extension.as_str() {
+        "bmp" => "image/bmp",
+        "gif" => "image/gif",
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "webp" => "image/webp",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(feature = "import-posts")]
+fn download_image(url: &str) -> Option<(Vec<u8>, Option<String>)> {
+    let response = HttpClient::new(
+        HttpRules::from_config(),
+        IMAGE_DOWNLOAD_TIMEOUT,
+        MAX_IMAGE_DOWNLOAD_SIZE,
+    )
+    .get(url, &[])
+    .ok()?;
+
+    Some((response.data, response.content_type))
+}
+
+#[cfg(feature = "import-posts")]
+async fn import_image<'a>(user: &User, website: &Website, image: &ImportableImage) -> Option<Blob<'a>> {
+    let file_name = image.file_name();
+    let (data, content_type) = match image {
+        ImportableImage::Embedded { data, .. } => (data.clone(), None),
+        ImportableImage::Remote(url) => {
+            let url = url.clone();
+
+            tokio::task::spawn_blocking(move || download_image(&url)).await.ok()??
+        }
+    };
+    let content_type = content_type.unwrap_or_else(|| guess_content_type(file_name).to_owned());
+    let tmp_file_path = MISC_CONFIG.storage_tmp_path().join(uuid::Uuid::new_v4().to_string());
+
+    std::fs::write(&tmp_file_path, data).ok()?;
+
+    super::blob_commands::insert_blob_from_tmp_file(user, Some(website), &tmp_file_path, file_name, &content_type)
+        .await
+        .ok()
+        .map(|success| success.data)
+}
+
+#[cfg(feature = "import-posts")]
+async fn import_website_image<'a>(
+    user: &User,
+    website: &Website,
+    image: Option<&ImportableImage>,
+    report: &mut PostImportReport,
+) -> Option<Blob<'a>> {
+    let image = image?;
+    let blob = import_image(user, website, image).await;
+
+    if blob.is_none() {
+        report.skip(
+            format!("{ARCHIVE_WEBSITE_PATH} ({})", image.key()),
+            "Image could not be imported",
+        );
+    }
+
+    blob
+}
+
+#[cfg(feature = "import-posts")]
+async fn import_website_settings(
+    core_context: &CoreContext,
+    website: &Website,
+    user: &User,
+    settings: ImportableWebsite,
+    report: &mut PostImportReport,
+) {
+    let icon_image_blob = import_website_image(user, website, settings.icon_image.as_ref(), report).await;
+    let cover_image_blob = import_website_image(user, website, settings.cover_image.as_ref(), report).await;
+
+    let result = super::update_website(
+        core_context,
+        website,
+        super::WebsiteInput {
+            name: &website.name,
+            description: &settings.description,
+            icon_image_blob: icon_image_blob.as_ref(),
+            cover_image_blob: cover_image_blob.as_ref(),
+            light_theme: Some(settings.light_theme.as_str())
+                .filter(|theme| !theme.is_empty())
+                .unwrap_or(&website.light_theme),
+            dark_theme: Some(settings.dark_theme.as_str())
+                .filter(|theme| !theme.is_empty())
+                .unwrap_or(&website.dark_theme),
+            language: &website.language,
+            publish: website.is_published(),
+        },
+    )
+    .await;
+
+    if let Err(error) = result {
+        report.skip(ARCHIVE_WEBSITE_PATH, error.message);
+    }
+}
+
+/// Stores the uploaded file and enqueues its import, so the remote images are downloaded outside of the request. The
+/// user receives an email with the report when it is finished.
+#[cfg(feature = "enqueue-post-import")]
+pub async fn enqueue_post_import(
+    core_context: &CoreContext,
+    website: &Website,
+    user: &User,
+    field: &mut multer::Field<'_>,
+) -> MutResult {
+    use std::io::Write;
+
+    if website.user_id != user.id {
+        return crate::mut_error!();
+    }
+
+    let file_path = crate::config::MISC_CONFIG
+        .storage_tmp_path()
+        .join(format!("import-{}", uuid::Uuid::new_v4()));
+    let mut file = std::fs::File::create(&file_path)?;
+    let mut file_size = 0;
+
+    while let Some(chunk) = field.chunk().await.map_err(|_| crate::utils::MutError::default())? {
+        file_size += chunk.len();
+
+        if file_size > MAX_POST_IMPORT_FILE_SIZE || file.write_all(&chunk).is_err() {
+            let _ = std::fs::remove_file(&file_path);
+
+            return crate::mut_error!();
+        }
+    }
+
+    core_context.jobs.import_posts(website.id, user, file_path).await;
+
+    crate::mut_success!()
+}
+
+#[cfg(feature = "import-posts")]
+pub async fn import_posts(
+    core_context: &CoreContext,
+    website: &Website,
+    user: &User,
+    data: &[u8],
+) -> MutResult<PostImportReport> {
+    let mut report = PostImportReport::default();
+
+    let Some(post_import) = parse_post_import(data, &mut report) else {
+        return crate::mut_error!();
+    };
+
+    if let Some(settings) = post_import.website {
+        import_website_settings(core_context, website, user, settings, &mut report).await;
+    }
+
+    if !post_import.navigation_items.is_empty() {
+        if super::all_navigation_items_by_website(website).await.is_empty() {
+            let items = post_import
+                .navigation_items
+                .into_iter()
+                .map(|item| (None, item.title, item.url))
+                .collect();
+
+            if let Err(error) = super::insert_or_update_many_navigation_items(website, items).await {
+                report.skip(ARCHIVE_NAVIGATION_PATH, error.message);
+            }
+        } else {
+            report.skip(ARCHIVE_NAVIGATION_PATH, "Website already has navigation items");
+        }
+    }
+
+    let mut imported_blobs: HashMap<String, Blob> = HashMap::new();
+
+    for post in post_import.posts {
+        let ImportablePost {
+            title,
+            slug,
+            mut content,
+            variables,
+            tags,
+            is_published,
+            published_at,
+            cover_image,
+            images,
+        } = post;
+        let mut blobs: Vec<Blob> = Vec::new();
+        let mut cover_image_blob = None;
+
+        for (reference, image) in images {
+            let blob = if let Some(blob) = imported_blobs.get(image.key()) {
+                blob.clone()
+            } else if let Some(blob) = import_image(user, website, &image).await {
+                imported_blobs.insert(image.key().to_owned(), blob.clone());
+                blob
+            } else {
+                report.skip(format!("{title} ({reference})"), "Image could not be imported");
+                continue;
+            };
+
+            content = content.replace(&reference, blob.url().as_str());
+
+            if cover_image.as_ref() == Some(&reference) {
+                cover_image_blob = Some(blob.clone());
+            }
+
+            if !blobs.iter().any(|other| other.id == blob.id) {
+                blobs.push(blob);
+            }
+        }
+
+        let mut hashtags = Vec::new();
+
+        for tag in tags {
+            match tag_to_hashtag(&tag).map(|name| format!("#{name}")) {
+                Some(hashtag) if content.contains(&hashtag) => {}
+                Some(hashtag) => hashtags.push(hashtag),
+                None => report.skip(format!("{title} ({tag})"), "Tag can't be converted into a hashtag"),
+            }
+        }
+
+        if !hashtags.is_empty() {
+            content = format!("{content}\n\n{}", hashtags.join(" "));
+        }
+
+        let result = super::insert_post(
+            core_context,
+            website,
+            user,
+            super::PostInput {
+                title: &title,
+                slug: &slug,
+                content: &content,
+                variables: &variables,
+                language: &website.language,
+                blobs,
+                cover_image_blob: cover_image_blob.as_ref(),
+                publish: is_published,
+            },
+            published_at,
+            None,
+        )
+        .await;
+
+        match result {
+            Ok(_) => report.imported_posts.push(title),
+            Err(error) if error.errors.is_empty() => report.skip(title, error.message),
+            Err(error) => {
+                let mut reasons = error
+                    .errors
+                    .iter()
+                    .map(|(input, input_error)| format!("{input} {input_error}"))
+                    .collect::<Vec<_>>();
+
+                reasons.sort();
+
+                report.skip(title, reasons.join(", "));
+            }
+        }
+    }
+
+    crate::mut_success!(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use chrono::{TimeZone, Utc};
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    use crate::commands::{get_post_by_id, get_post_by_slug};
+    use crate::test_utils::{insert_test_user, insert_test_website, setup_core_context};
+
+    use super::import_posts;
+
+    const WXR: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/" xmlns:wp="http://wordpress.org/export/1.2/">
+    <channel>
+        <item>
+            <title>Hello world</title>
+            <content:encoded><![CDATA[<p>Hello <strong>world</strong>.</p>]]></content:encoded>
+            <wp:post_name><![CDATA[hello-world]]></wp:post_name>
+            <wp:post_date_gmt><![CDATA[2020-01-02 03:04:05]]></wp:post_date_gmt>
+            <wp:status><![CDATA[publish]]></wp:status>
+            <wp:post_type><![CDATA[post]]></wp:post_type>
+            <category domain="post_tag" nicename="rust-lang"><![CDATA[Rust Lang]]></category>
+        </item>
+        <item>
+            <title>About</title>
+            <wp:post_name><![CDATA[about]]></wp:post_name>
+            <wp:status><![CDATA[publish]]></wp:status>
+            <wp:post_type><![CDATA[page]]></wp:post_type>
+        </item>
+    </channel>
+</rss>"#;
+
+    #[tokio::test]
+    async fn should_import_posts_from_wxr() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+
+        let result = import_posts(&core_context, &website, &user, WXR.as_bytes()).await;
+
+        assert!(result.is_ok());
+
+        let report = result.unwrap().data;
+
+        assert_eq!(report.imported_posts, vec!["Hello world".to_owned()]);
+        assert_eq!(report.skipped_items.len(), 1);
+
+        let post = get_post_by_slug(&core_context, "hello-world", &website, None)
+            .await
+            .unwrap();
+
+        assert!(post.content.contains("**world**"));
+        assert!(post.content.ends_with("#Rust-Lang"));
+        assert_eq!(post.hashtag_ids.len(), 1);
+        assert_eq!(
+            post.published_at,
+            Some(Utc.with_ymd_and_hms(2020, 1, 2, 3, 4, 5).unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_import_posts_from_markdown_archive() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let mut zip_writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+
+        zip_writer
+            .start_file("posts/2021-05-06-first-post.md", SimpleFileOptions::default())
+            .unwrap();
+        zip_writer
+            .write_all(b"---\ntitle: First post\ntags: [rust, web]\ncover_image: ../images/favicon.png\n---\n\n![Favicon](../images/favicon.png)\n")
+            .unwrap();
+        zip_writer
+            .start_file("posts/second-post.md", SimpleFileOptions::default())
+            .unwrap();
+        zip_writer
+            .write_all(b"---\ntitle: Second post\ndraft: true\n---\n\n![Missing](missing.png)\n")
+            .unwrap();
+        zip_writer
+            .start_file("images/favicon.png", SimpleFileOptions::default())
+            .unwrap();
+        zip_writer
+            .write_all(&std::fs::read("../../assets/favicon.png").unwrap())
+            .unwrap();
+
+        let data = zip_writer.finish().unwrap().into_inner();
+
+        let result = import_posts(&core_context, &website, &user, &data).await;
+
+        assert!(result.is_ok());
+
+        let report = result.unwrap().data;
+
+        assert_eq!(report.imported_posts.len(), 2);
+        assert_eq!(report.skipped_items.len(), 1);
+
+        let post = get_post_by_slug(&core_context, "first-post", &website, None)
+            .await
+            .unwrap();
+
+        assert_eq!(post.blob_ids.len(), 1);
+        assert_eq!(post.cover_image_blob_id, Some(post.blob_ids[0]));
+        assert_eq!(post.hashtag_ids.len(), 2);
+        assert_eq!(
+            post.published_at,
+            Some(Utc.with_ymd_and_hms(2021, 5, 6, 0, 0, 0).unwrap())
+        );
+
+        let draft_id = sqlx::query_scalar!(
+            "SELECT id FROM posts WHERE website_id = $1 AND slug = 'second-post' LIMIT 1",
+            website.id, // $1
+        )
+        .fetch_one(&core_context.db_pool)
+        .await
+        .unwrap();
+        let draft = get_post_by_id(&core_context, draft_id, Some(&website), None, Some(false)).await;
+
+        assert!(draft.is_ok());
+        assert!(draft.unwrap().published_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_not_import_posts_with_invalid_data() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+
+        let result = import_posts(&core_context, &website, &user, b"invalid").await;
+
+        assert!(result.is_err());
+    }
+}
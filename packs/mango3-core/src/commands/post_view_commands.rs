@@ -1,15 +1,119 @@
 use crate::models::*;
 use crate::CoreContext;
 
+/// Builds an anonymous visitor identifier from the IP address and user agent. The salt rotates daily and old salts are
+/// deleted, so hashes can't be linked across days nor reversed once the day is over.
+#[cfg(any(feature = "get-or-insert-post-view", feature = "insert-post-view-event"))]
+async fn visitor_hash(core_context: &CoreContext, ip_address: &str, user_agent: Option<&str>) -> sqlx::Result<String> {
+    use sha2::{Digest, Sha256};
+
+    // The conflicting row is updated without changing its salt, so it's returned when the salt of the day exists.
+    let salt = sqlx::query!(
+        "INSERT INTO visitor_hash_salts (day, salt) VALUES (current_date, $1)
+        ON CONFLICT (day) DO UPDATE SET day = EXCLUDED.day RETURNING salt",
+        crate::utils::generate_random_string(32), // $1
+    )
+    .fetch_one(&core_context.db_pool)
+    .await?
+    .salt;
+
+    Ok(format!(
+        "{:x}",
+        Sha256::digest(format!("{salt}:{ip_address}:{}", user_agent.unwrap_or_default()))
+    ))
+}
+
+/// Replaces the raw IP addresses of post views and the IP address hashes of view events stored by older versions with
+/// hashes salted with a random value that is discarded right away. Visitors are still counted once per day, except
+/// for the ones that come back after this on the same day. Returns the number of anonymized rows.
+#[cfg(feature = "anonymize-post-views")]
+pub async fn anonymize_post_views() -> crate::utils::MutResult<u64> {
+    let db_pool = crate::db_pool().await;
+    let salt = crate::utils::generate_random_string(32);
+
+    let mut transaction = db_pool.begin().await?;
+
+    let post_views_result = sqlx::query!(
+        "UPDATE post_views SET
+            visitor_hash = CASE WHEN user_id IS NULL THEN
+                encode(sha256(convert_to($1 || ':' || host(ip_address), 'UTF8')), 'hex')
+            END,
+            ip_address = NULL
+        WHERE ip_address IS NOT NULL",
+        salt, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    let post_view_events_result = sqlx::query!(
+        "UPDATE post_view_events SET
+            visitor_hash = encode(sha256(convert_to($1 || ':' || visitor_hash, 'UTF8')), 'hex')",
+        salt, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    crate::mut_success!(post_views_result.rows_affected() + post_view_events_result.rows_affected())
+}
+
+/// Moves the post views older than the retention period into the archived counts, deletes the expired view events and
+/// the visitor hash salts of previous days.
+#[cfg(feature = "delete-all-expired-post-views")]
+pub async fn delete_all_expired_post_views() -> crate::utils::MutResult {
+    use crate::config::MISC_CONFIG;
+
+    let db_pool = crate::db_pool().await;
+    let retention_days = i32::from(MISC_CONFIG.post_views_retention_days.max(2));
+
+    let mut transaction = db_pool.begin().await?;
+
+    sqlx::query!(
+        "INSERT INTO archived_post_views (post_id, views_count)
+        SELECT post_id, COUNT(*) FROM post_views
+        WHERE created_at < current_date - make_interval(days => $1)
+        GROUP BY post_id
+        ON CONFLICT (post_id) DO UPDATE SET views_count = archived_post_views.views_count + EXCLUDED.views_count",
+        retention_days, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM post_views WHERE created_at < current_date - make_interval(days => $1)",
+        retention_days, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM post_view_events WHERE created_at < current_date - make_interval(days => $1)",
+        retention_days, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    sqlx::query!("DELETE FROM visitor_hash_salts WHERE day < current_date")
+        .execute(&mut *transaction)
+        .await?;
+
+    transaction.commit().await?;
+
+    crate::mut_success!()
+}
+
 #[cfg(feature = "get-post-views-count")]
 pub async fn get_post_views_count(core_context: &CoreContext, post: &Post) -> i64 {
     sqlx::query!(
-        "SELECT COUNT(*) FROM post_views WHERE post_id = $1 LIMIT 1",
+        r#"SELECT
+            (SELECT COUNT(*) FROM post_views WHERE post_id = $1)
+            + COALESCE((SELECT views_count FROM archived_post_views WHERE post_id = $1 LIMIT 1), 0) AS "count!""#,
         post.id, // $1
     )
     .fetch_one(&core_context.db_pool)
     .await
-    .map(|record| record.count.unwrap_or_default())
+    .map(|record| record.count)
     .unwrap_or_default()
 }
 
@@ -19,23 +123,25 @@ pub async fn get_or_insert_post_view(
     post: &Post,
     user: Option<&User>,
     ip_address: &str,
+    user_agent: Option<&str>,
 ) -> crate::utils::MutResult<PostView> {
-    use ipnetwork::IpNetwork;
-    use std::str::FromStr;
-
     let user_id = user.map(|u| u.id);
 
-    let ip_address = IpNetwork::from_str(ip_address).map_err(|_| crate::utils::MutError::default())?;
+    let visitor_hash = if user_id.is_none() {
+        Some(visitor_hash(core_context, ip_address, user_agent).await?)
+    } else {
+        None
+    };
 
     if let Ok(view) = sqlx::query_as!(
         PostView,
         "SELECT * FROM post_views
             WHERE post_id = $1 AND (
-                ($2::uuid IS NOT NULL AND user_id = $2) OR ($2 IS NULL AND user_id IS NULL AND ip_address = $3)
+                ($2::uuid IS NOT NULL AND user_id = $2) OR ($2 IS NULL AND user_id IS NULL AND visitor_hash = $3)
             ) LIMIT 1",
-        post.id,    // $1
-        user_id,    // $2
-        ip_address, // $3
+        post.id,      // $1
+        user_id,      // $2
+        visitor_hash, // $3
     )
     .fetch_one(&core_context.db_pool)
     .await
@@ -45,10 +151,10 @@ pub async fn get_or_insert_post_view(
 
     let result = sqlx::query_as!(
         PostView,
-        "INSERT INTO post_views (post_id, user_id, ip_address) VALUES ($1, $2, $3) RETURNING *",
-        post.id,    // $1
-        user_id,    // $2
-        ip_address, // $3
+        "INSERT INTO post_views (post_id, user_id, visitor_hash) VALUES ($1, $2, $3) RETURNING *",
+        post.id,      // $1
+        user_id,      // $2
+        visitor_hash, // $3
     )
     .fetch_one(&core_context.db_pool)
     .await;
@@ -56,11 +162,139 @@ pub async fn get_or_insert_post_view(
     crate::mut_result!(result)
 }
 
+/// Records a single view of a post for the website analytics. The visitor is only kept as a daily salted hash, the
+/// referrer only as its host.
+#[cfg(feature = "insert-post-view-event")]
+pub async fn insert_post_view_event(
+    core_context: &CoreContext,
+    post: &Post,
+    user: Option<&User>,
+    ip_address: &str,
+    user_agent: Option<&str>,
+    referrer: Option<&str>,
+    country_alpha2: Option<&str>,
+) -> crate::utils::MutResult {
+    use crate::enums::UserAgentClass;
+
+    let visitor_hash = visitor_hash(core_context, ip_address, user_agent).await?;
+    let referrer_host = referrer
+        .and_then(|referrer| url::Url::parse(referrer).ok())
+        .and_then(|url| url.host_str().map(|host| host.to_lowercase()));
+    let user_agent_class = UserAgentClass::from(user_agent.unwrap_or_default());
+    let country_alpha2 = country_alpha2
+        .and_then(|alpha2| crate::utils::find_country(&alpha2.trim().to_uppercase()))
+        .map(|country| country.alpha2);
+
+    sqlx::query!(
+        "INSERT INTO post_view_events (
+            website_id, post_id, user_id, visitor_hash, referrer_host, user_agent_class, country_alpha2
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        post.website_id,                    // $1
+        post.id,                            // $2
+        user.map(|user| user.id),           // $3
+        visitor_hash,                       // $4
+        referrer_host,                      // $5
+        user_agent_class as UserAgentClass, // $6
+        country_alpha2,                     // $7
+    )
+    .execute(&core_context.db_pool)
+    .await?;
+
+    crate::mut_success!()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test_utils::{fake_ipv4, insert_test_post, insert_test_user, setup_core_context};
 
-    use super::{get_or_insert_post_view, get_post_views_count};
+    use super::{
+        anonymize_post_views, delete_all_expired_post_views, get_or_insert_post_view, get_post_views_count,
+        insert_post_view_event,
+    };
+
+    #[tokio::test]
+    async fn should_anonymize_post_views() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let ip_address = fake_ipv4();
+
+        let view_id = sqlx::query_scalar!(
+            "INSERT INTO post_views (post_id, ip_address) VALUES ($1, $2::text::cidr) RETURNING id",
+            post.id,    // $1
+            ip_address, // $2
+        )
+        .fetch_one(&core_context.db_pool)
+        .await
+        .unwrap();
+        let event_id = sqlx::query_scalar!(
+            "INSERT INTO post_view_events (website_id, post_id, visitor_hash) VALUES ($1, $2, $3) RETURNING id",
+            post.website_id, // $1
+            post.id,         // $2
+            "old-hash",      // $3
+        )
+        .fetch_one(&core_context.db_pool)
+        .await
+        .unwrap();
+
+        let result = anonymize_post_views().await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().data >= 2);
+
+        let view = sqlx::query!("SELECT ip_address, visitor_hash FROM post_views WHERE id = $1", view_id)
+            .fetch_one(&core_context.db_pool)
+            .await
+            .unwrap();
+
+        assert!(view.ip_address.is_none());
+        assert!(view.visitor_hash.is_some_and(|hash| !hash.contains(&ip_address)));
+
+        let event_visitor_hash =
+            sqlx::query_scalar!("SELECT visitor_hash FROM post_view_events WHERE id = $1", event_id)
+                .fetch_one(&core_context.db_pool)
+                .await
+                .unwrap();
+
+        assert_ne!(event_visitor_hash, "old-hash");
+    }
+
+    #[tokio::test]
+    async fn should_delete_all_expired_post_views() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let user = insert_test_user(&core_context).await;
+
+        sqlx::query!(
+            "INSERT INTO post_views (post_id, user_id, visitor_hash, created_at) VALUES
+            ($1, NULL, $2, current_date - make_interval(days => 400)),
+            ($1, $3, NULL, current_date - make_interval(days => 400))",
+            post.id,           // $1
+            "expired-visitor", // $2
+            user.id,           // $3
+        )
+        .execute(&core_context.db_pool)
+        .await
+        .unwrap();
+
+        get_or_insert_post_view(&core_context, &post, None, &fake_ipv4(), None)
+            .await
+            .expect("Could not insert post view");
+
+        let result = delete_all_expired_post_views().await;
+
+        assert!(result.is_ok());
+
+        let remaining_views_count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM post_views WHERE post_id = $1"#,
+            post.id
+        )
+        .fetch_one(&core_context.db_pool)
+        .await
+        .unwrap();
+
+        assert_eq!(remaining_views_count, 1);
+        assert_eq!(get_post_views_count(&core_context, &post).await, 3);
+    }
 
     #[tokio::test]
     async fn should_count_post_views() {
@@ -79,8 +313,62 @@ mod tests {
         let user = insert_test_user(&core_context).await;
         let ip_address = fake_ipv4();
 
-        let result = get_or_insert_post_view(&core_context, &post, Some(&user), &ip_address).await;
+        let result = get_or_insert_post_view(&core_context, &post, Some(&user), &ip_address, None).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_insert_anonymous_post_view_once() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let ip_address = fake_ipv4();
+        let user_agent = Some("Mozilla/5.0 (X11; Linux x86_64; rv:136.0) Gecko/20100101 Firefox/136.0");
+
+        let first_view = get_or_insert_post_view(&core_context, &post, None, &ip_address, user_agent)
+            .await
+            .expect("Could not insert post view")
+            .data;
+        let second_view = get_or_insert_post_view(&core_context, &post, None, &ip_address, user_agent)
+            .await
+            .expect("Could not get post view")
+            .data;
+
+        assert_eq!(first_view.id, second_view.id);
+        assert!(first_view.ip_address.is_none());
+        assert!(first_view.visitor_hash.is_some());
+        assert_eq!(get_post_views_count(&core_context, &post).await, 1);
+    }
+
+    #[tokio::test]
+    async fn should_insert_post_view_event() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let ip_address = fake_ipv4();
+
+        let result = insert_post_view_event(
+            &core_context,
+            &post,
+            None,
+            &ip_address,
+            Some("Mozilla/5.0 (X11; Linux x86_64; rv:136.0) Gecko/20100101 Firefox/136.0"),
+            Some("https://www.example.com/some/path?query=1"),
+            Some("pe"),
+        )
+        .await;
 
         assert!(result.is_ok());
+
+        let event = sqlx::query!(
+            "SELECT visitor_hash, referrer_host, country_alpha2 FROM post_view_events WHERE post_id = $1",
+            post.id, // $1
+        )
+        .fetch_one(&core_context.db_pool)
+        .await
+        .unwrap();
+
+        assert_eq!(event.referrer_host.as_deref(), Some("www.example.com"));
+        assert_eq!(event.country_alpha2.as_deref(), Some("PE"));
+        assert!(!event.visitor_hash.contains(&ip_address));
     }
 }
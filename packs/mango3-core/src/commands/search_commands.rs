@@ -0,0 +1,470 @@
+#[cfg(feature = "search-all")]
+use uuid::Uuid;
+
+#[cfg(feature = "search-all")]
+use crate::enums::SearchKind;
+#[cfg(feature = "search-all")]
+use crate::models::{SearchFilters, SearchResult, SEARCH_HEADLINE_START, SEARCH_HEADLINE_STOP};
+#[cfg(feature = "search-all")]
+use crate::utils::{CursorPage, CursorPageParams};
+#[cfg(feature = "search-all")]
+use crate::CoreContext;
+
+/// Returns similar names of hashtags, posts, websites and users, to suggest other queries when the given one has a
+/// typo.
+#[cfg(feature = "all-search-suggestions")]
+pub async fn all_search_suggestions(query: &str, limit: i64) -> Vec<String> {
+    let query = query.trim();
+
+    if query.is_empty() {
+        return vec![];
+    }
+
+    let db_pool = crate::db_pool().await;
+
+    sqlx::query!(
+        r#"SELECT term AS "term!" FROM (
+            SELECT DISTINCT ON (lower(term)) term, similarity(term, $1) AS score FROM (
+                SELECT name::varchar AS term FROM hashtags
+                UNION ALL SELECT posts.title FROM posts
+                    INNER JOIN websites ON websites.id = posts.website_id
+                    WHERE posts.published_at IS NOT NULL AND websites.published_at IS NOT NULL
+                        AND posts.suspended_at IS NULL AND websites.suspended_at IS NULL
+                UNION ALL SELECT name::varchar FROM websites WHERE published_at IS NOT NULL AND suspended_at IS NULL
+                UNION ALL SELECT username::varchar FROM users WHERE disabled_at IS NULL
+            ) AS terms
+            WHERE term % $1 AND lower(term) <> lower($1)
+            ORDER BY lower(term), score DESC
+        ) AS suggestions
+        ORDER BY score DESC LIMIT $2"#,
+        query, // $1
+        limit, // $2
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|records| records.into_iter().map(|record| record.term).collect())
+    .unwrap_or_default()
+}
+
+/// Returns the names of the text search configurations available in the database, which are the valid languages of
+/// posts and websites.
+#[cfg(feature = "all-text-search-languages")]
+pub async fn all_text_search_languages() -> Vec<String> {
+    let db_pool = crate::db_pool().await;
+
+    sqlx::query!(r#"SELECT cfgname::varchar AS "name!" FROM pg_ts_config ORDER BY cfgname"#)
+        .fetch_all(db_pool)
+        .await
+        .map(|records| records.into_iter().map(|record| record.name).collect())
+        .unwrap_or_default()
+}
+
+/// Searches published posts, websites, active users or comments of published posts, ranked by full text relevance and
+/// trigram similarity, so words with typos still find close matches.
+#[cfg(feature = "search-all")]
+pub async fn search_all<'a>(
+    core_context: &'a CoreContext,
+    cursor_page_params: &CursorPageParams,
+    kind: SearchKind,
+    filters: &'a SearchFilters<'a>,
+    query: &'a str,
+) -> CursorPage<SearchResult> {
+    crate::cursor_page!(
+        core_context,
+        cursor_page_params,
+        |node: SearchResult| node.id,
+        move |core_context, after| async move {
+            search_results(core_context, kind, filters, query, Some(after), None, 1)
+                .await
+                .pop()
+        },
+        move |core_context, cursor_resource, limit| async move {
+            search_results(core_context, kind, filters, query, None, cursor_resource, limit).await
+        },
+    )
+    .await
+}
+
+#[cfg(feature = "search-all")]
+async fn search_results(
+    core_context: &CoreContext,
+    kind: SearchKind,
+    filters: &SearchFilters<'_>,
+    query: &str,
+    only_id: Option<Uuid>,
+    cursor_resource: Option<SearchResult>,
+    limit: i64,
+) -> Vec<SearchResult> {
+    let query = query.trim();
+
+    if query.is_empty() {
+        return vec![];
+    }
+
+    let hashtag_id = filters.hashtag.map(|hashtag| hashtag.id);
+    let website_id = filters.website.map(|website| website.id);
+    let author_id = filters.author.map(|user| user.id);
+    let (cursor_id, cursor_search_rank, cursor_created_at) = cursor_resource
+        .map(|c| (Some(c.id), Some(c.search_rank), Some(c.created_at)))
+        .unwrap_or_default();
+    let headline_options = format!(
+        r#"StartSel="{SEARCH_HEADLINE_START}", StopSel="{SEARCH_HEADLINE_STOP}", MaxFragments=2, MaxWords=30, MinWords=10"#
+    );
+
+    let result = match kind {
+        SearchKind::Posts => {
+            sqlx::query_as!(
+                SearchResult,
+                r#"SELECT
+                    id AS "id!",
+                    title AS "title!",
+                    headline AS "headline!",
+                    website_subdomain,
+                    post_slug,
+                    username,
+                    search_rank AS "search_rank!",
+                    created_at AS "created_at!"
+                FROM (
+                    SELECT
+                        posts.id,
+                        posts.title,
+                        ts_headline(posts.language, posts.content, websearch_to_tsquery(posts.language, $1), $12)
+                            AS headline,
+                        websites.subdomain::varchar AS website_subdomain,
+                        posts.slug AS post_slug,
+                        NULL::varchar AS username,
+                        ts_rank(posts.search, websearch_to_tsquery(posts.language, $1))
+                            + word_similarity($1, posts.title) AS search_rank,
+                        posts.created_at
+                    FROM posts
+                    INNER JOIN websites ON websites.id = posts.website_id
+                    WHERE posts.published_at IS NOT NULL AND websites.published_at IS NOT NULL
+                        AND posts.suspended_at IS NULL AND websites.suspended_at IS NULL
+                        AND (posts.search @@ websearch_to_tsquery(posts.language, $1) OR $1 <% posts.title)
+                        AND ($2::uuid IS NULL OR $2 = ANY(posts.hashtag_ids))
+                        AND ($3::uuid IS NULL OR posts.website_id = $3)
+                        AND ($4::uuid IS NULL OR posts.user_id = $4)
+                        AND ($5::date IS NULL OR posts.published_at >= $5)
+                        AND ($6::date IS NULL OR posts.published_at < $6 + 1)
+                        AND ($7::uuid IS NULL OR posts.id = $7)
+                ) AS results
+                WHERE ($8::uuid IS NULL OR $9::real IS NULL OR $10::timestamptz IS NULL)
+                    OR search_rank < $9 OR (
+                        search_rank = $9 AND (created_at < $10 OR (created_at = $10 AND id < $8))
+                    )
+                ORDER BY search_rank DESC, created_at DESC, id DESC LIMIT $11"#,
+                query,              // $1
+                hashtag_id,         // $2
+                website_id,         // $3
+                author_id,          // $4
+                filters.since,      // $5
+                filters.until,      // $6
+                only_id,            // $7
+                cursor_id,          // $8
+                cursor_search_rank, // $9
+                cursor_created_at,  // $10
+                limit,              // $11
+                headline_options,   // $12
+            )
+            .fetch_all(&core_context.db_pool)
+            .await
+        }
+        SearchKind::Websites => {
+            sqlx::query_as!(
+                SearchResult,
+                r#"SELECT
+                    id AS "id!",
+                    title AS "title!",
+                    headline AS "headline!",
+                    website_subdomain,
+                    post_slug,
+                    username,
+                    search_rank AS "search_rank!",
+                    created_at AS "created_at!"
+                FROM (
+                    SELECT
+                        id,
+                        name::varchar AS title,
+                        ts_headline(language, description, websearch_to_tsquery(language, $1), $12) AS headline,
+                        subdomain::varchar AS website_subdomain,
+                        NULL::varchar AS post_slug,
+                        NULL::varchar AS username,
+                        ts_rank(search, websearch_to_tsquery(language, $1)) + word_similarity($1, name::varchar)
+                            AS search_rank,
+                        created_at
+                    FROM websites
+                    WHERE published_at IS NOT NULL AND suspended_at IS NULL
+                        AND (search @@ websearch_to_tsquery(language, $1) OR $1 <% name::varchar)
+                        AND ($2::uuid IS NULL OR $2 = ANY(hashtag_ids))
+                        AND ($3::uuid IS NULL OR id = $3)
+                        AND ($4::uuid IS NULL OR user_id = $4)
+                        AND ($5::date IS NULL OR created_at >= $5)
+                        AND ($6::date IS NULL OR created_at < $6 + 1)
+                        AND ($7::uuid IS NULL OR id = $7)
+                ) AS results
+                WHERE ($8::uuid IS NULL OR $9::real IS NULL OR $10::timestamptz IS NULL)
+                    OR search_rank < $9 OR (
+                        search_rank = $9 AND (created_at < $10 OR (created_at = $10 AND id < $8))
+                    )
+                ORDER BY search_rank DESC, created_at DESC, id DESC LIMIT $11"#,
+                query,              // $1
+                hashtag_id,         // $2
+                website_id,         // $3
+                author_id,          // $4
+                filters.since,      // $5
+                filters.until,      // $6
+                only_id,            // $7
+                cursor_id,          // $8
+                cursor_search_rank, // $9
+                cursor_created_at,  // $10
+                limit,              // $11
+                headline_options,   // $12
+            )
+            .fetch_all(&core_context.db_pool)
+            .await
+        }
+        SearchKind::Users => {
+            sqlx::query_as!(
+                SearchResult,
+                r#"SELECT
+                    id AS "id!",
+                    title AS "title!",
+                    headline AS "headline!",
+                    website_subdomain,
+                    post_slug,
+                    username,
+                    search_rank AS "search_rank!",
+                    created_at AS "created_at!"
+                FROM (
+                    SELECT
+                        id,
+                        display_name AS title,
+                        ts_headline('simple', bio, websearch_to_tsquery('simple', $1), $12) AS headline,
+                        NULL::varchar AS website_subdomain,
+                        NULL::varchar AS post_slug,
+                        username::varchar AS username,
+                        ts_rank(search, websearch_to_tsquery('simple', $1)) + GREATEST(
+                            word_similarity($1, username::varchar),
+                            word_similarity($1, display_name)
+                        ) AS search_rank,
+                        created_at
+                    FROM users
+                    WHERE disabled_at IS NULL
+                        AND (
+                            search @@ websearch_to_tsquery('simple', $1)
+                            OR $1 <% username::varchar
+                            OR $1 <% display_name
+                        )
+                        AND ($2::uuid IS NULL OR $2 = ANY(hashtag_ids))
+                        AND ($3::uuid IS NULL OR EXISTS(
+                            SELECT 1 FROM posts
+                            WHERE user_id = users.id AND website_id = $3 AND published_at IS NOT NULL
+                                AND suspended_at IS NULL
+                        ))
+                        AND ($4::uuid IS NULL OR id = $4)
+                        AND ($5::date IS NULL OR created_at >= $5)
+                        AND ($6::date IS NULL OR created_at < $6 + 1)
+                        AND ($7::uuid IS NULL OR id = $7)
+                ) AS results
+                WHERE ($8::uuid IS NULL OR $9::real IS NULL OR $10::timestamptz IS NULL)
+                    OR search_rank < $9 OR (
+                        search_rank = $9 AND (created_at < $10 OR (created_at = $10 AND id < $8))
+                    )
+                ORDER BY search_rank DESC, created_at DESC, id DESC LIMIT $11"#,
+                query,              // $1
+                hashtag_id,         // $2
+                website_id,         // $3
+                author_id,          // $4
+                filters.since,      // $5
+                filters.until,      // $6
+                only_id,            // $7
+                cursor_id,          // $8
+                cursor_search_rank, // $9
+                cursor_created_at,  // $10
+                limit,              // $11
+                headline_options,   // $12
+            )
+            .fetch_all(&core_context.db_pool)
+            .await
+        }
+        SearchKind::Comments => {
+            sqlx::query_as!(
+                SearchResult,
+                r#"SELECT
+                    id AS "id!",
+                    title AS "title!",
+                    headline AS "headline!",
+                    website_subdomain,
+                    post_slug,
+                    username,
+                    search_rank AS "search_rank!",
+                    created_at AS "created_at!"
+                FROM (
+                    SELECT
+                        post_comments.id,
+                        posts.title,
+                        ts_headline(
+                            post_comments.language,
+                            post_comments.content,
+                            websearch_to_tsquery(post_comments.language, $1),
+                            $12
+                        ) AS headline,
+                        websites.subdomain::varchar AS website_subdomain,
+                        posts.slug AS post_slug,
+                        NULL::varchar AS username,
+                        ts_rank(post_comments.search, websearch_to_tsquery(post_comments.language, $1))
+                            + word_similarity($1, post_comments.content) AS search_rank,
+                        post_comments.created_at
+                    FROM post_comments
+                    INNER JOIN posts ON posts.id = post_comments.post_id
+                    INNER JOIN websites ON websites.id = posts.website_id
+                    WHERE posts.published_at IS NOT NULL AND websites.published_at IS NOT NULL
+                        AND posts.suspended_at IS NULL AND websites.suspended_at IS NULL
+                        AND (
+                            post_comments.search @@ websearch_to_tsquery(post_comments.language, $1)
+                            OR $1 <% post_comments.content
+                        )
+                        AND ($2::uuid IS NULL OR $2 = ANY(posts.hashtag_ids))
+                        AND ($3::uuid IS NULL OR posts.website_id = $3)
+                        AND ($4::uuid IS NULL OR post_comments.user_id = $4)
+                        AND ($5::date IS NULL OR post_comments.created_at >= $5)
+                        AND ($6::date IS NULL OR post_comments.created_at < $6 + 1)
+                        AND ($7::uuid IS NULL OR post_comments.id = $7)
+                ) AS results
+                WHERE ($8::uuid IS NULL OR $9::real IS NULL OR $10::timestamptz IS NULL)
+                    OR search_rank < $9 OR (
+                        search_rank = $9 AND (created_at < $10 OR (created_at = $10 AND id < $8))
+                    )
+                ORDER BY search_rank DESC, created_at DESC, id DESC LIMIT $11"#,
+                query,              // $1
+                hashtag_id,         // $2
+                website_id,         // $3
+                author_id,          // $4
+                filters.since,      // $5
+                filters.until,      // $6
+                only_id,            // $7
+                cursor_id,          // $8
+                cursor_search_rank, // $9
+                cursor_created_at,  // $10
+                limit,              // $11
+                headline_options,   // $12
+            )
+            .fetch_all(&core_context.db_pool)
+            .await
+        }
+    };
+
+    result.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::get_or_insert_hashtag;
+    use crate::enums::SearchKind;
+    use crate::models::{Post, SearchFilters};
+    use crate::test_utils::{fake_slug, insert_test_post, insert_test_website, setup_core_context};
+    use crate::utils::CursorPageParams;
+    use crate::CoreContext;
+
+    use super::{all_search_suggestions, all_text_search_languages, search_all};
+
+    async fn insert_test_post_with_content(core_context: &CoreContext, content: &str) -> Post {
+        let post = insert_test_post(core_context, None, None).await;
+
+        sqlx::query!(
+            "UPDATE websites SET published_at = current_timestamp WHERE id = $1",
+            post.website_id // $1
+        )
+        .execute(&core_context.db_pool)
+        .await
+        .unwrap();
+        sqlx::query!(
+            "UPDATE posts SET content = $2 WHERE id = $1",
+            post.id, // $1
+            content, // $2
+        )
+        .execute(&core_context.db_pool)
+        .await
+        .unwrap();
+
+        post
+    }
+
+    #[tokio::test]
+    async fn should_get_search_suggestions() {
+        let suggestions = all_search_suggestions("", 5).await;
+
+        assert!(suggestions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_suggest_similar_terms_for_misspelled_query() {
+        let _ = get_or_insert_hashtag("mangoharvest").await;
+
+        let suggestions = all_search_suggestions("mangoharvst", 10).await;
+
+        assert!(suggestions.contains(&"mangoharvest".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn should_get_text_search_languages() {
+        let languages = all_text_search_languages().await;
+
+        assert!(languages.contains(&"english".to_owned()));
+        assert!(languages.contains(&"simple".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn should_search_posts_with_highlighted_headline() {
+        let core_context = setup_core_context().await;
+        let word = format!("mango{}", fake_slug().replace("-", ""));
+        let post = insert_test_post_with_content(
+            &core_context,
+            &format!("The orchard grew a new variety called {word} this summer."),
+        )
+        .await;
+        let website = crate::commands::get_website_by_id(&core_context, post.website_id, None)
+            .await
+            .expect("Could not get website");
+        let filters = SearchFilters {
+            website: Some(&website),
+            ..Default::default()
+        };
+
+        let page = search_all(
+            &core_context,
+            &CursorPageParams::default(),
+            SearchKind::Posts,
+            &filters,
+            &word,
+        )
+        .await;
+
+        assert_eq!(page.nodes.len(), 1);
+        assert_eq!(page.nodes[0].id, post.id);
+        assert!(page.nodes[0].headline_html().contains(&format!("<mark>{word}</mark>")));
+    }
+
+    #[tokio::test]
+    async fn should_not_search_posts_of_other_websites() {
+        let core_context = setup_core_context().await;
+        let word = format!("mango{}", fake_slug().replace("-", ""));
+        let _ = insert_test_post_with_content(&core_context, &format!("Nothing but {word} here.")).await;
+        let website = insert_test_website(&core_context, None).await;
+        let filters = SearchFilters {
+            website: Some(&website),
+            ..Default::default()
+        };
+
+        let page = search_all(
+            &core_context,
+            &CursorPageParams::default(),
+            SearchKind::Posts,
+            &filters,
+            &word,
+        )
+        .await;
+
+        assert!(page.nodes.is_empty());
+    }
+}
@@ -0,0 +1,523 @@
+use std::collections::HashMap;
+
+use regex::{Captures, Regex};
+use uuid::Uuid;
+
+use crate::config::{BASIC_CONFIG, MISC_CONFIG};
+use crate::models::*;
+use crate::utils::*;
+use crate::CoreContext;
+
+const STATIC_ATTACHED_IMAGE_SIZE: u16 = 128;
+const STATIC_COVER_IMAGE_SIZE: (u16, u16) = (1200, 200);
+const STATIC_FEED_ITEMS: usize = 20;
+const STATIC_ICON_IMAGE_SIZE: u16 = 32;
+
+type BlobVariant = (Uuid, Option<(u16, u16, bool)>);
+
+fn asset_file_name(blob: &Blob, variant: Option<(u16, u16, bool)>) -> String {
+    let (width, height, fill) = variant.map(|(w, h, f)| (Some(w), Some(h), Some(f))).unwrap_or_default();
+
+    blob.variant_filename(width, height, fill)
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "._-".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Writes the original or a resized variant of a blob, returning its path relative to the static website root.
+fn write_asset(writer: &mut StaticWebsiteWriter, blob: &Blob, variant: Option<(u16, u16, bool)>) -> Option<String> {
+    let variant = variant.filter(|_| blob.mime().type_() == mime::IMAGE);
+    let path = format!("assets/{}/{}", blob.id, asset_file_name(blob, variant));
+    let fingerprint = blob.updated_at.unwrap_or(blob.created_at).to_rfc3339();
+
+    if !writer.keep_if_fresh(&path, &fingerprint) {
+        let (width, height, fill) = variant.map(|(w, h, f)| (Some(w), Some(h), Some(f))).unwrap_or_default();
+        let data = blob.read(width, height, fill)?;
+
+        writer.write(&path, &fingerprint, &data, Vec::new()).ok()?;
+    }
+
+    Some(path)
+}
+
+fn parse_blob_variant(query: Option<&str>) -> Option<(u16, u16, bool)> {
+    let params = query?
+        .replace("&amp;", "&")
+        .split('&')
+        .filter_map(|param| {
+            param
+                .split_once('=')
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        })
+        .collect::<HashMap<_, _>>();
+
+    Some((
+        params.get("width")?.parse().ok()?,
+        params.get("height")?.parse().ok()?,
+        params.get("fill").is_some_and(|fill| fill == "true"),
+    ))
+}
+
+/// Replaces the blob URLs of the given HTML with the paths of the assets written into the static website.
+fn rewrite_blob_urls(
+    html: &str,
+    writer: &mut StaticWebsiteWriter,
+    blobs: &HashMap<Uuid, Blob>,
+    assets: &mut HashMap<BlobVariant, Option<String>>,
+    dependencies: &mut Vec<String>,
+) -> String {
+    let blob_url_prefix = BASIC_CONFIG
+        .blob_url(Uuid::nil())
+        .to_string()
+        .replace(&Uuid::nil().to_string(), "");
+    let regex_blob_url = Regex::new(&format!(
+        r#"{}(?<id>[0-9a-fA-F-]{{36}})(?:\?(?<query>[^"'\s<>)]*))?"#,
+        regex::escape(&blob_url_prefix)
+    ))
+    .unwrap();
+
+    regex_blob_url
+        .replace_all(html, |captures: &Captures| {
+            let id = Uuid::try_parse(&captures["id"]).ok();
+            let variant = parse_blob_variant(captures.name("query").map(|query| query.as_str()));
+
+            let path = id.and_then(|id| {
+                let blob = blobs.get(&id)?;
+
+                assets
+                    .entry((id, variant))
+                    .or_insert_with(|| write_asset(writer, blob, variant))
+                    .clone()
+            });
+
+            if let Some(path) = path {
+                dependencies.push(path.clone());
+
+                format!("/{path}")
+            } else {
+                captures[0].to_owned()
+            }
+        })
+        .into_owned()
+}
+
+fn post_date(post: &Post) -> String {
+    post.published_at
+        .unwrap_or(post.created_at)
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+fn post_summary_html(post: &Post, content_preview_html: &str) -> String {
+    format!(
+        r#"<article><h2><a href="/posts/{}/">{}</a></h2><p><time datetime="{}">{}</time></p>{content_preview_html}</article>"#,
+        escape_html(&post.slug),
+        escape_html(&post.title),
+        post.published_at.unwrap_or(post.created_at).to_rfc3339(),
+        post_date(post),
+    )
+}
+
+fn pagination_html(page: usize, pages: usize) -> String {
+    let page_url = |page: usize| {
+        if page == 1 {
+            "/".to_owned()
+        } else {
+            format!("/page/{page}/")
+        }
+    };
+    let previous = if page > 1 {
+        format!(r#"<a href="{}">&larr;</a>"#, page_url(page - 1))
+    } else {
+        "<span></span>".to_owned()
+    };
+    let next = if page < pages {
+        format!(r#"<a href="{}">&rarr;</a>"#, page_url(page + 1))
+    } else {
+        "<span></span>".to_owned()
+    };
+
+    format!(r#"<nav class="pagination">{previous}{next}</nav>"#)
+}
+
+/// Removes the static copy of a website, used once the website is unpublished, suspended or deleted.
+pub async fn delete_static_website(website_id: Uuid) -> MutResult {
+    let static_path = MISC_CONFIG.storage_static_websites_path().join(website_id.to_string());
+
+    if static_path.exists() {
+        std::fs::remove_dir_all(static_path)?;
+    }
+
+    crate::mut_success!()
+}
+
+/// Renders every published post, hashtag page, index page and the feed of a published website into
+/// `Website::static_path`, along with the resized images they use. Pages and assets that did not change since the
+/// previous generation are kept as they are, unless `force` is set.
+pub async fn generate_static_website(
+    core_context: &CoreContext,
+    website: &Website,
+    force: bool,
+) -> MutResult<StaticWebsiteReport> {
+    if !website.is_published() {
+        return crate::mut_error!();
+    }
+
+    let posts = sqlx::query_as!(
+        Post,
+        r#"SELECT
+            id,
+            website_id,
+            user_id,
+            translation_group_id,
+            language::varchar as "language!",
+            title,
+            slug,
+            content,
+            variables,
+            hashtag_ids,
+            mentioned_user_ids,
+            cover_image_blob_id,
+            blob_ids,
+            published_at,
+            suspended_at,
+            moderation_reason,
+            modified_at,
+            NULL::real AS search_rank,
+            created_at,
+            updated_at
+        FROM posts WHERE website_id = $1 AND published_at IS NOT NULL AND suspended_at IS NULL
+        ORDER BY published_at DESC, id DESC"#,
+        website.id, // $1
+    )
+    .fetch_all(&core_context.db_pool)
+    .await?;
+
+    let mut writer = StaticWebsiteWriter::new(website.static_path(), force);
+    let mut assets = HashMap::new();
+
+    let mut blob_ids = website
+        .icon_image_blob_id
+        .into_iter()
+        .chain(website.cover_image_blob_id)
+        .collect::<Vec<_>>();
+
+    for post in &posts {
+        blob_ids.extend(post.blob_ids.iter().chain(post.cover_image_blob_id.as_ref()));
+    }
+
+    blob_ids.sort();
+    blob_ids.dedup();
+
+    let blobs = super::all_blobs_by_ids(blob_ids, Some(website), None)
+        .await
+        .into_iter()
+        .map(|blob| (blob.id, blob))
+        .collect::<HashMap<_, _>>();
+
+    let icon_url = website
+        .icon_image_blob_id
+        .and_then(|id| blobs.get(&id))
+        .and_then(|blob| {
+            write_asset(
+                &mut writer,
+                blob,
+                Some((STATIC_ICON_IMAGE_SIZE, STATIC_ICON_IMAGE_SIZE, true)),
+            )
+        })
+        .map(|path| format!("/{path}"));
+    let navigation_items = super::all_navigation_items_by_website(website)
+        .await
+        .iter()
+        .map(|item| (item.title.to_string(), item.url.to_string()))
+        .collect::<Vec<_>>();
+    let layout = StaticLayout {
+        website_name: &website.name,
+        language: &website.language,
+        icon_path: icon_url.as_deref(),
+        navigation_items: &navigation_items,
+    };
+    let site_fingerprint = format!(
+        "{}|{}|{:?}",
+        website.updated_at.unwrap_or(website.created_at).to_rfc3339(),
+        icon_url.clone().unwrap_or_default(),
+        navigation_items,
+    );
+
+    let mut summaries = Vec::with_capacity(posts.len());
+    let mut hashtag_posts: HashMap<String, Vec<usize>> = HashMap::new();
+
+    for (index, post) in posts.iter().enumerate() {
+        let hashtags = post.hashtags().await;
+
+        summaries.push(post_summary_html(post, &post.content_preview_html().await));
+
+        for hashtag in &hashtags {
+            hashtag_posts.entry(hashtag.name.to_string()).or_default().push(index);
+        }
+
+        let path = format!("posts/{}/index.html", post.slug);
+        let fingerprint = format!(
+            "{site_fingerprint}|{}",
+            post.updated_at.unwrap_or(post.created_at).to_rfc3339()
+        );
+
+        // Dynamic templates can change without the post being updated, so they are always rendered again and only
+        // written when the output differs.
+        if !is_dynamic_template(&post.content, &post.variables) && writer.keep_if_fresh(&path, &fingerprint) {
+            continue;
+        }
+
+        let mut dependencies = Vec::new();
+        let cover_image = post
+            .cover_image_blob_id
+            .and_then(|id| blobs.get(&id))
+            .and_then(|blob| {
+                write_asset(
+                    &mut writer,
+                    blob,
+                    Some((STATIC_COVER_IMAGE_SIZE.0, STATIC_COVER_IMAGE_SIZE.1, true)),
+                )
+            })
+            .map(|path| {
+                let html = format!(r#"<img src="/{path}" alt="{}">"#, escape_html(&post.title));
+
+                dependencies.push(path);
+
+                html
+            })
+            .unwrap_or_default();
+        let content_html = rewrite_blob_urls(
+            &post.content_html(core_context).await,
+            &mut writer,
+            &blobs,
+            &mut assets,
+            &mut dependencies,
+        );
+        let mut attached_images = String::new();
+
+        for blob in post.blob_ids.iter().filter_map(|id| blobs.get(id)) {
+            let Some(path) = write_asset(&mut writer, blob, None) else {
+                continue;
+            };
+            let Some(thumbnail_path) = write_asset(
+                &mut writer,
+                blob,
+                Some((STATIC_ATTACHED_IMAGE_SIZE, STATIC_ATTACHED_IMAGE_SIZE, true)),
+            ) else {
+                continue;
+            };
+
+            attached_images += &format!(
+                r#"<a href="/{path}"><img src="/{thumbnail_path}" alt="{}"></a> "#,
+                escape_html(&blob.alt_text)
+            );
+
+            dependencies.extend([path, thumbnail_path]);
+        }
+        let hashtags = hashtags
+            .iter()
+            .map(|hashtag| format!(r#"<a href="/hashtags/{0}/">#{0}</a> "#, escape_html(&hashtag.name)))
+            .collect::<String>();
+        let body = format!(
+            r#"<article>{cover_image}<h1>{}</h1><p><time datetime="{}">{}</time></p>{content_html}<p>{attached_images}</p><p>{hashtags}</p></article>"#,
+            escape_html(&post.title),
+            post.published_at.unwrap_or(post.created_at).to_rfc3339(),
+            post_date(post),
+        );
+
+        writer.write(
+            &path,
+            &fingerprint,
+            render_static_page(&layout, &post.title, &body).as_bytes(),
+            dependencies,
+        )?;
+    }
+
+    let cover_image = website
+        .cover_image_blob_id
+        .and_then(|id| blobs.get(&id))
+        .and_then(|blob| {
+            write_asset(
+                &mut writer,
+                blob,
+                Some((STATIC_COVER_IMAGE_SIZE.0, STATIC_COVER_IMAGE_SIZE.1, true)),
+            )
+        })
+        .map(|path| format!(r#"<img src="/{path}" alt="">"#))
+        .unwrap_or_default();
+    let description_html = rewrite_blob_urls(
+        &website.description_html().await,
+        &mut writer,
+        &blobs,
+        &mut assets,
+        &mut Vec::new(),
+    );
+    let pages = summaries.chunks(STATIC_POSTS_PER_PAGE).count().max(1);
+
+    for page in 1..=pages {
+        let path = if page == 1 {
+            "index.html".to_owned()
+        } else {
+            format!("page/{page}/index.html")
+        };
+        let header = if page == 1 {
+            format!("{cover_image}<section>{description_html}</section>")
+        } else {
+            String::new()
+        };
+        let summaries = summaries
+            .iter()
+            .skip((page - 1) * STATIC_POSTS_PER_PAGE)
+            .take(STATIC_POSTS_PER_PAGE)
+            .cloned()
+            .collect::<String>();
+        let body = format!("{header}{summaries}{}", pagination_html(page, pages));
+
+        writer.write(&path, "", render_static_page(&layout, "", &body).as_bytes(), Vec::new())?;
+    }
+
+    for (name, indexes) in &hashtag_posts {
+        let title = format!("#{name}");
+        let summaries = indexes
+            .iter()
+            .map(|index| summaries[*index].as_str())
+            .collect::<String>();
+        let body = format!("<h1>{}</h1>{summaries}", escape_html(&title));
+
+        writer.write(
+            &format!("hashtags/{name}/index.html"),
+            "",
+            render_static_page(&layout, &title, &body).as_bytes(),
+            Vec::new(),
+        )?;
+    }
+
+    let website_url = website.url();
+    let mut feed_items = Vec::with_capacity(STATIC_FEED_ITEMS);
+
+    for post in posts.iter().take(STATIC_FEED_ITEMS) {
+        feed_items.push(StaticFeedItem {
+            title: post.title.clone(),
+            url: website_url.join(&format!("posts/{}/", post.slug)).unwrap().to_string(),
+            description_html: post.content_preview_html().await,
+            published_at: post.published_at.unwrap_or(post.created_at),
+        });
+    }
+
+    writer.write(
+        "feed.xml",
+        "",
+        render_static_feed(&layout, website_url.as_str(), &feed_items).as_bytes(),
+        Vec::new(),
+    )?;
+
+    let report = writer.finish()?;
+
+    crate::mut_success!(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::{update_website, WebsiteInput};
+    use crate::models::Website;
+    use crate::test_utils::{insert_test_post, insert_test_user, insert_test_website, setup_core_context};
+    use crate::CoreContext;
+
+    use super::{delete_static_website, generate_static_website};
+
+    async fn publish_website(core_context: &CoreContext, website: &Website) -> Website {
+        update_website(
+            core_context,
+            website,
+            WebsiteInput {
+                name: &website.name,
+                description: &website.description,
+                icon_image_blob: None,
+                cover_image_blob: None,
+                light_theme: &website.light_theme,
+                dark_theme: &website.dark_theme,
+                language: &website.language,
+                publish: true,
+            },
+        )
+        .await
+        .unwrap()
+        .data
+    }
+
+    #[tokio::test]
+    async fn should_delete_static_website() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let _ = insert_test_post(&core_context, Some(&website), Some(&user)).await;
+        let website = publish_website(&core_context, &website).await;
+
+        generate_static_website(&core_context, &website, false).await.unwrap();
+
+        let result = delete_static_website(website.id).await;
+
+        assert!(result.is_ok());
+        assert!(!website.static_path().exists());
+    }
+
+    #[tokio::test]
+    async fn should_generate_static_website() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let post = insert_test_post(&core_context, Some(&website), Some(&user)).await;
+        let website = publish_website(&core_context, &website).await;
+
+        let result = generate_static_website(&core_context, &website, false).await;
+
+        assert!(result.is_ok());
+
+        let static_path = website.static_path();
+
+        assert!(static_path.join("index.html").exists());
+        assert!(static_path.join("feed.xml").exists());
+        assert!(static_path.join(format!("posts/{}/index.html", post.slug)).exists());
+        assert!(static_path.join("assets").exists());
+    }
+
+    #[tokio::test]
+    async fn should_regenerate_static_website_incrementally() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        insert_test_post(&core_context, Some(&website), Some(&user)).await;
+        let website = publish_website(&core_context, &website).await;
+        let first_report = generate_static_website(&core_context, &website, false)
+            .await
+            .unwrap()
+            .data;
+
+        let result = generate_static_website(&core_context, &website, false).await;
+
+        assert!(result.is_ok());
+
+        let report = result.unwrap().data;
+
+        assert_eq!(report.written_files, 0);
+        assert_eq!(report.removed_files, 0);
+        assert_eq!(report.unchanged_files, first_report.written_files);
+    }
+
+    #[tokio::test]
+    async fn should_not_generate_static_website_when_is_not_published() {
+        let core_context = setup_core_context().await;
+        let website = insert_test_website(&core_context, None).await;
+
+        let result = generate_static_website(&core_context, &website, false).await;
+
+        assert!(result.is_err());
+    }
+}
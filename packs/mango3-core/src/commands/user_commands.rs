@@ -4,11 +4,25 @@ use crate::enums::UserRole;
 use crate::models::*;
 use crate::CoreContext;
 
+#[cfg(any(
+    feature = "disable-user",
+    feature = "enable-user",
+    feature = "reset-user-password",
+    feature = "update-user-email",
+    feature = "update-user-invitation-allowance",
+    feature = "update-user-password",
+    feature = "update-user-role",
+    feature = "update-user-storage-quota"
+))]
+use crate::enums::{AuditLogAction, AuditLogTargetType};
+
 #[cfg(any(
     feature = "insert-user",
     feature = "update-user-email",
+    feature = "update-user-invitation-allowance",
     feature = "update-user-password",
-    feature = "update-user-profile"
+    feature = "update-user-profile",
+    feature = "update-user-storage-quota"
 ))]
 use crate::enums::{Input, InputError};
 #[cfg(any(feature = "insert-user", feature = "update-user-profile"))]
@@ -42,6 +56,10 @@ impl Validator {
     }
 }
 
+/// Mentions after this number are kept as text and their users are not notified.
+#[cfg(feature = "all-mentioned-users")]
+const MAX_MENTIONS: usize = 20;
+
 #[cfg(feature = "all-admin-users")]
 pub async fn all_admin_users(core_context: &CoreContext) -> Vec<User> {
     sqlx::query_as!(
@@ -61,6 +79,7 @@ pub async fn all_admin_users(core_context: &CoreContext) -> Vec<User> {
                 hashtag_ids,
                 avatar_image_blob_id,
                 role as "role!: UserRole",
+                storage_quota,
                 disabled_at,
                 created_at,
                 updated_at
@@ -71,6 +90,31 @@ pub async fn all_admin_users(core_context: &CoreContext) -> Vec<User> {
     .unwrap_or_default()
 }
 
+#[cfg(feature = "all-mentioned-users")]
+pub async fn all_mentioned_users(core_context: &CoreContext, content: &str) -> Vec<User> {
+    let usernames = crate::utils::find_mentions(content);
+
+    futures::future::join_all(
+        usernames
+            .iter()
+            .take(MAX_MENTIONS)
+            .map(|username| get_user_by_username(core_context, username)),
+    )
+    .await
+    .into_iter()
+    .filter_map(|result| result.ok().filter(|user| !user.is_disabled()))
+    .collect()
+}
+
+#[cfg(feature = "all-mentioned-users-by-ids")]
+pub async fn all_mentioned_users_by_ids(core_context: &CoreContext, ids: &[Uuid]) -> Vec<User> {
+    futures::future::join_all(ids.iter().map(|id| get_user_by_id(core_context, *id)))
+        .await
+        .into_iter()
+        .filter_map(|result| result.ok().filter(|user| !user.is_disabled()))
+        .collect()
+}
+
 #[cfg(feature = "authenticate-user")]
 pub async fn authenticate_user(
     core_context: &CoreContext,
@@ -115,6 +159,43 @@ pub async fn clear_user_cache(user: &User) {
     );
 }
 
+/// Mentions of disabled users are shown as text, so the content mentioning the user has to be rendered again when they
+/// are disabled or enabled.
+#[cfg(feature = "clear-mentions-cache")]
+async fn clear_mentions_cache(core_context: &CoreContext, user: &User) {
+    use crate::constants::{PREFIX_POST_COMMENT_CONTENT_HTML, PREFIX_POST_CONTENT_HTML};
+    use crate::models::{POST_COMMENT_CONTENT_HTML, POST_CONTENT_HTML};
+    use crate::utils::AsyncRedisCacheTrait;
+
+    let (post_ids, post_comment_ids) = futures::join!(
+        sqlx::query_scalar!(
+            "SELECT id FROM posts WHERE $1 = ANY(mentioned_user_ids)",
+            user.id // $1
+        )
+        .fetch_all(&core_context.db_pool),
+        sqlx::query_scalar!(
+            "SELECT id FROM post_comments WHERE $1 = ANY(mentioned_user_ids)",
+            user.id // $1
+        )
+        .fetch_all(&core_context.db_pool),
+    );
+    let post_ids = post_ids.unwrap_or_default();
+    let post_comment_ids = post_comment_ids.unwrap_or_default();
+
+    futures::join!(
+        futures::future::join_all(
+            post_ids
+                .iter()
+                .map(|id| POST_CONTENT_HTML.cache_remove(PREFIX_POST_CONTENT_HTML, id))
+        ),
+        futures::future::join_all(
+            post_comment_ids
+                .iter()
+                .map(|id| POST_COMMENT_CONTENT_HTML.cache_remove(PREFIX_POST_COMMENT_CONTENT_HTML, id))
+        ),
+    );
+}
+
 #[cfg(feature = "confirm-user-email")]
 pub async fn confirm_user_email(core_context: &CoreContext, user: &User) -> crate::utils::MutResult<User> {
     let result = sqlx::query_as!(
@@ -135,6 +216,7 @@ pub async fn confirm_user_email(core_context: &CoreContext, user: &User) -> crat
             hashtag_ids,
             avatar_image_blob_id,
             role as "role!: UserRole",
+            storage_quota,
             disabled_at,
             created_at,
             updated_at"#,
@@ -154,55 +236,99 @@ pub async fn confirm_user_email(core_context: &CoreContext, user: &User) -> crat
 }
 
 #[cfg(feature = "disable-user")]
-pub async fn disable_user(core_context: &CoreContext, user: &User) -> crate::utils::MutResult {
-    let result = sqlx::query!(
-        "UPDATE users SET disabled_at = current_timestamp WHERE role = 'user' AND disabled_at IS NULL AND id = $1",
+pub async fn disable_user(core_context: &CoreContext, user: &User, actor: &AuditActor) -> crate::utils::MutResult {
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    // No row is returned when the user can't be disabled or is already disabled.
+    let Some(record) = sqlx::query!(
+        "UPDATE users SET disabled_at = current_timestamp WHERE role = 'user' AND disabled_at IS NULL AND id = $1
+        RETURNING disabled_at",
         user.id
     )
-    .execute(&core_context.db_pool)
-    .await;
+    .fetch_optional(&mut *transaction)
+    .await?
+    else {
+        return crate::mut_error!();
+    };
 
-    match result {
-        Ok(_) => {
-            super::delete_all_user_sessions(core_context, user)
-                .await
-                .expect("Could not delete user sessions");
+    super::insert_audit_log(
+        &mut *transaction,
+        actor,
+        AuditLogAction::DisableUser,
+        AuditLogTargetType::User,
+        user.id,
+        Some(serde_json::json!({ "disabled_at": user.disabled_at })),
+        Some(serde_json::json!({ "disabled_at": record.disabled_at })),
+    )
+    .await?;
 
-            core_context
-                .jobs
-                .mailer(user, crate::enums::MailerJobCommand::Disabled)
-                .await;
+    transaction.commit().await?;
 
-            clear_user_cache(user).await;
+    super::delete_all_user_sessions(core_context, user)
+        .await
+        .expect("Could not delete user sessions");
 
-            crate::mut_success!()
-        }
-        Err(_) => crate::mut_error!(),
-    }
+    core_context
+        .jobs
+        .mailer(user, crate::enums::MailerJobCommand::Disabled)
+        .await;
+
+    clear_user_cache(user).await;
+    clear_mentions_cache(core_context, user).await;
+
+    crate::mut_success!()
 }
 
 #[cfg(feature = "enable-user")]
-pub async fn enable_user(core_context: &CoreContext, user: &User) -> crate::utils::MutResult {
+pub async fn enable_user(core_context: &CoreContext, user: &User, actor: &AuditActor) -> crate::utils::MutResult {
+    let mut transaction = core_context.db_pool.begin().await?;
+
     let result = sqlx::query!(
         "UPDATE users SET disabled_at = NULL WHERE disabled_at IS NOT NULL AND id = $1",
         user.id
     )
-    .execute(&core_context.db_pool)
-    .await;
+    .execute(&mut *transaction)
+    .await?;
 
-    match result {
-        Ok(_) => {
-            core_context
-                .jobs
-                .mailer(user, crate::enums::MailerJobCommand::Enabled)
-                .await;
+    if result.rows_affected() == 0 {
+        return crate::mut_error!();
+    }
 
-            clear_user_cache(user).await;
+    super::insert_audit_log(
+        &mut *transaction,
+        actor,
+        AuditLogAction::EnableUser,
+        AuditLogTargetType::User,
+        user.id,
+        Some(serde_json::json!({ "disabled_at": user.disabled_at })),
+        Some(serde_json::json!({ "disabled_at": null })),
+    )
+    .await?;
 
-            crate::mut_success!()
-        }
-        Err(_) => crate::mut_error!(),
-    }
+    transaction.commit().await?;
+
+    core_context
+        .jobs
+        .mailer(user, crate::enums::MailerJobCommand::Enabled)
+        .await;
+
+    clear_user_cache(user).await;
+    clear_mentions_cache(core_context, user).await;
+
+    crate::mut_success!()
+}
+
+#[cfg(feature = "get-used-user-storage")]
+pub async fn get_used_user_storage(user: &User) -> sqlx::Result<size::Size> {
+    let db_pool = crate::db_pool().await;
+
+    sqlx::query!(
+        "SELECT SUM(byte_size)::bigint AS total_size FROM blobs WHERE user_id = $1 AND website_id IS NULL LIMIT 1",
+        user.id // $1
+    )
+    .fetch_one(db_pool)
+    .await
+    .map(|record| size::Size::from_bytes(record.total_size.unwrap_or_default()))
 }
 
 #[cfg(feature = "get-user-by-id")]
@@ -230,6 +356,7 @@ pub async fn get_user_by_id(core_context: &CoreContext, id: Uuid) -> sqlx::Resul
             hashtag_ids,
             avatar_image_blob_id,
             role as "role!: UserRole",
+            storage_quota,
             disabled_at,
             created_at,
             updated_at
@@ -269,6 +396,7 @@ pub async fn get_user_by_username(core_context: &CoreContext, username: &str) ->
             hashtag_ids,
             avatar_image_blob_id,
             role as "role!: UserRole",
+            storage_quota,
             disabled_at,
             created_at,
             updated_at
@@ -308,6 +436,7 @@ pub async fn get_user_by_username_or_email(core_context: &CoreContext, username_
             hashtag_ids,
             avatar_image_blob_id,
             role as "role!: UserRole",
+            storage_quota,
             disabled_at,
             created_at,
             updated_at
@@ -322,6 +451,21 @@ pub async fn get_user_by_username_or_email(core_context: &CoreContext, username_
     .await
 }
 
+#[cfg(feature = "get-user-invitation-allowance")]
+pub async fn get_user_invitation_allowance(core_context: &CoreContext, user: &User) -> i32 {
+    sqlx::query!(
+        "SELECT invitation_allowance FROM users WHERE id = $1 LIMIT 1",
+        user.id // $1
+    )
+    .fetch_one(&core_context.db_pool)
+    .await
+    .map(|record| record.invitation_allowance)
+    .unwrap_or_default()
+}
+
+/// Registers a user, with the role carried by the invitation code when one is given. A role other than the default one
+/// is only granted to the email the code was sent to. The code is used in the same transaction, so the registration
+/// fails if the code was used by someone else in the meantime.
 #[cfg(feature = "insert-user")]
 pub async fn insert_user(
     core_context: &CoreContext,
@@ -332,6 +476,7 @@ pub async fn insert_user(
     birthdate: &str,
     language_code: &str,
     country_alpha2: &str,
+    invitation_code: Option<&InvitationCode>,
 ) -> crate::utils::MutResult<User> {
     use crate::config::USER_CONFIG;
     use crate::constants::{BLACKLISTED_SLUGS, REGEX_EMAIL, REGEX_USERNAME};
@@ -378,6 +523,12 @@ pub async fn insert_user(
         validator.custom_validation(Input::Email, InputError::AlreadyInUse, &|| !email_exists);
     }
 
+    if let Some(invitation_code) = invitation_code {
+        validator.custom_validation(Input::Email, InputError::IsInvalid, &|| {
+            invitation_code.role == USER_CONFIG.default_role() || invitation_code.email == email
+        });
+    }
+
     validator.validate_password(Input::Password, password);
 
     validator.validate_full_name(full_name);
@@ -392,6 +543,12 @@ pub async fn insert_user(
 
     let display_name = full_name.split(' ').next().unwrap();
     let encrypted_password = crate::utils::encrypt_password(password);
+    let role = invitation_code.map_or_else(
+        || USER_CONFIG.default_role(),
+        |invitation_code| invitation_code.role.clone(),
+    );
+
+    let mut transaction = core_context.db_pool.begin().await?;
 
     let result = sqlx::query_as!(
         User,
@@ -425,25 +582,37 @@ pub async fn insert_user(
             hashtag_ids,
             avatar_image_blob_id,
             role as "role!: UserRole",
+            storage_quota,
             disabled_at,
             created_at,
             updated_at"#,
-        username,                                 // $1
-        email,                                    // $2
-        encrypted_password,                       // $3
-        display_name,                             // $4
-        full_name,                                // $5
-        birthdate,                                // $6
-        language_code,                            // $7
-        country.unwrap().alpha2,                  // $8
-        &USER_CONFIG.default_role() as &UserRole, // $9
-        USER_CONFIG.default_disabled,             // $10
+        username,                     // $1
+        email,                        // $2
+        encrypted_password,           // $3
+        display_name,                 // $4
+        full_name,                    // $5
+        birthdate,                    // $6
+        language_code,                // $7
+        country.unwrap().alpha2,      // $8
+        role as UserRole,             // $9
+        USER_CONFIG.default_disabled, // $10
     )
-    .fetch_one(&core_context.db_pool)
+    .fetch_one(&mut *transaction)
     .await;
 
     match result {
         Ok(user) => {
+            if let Some(invitation_code) = invitation_code {
+                if super::use_invitation_code(&mut *transaction, invitation_code, &user)
+                    .await
+                    .is_err()
+                {
+                    return crate::mut_error!();
+                }
+            }
+
+            transaction.commit().await?;
+
             futures::future::join(
                 core_context
                     .jobs
@@ -458,10 +627,26 @@ pub async fn insert_user(
     }
 }
 
+#[cfg(feature = "notify-mentioned-users")]
+pub async fn notify_mentioned_users(core_context: &CoreContext, author: &User, users: &[User], url: &url::Url) {
+    futures::future::join_all(users.iter().filter(|user| user.id != author.id).map(|user| {
+        core_context.jobs.mailer(
+            user,
+            crate::enums::MailerJobCommand::Mention {
+                author_username: author.username.clone(),
+                url: url.to_string(),
+            },
+        )
+    }))
+    .await;
+}
+
+/// Lists the users sorted by username, filtered by a part of their username or email, or by their id.
 #[cfg(feature = "paginate-users")]
-pub async fn paginate_users(
-    core_context: &CoreContext,
+pub async fn paginate_users<'a>(
+    core_context: &'a CoreContext,
     cursor_page_params: &crate::utils::CursorPageParams,
+    query: &'a str,
 ) -> crate::utils::CursorPage<User> {
     crate::cursor_page!(
         core_context,
@@ -488,12 +673,17 @@ pub async fn paginate_users(
                     hashtag_ids,
                     avatar_image_blob_id,
                     role as "role!: UserRole",
+                    storage_quota,
                     disabled_at,
                     created_at,
                     updated_at
-                FROM users WHERE $1::citext IS NULL OR username > $1 ORDER BY username ASC LIMIT $2"#,
-                cursor_username, // $1
-                limit,           // $2
+                FROM users
+                WHERE ($1 = '' OR id::text = $1 OR username ILIKE '%' || $1 || '%' OR email ILIKE '%' || $1 || '%')
+                    AND ($2::citext IS NULL OR username > $2)
+                ORDER BY username ASC LIMIT $3"#,
+                query.trim(),    // $1
+                cursor_username, // $2
+                limit,           // $3
             )
             .fetch_all(&core_context.db_pool)
             .await
@@ -508,6 +698,7 @@ pub async fn reset_user_password(
     core_context: &CoreContext,
     user: &User,
     new_password: &str,
+    actor: &AuditActor,
 ) -> crate::utils::MutResult<User> {
     let mut validator = crate::validator!();
 
@@ -519,6 +710,8 @@ pub async fn reset_user_password(
 
     let encrypted_password = crate::utils::encrypt_password(new_password);
 
+    let mut transaction = core_context.db_pool.begin().await?;
+
     let result = sqlx::query_as!(
         User,
         r#"UPDATE users SET encrypted_password = $2 WHERE disabled_at IS NULL AND id = $1
@@ -537,17 +730,31 @@ pub async fn reset_user_password(
                 hashtag_ids,
                 avatar_image_blob_id,
                 role as "role!: UserRole",
+                storage_quota,
                 disabled_at,
                 created_at,
                 updated_at"#,
         user.id,            // $1
         encrypted_password, // $2
     )
-    .fetch_one(&core_context.db_pool)
+    .fetch_one(&mut *transaction)
     .await;
 
     match result {
         Ok(user1) => {
+            super::insert_audit_log(
+                &mut *transaction,
+                actor,
+                AuditLogAction::ResetUserPassword,
+                AuditLogTargetType::User,
+                user.id,
+                None,
+                None,
+            )
+            .await?;
+
+            transaction.commit().await?;
+
             clear_user_cache(user).await;
 
             crate::mut_success!(user1)
@@ -589,6 +796,7 @@ pub async fn update_user_email(
     user: &User,
     email: &str,
     password: &str,
+    actor: &AuditActor,
 ) -> crate::utils::MutResult<User> {
     use crate::enums::{Input, InputError};
 
@@ -625,6 +833,8 @@ pub async fn update_user_email(
         return crate::mut_success!(user.clone());
     }
 
+    let mut transaction = core_context.db_pool.begin().await?;
+
     let result = sqlx::query_as!(
         User,
         r#"UPDATE users SET email = $2::text, email_confirmed_at = NULL WHERE disabled_at IS NULL AND id = $1
@@ -643,17 +853,31 @@ pub async fn update_user_email(
             hashtag_ids,
             avatar_image_blob_id,
             role as "role!: UserRole",
+            storage_quota,
             disabled_at,
             created_at,
             updated_at"#,
         user.id, // $1
         email,   // $2
     )
-    .fetch_one(&core_context.db_pool)
+    .fetch_one(&mut *transaction)
     .await;
 
     match result {
         Ok(user1) => {
+            super::insert_audit_log(
+                &mut *transaction,
+                actor,
+                AuditLogAction::UpdateUserEmail,
+                AuditLogTargetType::User,
+                user.id,
+                Some(serde_json::json!({ "email": user.email })),
+                Some(serde_json::json!({ "email": user1.email })),
+            )
+            .await?;
+
+            transaction.commit().await?;
+
             clear_user_cache(user).await;
 
             crate::mut_success!(user1)
@@ -662,12 +886,60 @@ pub async fn update_user_email(
     }
 }
 
+#[cfg(feature = "update-user-invitation-allowance")]
+pub async fn update_user_invitation_allowance(
+    core_context: &CoreContext,
+    user: &User,
+    invitation_allowance: &str,
+    actor: &AuditActor,
+) -> crate::utils::MutResult {
+    let mut validator = crate::validator!();
+
+    let invitation_allowance = invitation_allowance.trim().parse::<i32>().ok();
+
+    validator.custom_validation(Input::InvitationAllowance, InputError::IsInvalid, || {
+        invitation_allowance.is_some_and(|value| value >= 0)
+    });
+
+    if !validator.is_valid {
+        return crate::mut_error!(validator.errors);
+    }
+
+    let before_value = super::get_user_invitation_allowance(core_context, user).await;
+
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    sqlx::query!(
+        "UPDATE users SET invitation_allowance = $2 WHERE id = $1",
+        user.id,              // $1
+        invitation_allowance, // $2
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    super::insert_audit_log(
+        &mut *transaction,
+        actor,
+        AuditLogAction::UpdateUserInvitationAllowance,
+        AuditLogTargetType::User,
+        user.id,
+        Some(serde_json::json!({ "invitation_allowance": before_value })),
+        Some(serde_json::json!({ "invitation_allowance": invitation_allowance })),
+    )
+    .await?;
+
+    transaction.commit().await?;
+
+    crate::mut_success!()
+}
+
 #[cfg(feature = "update-user-password")]
 pub async fn update_user_password(
     core_context: &CoreContext,
     user: &User,
     current_password: &str,
     new_password: &str,
+    actor: &AuditActor,
 ) -> crate::utils::MutResult<User> {
     let mut validator = crate::validator!();
 
@@ -689,6 +961,8 @@ pub async fn update_user_password(
 
     let encrypted_password = crate::utils::encrypt_password(new_password);
 
+    let mut transaction = core_context.db_pool.begin().await?;
+
     let result = sqlx::query_as!(
         User,
         r#"UPDATE users SET encrypted_password = $2 WHERE disabled_at IS NULL AND id = $1 RETURNING
@@ -706,17 +980,31 @@ pub async fn update_user_password(
             hashtag_ids,
             avatar_image_blob_id,
             role as "role!: UserRole",
+            storage_quota,
             disabled_at,
             created_at,
             updated_at"#,
         user.id,            // $1
         encrypted_password, // $2
     )
-    .fetch_one(&core_context.db_pool)
+    .fetch_one(&mut *transaction)
     .await;
 
     match result {
         Ok(user1) => {
+            super::insert_audit_log(
+                &mut *transaction,
+                actor,
+                AuditLogAction::UpdateUserPassword,
+                AuditLogTargetType::User,
+                user.id,
+                None,
+                None,
+            )
+            .await?;
+
+            transaction.commit().await?;
+
             clear_user_cache(user).await;
 
             crate::mut_success!(user1)
@@ -790,6 +1078,7 @@ pub async fn update_user_profile(
                 hashtag_ids,
                 avatar_image_blob_id,
                 role as "role!: UserRole",
+                storage_quota,
                 disabled_at,
                 created_at,
                 updated_at"#,
@@ -820,14 +1109,17 @@ pub async fn update_user_role(
     core_context: &CoreContext,
     user: &User,
     role: UserRole,
+    actor: &AuditActor,
 ) -> crate::utils::MutResult<User> {
+    let mut transaction = core_context.db_pool.begin().await?;
+
     if role == UserRole::Superuser {
-        let _ = sqlx::query!(
+        sqlx::query!(
             r#"UPDATE users SET role = 'admin' WHERE role = 'superuser' AND id != $1"#,
             user.id
         )
-        .execute(&core_context.db_pool)
-        .await;
+        .execute(&mut *transaction)
+        .await?;
     }
 
     let result = sqlx::query_as!(
@@ -847,17 +1139,103 @@ pub async fn update_user_role(
                 hashtag_ids,
                 avatar_image_blob_id,
                 role as "role!: UserRole",
+                storage_quota,
                 disabled_at,
                 created_at,
                 updated_at"#,
         user.id,          // $1
         role as UserRole, // $2
     )
-    .fetch_one(&core_context.db_pool)
+    .fetch_one(&mut *transaction)
+    .await;
+
+    match result {
+        Ok(user1) => {
+            super::insert_audit_log(
+                &mut *transaction,
+                actor,
+                AuditLogAction::UpdateUserRole,
+                AuditLogTargetType::User,
+                user.id,
+                Some(serde_json::json!({ "role": user.role.to_string() })),
+                Some(serde_json::json!({ "role": user1.role.to_string() })),
+            )
+            .await?;
+
+            transaction.commit().await?;
+
+            clear_user_cache(user).await;
+
+            crate::mut_success!(user1)
+        }
+        Err(_) => crate::mut_error!(),
+    }
+}
+
+#[cfg(feature = "update-user-storage-quota")]
+pub async fn update_user_storage_quota(
+    core_context: &CoreContext,
+    user: &User,
+    storage_quota: &str,
+    actor: &AuditActor,
+) -> crate::utils::MutResult<User> {
+    let mut validator = crate::validator!();
+
+    let storage_quota = storage_quota.trim();
+    let max_storage = crate::utils::parse_size(storage_quota);
+
+    validator.custom_validation(Input::StorageQuota, InputError::IsInvalid, &|| {
+        storage_quota.is_empty() || max_storage.is_some()
+    });
+
+    if !validator.is_valid {
+        return crate::mut_error!(validator.errors);
+    }
+
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    let result = sqlx::query_as!(
+        User,
+        r#"UPDATE users SET storage_quota = $2 WHERE id = $1 RETURNING
+                id,
+                username,
+                email,
+                email_confirmed_at,
+                encrypted_password,
+                display_name,
+                full_name,
+                birthdate,
+                language_code,
+                country_alpha2,
+                bio,
+                hashtag_ids,
+                avatar_image_blob_id,
+                role as "role!: UserRole",
+                storage_quota,
+                disabled_at,
+                created_at,
+                updated_at"#,
+        user.id,                              // $1
+        max_storage.map(|size| size.bytes()), // $2
+    )
+    .fetch_one(&mut *transaction)
     .await;
 
     match result {
         Ok(user1) => {
+            super::insert_audit_log(
+                &mut *transaction,
+                actor,
+                AuditLogAction::UpdateUserStorageQuota,
+                AuditLogTargetType::User,
+                user.id,
+                Some(serde_json::json!({ "storage_quota": user.storage_quota })),
+                Some(serde_json::json!({ "storage_quota": user1.storage_quota })),
+            )
+            .await?;
+
+            transaction.commit().await?;
+
             clear_user_cache(user).await;
 
             crate::mut_success!(user1)
@@ -877,23 +1255,92 @@ pub fn verify_user_password(user: &User, password: &str) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::enums::UserRole;
+    use crate::commands::paginate_audit_logs;
+    use crate::enums::{AuditLogAction, UserRole};
+    use crate::models::AuditActor;
     use crate::test_utils::{fake_username, fake_uuid, insert_test_user, setup_core_context};
     use crate::utils::CursorPageParams;
 
     use super::{
-        disable_user, get_user_by_id, get_user_by_username, get_user_by_username_or_email, paginate_users,
-        update_user_role,
+        all_mentioned_users, disable_user, enable_user, get_user_by_id, get_user_by_username,
+        get_user_by_username_or_email, paginate_users, update_user_role, update_user_storage_quota, MAX_MENTIONS,
     };
 
+    #[tokio::test]
+    async fn should_limit_mentioned_users() {
+        let core_context = setup_core_context().await;
+        let mut content = String::new();
+
+        for _ in 0..=MAX_MENTIONS {
+            let user = insert_test_user(&core_context).await;
+
+            content.push_str(&format!("@{} ", user.username));
+        }
+
+        let users = all_mentioned_users(&core_context, &content).await;
+
+        assert_eq!(users.len(), MAX_MENTIONS);
+    }
+
     #[tokio::test]
     async fn should_disable_user() {
         let core_context = setup_core_context().await;
         let user = insert_test_user(&core_context).await;
 
-        let result = disable_user(&core_context, &user).await;
+        let result = disable_user(&core_context, &user, &AuditActor::default()).await;
 
         assert!(result.is_ok());
+
+        let disabled_at = get_user_by_id(&core_context, user.id).await.unwrap().disabled_at;
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::DisableUser),
+            None,
+            Some(user.id),
+        )
+        .await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+        assert_eq!(
+            cursor_page.nodes[0].after_value,
+            Some(serde_json::json!({ "disabled_at": disabled_at }))
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_disable_user_when_is_disabled() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+
+        disable_user(&core_context, &user, &AuditActor::default())
+            .await
+            .unwrap();
+
+        let result = disable_user(&core_context, &user, &AuditActor::default()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_not_enable_user_when_is_not_disabled() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+
+        let result = enable_user(&core_context, &user, &AuditActor::default()).await;
+
+        assert!(result.is_err());
+
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::EnableUser),
+            None,
+            Some(user.id),
+        )
+        .await;
+
+        assert!(cursor_page.nodes.is_empty());
     }
 
     #[tokio::test]
@@ -962,22 +1409,87 @@ mod tests {
 
         insert_test_user(&core_context).await;
 
-        let cursor_page = paginate_users(&core_context, &CursorPageParams::default()).await;
+        let cursor_page = paginate_users(&core_context, &CursorPageParams::default(), "").await;
 
         assert!(!cursor_page.nodes.is_empty());
     }
 
+    #[tokio::test]
+    async fn should_get_users_by_id() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+
+        let cursor_page = paginate_users(&core_context, &CursorPageParams::default(), &user.id.to_string()).await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+        assert_eq!(cursor_page.nodes.first().unwrap().id, user.id);
+    }
+
     #[tokio::test]
     async fn should_update_user_role() {
         let core_context = setup_core_context().await;
         let user = insert_test_user(&core_context).await;
 
-        let result = update_user_role(&core_context, &user, UserRole::Admin).await;
+        let result = update_user_role(&core_context, &user, UserRole::Admin, &AuditActor::default()).await;
 
         assert!(result.is_ok());
 
         let user = result.unwrap();
 
         assert_eq!(user.data.role, UserRole::Admin);
+
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::UpdateUserRole),
+            None,
+            Some(user.data.id),
+        )
+        .await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+        assert_eq!(
+            cursor_page.nodes[0].after_value,
+            Some(serde_json::json!({ "role": "admin" }))
+        );
+    }
+
+    #[tokio::test]
+    async fn should_update_user_storage_quota() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+
+        let result = update_user_storage_quota(&core_context, &user, "500 MiB", &AuditActor::default()).await;
+
+        assert!(result.is_ok());
+
+        let user = result.unwrap();
+
+        assert_eq!(user.data.storage_quota, Some(500 * 1024 * 1024));
+
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::UpdateUserStorageQuota),
+            None,
+            Some(user.data.id),
+        )
+        .await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_reset_user_storage_quota_when_value_is_empty() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+
+        let result = update_user_storage_quota(&core_context, &user, "", &AuditActor::default()).await;
+
+        assert!(result.is_ok());
+
+        let user = result.unwrap();
+
+        assert_eq!(user.data.storage_quota, None);
     }
 }
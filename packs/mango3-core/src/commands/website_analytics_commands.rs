@@ -0,0 +1,274 @@
+use chrono::NaiveDate;
+
+use crate::models::*;
+use crate::CoreContext;
+
+#[cfg(feature = "all-website-countries")]
+pub async fn all_website_countries(
+    core_context: &CoreContext,
+    website: &Website,
+    since: NaiveDate,
+    limit: i64,
+) -> Vec<WebsiteViewsSource> {
+    sqlx::query_as!(
+        WebsiteViewsSource,
+        r#"SELECT country_alpha2 AS name, SUM(views_count)::bigint AS "views_count!"
+        FROM website_daily_countries
+        WHERE website_id = $1 AND day >= $2
+        GROUP BY country_alpha2
+        ORDER BY 2 DESC, 1 ASC LIMIT $3"#,
+        website.id, // $1
+        since,      // $2
+        limit,      // $3
+    )
+    .fetch_all(&core_context.db_pool)
+    .await
+    .unwrap_or_default()
+}
+
+/// One stat per day since the given date until today, including the days without activity.
+#[cfg(feature = "all-website-daily-stats")]
+pub async fn all_website_daily_stats(
+    core_context: &CoreContext,
+    website: &Website,
+    since: NaiveDate,
+) -> Vec<WebsiteDailyStat> {
+    sqlx::query_as!(
+        WebsiteDailyStat,
+        r#"SELECT
+            d.day::date AS "day!",
+            COALESCE(s.views_count, 0) AS "views_count!",
+            COALESCE(s.visitors_count, 0) AS "visitors_count!",
+            COALESCE(s.reactions_count, 0) AS "reactions_count!",
+            COALESCE(s.comments_count, 0) AS "comments_count!"
+        FROM generate_series($2::date, current_date, interval '1 day') AS d(day)
+        LEFT JOIN website_daily_stats AS s ON s.website_id = $1 AND s.day = d.day::date
+        ORDER BY d.day ASC"#,
+        website.id, // $1
+        since,      // $2
+    )
+    .fetch_all(&core_context.db_pool)
+    .await
+    .unwrap_or_default()
+}
+
+#[cfg(feature = "all-website-referrers")]
+pub async fn all_website_referrers(
+    core_context: &CoreContext,
+    website: &Website,
+    since: NaiveDate,
+    limit: i64,
+) -> Vec<WebsiteViewsSource> {
+    sqlx::query_as!(
+        WebsiteViewsSource,
+        r#"SELECT referrer_host AS name, SUM(views_count)::bigint AS "views_count!"
+        FROM website_daily_referrers
+        WHERE website_id = $1 AND day >= $2
+        GROUP BY referrer_host
+        ORDER BY 2 DESC, 1 ASC LIMIT $3"#,
+        website.id, // $1
+        since,      // $2
+        limit,      // $3
+    )
+    .fetch_all(&core_context.db_pool)
+    .await
+    .unwrap_or_default()
+}
+
+#[cfg(feature = "all-website-top-posts")]
+pub async fn all_website_top_posts(
+    core_context: &CoreContext,
+    website: &Website,
+    since: NaiveDate,
+    limit: i64,
+) -> Vec<WebsiteTopPost> {
+    sqlx::query_as!(
+        WebsiteTopPost,
+        r#"SELECT p.id AS post_id, p.title, SUM(s.views_count)::bigint AS "views_count!"
+        FROM post_daily_stats AS s
+        INNER JOIN posts AS p ON p.id = s.post_id
+        WHERE s.website_id = $1 AND s.day >= $2
+        GROUP BY p.id, p.title
+        ORDER BY 3 DESC, 2 ASC LIMIT $3"#,
+        website.id, // $1
+        since,      // $2
+        limit,      // $3
+    )
+    .fetch_all(&core_context.db_pool)
+    .await
+    .unwrap_or_default()
+}
+
+/// Rolls up the post view events, reactions and comments since the given date into the daily aggregates, replacing
+/// the ones already computed for those days. Views from bots are left out.
+#[cfg(feature = "update-website-analytics")]
+pub async fn update_website_analytics(since: NaiveDate) -> crate::utils::MutResult {
+    let db_pool = crate::db_pool().await;
+    let mut transaction = db_pool.begin().await?;
+
+    sqlx::query!(
+        "DELETE FROM website_daily_stats WHERE day >= $1",
+        since, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM post_daily_stats WHERE day >= $1",
+        since, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM website_daily_referrers WHERE day >= $1",
+        since, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM website_daily_countries WHERE day >= $1",
+        since, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    sqlx::query!(
+        r#"WITH views AS (
+            SELECT
+                website_id,
+                created_at::date AS day,
+                COUNT(*) AS views_count,
+                COUNT(DISTINCT COALESCE(user_id::text, visitor_hash)) AS visitors_count
+            FROM post_view_events
+            WHERE created_at >= $1::date AND user_agent_class != 'bot'
+            GROUP BY website_id, created_at::date
+        ), reactions AS (
+            SELECT p.website_id, r.created_at::date AS day, COUNT(*) AS reactions_count
+            FROM post_reactions AS r
+            INNER JOIN posts AS p ON p.id = r.post_id
+            WHERE r.created_at >= $1::date
+            GROUP BY p.website_id, r.created_at::date
+        ), comments AS (
+            SELECT p.website_id, c.created_at::date AS day, COUNT(*) AS comments_count
+            FROM post_comments AS c
+            INNER JOIN posts AS p ON p.id = c.post_id
+            WHERE c.created_at >= $1::date
+            GROUP BY p.website_id, c.created_at::date
+        )
+        INSERT INTO website_daily_stats (website_id, day, views_count, visitors_count, reactions_count, comments_count)
+        SELECT
+            website_id,
+            day,
+            COALESCE(v.views_count, 0),
+            COALESCE(v.visitors_count, 0),
+            COALESCE(r.reactions_count, 0),
+            COALESCE(c.comments_count, 0)
+        FROM views AS v
+        FULL OUTER JOIN reactions AS r USING (website_id, day)
+        FULL OUTER JOIN comments AS c USING (website_id, day)"#,
+        since, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO post_daily_stats (website_id, post_id, day, views_count, visitors_count)
+        SELECT
+            website_id,
+            post_id,
+            created_at::date,
+            COUNT(*),
+            COUNT(DISTINCT COALESCE(user_id::text, visitor_hash))
+        FROM post_view_events
+        WHERE created_at >= $1::date AND user_agent_class != 'bot'
+        GROUP BY website_id, post_id, created_at::date",
+        since, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO website_daily_referrers (website_id, day, referrer_host, views_count)
+        SELECT website_id, created_at::date, referrer_host, COUNT(*)
+        FROM post_view_events
+        WHERE created_at >= $1::date AND user_agent_class != 'bot' AND referrer_host IS NOT NULL
+        GROUP BY website_id, created_at::date, referrer_host",
+        since, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO website_daily_countries (website_id, day, country_alpha2, views_count)
+        SELECT website_id, created_at::date, country_alpha2, COUNT(*)
+        FROM post_view_events
+        WHERE created_at >= $1::date AND user_agent_class != 'bot' AND country_alpha2 IS NOT NULL
+        GROUP BY website_id, created_at::date, country_alpha2",
+        since, // $1
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    crate::mut_success!()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Days, Utc};
+
+    use crate::commands::insert_post_view_event;
+    use crate::test_utils::{fake_ipv4, insert_test_post, setup_core_context};
+
+    use super::{
+        all_website_countries, all_website_daily_stats, all_website_referrers, all_website_top_posts,
+        update_website_analytics,
+    };
+
+    #[tokio::test]
+    async fn should_update_website_analytics() {
+        let core_context = setup_core_context().await;
+        let post = insert_test_post(&core_context, None, None).await;
+        let website = post.website(&core_context).await.unwrap();
+        let today = Utc::now().date_naive();
+        let since = today.checked_sub_days(Days::new(1)).unwrap();
+
+        let _ = insert_post_view_event(
+            &core_context,
+            &post,
+            None,
+            &fake_ipv4(),
+            Some("Mozilla/5.0 (X11; Linux x86_64; rv:136.0) Gecko/20100101 Firefox/136.0"),
+            Some("https://www.example.com/"),
+            Some("PE"),
+        )
+        .await;
+
+        let result = update_website_analytics(since).await;
+
+        assert!(result.is_ok());
+
+        let daily_stats = all_website_daily_stats(&core_context, &website, since).await;
+
+        assert_eq!(daily_stats.len(), 2);
+        assert_eq!(daily_stats.last().unwrap().day, today);
+        assert_eq!(daily_stats.last().unwrap().views_count, 1);
+        assert_eq!(daily_stats.last().unwrap().visitors_count, 1);
+
+        let top_posts = all_website_top_posts(&core_context, &website, since, 10).await;
+
+        assert_eq!(top_posts.len(), 1);
+        assert_eq!(top_posts[0].post_id, post.id);
+
+        let referrers = all_website_referrers(&core_context, &website, since, 10).await;
+
+        assert_eq!(referrers[0].name, "www.example.com");
+
+        let countries = all_website_countries(&core_context, &website, since, 10).await;
+
+        assert_eq!(countries[0].name, "PE");
+    }
+}
@@ -3,11 +3,32 @@ use uuid::Uuid;
 use crate::models::*;
 use crate::CoreContext;
 
-#[cfg(feature = "insert-website")]
+#[cfg(feature = "moderate-website")]
+use crate::enums::ModerationAction;
+#[cfg(any(
+    feature = "moderate-website",
+    feature = "restore-website",
+    feature = "update-website-storage-quota"
+))]
+use crate::enums::{AuditLogAction, AuditLogTargetType};
+#[cfg(any(feature = "insert-website", feature = "update-website-storage-quota"))]
 use crate::enums::{Input, InputError};
 #[cfg(feature = "insert-website")]
 use crate::utils::{Validator, ValidatorTrait};
 
+/// The fields of a website edited by its owner.
+#[cfg(feature = "update-website")]
+pub struct WebsiteInput<'a> {
+    pub name: &'a str,
+    pub description: &'a str,
+    pub icon_image_blob: Option<&'a Blob<'a>>,
+    pub cover_image_blob: Option<&'a Blob<'a>>,
+    pub light_theme: &'a str,
+    pub dark_theme: &'a str,
+    pub language: &'a str,
+    pub publish: bool,
+}
+
 #[cfg(feature = "insert-website")]
 impl Validator {
     async fn validate_website_name(
@@ -64,6 +85,9 @@ pub async fn delete_website(core_context: &CoreContext, website: &Website) -> cr
 
     clear_website_cache(website).await;
 
+    #[cfg(feature = "jobs")]
+    core_context.jobs.refresh_static_website(website.id).await;
+
     crate::mut_success!()
 }
 
@@ -88,8 +112,11 @@ async fn get_cached_website_by_id(core_context: &CoreContext, id: Uuid) -> sqlx:
             cover_image_blob_id,
             light_theme,
             dark_theme,
+            storage_quota,
             language::varchar AS "language!",
             published_at,
+            suspended_at,
+            moderation_reason,
             NULL::real AS search_rank,
             created_at,
             updated_at
@@ -125,12 +152,15 @@ async fn get_cached_website_by_subdomain(core_context: &CoreContext, subdomain:
             cover_image_blob_id,
             light_theme,
             dark_theme,
+            storage_quota,
             language::varchar AS "language!",
             published_at,
+            suspended_at,
+            moderation_reason,
             NULL::real AS search_rank,
             created_at,
             updated_at
-        FROM websites WHERE subdomain = $1 AND published_at IS NOT NULL LIMIT 1"#,
+        FROM websites WHERE subdomain = $1 AND published_at IS NOT NULL AND suspended_at IS NULL LIMIT 1"#,
         subdomain // $1
     )
     .fetch_one(&core_context.db_pool)
@@ -172,9 +202,12 @@ pub async fn get_website_by_id_with_search_rank(
                 cover_image_blob_id,
                 light_theme,
                 dark_theme,
+                storage_quota,
                 language::varchar AS "language!",
                 published_at,
-                ts_rank(search, websearch_to_tsquery($3)) AS search_rank,
+                suspended_at,
+                moderation_reason,
+                ts_rank(search, websearch_to_tsquery(language, $3)) AS search_rank,
                 created_at,
                 updated_at
             FROM websites WHERE id = $1 AND ($2::uuid IS NULL OR user_id = $2) LIMIT 1"#,
@@ -199,6 +232,21 @@ pub async fn get_used_website_storage(website: &Website) -> sqlx::Result<size::S
     .map(|record| size::Size::from_bytes(record.total_size.unwrap_or_default()))
 }
 
+#[cfg(feature = "get-website-max-storage")]
+pub async fn get_website_max_storage(website: &Website) -> sqlx::Result<size::Size> {
+    use crate::enums::UserRole;
+
+    let db_pool = crate::db_pool().await;
+
+    sqlx::query!(
+        r#"SELECT role AS "role!: UserRole" FROM users WHERE id = $1 LIMIT 1"#,
+        website.user_id // $1
+    )
+    .fetch_one(db_pool)
+    .await
+    .map(|record| crate::config::WEBSITE_CONFIG.max_storage_by_role(&record.role))
+}
+
 #[cfg(feature = "get-website-by-subdomain")]
 pub async fn get_website_by_subdomain(core_context: &CoreContext, subdomain: &str) -> sqlx::Result<Website> {
     get_cached_website_by_subdomain(core_context, subdomain).await
@@ -211,6 +259,7 @@ pub async fn insert_website(
     name: &str,
     subdomain: &str,
     description: &str,
+    language: &str,
 ) -> crate::utils::MutResult<Website> {
     let mut validator = crate::validator!();
 
@@ -241,6 +290,7 @@ pub async fn insert_website(
     }
 
     validator.validate_website_description(description);
+    validator.validate_language(language).await;
 
     if !validator.is_valid {
         return crate::mut_error!(validator.errors);
@@ -251,7 +301,8 @@ pub async fn insert_website(
 
     let result = sqlx::query_as!(
         Website,
-        r#"INSERT INTO websites (user_id, name, subdomain, description, hashtag_ids) VALUES ($1, $2, $3, $4, $5)
+        r#"INSERT INTO websites (user_id, name, subdomain, description, hashtag_ids, language)
+        VALUES ($1, $2, $3, $4, $5, $6::varchar::regconfig)
         RETURNING
             id,
             user_id,
@@ -263,8 +314,11 @@ pub async fn insert_website(
             cover_image_blob_id,
             light_theme,
             dark_theme,
+            storage_quota,
             language::varchar AS "language!",
             published_at,
+            suspended_at,
+            moderation_reason,
             NULL::real AS search_rank,
             created_at,
             updated_at"#,
@@ -273,6 +327,7 @@ pub async fn insert_website(
         subdomain,    // $3
         description,  // $4
         &hashtag_ids, // $5
+        language,     // $6
     )
     .fetch_one(&core_context.db_pool)
     .await;
@@ -280,6 +335,104 @@ pub async fn insert_website(
     crate::mut_result!(result)
 }
 
+#[cfg(feature = "moderate-website")]
+pub async fn moderate_website(
+    core_context: &CoreContext,
+    website: &Website,
+    action: ModerationAction,
+    reason: &str,
+    actor: &AuditActor,
+) -> crate::utils::MutResult<Website> {
+    let mut validator = crate::validator!();
+
+    let reason = reason.trim();
+
+    validator.validate_moderation_reason(reason);
+
+    if !validator.is_valid {
+        return crate::mut_error!(validator.errors);
+    }
+
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    let result = sqlx::query_as!(
+        Website,
+        r#"UPDATE websites SET
+            published_at = CASE WHEN $2 IS TRUE THEN published_at ELSE NULL END,
+            suspended_at = CASE WHEN $2 IS TRUE THEN current_timestamp ELSE NULL END,
+            moderation_reason = $3
+        WHERE id = $1 RETURNING
+            id,
+            user_id,
+            name,
+            subdomain,
+            description,
+            hashtag_ids,
+            icon_image_blob_id,
+            cover_image_blob_id,
+            light_theme,
+            dark_theme,
+            storage_quota,
+            language::varchar AS "language!",
+            published_at,
+            suspended_at,
+            moderation_reason,
+            NULL::real AS search_rank,
+            created_at,
+            updated_at"#,
+        website.id,                          // $1
+        action == ModerationAction::Suspend, // $2
+        reason,                              // $3
+    )
+    .fetch_one(&mut *transaction)
+    .await;
+
+    match result {
+        Ok(website1) => {
+            super::insert_audit_log(
+                &mut *transaction,
+                actor,
+                AuditLogAction::ModerateWebsite,
+                AuditLogTargetType::Website,
+                website.id,
+                Some(serde_json::json!({
+                    "published_at": website.published_at,
+                    "suspended_at": website.suspended_at,
+                    "moderation_reason": website.moderation_reason,
+                })),
+                Some(serde_json::json!({
+                    "published_at": website1.published_at,
+                    "suspended_at": website1.suspended_at,
+                    "moderation_reason": website1.moderation_reason,
+                })),
+            )
+            .await?;
+
+            transaction.commit().await?;
+
+            clear_website_cache(website).await;
+            core_context.jobs.refresh_static_website(website.id).await;
+
+            if let Ok(user) = super::get_user_by_id(core_context, website.user_id).await {
+                core_context
+                    .jobs
+                    .mailer(
+                        &user,
+                        crate::enums::MailerJobCommand::Moderated {
+                            action,
+                            target_name: website.name.clone(),
+                            reason: reason.to_owned(),
+                        },
+                    )
+                    .await;
+            }
+
+            crate::mut_success!(website1)
+        }
+        Err(_) => crate::mut_error!(),
+    }
+}
+
 #[cfg(feature = "paginate-websites-sorted-by-name-asc")]
 pub async fn paginate_websites_sorted_by_name_asc<'a>(
     core_context: &'a CoreContext,
@@ -309,8 +462,11 @@ pub async fn paginate_websites_sorted_by_name_asc<'a>(
                     cover_image_blob_id,
                     light_theme,
                     dark_theme,
+                    storage_quota,
                     language::varchar AS "language!",
                     published_at,
+                    suspended_at,
+                    moderation_reason,
                     NULL::real AS search_rank,
                     created_at,
                     updated_at
@@ -318,6 +474,8 @@ pub async fn paginate_websites_sorted_by_name_asc<'a>(
                     AND (
                         $2::bool IS NULL OR ($2 IS TRUE AND published_at IS NOT NULL)
                         OR ($2 IS FALSE AND published_at IS NULL)
+                    ) AND (
+                        ($2 IS NOT TRUE AND $1::uuid IS NOT NULL) OR suspended_at IS NULL
                     ) AND ($3::text IS NULL OR name > $3)
                 ORDER BY name ASC LIMIT $4"#,
                 user_id,      // $1
@@ -364,8 +522,11 @@ pub async fn paginate_websites<'a>(
                     cover_image_blob_id,
                     light_theme,
                     dark_theme,
+                    storage_quota,
                     language::varchar AS "language!",
                     published_at,
+                    suspended_at,
+                    moderation_reason,
                     NULL::real AS search_rank,
                     created_at,
                     updated_at
@@ -373,6 +534,8 @@ pub async fn paginate_websites<'a>(
                     AND (
                         $2::bool IS NULL OR ($2 IS TRUE AND published_at IS NOT NULL)
                         OR ($2 IS FALSE AND published_at IS NULL)
+                    ) AND (
+                        ($2 IS NOT TRUE AND $1::uuid IS NOT NULL) OR suspended_at IS NULL
                     ) AND ($4::timestamptz IS NULL OR created_at < $4 OR (created_at = $4 AND id < $3))
                 ORDER BY created_at DESC, id DESC LIMIT $5"#,
                 user_id,           // $1
@@ -389,6 +552,143 @@ pub async fn paginate_websites<'a>(
     .await
 }
 
+/// Lists all websites, including unpublished and suspended ones, so it must only be used by staff.
+#[cfg(feature = "paginate-websites-for-moderation")]
+pub async fn paginate_websites_for_moderation<'a>(
+    core_context: &'a CoreContext,
+    page_params: &crate::utils::CursorPageParams,
+    query: &'a str,
+) -> crate::utils::CursorPage<Website> {
+    crate::cursor_page!(
+        core_context,
+        page_params,
+        |node: Website| node.id,
+        move |core_context, after| async move { get_website_by_id(core_context, after, None).await.ok() },
+        move |core_context, cursor_resource, limit| async move {
+            let (cursor_id, cursor_created_at) = cursor_resource
+                .map(|c| (Some(c.id), Some(c.created_at)))
+                .unwrap_or_default();
+
+            sqlx::query_as!(
+                Website,
+                r#"SELECT
+                    id,
+                    user_id,
+                    name,
+                    subdomain,
+                    description,
+                    hashtag_ids,
+                    icon_image_blob_id,
+                    cover_image_blob_id,
+                    light_theme,
+                    dark_theme,
+                    storage_quota,
+                    language::varchar AS "language!",
+                    published_at,
+                    suspended_at,
+                    moderation_reason,
+                    NULL::real AS search_rank,
+                    created_at,
+                    updated_at
+                FROM websites
+                WHERE ($1 = '' OR id::text = $1 OR name ILIKE '%' || $1 || '%' OR subdomain ILIKE '%' || $1 || '%')
+                    AND ($3::timestamptz IS NULL OR created_at < $3 OR (created_at = $3 AND id < $2))
+                ORDER BY created_at DESC, id DESC LIMIT $4"#,
+                query.trim(),      // $1
+                cursor_id,         // $2
+                cursor_created_at, // $3
+                limit,             // $4
+            )
+            .fetch_all(&core_context.db_pool)
+            .await
+            .unwrap_or_default()
+        },
+    )
+    .await
+}
+
+#[cfg(feature = "restore-website")]
+pub async fn restore_website(
+    core_context: &CoreContext,
+    website: &Website,
+    actor: &AuditActor,
+) -> crate::utils::MutResult<Website> {
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    let result = sqlx::query_as!(
+        Website,
+        r#"UPDATE websites SET
+            published_at = CASE WHEN suspended_at IS NULL THEN current_timestamp ELSE published_at END,
+            suspended_at = NULL,
+            moderation_reason = NULL
+        WHERE id = $1 AND moderation_reason IS NOT NULL RETURNING
+            id,
+            user_id,
+            name,
+            subdomain,
+            description,
+            hashtag_ids,
+            icon_image_blob_id,
+            cover_image_blob_id,
+            light_theme,
+            dark_theme,
+            storage_quota,
+            language::varchar AS "language!",
+            published_at,
+            suspended_at,
+            moderation_reason,
+            NULL::real AS search_rank,
+            created_at,
+            updated_at"#,
+        website.id, // $1
+    )
+    .fetch_one(&mut *transaction)
+    .await;
+
+    match result {
+        Ok(website1) => {
+            super::insert_audit_log(
+                &mut *transaction,
+                actor,
+                AuditLogAction::RestoreWebsite,
+                AuditLogTargetType::Website,
+                website.id,
+                Some(serde_json::json!({
+                    "published_at": website.published_at,
+                    "suspended_at": website.suspended_at,
+                    "moderation_reason": website.moderation_reason,
+                })),
+                Some(serde_json::json!({
+                    "published_at": website1.published_at,
+                    "suspended_at": website1.suspended_at,
+                    "moderation_reason": website1.moderation_reason,
+                })),
+            )
+            .await?;
+
+            transaction.commit().await?;
+
+            clear_website_cache(website).await;
+            core_context.jobs.refresh_static_website(website.id).await;
+
+            if let Ok(user) = super::get_user_by_id(core_context, website.user_id).await {
+                core_context
+                    .jobs
+                    .mailer(
+                        &user,
+                        crate::enums::MailerJobCommand::Restored {
+                            target_name: website.name.clone(),
+                        },
+                    )
+                    .await;
+            }
+
+            crate::mut_success!(website1)
+        }
+        Err(_) => crate::mut_error!(),
+    }
+}
+
 #[cfg(feature = "search-websites")]
 pub async fn search_websites<'a>(
     core_context: &'a CoreContext,
@@ -425,9 +725,12 @@ pub async fn search_websites<'a>(
                     cover_image_blob_id,
                     light_theme,
                     dark_theme,
+                    storage_quota,
                     language::varchar as "language!",
                     published_at,
-                    ts_rank(search, websearch_to_tsquery($3)) AS search_rank,
+                    suspended_at,
+                    moderation_reason,
+                    ts_rank(search, websearch_to_tsquery(language, $3)) AS search_rank,
                     created_at,
                     updated_at
                 FROM websites
@@ -436,14 +739,16 @@ pub async fn search_websites<'a>(
                         $2::bool IS NULL OR ($2 IS TRUE AND published_at IS NOT NULL)
                         OR ($2 IS FALSE AND published_at IS NULL)
                     ) AND (
-                        search @@ websearch_to_tsquery($3)
+                        ($2 IS NOT TRUE AND $1::uuid IS NOT NULL) OR suspended_at IS NULL
+                    ) AND (
+                        search @@ websearch_to_tsquery(language, $3)
                         OR name ILIKE '%' || $3 || '%'
                         OR subdomain ILIKE '%' || $3 || '%'
                         OR description ILIKE '%' || $3 || '%'
                     ) AND (
                         ($4::uuid IS NULL OR $5::real IS NULL OR $6::timestamptz IS NULL)
-                        OR ts_rank(search, websearch_to_tsquery($3)) < $5 OR (
-                            ts_rank(search, websearch_to_tsquery($3)) = $5 AND (
+                        OR ts_rank(search, websearch_to_tsquery(language, $3)) < $5 OR (
+                            ts_rank(search, websearch_to_tsquery(language, $3)) = $5 AND (
                                 created_at < $6 OR (created_at = $6 AND id < $4)
                             )
                         )
@@ -469,16 +774,21 @@ pub async fn search_websites<'a>(
 pub async fn update_website(
     core_context: &CoreContext,
     website: &Website,
-    name: &str,
-    description: &str,
-    icon_image_blob: Option<&Blob<'_>>,
-    cover_image_blob: Option<&Blob<'_>>,
-    light_theme: &str,
-    dark_theme: &str,
-    publish: bool,
+    input: WebsiteInput<'_>,
 ) -> crate::utils::MutResult<Website> {
     let mut validator = crate::validator!();
 
+    let WebsiteInput {
+        name,
+        description,
+        icon_image_blob,
+        cover_image_blob,
+        light_theme,
+        dark_theme,
+        language,
+        publish,
+    } = input;
+
     let name = name.trim();
     let description = description.trim();
     let icon_image_blob_id = icon_image_blob.map(|blob| blob.id);
@@ -494,6 +804,7 @@ pub async fn update_website(
     validator.custom_validation(Input::DarkTheme, InputError::IsInvalid, &|| {
         crate::constants::DARK_THEMES.contains(&dark_theme)
     });
+    validator.validate_language(language).await;
 
     if !validator.is_valid {
         return crate::mut_error!(validator.errors);
@@ -513,10 +824,12 @@ pub async fn update_website(
             light_theme = $7,
             dark_theme = $8,
             published_at = CASE
+                WHEN moderation_reason IS NOT NULL THEN published_at
                 WHEN $9 IS TRUE AND published_at IS NOT NULL THEN published_at
                 WHEN $9 IS TRUE THEN current_timestamp
                 ELSE NULL
-            END
+            END,
+            language = $10::varchar::regconfig
         WHERE id = $1 RETURNING
             id,
             user_id,
@@ -528,8 +841,11 @@ pub async fn update_website(
             cover_image_blob_id,
             light_theme,
             dark_theme,
+            storage_quota,
             language::varchar AS "language!",
             published_at,
+            suspended_at,
+            moderation_reason,
             NULL::real AS search_rank,
             created_at,
             updated_at"#,
@@ -542,6 +858,7 @@ pub async fn update_website(
         light_theme,         // $7
         dark_theme,          // $8
         publish,             // $9
+        language,            // $10
     )
     .fetch_one(&core_context.db_pool)
     .await;
@@ -550,6 +867,81 @@ pub async fn update_website(
         Ok(website1) => {
             clear_website_cache(website).await;
 
+            #[cfg(feature = "jobs")]
+            core_context.jobs.refresh_static_website(website.id).await;
+
+            crate::mut_success!(website1)
+        }
+        Err(_) => crate::mut_error!(),
+    }
+}
+
+#[cfg(feature = "update-website-storage-quota")]
+pub async fn update_website_storage_quota(
+    core_context: &CoreContext,
+    website: &Website,
+    storage_quota: &str,
+    actor: &AuditActor,
+) -> crate::utils::MutResult<Website> {
+    let mut validator = crate::validator!();
+
+    let storage_quota = storage_quota.trim();
+    let max_storage = crate::utils::parse_size(storage_quota);
+
+    validator.custom_validation(Input::StorageQuota, InputError::IsInvalid, &|| {
+        storage_quota.is_empty() || max_storage.is_some()
+    });
+
+    if !validator.is_valid {
+        return crate::mut_error!(validator.errors);
+    }
+
+    let mut transaction = core_context.db_pool.begin().await?;
+
+    let result = sqlx::query_as!(
+        Website,
+        r#"UPDATE websites SET storage_quota = $2 WHERE id = $1 RETURNING
+            id,
+            user_id,
+            name,
+            subdomain,
+            description,
+            hashtag_ids,
+            icon_image_blob_id,
+            cover_image_blob_id,
+            light_theme,
+            dark_theme,
+            storage_quota,
+            language::varchar AS "language!",
+            published_at,
+            suspended_at,
+            moderation_reason,
+            NULL::real AS search_rank,
+            created_at,
+            updated_at"#,
+        website.id,                           // $1
+        max_storage.map(|size| size.bytes()), // $2
+    )
+    .fetch_one(&mut *transaction)
+    .await;
+
+    match result {
+        Ok(website1) => {
+            super::insert_audit_log(
+                &mut *transaction,
+                actor,
+                AuditLogAction::UpdateWebsiteStorageQuota,
+                AuditLogTargetType::Website,
+                website.id,
+                Some(serde_json::json!({ "storage_quota": website.storage_quota })),
+                Some(serde_json::json!({ "storage_quota": website1.storage_quota })),
+            )
+            .await?;
+
+            transaction.commit().await?;
+
+            clear_website_cache(website).await;
+
             crate::mut_success!(website1)
         }
         Err(_) => crate::mut_error!(),
@@ -558,6 +950,9 @@ pub async fn update_website(
 
 #[cfg(test)]
 mod tests {
+    use crate::commands::paginate_audit_logs;
+    use crate::enums::{AuditLogAction, ModerationAction};
+    use crate::models::AuditActor;
     use crate::test_utils::{
         fake_name, fake_sentence, fake_uuid, insert_test_user, insert_test_website, setup_core_context,
     };
@@ -565,7 +960,8 @@ mod tests {
 
     use super::{
         delete_website, get_website_by_id, get_website_by_id_with_search_rank, get_website_by_subdomain,
-        paginate_websites, paginate_websites_sorted_by_name_asc, search_websites, update_website,
+        moderate_website, paginate_websites, paginate_websites_for_moderation, paginate_websites_sorted_by_name_asc,
+        restore_website, search_websites, update_website, update_website_storage_quota, WebsiteInput,
     };
 
     #[tokio::test]
@@ -722,17 +1118,21 @@ mod tests {
         let result = update_website(
             &core_context,
             &website,
-            &name,
-            &description,
-            None,
-            None,
-            "light",
-            "dark",
-            true,
+            WebsiteInput {
+                name: &name,
+                description: &description,
+                icon_image_blob: None,
+                cover_image_blob: None,
+                light_theme: "light",
+                dark_theme: "dark",
+                language: "spanish",
+                publish: true,
+            },
         )
         .await;
 
         assert!(result.is_ok());
+        assert_eq!(result.unwrap().data.language, "spanish");
     }
 
     #[tokio::test]
@@ -740,8 +1140,160 @@ mod tests {
         let core_context = setup_core_context().await;
         let website = insert_test_website(&core_context, None).await;
 
-        let result = update_website(&core_context, &website, "", "", None, None, "", "", true).await;
+        let result = update_website(
+            &core_context,
+            &website,
+            WebsiteInput {
+                name: "",
+                description: "",
+                icon_image_blob: None,
+                cover_image_blob: None,
+                light_theme: "",
+                dark_theme: "",
+                language: "",
+                publish: true,
+            },
+        )
+        .await;
 
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn should_update_website_storage_quota() {
+        let core_context = setup_core_context().await;
+        let website = insert_test_website(&core_context, None).await;
+
+        let result = update_website_storage_quota(&core_context, &website, "2 GiB", &AuditActor::default()).await;
+
+        assert!(result.is_ok());
+
+        let website = result.ok().unwrap().data;
+
+        assert_eq!(website.storage_quota, Some(2 * 1024 * 1024 * 1024));
+
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::UpdateWebsiteStorageQuota),
+            None,
+            Some(website.id),
+        )
+        .await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_update_website_storage_quota_when_value_is_invalid() {
+        let core_context = setup_core_context().await;
+        let website = insert_test_website(&core_context, None).await;
+
+        let result = update_website_storage_quota(&core_context, &website, "lots", &AuditActor::default()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_suspend_website() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+
+        let result = moderate_website(
+            &core_context,
+            &website,
+            ModerationAction::Suspend,
+            "Spam",
+            &AuditActor::default(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().data.is_suspended());
+
+        let cursor_page = paginate_websites(&core_context, &CursorPageParams::default(), Some(&user), None).await;
+
+        assert!(cursor_page
+            .nodes
+            .iter()
+            .any(|node| node.id == website.id && node.is_suspended()));
+
+        let cursor_page = paginate_websites(&core_context, &CursorPageParams::default(), Some(&user), Some(true)).await;
+
+        assert!(cursor_page.nodes.is_empty());
+
+        let cursor_page = paginate_websites(&core_context, &CursorPageParams::default(), None, None).await;
+
+        assert!(!cursor_page.nodes.iter().any(|node| node.id == website.id));
+
+        let cursor_page =
+            paginate_websites_for_moderation(&core_context, &CursorPageParams::default(), &website.subdomain).await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::ModerateWebsite),
+            None,
+            Some(website.id),
+        )
+        .await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_moderate_website_when_reason_is_empty() {
+        let core_context = setup_core_context().await;
+        let website = insert_test_website(&core_context, None).await;
+
+        let result = moderate_website(
+            &core_context,
+            &website,
+            ModerationAction::Suspend,
+            "",
+            &AuditActor::default(),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_restore_website() {
+        let core_context = setup_core_context().await;
+        let website = insert_test_website(&core_context, None).await;
+        let website = moderate_website(
+            &core_context,
+            &website,
+            ModerationAction::Suspend,
+            "Spam",
+            &AuditActor::default(),
+        )
+        .await
+        .unwrap()
+        .data;
+
+        let result = restore_website(&core_context, &website, &AuditActor::default()).await;
+
+        assert!(result.is_ok());
+
+        let website = result.unwrap().data;
+
+        assert!(!website.is_suspended());
+        assert!(website.moderation_reason.is_none());
+
+        let cursor_page = paginate_audit_logs(
+            &core_context,
+            &CursorPageParams::default(),
+            Some(AuditLogAction::RestoreWebsite),
+            None,
+            Some(website.id),
+        )
+        .await;
+
+        assert_eq!(cursor_page.nodes.len(), 1);
+    }
 }
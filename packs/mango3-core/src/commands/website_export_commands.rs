@@ -0,0 +1,398 @@
+use crate::utils::MutResult;
+
+#[cfg(any(feature = "enqueue-website-export", feature = "export-website"))]
+use crate::models::*;
+#[cfg(any(feature = "enqueue-website-export", feature = "export-website"))]
+use crate::CoreContext;
+
+#[cfg(feature = "export-website")]
+use crate::utils::{ArchiveNavigationItem, ArchiveWebsite, FrontMatter, FrontMatterTags};
+
+#[cfg(feature = "delete-all-expired-website-exports")]
+const WEBSITE_EXPORT_LIFETIME: std::time::Duration = std::time::Duration::from_secs(7 * 24 * 60 * 60);
+#[cfg(any(feature = "enqueue-website-export", feature = "export-website"))]
+const WEBSITE_EXPORT_LOCK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+#[cfg(feature = "export-website")]
+fn archive_blob_path(blob: &Blob) -> String {
+    let file_name = blob.file_name.replace(['/', '\\'], "_");
+
+    if file_name.is_empty() {
+        format!("blobs/{}/default{}", blob.id, blob.extension())
+    } else {
+        format!("blobs/{}/{}", blob.id, file_name)
+    }
+}
+
+#[cfg(feature = "export-website")]
+fn archive_post_path(post: &Post) -> String {
+    format!("posts/{}-{}.md", post.slug, post.id)
+}
+
+/// Returns the ids of every blob URL found in the content, including the URLs with variant parameters.
+#[cfg(feature = "export-website")]
+fn find_blob_ids(content: &str) -> Vec<uuid::Uuid> {
+    crate::constants::REGEX_BLOB_URL
+        .captures_iter(content)
+        .filter_map(|captures| uuid::Uuid::try_parse(&captures["id"]).ok())
+        .collect()
+}
+
+/// A website export is locked while its archive is being written, stale locks are ignored after an hour.
+#[cfg(any(feature = "enqueue-website-export", feature = "export-website"))]
+fn is_website_export_locked(website: &Website) -> bool {
+    std::fs::metadata(website.export_lock_path())
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|elapsed| elapsed < WEBSITE_EXPORT_LOCK_TIMEOUT)
+}
+
+#[cfg(feature = "export-website")]
+fn lock_website_export(website: &Website) -> bool {
+    let lock_path = website.export_lock_path();
+
+    if !is_website_export_locked(website) {
+        let _ = std::fs::remove_file(&lock_path);
+    }
+
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&lock_path)
+        .is_ok()
+}
+
+#[cfg(feature = "export-website")]
+fn write_archive_entry<W: std::io::Write + std::io::Seek>(
+    zip_writer: &mut zip::ZipWriter<W>,
+    path: &str,
+    data: &[u8],
+) -> std::io::Result<()> {
+    use std::io::Write;
+
+    zip_writer.start_file(path, zip::write::SimpleFileOptions::default())?;
+    zip_writer.write_all(data)
+}
+
+#[cfg(feature = "delete-all-expired-website-exports")]
+pub async fn delete_all_expired_website_exports() -> MutResult {
+    use crate::config::MISC_CONFIG;
+
+    let Ok(entries) = std::fs::read_dir(MISC_CONFIG.storage_exports_path()) else {
+        return crate::mut_success!();
+    };
+
+    for entry in entries.flatten() {
+        let is_expired = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|elapsed| elapsed > WEBSITE_EXPORT_LIFETIME);
+
+        if is_expired {
+            let _ = std::fs::remove_file(entry.path());
+        }
+    }
+
+    crate::mut_success!()
+}
+
+#[cfg(feature = "enqueue-website-export")]
+pub async fn enqueue_website_export(core_context: &CoreContext, website: &Website, user: &User) -> MutResult {
+    if website.user_id != user.id || is_website_export_locked(website) {
+        return crate::mut_error!();
+    }
+
+    core_context.jobs.export_website(website.id, user).await;
+
+    crate::mut_success!()
+}
+
+/// Writes the website settings, navigation, posts and the blobs they reference into a zip archive that can be
+/// imported again with `import_posts`. Only one export of a website runs at a time.
+#[cfg(feature = "export-website")]
+pub async fn export_website(core_context: &CoreContext, website: &Website) -> MutResult<std::path::PathBuf> {
+    use uuid::Uuid;
+
+    use crate::config::MISC_CONFIG;
+
+    std::fs::create_dir_all(MISC_CONFIG.storage_exports_path())?;
+
+    if !lock_website_export(website) {
+        return crate::mut_error!();
+    }
+
+    let tmp_file_path = MISC_CONFIG
+        .storage_tmp_path()
+        .join(format!("export-{}", Uuid::new_v4()));
+    let export_path = website.export_path();
+
+    let result = write_website_export(core_context, website, &tmp_file_path)
+        .await
+        .and_then(|_| std::fs::rename(&tmp_file_path, &export_path).map_err(Into::into));
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp_file_path);
+    }
+
+    let _ = std::fs::remove_file(website.export_lock_path());
+
+    result?;
+
+    crate::mut_success!(export_path)
+}
+
+#[cfg(feature = "export-website")]
+async fn write_website_export(
+    core_context: &CoreContext,
+    website: &Website,
+    tmp_file_path: &std::path::Path,
+) -> Result<(), crate::utils::MutError> {
+    use std::collections::HashMap;
+
+    use crate::utils::{ARCHIVE_NAVIGATION_PATH, ARCHIVE_WEBSITE_PATH};
+
+    let posts = sqlx::query_as!(
+        Post,
+        r#"SELECT
+            id,
+            website_id,
+            user_id,
+            translation_group_id,
+            language::varchar as "language!",
+            title,
+            slug,
+            content,
+            variables,
+            hashtag_ids,
+            mentioned_user_ids,
+            cover_image_blob_id,
+            blob_ids,
+            published_at,
+            suspended_at,
+            moderation_reason,
+            modified_at,
+            NULL::real AS search_rank,
+            created_at,
+            updated_at
+        FROM posts WHERE website_id = $1 ORDER BY created_at ASC"#,
+        website.id, // $1
+    )
+    .fetch_all(&core_context.db_pool)
+    .await?;
+
+    let mut zip_writer = zip::ZipWriter::new(std::fs::File::create(tmp_file_path)?);
+    let mut blobs = HashMap::new();
+
+    let mut blob_ids = website
+        .icon_image_blob_id
+        .into_iter()
+        .chain(website.cover_image_blob_id)
+        .collect::<Vec<_>>();
+
+    for post in &posts {
+        blob_ids.extend(post.blob_ids.iter().chain(post.cover_image_blob_id.as_ref()));
+        blob_ids.extend(find_blob_ids(&post.content));
+    }
+
+    blob_ids.sort();
+    blob_ids.dedup();
+
+    for blob in super::all_blobs_by_ids(blob_ids, Some(website), None).await {
+        let Ok(data) = std::fs::read(&*blob.default_path()) else {
+            continue;
+        };
+
+        let path = archive_blob_path(&blob);
+
+        write_archive_entry(&mut zip_writer, &path, &data)?;
+
+        blobs.insert(blob.id, path);
+    }
+
+    let blob_path = |id: Option<uuid::Uuid>| id.and_then(|id| blobs.get(&id)).cloned();
+
+    let archive_website = ArchiveWebsite {
+        name: website.name.clone(),
+        description: website.description.clone(),
+        language: website.language.clone(),
+        light_theme: website.light_theme.clone(),
+        dark_theme: website.dark_theme.clone(),
+        icon_image: blob_path(website.icon_image_blob_id),
+        cover_image: blob_path(website.cover_image_blob_id),
+    };
+
+    write_archive_entry(
+        &mut zip_writer,
+        ARCHIVE_WEBSITE_PATH,
+        serde_yaml::to_string(&archive_website).unwrap_or_default().as_bytes(),
+    )?;
+
+    let navigation_items = super::all_navigation_items_by_website(website)
+        .await
+        .iter()
+        .map(|item| ArchiveNavigationItem {
+            title: item.title.to_string(),
+            url: item.url.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    write_archive_entry(
+        &mut zip_writer,
+        ARCHIVE_NAVIGATION_PATH,
+        serde_yaml::to_string(&navigation_items).unwrap_or_default().as_bytes(),
+    )?;
+
+    for post in posts {
+        let content = crate::constants::REGEX_BLOB_URL.replace_all(&post.content, |captures: &regex::Captures| {
+            uuid::Uuid::try_parse(&captures["id"])
+                .ok()
+                .and_then(|id| blobs.get(&id))
+                .map_or_else(|| captures[0].to_owned(), |path| format!("../{path}"))
+        });
+
+        let front_matter = FrontMatter {
+            title: Some(post.title.clone()),
+            slug: Some(post.slug.clone()),
+            date: Some(post.published_at.unwrap_or(post.created_at).to_rfc3339()),
+            draft: post.published_at.is_none(),
+            published: None,
+            tags: FrontMatterTags::List(post.hashtags().await.into_iter().map(|hashtag| hashtag.name).collect()),
+            variables: Some(post.variables.clone()),
+            cover_image: blob_path(post.cover_image_blob_id).map(|path| format!("../{path}")),
+        };
+
+        write_archive_entry(
+            &mut zip_writer,
+            &archive_post_path(&post),
+            front_matter.to_markdown(&content).as_bytes(),
+        )?;
+    }
+
+    zip_writer.finish().map_err(std::io::Error::from)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use crate::commands::{get_post_by_slug, import_posts};
+    use crate::test_utils::{
+        insert_test_blob, insert_test_post, insert_test_user, insert_test_website, setup_core_context,
+    };
+
+    use super::export_website;
+
+    #[tokio::test]
+    async fn should_export_website() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let post = insert_test_post(&core_context, Some(&website), Some(&user)).await;
+
+        let result = export_website(&core_context, &website).await;
+
+        assert!(result.is_ok());
+
+        let export_path = result.unwrap().data;
+
+        assert_eq!(export_path, website.export_path());
+
+        let data = std::fs::read(&export_path).unwrap();
+        let archive = zip::ZipArchive::new(std::io::Cursor::new(&data)).unwrap();
+
+        assert!(archive.file_names().any(|name| name == "website.yaml"));
+        assert!(archive.file_names().any(|name| name == "navigation.yaml"));
+        assert!(archive
+            .file_names()
+            .any(|name| name == format!("posts/{}-{}.md", post.slug, post.id)));
+        assert!(archive.file_names().any(|name| name.starts_with("blobs/")));
+    }
+
+    #[tokio::test]
+    async fn should_import_exported_website() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let post = insert_test_post(&core_context, Some(&website), Some(&user)).await;
+        let export_path = export_website(&core_context, &website).await.unwrap().data;
+        let other_website = insert_test_website(&core_context, Some(&user)).await;
+
+        let result = import_posts(
+            &core_context,
+            &other_website,
+            &user,
+            &std::fs::read(export_path).unwrap(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+
+        let imported_post = get_post_by_slug(&core_context, &post.slug, &other_website, None)
+            .await
+            .unwrap();
+
+        assert_eq!(imported_post.title, post.title);
+        assert_eq!(imported_post.variables, post.variables);
+        assert_eq!(imported_post.blob_ids.len(), post.blob_ids.len());
+        assert!(imported_post.cover_image_blob_id.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_rewrite_all_blob_urls_on_export() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+        let post = insert_test_post(&core_context, Some(&website), Some(&user)).await;
+        let blob = insert_test_blob(&core_context, Some(&user), Some(&website)).await;
+        let blob_url = format!("{}?width=100&height=100", blob.url());
+
+        sqlx::query!(
+            "UPDATE posts SET content = $2 WHERE id = $1",
+            post.id,                         // $1
+            format!("![Image]({blob_url})"), // $2
+        )
+        .execute(&core_context.db_pool)
+        .await
+        .unwrap();
+
+        let export_path = export_website(&core_context, &website).await.unwrap().data;
+        let data = std::fs::read(&export_path).unwrap();
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(&data)).unwrap();
+        let mut content = String::new();
+
+        archive
+            .by_name(&format!("posts/{}-{}.md", post.slug, post.id))
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+
+        assert!(!content.contains(&blob_url));
+        assert!(content.contains(&format!("](../blobs/{}/", blob.id)));
+        assert!(archive
+            .file_names()
+            .any(|name| name.starts_with(&format!("blobs/{}/", blob.id))));
+    }
+
+    #[tokio::test]
+    async fn should_not_export_website_while_locked() {
+        let core_context = setup_core_context().await;
+        let user = insert_test_user(&core_context).await;
+        let website = insert_test_website(&core_context, Some(&user)).await;
+
+        std::fs::create_dir_all(website.export_lock_path().parent().unwrap()).unwrap();
+        std::fs::write(website.export_lock_path(), []).unwrap();
+
+        let result = export_website(&core_context, &website).await;
+
+        std::fs::remove_file(website.export_lock_path()).unwrap();
+
+        assert!(result.is_err());
+        assert!(export_website(&core_context, &website).await.is_ok());
+        assert!(!website.export_lock_path().exists());
+    }
+}
@@ -48,6 +48,10 @@ impl BasicConfig {
         }
     }
 
+    pub fn admin_url(&self) -> Url {
+        self.subdomain_url("admin")
+    }
+
     pub fn assets_url(&self) -> Url {
         self.subdomain_url("assets")
     }
@@ -56,6 +60,14 @@ impl BasicConfig {
         self.subdomain_url("accounts")
     }
 
+    pub fn blob_upload_url(&self, id: Uuid) -> Url {
+        self.uploads_url().join(&format!("blob-uploads/{}", id)).unwrap()
+    }
+
+    pub fn blob_uploads_url(&self) -> Url {
+        self.uploads_url().join("blob-uploads").unwrap()
+    }
+
     pub fn blob_url(&self, id: Uuid) -> Url {
         self.uploads_url().join(&format!("blobs/{}", id)).unwrap()
     }
@@ -100,6 +112,10 @@ impl BasicConfig {
         self.home_url().join(&format!("users/{username}")).unwrap()
     }
 
+    pub fn website_export_url(&self, website_id: Uuid) -> Url {
+        self.uploads_url().join(&format!("website-exports/{}", website_id)).unwrap()
+    }
+
     pub fn website_url(&self, subdomain: &str) -> Url {
         self.subdomain_url(subdomain)
     }
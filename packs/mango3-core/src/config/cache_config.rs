@@ -6,6 +6,7 @@ use super::extract_from_env;
 pub(crate) struct CacheConfig {
     pub(crate) redis_url: String,
     pub(crate) ttl: u64,
+    pub(crate) stats_ttl: u64,
 }
 
 impl Default for CacheConfig {
@@ -15,6 +16,7 @@ impl Default for CacheConfig {
         Self {
             redis_url: format!("redis://127.0.0.1:6379/{db_number}"),
             ttl: 3600,
+            stats_ttl: 60,
         }
     }
 }
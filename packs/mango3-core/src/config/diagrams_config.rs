@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::extract_from_env;
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct DiagramsConfig {
+    pub(crate) max_svg_size: u64,
+    /// URL of a Kroki compatible service. Diagrams are shown as code blocks when it is empty.
+    pub(crate) renderer_url: String,
+    timeout: u64,
+}
+
+impl Default for DiagramsConfig {
+    fn default() -> Self {
+        Self {
+            max_svg_size: 1024 * 1024,
+            renderer_url: String::new(),
+            timeout: 10,
+        }
+    }
+}
+
+impl DiagramsConfig {
+    pub(crate) fn load() -> Self {
+        extract_from_env("DIAGRAMS_")
+    }
+
+    pub(crate) fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout)
+    }
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::extract_from_env;
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct HttpConfig {
+    pub(crate) allow_private_ips: bool,
+    allowed_hosts: String,
+    pub(crate) cache_ttl: u64,
+    denied_hosts: String,
+    pub(crate) max_response_size: u64,
+    pub(crate) rate_limit: u32,
+    timeout: u64,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            allow_private_ips: false,
+            allowed_hosts: String::new(),
+            cache_ttl: 300,
+            denied_hosts: String::new(),
+            max_response_size: 1024 * 1024,
+            rate_limit: 60,
+            timeout: 5,
+        }
+    }
+}
+
+impl HttpConfig {
+    pub(crate) fn load() -> Self {
+        extract_from_env("HTTP_")
+    }
+
+    pub(crate) fn allowed_hosts(&self) -> Vec<String> {
+        split_hosts(&self.allowed_hosts)
+    }
+
+    pub(crate) fn denied_hosts(&self) -> Vec<String> {
+        split_hosts(&self.denied_hosts)
+    }
+
+    pub(crate) fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout)
+    }
+}
+
+fn split_hosts(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|host| host.trim().trim_end_matches('.').to_lowercase())
+        .filter(|host| !host.is_empty())
+        .collect()
+}
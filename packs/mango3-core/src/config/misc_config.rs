@@ -9,30 +9,42 @@ use super::extract_from_env;
 
 #[derive(Deserialize, Serialize)]
 pub struct MiscConfig {
+    blob_gc_grace_period_hours: u64,
+    pub client_country_header: String,
     pub client_ip_source: String,
     pub(crate) confirmation_code_length: u8,
     pub(crate) font_path: String,
+    pub(crate) hashtag_trends_period_days: u16,
     image_ops_filter_type: String,
     pub(crate) invitation_code_length: u8,
+    pub(crate) max_abuse_reports_per_hour: u16,
     pub(crate) max_comment_content_length: u32,
     pub(crate) max_post_content_length: u32,
+    pub(crate) post_views_retention_days: u16,
     pub(crate) storage_path: String,
+    pub(crate) storage_warning_threshold: u8,
 }
 
 impl Default for MiscConfig {
     fn default() -> Self {
         Self {
+            blob_gc_grace_period_hours: 24,
+            client_country_header: "CF-IPCountry".to_owned(),
             client_ip_source: "XRealIp".to_owned(),
             confirmation_code_length: 6,
             font_path: "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf".to_owned(),
+            hashtag_trends_period_days: 7,
             image_ops_filter_type: "CatmullRom".to_owned(),
             invitation_code_length: 6,
+            max_abuse_reports_per_hour: 5,
             max_comment_content_length: 8192,
             max_post_content_length: 16384,
+            post_views_retention_days: 30,
             #[cfg(not(test))]
             storage_path: format!("{}/storage", env!("CARGO_MANIFEST_DIR")),
             #[cfg(test)]
             storage_path: format!("{}/storage/tests", env!("CARGO_MANIFEST_DIR")),
+            storage_warning_threshold: 90,
         }
     }
 }
@@ -42,6 +54,10 @@ impl MiscConfig {
         extract_from_env("MISC_")
     }
 
+    pub fn blob_gc_grace_period(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.blob_gc_grace_period_hours * 60 * 60)
+    }
+
     #[cfg(feature = "blob-read")]
     pub(crate) fn image_ops_filter_type(&self) -> FilterType {
         match self.image_ops_filter_type.as_str() {
@@ -53,6 +69,14 @@ impl MiscConfig {
         }
     }
 
+    pub fn storage_exports_path(&self) -> PathBuf {
+        Path::new(&self.storage_path).join("exports")
+    }
+
+    pub fn storage_static_websites_path(&self) -> PathBuf {
+        Path::new(&self.storage_path).join("static-websites")
+    }
+
     pub fn storage_tmp_path(&self) -> PathBuf {
         Path::new(&self.storage_path).join("tmp")
     }
@@ -11,6 +11,10 @@ mod cache_config;
 mod misc_config;
 mod user_config;
 
+#[cfg(feature = "diagrams")]
+mod diagrams_config;
+#[cfg(feature = "http-client")]
+mod http_config;
 #[cfg(feature = "jobs")]
 mod jobs_config;
 #[cfg(feature = "mailer")]
@@ -23,6 +27,10 @@ pub(crate) use cache_config::CacheConfig;
 pub use misc_config::MiscConfig;
 pub(crate) use user_config::UserConfig;
 
+#[cfg(feature = "diagrams")]
+pub(crate) use diagrams_config::DiagramsConfig;
+#[cfg(feature = "http-client")]
+pub(crate) use http_config::HttpConfig;
 #[cfg(feature = "jobs")]
 pub(crate) use jobs_config::JobsConfig;
 #[cfg(feature = "mailer")]
@@ -37,6 +45,10 @@ pub static MISC_CONFIG: LazyLock<MiscConfig> = LazyLock::new(MiscConfig::load);
 pub static SESSIONS_CONFIG: LazyLock<SessionsConfig> = LazyLock::new(SessionsConfig::load);
 pub static USER_CONFIG: LazyLock<UserConfig> = LazyLock::new(UserConfig::load);
 
+#[cfg(feature = "diagrams")]
+pub(crate) static DIAGRAMS_CONFIG: LazyLock<DiagramsConfig> = LazyLock::new(DiagramsConfig::load);
+#[cfg(feature = "http-client")]
+pub(crate) static HTTP_CONFIG: LazyLock<HttpConfig> = LazyLock::new(HttpConfig::load);
 #[cfg(feature = "jobs")]
 pub(crate) static JOBS_CONFIG: LazyLock<JobsConfig> = LazyLock::new(JobsConfig::load);
 #[cfg(feature = "mailer")]
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "user-storage")]
+use size::Size;
+
 use crate::enums::UserRole;
 
 use super::extract_from_env;
@@ -8,6 +11,14 @@ use super::extract_from_env;
 pub struct UserConfig {
     pub default_disabled: bool,
     default_role: String,
+    #[cfg(feature = "user-storage")]
+    pub(crate) max_storage: Size,
+    #[cfg(feature = "user-storage")]
+    max_storage_admin: Option<Size>,
+    #[cfg(feature = "user-storage")]
+    max_storage_creator: Option<Size>,
+    #[cfg(feature = "user-storage")]
+    max_storage_superuser: Option<Size>,
 }
 
 impl Default for UserConfig {
@@ -15,6 +26,14 @@ impl Default for UserConfig {
         Self {
             default_disabled: false,
             default_role: "user".to_owned(),
+            #[cfg(feature = "user-storage")]
+            max_storage: Size::from_mib(100),
+            #[cfg(feature = "user-storage")]
+            max_storage_admin: None,
+            #[cfg(feature = "user-storage")]
+            max_storage_creator: None,
+            #[cfg(feature = "user-storage")]
+            max_storage_superuser: None,
         }
     }
 }
@@ -28,4 +47,15 @@ impl UserConfig {
     pub(crate) fn default_role(&self) -> UserRole {
         (&self.default_role).into()
     }
+
+    #[cfg(feature = "user-storage")]
+    pub(crate) fn max_storage_by_role(&self, role: &UserRole) -> Size {
+        match role {
+            UserRole::Superuser => self.max_storage_superuser,
+            UserRole::Admin => self.max_storage_admin,
+            UserRole::Creator => self.max_storage_creator,
+            UserRole::User => None,
+        }
+        .unwrap_or(self.max_storage)
+    }
 }
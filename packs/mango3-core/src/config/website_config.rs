@@ -1,19 +1,36 @@
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "website-storage")]
 use size::Size;
 
+#[cfg(feature = "website-storage")]
+use crate::enums::UserRole;
+
 use super::extract_from_env;
 
 #[derive(Deserialize, Serialize)]
 pub(crate) struct WebsiteConfig {
     #[cfg(feature = "website-storage")]
     pub(crate) max_storage: Size,
+    #[cfg(feature = "website-storage")]
+    max_storage_admin: Option<Size>,
+    #[cfg(feature = "website-storage")]
+    max_storage_creator: Option<Size>,
+    #[cfg(feature = "website-storage")]
+    max_storage_superuser: Option<Size>,
 }
 
 impl Default for WebsiteConfig {
     fn default() -> Self {
         Self {
+            #[cfg(feature = "website-storage")]
             max_storage: Size::from_gib(1),
+            #[cfg(feature = "website-storage")]
+            max_storage_admin: None,
+            #[cfg(feature = "website-storage")]
+            max_storage_creator: None,
+            #[cfg(feature = "website-storage")]
+            max_storage_superuser: None,
         }
     }
 }
@@ -22,4 +39,15 @@ impl WebsiteConfig {
     pub(crate) fn load() -> Self {
         extract_from_env("WEBSITE_")
     }
+
+    #[cfg(feature = "website-storage")]
+    pub(crate) fn max_storage_by_role(&self, role: &UserRole) -> Size {
+        match role {
+            UserRole::Superuser => self.max_storage_superuser,
+            UserRole::Admin => self.max_storage_admin,
+            UserRole::Creator => self.max_storage_creator,
+            UserRole::User => None,
+        }
+        .unwrap_or(self.max_storage)
+    }
 }
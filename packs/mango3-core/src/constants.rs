@@ -2,7 +2,7 @@ use std::sync::LazyLock;
 
 use regex::Regex;
 
-#[cfg(feature = "insert-blob")]
+#[cfg(any(feature = "insert-blob-from-tmp-file", feature = "insert-blob-upload"))]
 pub(crate) const ALLOWED_FILE_TYPES: [&str; 5] = ["image/bmp", "image/gif", "image/jpeg", "image/png", "image/webp"];
 
 pub const BLACKLISTED_HASHTAGS: [&str; 6] = ["each", "if", "log", "lookup", "unless", "with"];
@@ -154,7 +154,22 @@ pub static REACTION_EMOJIS: LazyLock<[&str; 24]> = LazyLock::new(|| {
 
 pub static REGEX_FIND_HASHTAGS: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"#(?<name>[a-zA-Z0-9]+(?:[-_][a-zA-Z0-9]+)*)").unwrap());
+pub static REGEX_FIND_MENTIONS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"@(?<username>[-_.]?[[:alnum:]]+(?:[-_.][[:alnum:]]+)*[-_]?)").unwrap());
 
+#[cfg(feature = "export-website")]
+pub(crate) static REGEX_BLOB_URL: LazyLock<Regex> = LazyLock::new(|| {
+    let blobs_url = crate::config::BASIC_CONFIG
+        .subdomain_url("uploads")
+        .join("blobs/")
+        .unwrap();
+
+    Regex::new(&format!(
+        r#"{}(?<id>[0-9a-fA-F-]{{36}})(?:\?[^\s)\]"'<>]*)?"#,
+        regex::escape(blobs_url.as_str())
+    ))
+    .unwrap()
+});
 #[cfg(feature = "regex-email")]
 pub(crate) static REGEX_EMAIL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\A[^@\s]+@[^@\s]+\z").unwrap());
 #[cfg(feature = "regex-handlebars")]
@@ -179,12 +194,18 @@ pub(crate) const HASHTAG_LOOKAROUND: [Option<&str>; 3] = [Some(" "), Some("\n"),
 pub(crate) const PREFIX_ALL_HASHTAGS_BY_IDS: &str = "all_hashtags_by_ids";
 #[cfg(feature = "all-navigation-items-by-website")]
 pub(crate) const PREFIX_ALL_NAVIGATION_ITEMS_BY_WEBSITE: &str = "all_navigation_items_by_website";
+#[cfg(feature = "all-platform-daily-stats")]
+pub(crate) const PREFIX_ALL_PLATFORM_DAILY_STATS: &str = "all_platform_daily_stats";
+#[cfg(feature = "diagrams")]
+pub(crate) const PREFIX_DIAGRAM_SVG: &str = "diagram_svg";
 #[cfg(feature = "get-blob-by-id")]
 pub(crate) const PREFIX_GET_BLOB_BY_ID: &str = "get_blob_by_id";
 #[cfg(feature = "get-hashtag-by-id")]
 pub(crate) const PREFIX_GET_HASHTAG_BY_ID: &str = "get_hashtag_by_id";
 #[cfg(feature = "get-hashtag-by-name")]
 pub(crate) const PREFIX_GET_HASHTAG_BY_NAME: &str = "get_hashtag_by_name";
+#[cfg(feature = "get-platform-stats")]
+pub(crate) const PREFIX_GET_PLATFORM_STATS: &str = "get_platform_stats";
 #[cfg(feature = "get-post-by-id")]
 pub(crate) const PREFIX_GET_POST_BY_ID: &str = "get_post_by_id";
 #[cfg(feature = "get-post-by-slug")]
@@ -201,6 +222,8 @@ pub(crate) const PREFIX_GET_USER_SESSION_BY_ID: &str = "get_user_session_by_id";
 pub(crate) const PREFIX_GET_WEBSITE_BY_ID: &str = "get_website_by_id";
 #[cfg(feature = "get-website-by-subdomain")]
 pub(crate) const PREFIX_GET_WEBSITE_BY_SUBDOMAIN: &str = "get_website_by_subdomain";
+#[cfg(feature = "http-client")]
+pub(crate) const PREFIX_HTTP_RATE_LIMIT: &str = "http_rate_limit";
 #[cfg(feature = "post-comment")]
 pub(crate) const PREFIX_POST_COMMENT_CONTENT_HTML: &str = "post_comment_content_html";
 #[cfg(feature = "post-content-html")]
@@ -215,3 +238,35 @@ pub(crate) const PREFIX_USER_BIO_PREVIEW_HTML: &str = "user_bio_preview_html";
 pub(crate) const PREFIX_WEBSITE_DESCRIPTION_HTML: &str = "website_description_html";
 #[cfg(feature = "website")]
 pub(crate) const PREFIX_WEBSITE_DESCRIPTION_PREVIEW_HTML: &str = "website_description_preview_html";
+
+#[cfg(any(feature = "post", feature = "website"))]
+pub(crate) const TEXT_SEARCH_LANGUAGE_CODES: [(&str, &str); 28] = [
+    ("arabic", "ar"),
+    ("armenian", "hy"),
+    ("basque", "eu"),
+    ("catalan", "ca"),
+    ("danish", "da"),
+    ("dutch", "nl"),
+    ("english", "en"),
+    ("finnish", "fi"),
+    ("french", "fr"),
+    ("german", "de"),
+    ("greek", "el"),
+    ("hindi", "hi"),
+    ("hungarian", "hu"),
+    ("indonesian", "id"),
+    ("irish", "ga"),
+    ("italian", "it"),
+    ("lithuanian", "lt"),
+    ("nepali", "ne"),
+    ("norwegian", "no"),
+    ("portuguese", "pt"),
+    ("romanian", "ro"),
+    ("russian", "ru"),
+    ("serbian", "sr"),
+    ("spanish", "es"),
+    ("swedish", "sv"),
+    ("tamil", "ta"),
+    ("turkish", "tr"),
+    ("yiddish", "yi"),
+];
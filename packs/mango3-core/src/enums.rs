@@ -1,9 +1,123 @@
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::models::User;
 
+#[derive(sqlx::Type, strum::Display, Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[sqlx(type_name = "abuse_report_category", rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AbuseReportCategory {
+    Spam,
+    Harassment,
+    HateSpeech,
+    Violence,
+    IllegalContent,
+    Other,
+}
+
+impl FromStr for AbuseReportCategory {
+    type Err = FromStrError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "spam" => Ok(Self::Spam),
+            "harassment" => Ok(Self::Harassment),
+            "hate_speech" => Ok(Self::HateSpeech),
+            "violence" => Ok(Self::Violence),
+            "illegal_content" => Ok(Self::IllegalContent),
+            "other" => Ok(Self::Other),
+            _ => Err(FromStrError),
+        }
+    }
+}
+
+#[derive(sqlx::Type, strum::Display, Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[sqlx(type_name = "abuse_report_target_type", rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AbuseReportTargetType {
+    Post,
+    PostComment,
+    User,
+    Website,
+}
+
+impl FromStr for AbuseReportTargetType {
+    type Err = FromStrError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "post" => Ok(Self::Post),
+            "post_comment" => Ok(Self::PostComment),
+            "user" => Ok(Self::User),
+            "website" => Ok(Self::Website),
+            _ => Err(FromStrError),
+        }
+    }
+}
+
+#[derive(sqlx::Type, strum::Display, Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[sqlx(type_name = "audit_log_action", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditLogAction {
+    DeleteInvitationCode,
+    DisableUser,
+    EnableUser,
+    InsertInvitationCode,
+    ModeratePost,
+    ModerateWebsite,
+    ResetUserPassword,
+    ResolveAbuseReport,
+    RestorePost,
+    RestoreWebsite,
+    UpdateUserEmail,
+    UpdateUserInvitationAllowance,
+    UpdateUserPassword,
+    UpdateUserRole,
+    UpdateUserStorageQuota,
+    UpdateWebsiteStorageQuota,
+}
+
+impl FromStr for AuditLogAction {
+    type Err = FromStrError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "delete_invitation_code" => Ok(Self::DeleteInvitationCode),
+            "disable_user" => Ok(Self::DisableUser),
+            "enable_user" => Ok(Self::EnableUser),
+            "insert_invitation_code" => Ok(Self::InsertInvitationCode),
+            "moderate_post" => Ok(Self::ModeratePost),
+            "moderate_website" => Ok(Self::ModerateWebsite),
+            "reset_user_password" => Ok(Self::ResetUserPassword),
+            "resolve_abuse_report" => Ok(Self::ResolveAbuseReport),
+            "restore_post" => Ok(Self::RestorePost),
+            "restore_website" => Ok(Self::RestoreWebsite),
+            "update_user_email" => Ok(Self::UpdateUserEmail),
+            "update_user_invitation_allowance" => Ok(Self::UpdateUserInvitationAllowance),
+            "update_user_password" => Ok(Self::UpdateUserPassword),
+            "update_user_role" => Ok(Self::UpdateUserRole),
+            "update_user_storage_quota" => Ok(Self::UpdateUserStorageQuota),
+            "update_website_storage_quota" => Ok(Self::UpdateWebsiteStorageQuota),
+            _ => Err(FromStrError),
+        }
+    }
+}
+
+#[derive(sqlx::Type, strum::Display, Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[sqlx(type_name = "audit_log_target_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditLogTargetType {
+    AbuseReport,
+    InvitationCode,
+    Post,
+    User,
+    Website,
+}
+
 #[derive(sqlx::Type, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[sqlx(type_name = "confirmation_code_action", rename_all = "snake_case")]
 pub enum ConfirmationCodeAction {
@@ -20,8 +134,11 @@ pub enum GuestMailerJobCommand {
 #[derive(strum::Display, Clone, Debug, Eq, Hash, PartialEq)]
 #[strum(serialize_all = "snake_case")]
 pub enum Input {
+    AltText,
     Bio,
     Birthdate,
+    Caption,
+    Category,
     Code,
     Content,
     CountryAlpha2,
@@ -31,12 +148,21 @@ pub enum Input {
     DisplayName,
     Email,
     Emoji,
+    ExpiresAt,
+    FocalPointX,
+    FocalPointY,
     FullName,
+    InvitationAllowance,
+    Language,
     LightTheme,
     Name,
     NewPassword,
+    Note,
     Password,
+    Reason,
+    Role,
     Slug,
+    StorageQuota,
     Subdomain,
     Title,
     Username,
@@ -54,6 +180,7 @@ pub enum InputError {
     InvalidFormat,
     InvalidLength(Option<u32>, Option<u32>),
     IsInvalid,
+    TooManyRequests,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -63,16 +190,135 @@ pub enum MailerJobCommand {
         code: String,
     },
     Enabled,
+    Mention {
+        author_username: String,
+        url: String,
+    },
+    Moderated {
+        action: ModerationAction,
+        target_name: String,
+        reason: String,
+    },
     NewUserSession,
     Disabled,
+    PostImportFailed {
+        website_name: String,
+    },
+    PostsImported {
+        website_name: String,
+        imported_posts: Vec<String>,
+        skipped_items: Vec<String>,
+    },
+    Restored {
+        target_name: String,
+    },
+    StorageWarning {
+        website_name: Option<String>,
+        used_storage: String,
+        max_storage: String,
+    },
+    WebsiteExportReady {
+        website_name: String,
+        url: String,
+    },
     Welcome,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub enum AdminMailerJobCommand {
+    NewAbuseReport {
+        category: AbuseReportCategory,
+        target_type: AbuseReportTargetType,
+        target_id: Uuid,
+        note: String,
+    },
     NewUser(User),
 }
 
+#[derive(strum::Display, Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[strum(serialize_all = "snake_case")]
+pub enum ModerationAction {
+    #[default]
+    Unpublish,
+    Suspend,
+}
+
+impl From<&str> for ModerationAction {
+    fn from(value: &str) -> Self {
+        match value {
+            "suspend" => Self::Suspend,
+            _ => Self::Unpublish,
+        }
+    }
+}
+
+#[derive(strum::Display, Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[strum(serialize_all = "snake_case")]
+pub enum SearchKind {
+    #[default]
+    Posts,
+    Websites,
+    Users,
+    Comments,
+}
+
+impl From<&str> for SearchKind {
+    fn from(value: &str) -> Self {
+        match value {
+            "websites" => Self::Websites,
+            "users" => Self::Users,
+            "comments" => Self::Comments,
+            _ => Self::Posts,
+        }
+    }
+}
+
+#[derive(sqlx::Type, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[sqlx(type_name = "user_agent_class", rename_all = "snake_case")]
+pub enum UserAgentClass {
+    Desktop,
+    Mobile,
+    Tablet,
+    Bot,
+    Unknown,
+}
+
+impl From<&str> for UserAgentClass {
+    fn from(user_agent: &str) -> Self {
+        const BOT_PATTERNS: [&str; 8] = [
+            "bot",
+            "crawl",
+            "spider",
+            "slurp",
+            "curl",
+            "wget",
+            "headless",
+            "python-requests",
+        ];
+        const TABLET_PATTERNS: [&str; 2] = ["ipad", "tablet"];
+        const MOBILE_PATTERNS: [&str; 4] = ["mobi", "iphone", "ipod", "android"];
+        const DESKTOP_PATTERNS: [&str; 5] = ["windows", "macintosh", "x11", "linux", "cros"];
+
+        let user_agent = user_agent.to_lowercase();
+        let contains_any = |patterns: &[&str]| patterns.iter().any(|pattern| user_agent.contains(pattern));
+        let is_android_tablet = user_agent.contains("android") && !user_agent.contains("mobi");
+
+        if user_agent.trim().is_empty() {
+            Self::Unknown
+        } else if contains_any(&BOT_PATTERNS) {
+            Self::Bot
+        } else if contains_any(&TABLET_PATTERNS) || is_android_tablet {
+            Self::Tablet
+        } else if contains_any(&MOBILE_PATTERNS) {
+            Self::Mobile
+        } else if contains_any(&DESKTOP_PATTERNS) {
+            Self::Desktop
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
 #[derive(sqlx::Type, strum::Display, Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[sqlx(type_name = "user_role", rename_all = "snake_case")]
 #[strum(serialize_all = "kebab-case")]
@@ -105,3 +351,113 @@ impl From<&String> for UserRole {
         Self::from_str(value).expect("User role is invalid")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{AbuseReportCategory, AbuseReportTargetType, AuditLogAction, SearchKind, UserAgentClass};
+
+    #[test]
+    fn should_parse_abuse_report_categories() {
+        for category in [
+            AbuseReportCategory::Spam,
+            AbuseReportCategory::Harassment,
+            AbuseReportCategory::HateSpeech,
+            AbuseReportCategory::Violence,
+            AbuseReportCategory::IllegalContent,
+            AbuseReportCategory::Other,
+        ] {
+            assert_eq!(
+                AbuseReportCategory::from_str(&category.to_string()).ok(),
+                Some(category)
+            );
+        }
+
+        assert!(AbuseReportCategory::from_str("unknown").is_err());
+    }
+
+    #[test]
+    fn should_parse_abuse_report_target_types() {
+        for target_type in [
+            AbuseReportTargetType::Post,
+            AbuseReportTargetType::PostComment,
+            AbuseReportTargetType::User,
+            AbuseReportTargetType::Website,
+        ] {
+            assert_eq!(
+                AbuseReportTargetType::from_str(&target_type.to_string()).ok(),
+                Some(target_type)
+            );
+        }
+
+        assert!(AbuseReportTargetType::from_str("unknown").is_err());
+    }
+
+    #[test]
+    fn should_parse_audit_log_actions() {
+        for action in [
+            AuditLogAction::DeleteInvitationCode,
+            AuditLogAction::DisableUser,
+            AuditLogAction::EnableUser,
+            AuditLogAction::InsertInvitationCode,
+            AuditLogAction::ModeratePost,
+            AuditLogAction::ModerateWebsite,
+            AuditLogAction::ResetUserPassword,
+            AuditLogAction::ResolveAbuseReport,
+            AuditLogAction::RestorePost,
+            AuditLogAction::RestoreWebsite,
+            AuditLogAction::UpdateUserEmail,
+            AuditLogAction::UpdateUserInvitationAllowance,
+            AuditLogAction::UpdateUserPassword,
+            AuditLogAction::UpdateUserRole,
+            AuditLogAction::UpdateUserStorageQuota,
+            AuditLogAction::UpdateWebsiteStorageQuota,
+        ] {
+            assert_eq!(AuditLogAction::from_str(&action.to_string()).ok(), Some(action));
+        }
+
+        assert!(AuditLogAction::from_str("unknown").is_err());
+    }
+
+    #[test]
+    fn should_classify_user_agents() {
+        let cases = [
+            (
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/134.0.0.0 Safari/537.36",
+                UserAgentClass::Desktop,
+            ),
+            (
+                "Mozilla/5.0 (iPhone; CPU iPhone OS 18_3 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148",
+                UserAgentClass::Mobile,
+            ),
+            (
+                "Mozilla/5.0 (Linux; Android 14; SM-X710) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/134.0.0.0 Safari/537.36",
+                UserAgentClass::Tablet,
+            ),
+            (
+                "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
+                UserAgentClass::Bot,
+            ),
+            ("", UserAgentClass::Unknown),
+        ];
+
+        for (user_agent, expected) in cases {
+            assert_eq!(UserAgentClass::from(user_agent), expected);
+        }
+    }
+
+    #[test]
+    fn should_parse_search_kinds() {
+        for kind in [
+            SearchKind::Posts,
+            SearchKind::Websites,
+            SearchKind::Users,
+            SearchKind::Comments,
+        ] {
+            assert_eq!(SearchKind::from(kind.to_string().as_str()), kind);
+        }
+
+        assert_eq!(SearchKind::from("unknown"), SearchKind::Posts);
+    }
+}
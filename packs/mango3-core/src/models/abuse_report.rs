@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::enums::{AbuseReportCategory, AbuseReportTargetType};
+use crate::CoreContext;
+
+use super::User;
+
+#[derive(Clone)]
+pub struct AbuseReport {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub target_type: AbuseReportTargetType,
+    pub target_id: Uuid,
+    pub category: AbuseReportCategory,
+    pub note: String,
+    pub resolved_by_user_id: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl AbuseReport {
+    pub fn is_resolved(&self) -> bool {
+        self.resolved_at.is_some()
+    }
+
+    pub async fn user(&self, core_context: &CoreContext) -> sqlx::Result<User> {
+        crate::commands::get_user_by_id(core_context, self.user_id).await
+    }
+}
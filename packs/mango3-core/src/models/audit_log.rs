@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::enums::{AuditLogAction, AuditLogTargetType};
+use crate::CoreContext;
+
+use super::User;
+
+#[derive(Clone, Serialize)]
+pub struct AuditLog {
+    pub id: Uuid,
+    pub actor_user_id: Option<Uuid>,
+    pub ip_address: Option<String>,
+    pub action: AuditLogAction,
+    pub target_type: AuditLogTargetType,
+    pub target_id: Uuid,
+    pub before_value: Option<Value>,
+    pub after_value: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditLog {
+    pub async fn actor_user(&self, core_context: &CoreContext) -> Option<User> {
+        if let Some(actor_user_id) = self.actor_user_id {
+            crate::commands::get_user_by_id(core_context, actor_user_id).await.ok()
+        } else {
+            None
+        }
+    }
+}
+
+/// Who performed an audited action. The default value stands for the system itself, e.g. the CLI.
+#[derive(Clone, Debug, Default)]
+pub struct AuditActor {
+    pub user_id: Option<Uuid>,
+    pub ip_address: Option<String>,
+}
+
+impl AuditActor {
+    pub fn new(user: Option<&User>, ip_address: Option<&str>) -> Self {
+        Self {
+            user_id: user.map(|user| user.id),
+            ip_address: ip_address.map(|ip_address| ip_address.to_owned()),
+        }
+    }
+}
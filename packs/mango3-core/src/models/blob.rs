@@ -19,6 +19,10 @@ pub struct Blob<'a> {
     pub content_type: Cow<'a, str>,
     pub byte_size: i64,
     pub md5_checksum: Cow<'a, str>,
+    pub alt_text: Cow<'a, str>,
+    pub caption: Cow<'a, str>,
+    pub focal_point_x: f32,
+    pub focal_point_y: f32,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -141,7 +145,7 @@ impl Blob<'_> {
                 dynamic_image.apply_orientation(orientation);
 
                 dynamic_image = if fill {
-                    dynamic_image.resize_to_fill(width as u32, height as u32, MISC_CONFIG.image_ops_filter_type())
+                    self.resize_to_fill(&dynamic_image, width as u32, height as u32)
                 } else {
                     dynamic_image.resize(width as u32, height as u32, MISC_CONFIG.image_ops_filter_type())
                 };
@@ -155,6 +159,45 @@ impl Blob<'_> {
         std::fs::read(self.default_path().to_string()).ok()
     }
 
+    #[cfg(feature = "update-blob")]
+    pub(crate) fn remove_variants(&self) {
+        let Ok(entries) = std::fs::read_dir(self.directory().to_string()) else {
+            return;
+        };
+
+        let default_path = std::path::PathBuf::from(self.default_path().to_string());
+
+        for entry in entries.flatten() {
+            if entry.path() != default_path {
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+    }
+
+    #[cfg(feature = "blob-read")]
+    fn resize_to_fill(&self, dynamic_image: &image::DynamicImage, width: u32, height: u32) -> image::DynamicImage {
+        let ratio = f32::max(
+            width as f32 / dynamic_image.width() as f32,
+            height as f32 / dynamic_image.height() as f32,
+        );
+        let resized_width = ((dynamic_image.width() as f32 * ratio).round() as u32).max(width);
+        let resized_height = ((dynamic_image.height() as f32 * ratio).round() as u32).max(height);
+        let resized_image = dynamic_image.resize_exact(
+            resized_width,
+            resized_height,
+            MISC_CONFIG.image_ops_filter_type(),
+        );
+
+        let x = (resized_width as f32 * self.focal_point_x - width as f32 / 2.0)
+            .clamp(0.0, (resized_width - width) as f32)
+            .round() as u32;
+        let y = (resized_height as f32 * self.focal_point_y - height as f32 / 2.0)
+            .clamp(0.0, (resized_height - height) as f32)
+            .round() as u32;
+
+        resized_image.crop_imm(x, y, width, height)
+    }
+
     pub fn url(&self) -> Url {
         BASIC_CONFIG.blob_url(self.id)
     }
@@ -0,0 +1,30 @@
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+use sqlx::types::chrono::{DateTime, Utc};
+use sqlx::types::Uuid;
+
+use crate::config::MISC_CONFIG;
+
+#[derive(Clone)]
+pub struct BlobUpload<'a> {
+    pub id: Uuid,
+    pub website_id: Option<Uuid>,
+    pub user_id: Uuid,
+    pub file_name: Cow<'a, str>,
+    pub content_type: Cow<'a, str>,
+    pub upload_length: i64,
+    pub upload_offset: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl BlobUpload<'_> {
+    pub fn is_complete(&self) -> bool {
+        self.upload_offset == self.upload_length
+    }
+
+    pub fn tmp_path(&self) -> PathBuf {
+        MISC_CONFIG.storage_tmp_path().join(format!("upload-{}", self.id))
+    }
+}
@@ -1,10 +1,61 @@
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::enums::UserRole;
+use crate::CoreContext;
+
+use super::User;
+
 pub struct InvitationCode {
     pub id: Uuid,
     pub email: String,
     pub code: String,
+    pub role: UserRole,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub invited_by_user_id: Option<Uuid>,
+    pub used_by_user_id: Option<Uuid>,
+    pub used_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
 }
+
+impl InvitationCode {
+    pub async fn invited_by(&self, core_context: &CoreContext) -> Option<User> {
+        if let Some(invited_by_user_id) = self.invited_by_user_id {
+            crate::commands::get_user_by_id(core_context, invited_by_user_id)
+                .await
+                .ok()
+        } else {
+            None
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= Utc::now())
+    }
+
+    pub fn is_usable(&self) -> bool {
+        !self.is_used() && !self.is_expired()
+    }
+
+    pub fn is_used(&self) -> bool {
+        self.used_at.is_some()
+    }
+
+    pub async fn used_by(&self, core_context: &CoreContext) -> Option<User> {
+        if let Some(used_by_user_id) = self.used_by_user_id {
+            crate::commands::get_user_by_id(core_context, used_by_user_id)
+                .await
+                .ok()
+        } else {
+            None
+        }
+    }
+}
+
+/// The outcome of inviting many emails at once, e.g. from a CSV file.
+#[derive(Clone, Debug, Default)]
+pub struct InvitationCodesReport {
+    pub invited_emails: Vec<String>,
+    pub failed_emails: Vec<String>,
+}
@@ -1,5 +1,11 @@
+#[cfg(feature = "abuse-report")]
+mod abuse_report;
+#[cfg(feature = "audit-log")]
+mod audit_log;
 #[cfg(feature = "blob")]
 mod blob;
+#[cfg(feature = "blob-upload")]
+mod blob_upload;
 #[cfg(feature = "confirmation-code")]
 mod confirmation_code;
 #[cfg(feature = "hashtag")]
@@ -8,6 +14,8 @@ mod hashtag;
 mod invitation_code;
 #[cfg(feature = "navigation-item")]
 mod navigation_item;
+#[cfg(feature = "platform-stats")]
+mod platform_stats;
 #[cfg(feature = "post")]
 mod post;
 #[cfg(feature = "post-comment")]
@@ -16,37 +24,55 @@ mod post_comment;
 mod post_reaction;
 #[cfg(feature = "post-view")]
 mod post_view;
+#[cfg(feature = "search-result")]
+mod search_result;
+#[cfg(feature = "storage-usage")]
+mod storage_usage;
 #[cfg(feature = "user")]
 mod user;
 #[cfg(feature = "user-session")]
 mod user_session;
 #[cfg(feature = "website")]
 mod website;
+#[cfg(feature = "website-analytics")]
+mod website_analytics;
 
 #[cfg(feature = "hashtag")]
 pub(crate) use hashtag::Hashtags;
 #[cfg(feature = "navigation-item")]
 pub(crate) use navigation_item::NavigationItems;
+#[cfg(feature = "platform-stats")]
+pub(crate) use platform_stats::PlatformDailyStats;
 #[cfg(feature = "clear-post-cache")]
 pub(crate) use post::{POST_CONTENT_HTML, POST_CONTENT_PREVIEW_HTML};
 #[cfg(feature = "post-comment")]
 #[allow(unused_imports)]
 pub(crate) use post_comment::POST_COMMENT_CONTENT_HTML;
+#[cfg(feature = "search-all")]
+pub(crate) use search_result::{SEARCH_HEADLINE_START, SEARCH_HEADLINE_STOP};
 #[cfg(feature = "clear-user-cache")]
 pub(crate) use user::{USER_BIO_HTML, USER_BIO_PREVIEW_HTML};
 #[cfg(feature = "clear-website-cache")]
 pub(crate) use website::{WEBSITE_DESCRIPTION_HTML, WEBSITE_DESCRIPTION_PREVIEW_HTML};
 
+#[cfg(feature = "abuse-report")]
+pub use abuse_report::AbuseReport;
+#[cfg(feature = "audit-log")]
+pub use audit_log::{AuditActor, AuditLog};
 #[cfg(feature = "blob")]
 pub use blob::Blob;
+#[cfg(feature = "blob-upload")]
+pub use blob_upload::BlobUpload;
 #[cfg(feature = "confirmation-code")]
 pub use confirmation_code::ConfirmationCode;
 #[cfg(feature = "hashtag")]
 pub use hashtag::Hashtag;
 #[cfg(feature = "invitation-code")]
-pub use invitation_code::InvitationCode;
+pub use invitation_code::{InvitationCode, InvitationCodesReport};
 #[cfg(feature = "navigation-item")]
 pub use navigation_item::NavigationItem;
+#[cfg(feature = "platform-stats")]
+pub use platform_stats::{JobQueueDepth, PlatformDailyStat, PlatformRoleStat, PlatformStats};
 #[cfg(feature = "post")]
 pub use post::Post;
 #[cfg(feature = "post-comment")]
@@ -55,9 +81,15 @@ pub use post_comment::PostComment;
 pub use post_reaction::PostReaction;
 #[cfg(feature = "post-view")]
 pub use post_view::PostView;
+#[cfg(feature = "search-result")]
+pub use search_result::{SearchFilters, SearchResult};
+#[cfg(feature = "storage-usage")]
+pub use storage_usage::StorageUsage;
 #[cfg(feature = "user")]
 pub use user::User;
 #[cfg(feature = "user-session")]
 pub use user_session::UserSession;
 #[cfg(feature = "website")]
 pub use website::Website;
+#[cfg(feature = "website-analytics")]
+pub use website_analytics::{WebsiteDailyStat, WebsiteTopPost, WebsiteViewsSource};
@@ -0,0 +1,82 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::enums::UserRole;
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct PlatformStats {
+    pub users_count: i64,
+    pub disabled_users_count: i64,
+    pub unconfirmed_users_count: i64,
+    pub users_by_role: Vec<PlatformRoleStat>,
+    pub websites_count: i64,
+    pub published_websites_count: i64,
+    pub published_posts_count: i64,
+    pub comments_count: i64,
+    pub used_bytes: i64,
+}
+
+impl Display for PlatformStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.users_count)
+    }
+}
+
+impl PlatformStats {
+    pub fn used_storage(&self) -> size::Size {
+        size::Size::from_bytes(self.used_bytes)
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct PlatformRoleStat {
+    pub role: UserRole,
+    pub users_count: i64,
+}
+
+/// What happened on the whole platform in one day. Posts are counted the day they were published.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct PlatformDailyStat {
+    pub day: NaiveDate,
+    pub users_count: i64,
+    pub websites_count: i64,
+    pub posts_count: i64,
+    pub comments_count: i64,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub(crate) struct PlatformDailyStats(Vec<PlatformDailyStat>);
+
+impl Display for PlatformDailyStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.0
+                .iter()
+                .map(|stat| stat.day.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        )
+    }
+}
+
+impl From<Vec<PlatformDailyStat>> for PlatformDailyStats {
+    fn from(stats: Vec<PlatformDailyStat>) -> Self {
+        Self(stats)
+    }
+}
+
+impl From<PlatformDailyStats> for Vec<PlatformDailyStat> {
+    fn from(stats: PlatformDailyStats) -> Self {
+        stats.0
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct JobQueueDepth {
+    pub name: String,
+    pub pending_count: i64,
+}
@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+#[cfg(feature = "post-content-html")]
+use cached::Cached;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -15,15 +17,19 @@ pub struct Post {
     pub id: Uuid,
     pub website_id: Uuid,
     pub user_id: Uuid,
+    pub translation_group_id: Uuid,
     pub language: String,
     pub title: String,
     pub slug: String,
     pub content: String,
     pub variables: Value,
     pub hashtag_ids: Vec<Uuid>,
+    pub mentioned_user_ids: Vec<Uuid>,
     pub cover_image_blob_id: Option<Uuid>,
     pub blob_ids: Vec<Uuid>,
     pub published_at: Option<DateTime<Utc>>,
+    pub suspended_at: Option<DateTime<Utc>>,
+    pub moderation_reason: Option<String>,
     pub modified_at: Option<DateTime<Utc>>,
     pub search_rank: Option<f32>,
     pub created_at: DateTime<Utc>,
@@ -46,9 +52,48 @@ impl Post {
         crate::commands::get_post_comments_count(self).await
     }
 
+    /// Posts with dynamic templates are not kept with the cached content: the ones using data sources are cached for the
+    /// shortest TTL of their sources, and the ones using website helpers are rendered on every call, so they always list
+    /// current data.
     #[cfg(feature = "post-content-html")]
-    pub async fn content_html(&self) -> String {
-        post_content_html(self).await.unwrap_or_default()
+    pub async fn content_html(&self, core_context: &CoreContext) -> String {
+        if !crate::utils::is_dynamic_template(&self.content, &self.variables) {
+            return post_content_html(self, core_context).await.unwrap_or_default();
+        }
+
+        let Some(ttl) = crate::utils::dynamic_template_ttl(&self.content, &self.variables) else {
+            return render_post_content_html(self, core_context).await;
+        };
+
+        // The key changes with every update of the post, so outdated content is never returned.
+        let key = format!(
+            "{}:{}",
+            self.id,
+            self.updated_at.unwrap_or(self.created_at).timestamp_micros()
+        );
+
+        if let Some(content_html) = lock_dynamic_content_html_cache()
+            .cache_get(&key)
+            .filter(|(rendered_at, _)| rendered_at.elapsed() < ttl)
+            .map(|(_, content_html)| content_html.clone())
+        {
+            return content_html;
+        }
+
+        let content_html = render_post_content_html(self, core_context).await;
+
+        lock_dynamic_content_html_cache().cache_set(key, (std::time::Instant::now(), content_html.clone()));
+
+        content_html
+    }
+
+    /// Renders the diagrams of the content its template renders, so they are shown without requests to the renderer.
+    /// Returns whether every diagram could be rendered.
+    #[cfg(feature = "post-content-html")]
+    pub(crate) async fn render_diagrams(&self, core_context: &CoreContext) -> bool {
+        crate::utils::render_diagrams(&render_post_content(self, core_context).await)
+            .await
+            .1
     }
 
     pub async fn content_preview_html(&self) -> String {
@@ -68,7 +113,19 @@ impl Post {
     }
 
     pub async fn is_published(&self, core_context: &CoreContext) -> bool {
-        self.website(core_context).await.unwrap().is_published() && self.published_at.is_some()
+        self.website(core_context).await.unwrap().is_published() && self.published_at.is_some() && !self.is_suspended()
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_at.is_some()
+    }
+
+    pub fn language_code(&self) -> &'static str {
+        crate::utils::text_search_language_code(&self.language)
+    }
+
+    pub async fn mentioned_users(&self, core_context: &CoreContext) -> Vec<User> {
+        crate::commands::all_mentioned_users_by_ids(core_context, &self.mentioned_user_ids).await
     }
 
     pub async fn reactions_count(&self) -> i64 {
@@ -104,11 +161,67 @@ impl Post {
     ty = "cached::AsyncRedisCache<Uuid, String>",
     create = r##" { crate::async_redis_cache!(crate::constants::PREFIX_POST_CONTENT_HTML).await } "##
 )]
-pub(crate) async fn post_content_html(post: &Post) -> Result<String, cached::RedisCacheError> {
-    Ok(crate::parse_html!(
-        &crate::render_handlebars!(&post.content, &post.variables).unwrap_or_default(),
-        true
-    ))
+pub(crate) async fn post_content_html(
+    post: &Post,
+    core_context: &CoreContext,
+) -> Result<String, cached::RedisCacheError> {
+    Ok(render_post_content_html(post, core_context).await)
+}
+
+#[cfg(feature = "post-content-html")]
+const DYNAMIC_CONTENT_HTML_CACHE_SIZE: usize = 1000;
+
+#[cfg(feature = "post-content-html")]
+type DynamicContentHtmlCache = cached::SizedCache<String, (std::time::Instant, String)>;
+
+/// Content of posts with data sources, kept in memory because it expires with the TTL of their sources.
+#[cfg(feature = "post-content-html")]
+static DYNAMIC_CONTENT_HTML_CACHE: std::sync::LazyLock<std::sync::Mutex<DynamicContentHtmlCache>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(cached::SizedCache::with_size(DYNAMIC_CONTENT_HTML_CACHE_SIZE)));
+
+#[cfg(feature = "post-content-html")]
+fn lock_dynamic_content_html_cache() -> std::sync::MutexGuard<'static, DynamicContentHtmlCache> {
+    DYNAMIC_CONTENT_HTML_CACHE
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+/// Returns the content of the post with its template rendered, which is the Markdown that is shown.
+#[cfg(feature = "post-content-html")]
+async fn render_post_content(post: &Post, core_context: &CoreContext) -> String {
+    let scope = post
+        .website(core_context)
+        .await
+        .ok()
+        .map(|website| crate::utils::TemplateScope {
+            core_context: core_context.clone(),
+            website,
+            post: Some(post.clone()),
+        });
+
+    crate::render_handlebars!(&post.content, &post.variables, scope)
+        .await
+        .map(|rendered| rendered.content)
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "post-content-html")]
+async fn render_post_content_html(post: &Post, core_context: &CoreContext) -> String {
+    let content = render_post_content(post, core_context).await;
+    let (diagrams, has_all_diagrams) = crate::utils::cached_diagrams(&content).await;
+
+    // Rendered diagrams expire from the cache like the content HTML, so the missing ones are rendered again by a job.
+    if !has_all_diagrams {
+        core_context.jobs.render_post_diagrams(post.id).await;
+    }
+
+    let mentions = post
+        .mentioned_users(core_context)
+        .await
+        .iter()
+        .collect::<crate::utils::Mentions>();
+
+    crate::utils::parse_html_with_shortcodes(&content, true, &diagrams, &mentions)
 }
 
 #[cached::proc_macro::io_cached(
@@ -13,13 +13,18 @@ pub struct PostComment<'a> {
     pub post_id: Uuid,
     pub user_id: Uuid,
     pub content: Cow<'a, str>,
+    pub mentioned_user_ids: Vec<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
 }
 
 impl PostComment<'_> {
-    pub async fn content_html(&self) -> Cow<'_, str> {
-        Cow::Owned(post_comment_content_html(self).await.unwrap_or_default())
+    pub async fn content_html(&self, core_context: &CoreContext) -> Cow<'_, str> {
+        Cow::Owned(post_comment_content_html(self, core_context).await.unwrap_or_default())
+    }
+
+    pub async fn mentioned_users(&self, core_context: &CoreContext) -> Vec<User> {
+        crate::commands::all_mentioned_users_by_ids(core_context, &self.mentioned_user_ids).await
     }
 
     pub async fn user(&self, core_context: &CoreContext) -> sqlx::Result<User> {
@@ -33,6 +38,19 @@ impl PostComment<'_> {
     ty = "cached::AsyncRedisCache<Uuid, String>",
     create = r##" { crate::async_redis_cache!(crate::constants::PREFIX_POST_COMMENT_CONTENT_HTML).await } "##
 )]
-pub(crate) async fn post_comment_content_html(comment: &PostComment<'_>) -> Result<String, cached::RedisCacheError> {
-    Ok(crate::utils::parse_html(&comment.content, true))
+pub(crate) async fn post_comment_content_html(
+    comment: &PostComment<'_>,
+    core_context: &CoreContext,
+) -> Result<String, cached::RedisCacheError> {
+    let mentions = comment
+        .mentioned_users(core_context)
+        .await
+        .iter()
+        .collect::<crate::utils::Mentions>();
+
+    Ok(crate::utils::parse_html_with_mentions(
+        &comment.content,
+        true,
+        &mentions,
+    ))
 }
@@ -6,7 +6,8 @@ pub struct PostView {
     pub id: Uuid,
     pub post_id: Uuid,
     pub user_id: Option<Uuid>,
-    pub ip_address: IpNetwork,
+    pub ip_address: Option<IpNetwork>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
+    pub visitor_hash: Option<String>,
 }
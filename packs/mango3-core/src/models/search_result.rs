@@ -0,0 +1,50 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use url::Url;
+use uuid::Uuid;
+
+use crate::config::BASIC_CONFIG;
+
+use super::{Hashtag, User, Website};
+
+pub(crate) const SEARCH_HEADLINE_START: &str = "{{{mark}}}";
+pub(crate) const SEARCH_HEADLINE_STOP: &str = "{{{/mark}}}";
+
+#[derive(Default)]
+pub struct SearchFilters<'a> {
+    pub hashtag: Option<&'a Hashtag<'a>>,
+    pub website: Option<&'a Website>,
+    pub author: Option<&'a User>,
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+}
+
+/// A post, website, user or comment matching a search query. The headline is the part of the text with the best
+/// matches, delimited by `SEARCH_HEADLINE_START` and `SEARCH_HEADLINE_STOP`.
+#[derive(Clone)]
+pub struct SearchResult {
+    pub id: Uuid,
+    pub title: String,
+    pub headline: String,
+    pub website_subdomain: Option<String>,
+    pub post_slug: Option<String>,
+    pub username: Option<String>,
+    pub search_rank: f32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SearchResult {
+    pub fn headline_html(&self) -> String {
+        crate::utils::escape_html(&self.headline)
+            .replace(SEARCH_HEADLINE_START, "<mark>")
+            .replace(SEARCH_HEADLINE_STOP, "</mark>")
+    }
+
+    pub fn url(&self) -> Url {
+        match (&self.website_subdomain, &self.post_slug, &self.username) {
+            (Some(subdomain), Some(slug), _) => BASIC_CONFIG.website_url(subdomain).join(slug).unwrap(),
+            (Some(subdomain), None, _) => BASIC_CONFIG.website_url(subdomain),
+            (None, _, Some(username)) => BASIC_CONFIG.user_url(username),
+            _ => BASIC_CONFIG.home_url(),
+        }
+    }
+}
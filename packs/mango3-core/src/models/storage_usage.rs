@@ -0,0 +1,31 @@
+use uuid::Uuid;
+
+use crate::CoreContext;
+
+use super::{User, Website};
+
+#[derive(Clone)]
+pub struct StorageUsage {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub website_id: Option<Uuid>,
+    pub used_bytes: i64,
+}
+
+impl StorageUsage {
+    pub async fn user(&self, core_context: &CoreContext) -> sqlx::Result<User> {
+        crate::commands::get_user_by_id(core_context, self.user_id).await
+    }
+
+    pub fn used_storage(&self) -> size::Size {
+        size::Size::from_bytes(self.used_bytes)
+    }
+
+    pub async fn website(&self, core_context: &CoreContext) -> Option<sqlx::Result<Website>> {
+        if let Some(id) = self.website_id {
+            Some(crate::commands::get_website_by_id(core_context, id, None).await)
+        } else {
+            None
+        }
+    }
+}
@@ -28,6 +28,7 @@ pub struct User {
     pub hashtag_ids: Vec<Uuid>,
     pub avatar_image_blob_id: Option<Uuid>,
     pub role: UserRole,
+    pub storage_quota: Option<i64>,
     pub disabled_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
@@ -39,7 +40,24 @@ impl Display for User {
     }
 }
 
+impl<'a> FromIterator<&'a User> for crate::utils::Mentions {
+    fn from_iter<I: IntoIterator<Item = &'a User>>(users: I) -> Self {
+        let mut mentions = Self::default();
+
+        for user in users {
+            mentions.insert(&user.username, user.url().to_string());
+        }
+
+        mentions
+    }
+}
+
 impl User {
+    #[cfg(feature = "user-storage")]
+    pub async fn available_storage(&self) -> size::Size {
+        self.max_storage() - self.used_storage().await
+    }
+
     pub async fn avatar_image_blob(&self) -> Option<sqlx::Result<Blob<'_>>> {
         if let Some(id) = self.avatar_image_blob_id {
             Some(crate::commands::get_blob_by_id(id, None, None).await)
@@ -95,6 +113,13 @@ impl User {
         self.disabled_at.is_some()
     }
 
+    #[cfg(feature = "user-storage")]
+    pub fn max_storage(&self) -> size::Size {
+        self.storage_quota
+            .map(size::Size::from_bytes)
+            .unwrap_or_else(|| crate::config::USER_CONFIG.max_storage_by_role(&self.role))
+    }
+
     pub fn text_avatar_url(&self) -> Url {
         BASIC_CONFIG.text_icon_url(&self.initials())
     }
@@ -102,6 +127,13 @@ impl User {
     pub fn url(&self) -> Url {
         BASIC_CONFIG.user_url(&self.username)
     }
+
+    #[cfg(feature = "user-storage")]
+    pub async fn used_storage(&self) -> size::Size {
+        crate::commands::get_used_user_storage(self)
+            .await
+            .expect("Could not get used storage")
+    }
 }
 
 #[cfg(feature = "user-bio-html")]
@@ -6,7 +6,7 @@ use sqlx::types::chrono::{DateTime, Utc};
 use sqlx::types::Uuid;
 use url::Url;
 
-use crate::config::BASIC_CONFIG;
+use crate::config::{BASIC_CONFIG, MISC_CONFIG};
 use crate::CoreContext;
 
 use super::{Blob, Hashtag, User};
@@ -23,8 +23,11 @@ pub struct Website {
     pub cover_image_blob_id: Option<Uuid>,
     pub light_theme: String,
     pub dark_theme: String,
+    pub storage_quota: Option<i64>,
     pub language: String,
     pub published_at: Option<DateTime<Utc>>,
+    pub suspended_at: Option<DateTime<Utc>>,
+    pub moderation_reason: Option<String>,
     pub search_rank: Option<f32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
@@ -70,6 +73,14 @@ impl Website {
         crate::commands::all_hashtags_by_ids(&self.hashtag_ids).await
     }
 
+    pub fn export_lock_path(&self) -> std::path::PathBuf {
+        MISC_CONFIG.storage_exports_path().join(format!("{}.lock", self.id))
+    }
+
+    pub fn export_path(&self) -> std::path::PathBuf {
+        MISC_CONFIG.storage_exports_path().join(format!("{}.zip", self.id))
+    }
+
     pub fn host(&self) -> String {
         self.url().host().unwrap().to_string()
     }
@@ -91,7 +102,19 @@ impl Website {
     }
 
     pub fn is_published(&self) -> bool {
-        self.published_at.is_some()
+        self.published_at.is_some() && !self.is_suspended()
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_at.is_some()
+    }
+
+    pub fn language_code(&self) -> &'static str {
+        crate::utils::text_search_language_code(&self.language)
+    }
+
+    pub fn static_path(&self) -> std::path::PathBuf {
+        MISC_CONFIG.storage_static_websites_path().join(self.id.to_string())
     }
 
     pub fn text_icon_url(&self) -> Url {
@@ -104,12 +127,18 @@ impl Website {
 
     #[cfg(feature = "website-storage")]
     pub async fn available_storage(&self) -> size::Size {
-        self.max_storage() - self.used_storage().await
+        self.max_storage().await - self.used_storage().await
     }
 
     #[cfg(feature = "website-storage")]
-    pub fn max_storage(&self) -> size::Size {
-        crate::config::WEBSITE_CONFIG.max_storage
+    pub async fn max_storage(&self) -> size::Size {
+        if let Some(storage_quota) = self.storage_quota {
+            return size::Size::from_bytes(storage_quota);
+        }
+
+        crate::commands::get_website_max_storage(self)
+            .await
+            .expect("Could not get max storage")
     }
 
     #[cfg(feature = "website-storage")]
@@ -0,0 +1,25 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct WebsiteDailyStat {
+    pub day: NaiveDate,
+    pub views_count: i64,
+    pub visitors_count: i64,
+    pub reactions_count: i64,
+    pub comments_count: i64,
+}
+
+#[derive(Clone)]
+pub struct WebsiteTopPost {
+    pub post_id: Uuid,
+    pub title: String,
+    pub views_count: i64,
+}
+
+/// Where the views of a website come from, like a referrer host or a country.
+#[derive(Clone)]
+pub struct WebsiteViewsSource {
+    pub name: String,
+    pub views_count: i64,
+}
@@ -14,7 +14,8 @@ use rand::rng;
 use url::Url;
 use uuid::Uuid;
 
-use crate::models::{NavigationItem, User, Website};
+use crate::models::{InvitationCode, NavigationItem, User, Website};
+use crate::utils::MutResult;
 use crate::CoreContext;
 
 mod test_blob;
@@ -82,7 +83,7 @@ fn fake_country_alpha2() -> String {
     CountryCode().fake()
 }
 
-fn fake_email() -> String {
+pub fn fake_email() -> String {
     unique_fake("email", || FreeEmail().fake_with_rng(&mut rng()))
 }
 
@@ -154,8 +155,18 @@ pub async fn insert_test_navigation_item<'a>(
 }
 
 pub async fn insert_test_user(core_context: &CoreContext) -> User {
+    insert_test_user_with_invitation_code(core_context, &fake_email(), None)
+        .await
+        .expect("Could not insert user")
+        .data
+}
+
+pub async fn insert_test_user_with_invitation_code(
+    core_context: &CoreContext,
+    email: &str,
+    invitation_code: Option<&InvitationCode>,
+) -> MutResult<User> {
     let username = fake_username();
-    let email = fake_email();
     let password = fake_password();
     let full_name = fake_name();
     let birthdate = fake_birthdate();
@@ -164,16 +175,15 @@ pub async fn insert_test_user(core_context: &CoreContext) -> User {
     crate::commands::insert_user(
         core_context,
         &username,
-        &email,
+        email,
         &password,
         &full_name,
         &birthdate,
         "en",
         &country_alpha2,
+        invitation_code,
     )
     .await
-    .expect("Could not insert user")
-    .data
 }
 
 pub async fn insert_test_website(core_context: &CoreContext, user: Option<&User>) -> Website {
@@ -186,7 +196,7 @@ pub async fn insert_test_website(core_context: &CoreContext, user: Option<&User>
     let subdomain = fake_slug();
     let description = fake_sentence();
 
-    crate::commands::insert_website(core_context, &user, &name, &subdomain, &description)
+    crate::commands::insert_website(core_context, &user, &name, &subdomain, &description, "english")
         .await
         .ok()
         .expect("Could not insert website")
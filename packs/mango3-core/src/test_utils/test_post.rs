@@ -23,13 +23,18 @@ pub async fn insert_test_post(core_context: &CoreContext, website: Option<&Websi
         core_context,
         &website,
         &user,
-        &title,
-        &slug,
-        &content,
-        "{}",
-        vec![blob.clone()],
-        Some(&blob),
-        true,
+        crate::commands::PostInput {
+            title: &title,
+            slug: &slug,
+            content: &content,
+            variables: "{}",
+            language: &website.language,
+            blobs: vec![blob.clone()],
+            cover_image_blob: Some(&blob),
+            publish: true,
+        },
+        None,
+        None,
     )
     .await
     .ok()
@@ -20,7 +20,7 @@ pub async fn insert_test_post_comment<'a>(
     };
     let content = fake_paragraph();
 
-    crate::commands::insert_post_comment(&post, &user, &content)
+    crate::commands::insert_post_comment(core_context, &post, &user, &content)
         .await
         .ok()
         .expect("Could not insert post comment")
@@ -10,18 +10,23 @@ use crate::config::CACHE_CONFIG;
 #[macro_export]
 macro_rules! async_redis_cache {
     ($prefix:expr) => {
-        $crate::utils::async_redis_cache($prefix)
+        $crate::utils::async_redis_cache($prefix, $crate::config::CACHE_CONFIG.ttl, true)
+    };
+    ($prefix:expr, $ttl:expr, $refresh:expr) => {
+        $crate::utils::async_redis_cache($prefix, $ttl, $refresh)
     };
 }
 
-pub(crate) async fn async_redis_cache<K, V>(prefix: &str) -> AsyncRedisCache<K, V>
+/// With `refresh`, reading an entry resets its TTL, so only values that are cleared on change should use it. Values
+/// that go stale on their own, like stats, expire after the TTL no matter how often they are read.
+pub(crate) async fn async_redis_cache<K, V>(prefix: &str, ttl: u64, refresh: bool) -> AsyncRedisCache<K, V>
 where
     K: Display + Send + Sync,
     V: DeserializeOwned + Display + Send + Serialize + Sync,
 {
-    AsyncRedisCache::new(format!("{prefix}:"), CACHE_CONFIG.ttl)
+    AsyncRedisCache::new(format!("{prefix}:"), ttl)
         .set_connection_string(&CACHE_CONFIG.redis_url)
-        .set_refresh(true)
+        .set_refresh(refresh)
         .build()
         .await
         .expect("Could not get redis cache")
@@ -39,7 +44,7 @@ where
 {
     async fn cache_remove(&self, prefix: &str, key: &K) {
         let _ = self
-            .get_or_init(|| async { async_redis_cache(prefix).await })
+            .get_or_init(|| async { async_redis_cache(prefix, CACHE_CONFIG.ttl, true).await })
             .await
             .cache_remove(key)
             .await;
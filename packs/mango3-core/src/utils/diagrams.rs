@@ -0,0 +1,120 @@
+use std::sync::LazyLock;
+
+use cached::async_sync::OnceCell;
+use cached::{AsyncRedisCache, IOCachedAsync};
+use md5::{Digest, Md5};
+use regex::Regex;
+use serde_json::json;
+
+use crate::config::DIAGRAMS_CONFIG;
+use crate::constants::PREFIX_DIAGRAM_SVG;
+
+use super::markdown::{find_diagrams, is_safe_svg};
+use super::{HttpClient, HttpRules, RenderedDiagrams};
+
+static DIAGRAM_SVG: OnceCell<AsyncRedisCache<String, String>> = OnceCell::const_new();
+static REGEX_SVG_COMMENTS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<!--.*?-->").unwrap());
+static REGEX_SVG_PROLOG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)\A\s*(?:<\?xml.*?\?>\s*)?(?:<!DOCTYPE[^>]*>\s*)?").unwrap());
+
+/// Renders the diagrams in fenced `mermaid`, `graphviz` and `dot` blocks with the configured renderer, caching their
+/// sanitized SVG by source like the content HTML. This is called when a post is saved, with the content its template
+/// renders. Also returns whether every diagram could be rendered.
+pub async fn render_diagrams(input: &str) -> (RenderedDiagrams, bool) {
+    find_rendered_diagrams(input, true).await
+}
+
+/// Returns the diagrams already rendered by `render_diagrams`, so showing the content never makes requests to the
+/// renderer, and whether none is missing from the cache. Missing diagrams are shown as code blocks until they are
+/// rendered again.
+pub(crate) async fn cached_diagrams(input: &str) -> (RenderedDiagrams, bool) {
+    find_rendered_diagrams(input, false).await
+}
+
+async fn find_rendered_diagrams(input: &str, render: bool) -> (RenderedDiagrams, bool) {
+    let mut diagrams = RenderedDiagrams::default();
+    let mut is_complete = true;
+
+    if DIAGRAMS_CONFIG.renderer_url.is_empty() {
+        return (diagrams, is_complete);
+    }
+
+    let cache = DIAGRAM_SVG
+        .get_or_init(|| async { crate::async_redis_cache!(PREFIX_DIAGRAM_SVG).await })
+        .await;
+
+    for (language, source) in find_diagrams(input) {
+        let key = format!("{:x}", Md5::digest(format!("{language}:{source}")));
+        let svg = match cache.cache_get(&key).await {
+            Ok(Some(svg)) => Some(svg),
+            _ if render => {
+                let svg = render_diagram(&language, &source).await;
+
+                if let Some(svg) = &svg {
+                    let _ = cache.cache_set(key, svg.clone()).await;
+                }
+
+                svg
+            }
+            _ => None,
+        };
+
+        match svg {
+            Some(svg) => diagrams.insert(&language, &source, svg),
+            None => is_complete = false,
+        }
+    }
+
+    (diagrams, is_complete)
+}
+
+async fn render_diagram(language: &str, source: &str) -> Option<String> {
+    let diagram_type = if language == "mermaid" { "mermaid" } else { "graphviz" };
+    let data = json!({ "diagram_source": source, "diagram_type": diagram_type, "output_format": "svg" });
+
+    // The renderer is set by the administrators and usually runs next to the app, so private addresses are allowed.
+    let response = tokio::task::spawn_blocking(move || {
+        HttpClient::new(
+            HttpRules {
+                allow_private_ips: true,
+                ..Default::default()
+            },
+            DIAGRAMS_CONFIG.timeout(),
+            DIAGRAMS_CONFIG.max_svg_size,
+        )
+        .post(&DIAGRAMS_CONFIG.renderer_url, &[], &data)
+    })
+    .await
+    .ok()?
+    .ok()?;
+
+    let text = response.text();
+    let svg = REGEX_SVG_COMMENTS
+        .replace_all(&REGEX_SVG_PROLOG.replace(&text, ""), "")
+        .trim()
+        .to_owned();
+
+    // The SVG is inlined in the content, so it is rejected when it has anything that could run scripts or load external
+    // resources.
+    (svg.starts_with("<svg") && is_safe_svg(&svg)).then_some(svg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_diagrams() {
+        let diagrams = find_diagrams(
+            "```mermaid\ngraph TD;\nA-->B;\n```\n\n```rust\nfn main() {}\n```\n\n```dot\ndigraph {}\n```",
+        );
+
+        assert_eq!(
+            diagrams,
+            vec![
+                ("mermaid".to_owned(), "graph TD;\nA-->B;\n".to_owned()),
+                ("dot".to_owned(), "digraph {}\n".to_owned()),
+            ]
+        );
+    }
+}
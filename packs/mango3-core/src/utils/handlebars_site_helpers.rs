@@ -0,0 +1,339 @@
+use std::future::Future;
+use std::sync::LazyLock;
+
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, Output, PathAndJson, RenderContext, Renderable, ScopedJson,
+};
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::models::{Post, Website};
+use crate::utils::CursorPageParams;
+use crate::CoreContext;
+
+use super::handlebars_utils::create_block;
+
+const DEFAULT_POSTS_LIMIT: u64 = 5;
+const MAX_POSTS_LIMIT: u64 = 50;
+
+static REGEX_SITE_HELPERS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\{\{~?[#>]?\s*(recent_posts|posts_by_hashtag|navigation|reactions_count)\b").unwrap()
+});
+
+/// The website a template is rendered for, along with the post when it is not a preview. Built-in helpers only read
+/// data from this website.
+#[derive(Clone)]
+pub struct TemplateScope {
+    pub core_context: CoreContext,
+    pub website: Website,
+    pub post: Option<Post>,
+}
+
+pub(crate) fn has_site_helpers(input: &str) -> bool {
+    REGEX_SITE_HELPERS.is_match(input)
+}
+
+pub(crate) fn register_site_helpers<'reg>(registry: &mut Handlebars<'reg>, scope: &'reg TemplateScope) {
+    for (name, kind) in [
+        ("navigation", SiteHelperKind::Navigation),
+        ("posts_by_hashtag", SiteHelperKind::PostsByHashtag),
+        ("reactions_count", SiteHelperKind::ReactionsCount),
+        ("recent_posts", SiteHelperKind::RecentPosts),
+    ] {
+        registry.register_helper(name, Box::new(SiteHelper { scope, kind }));
+    }
+}
+
+/// Runs a command from the blocking thread the template is rendered on.
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Handle::current().block_on(future)
+}
+
+fn param_limit(helper: &Helper<'_>) -> u8 {
+    helper
+        .hash_get("limit")
+        .and_then(|limit| limit.value().as_u64())
+        .unwrap_or(DEFAULT_POSTS_LIMIT)
+        .clamp(1, MAX_POSTS_LIMIT) as u8
+}
+
+fn post_value(post: &Post, website: &Website) -> Value {
+    json!({
+        "id": post.id,
+        "title": post.title,
+        "slug": post.slug,
+        "url": website.url().join(&post.slug).map(|url| url.to_string()).unwrap_or_default(),
+        "published_at": post.published_at,
+    })
+}
+
+fn escape_markdown_link_text(text: &str) -> String {
+    text.chars().fold(String::with_capacity(text.len()), |mut escaped, c| {
+        if "\\`*_[]()<>!#|~".contains(c) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+        escaped
+    })
+}
+
+/// Wraps the URL in angle brackets, so spaces and parentheses don't end the link destination.
+fn escape_markdown_link_url(url: &str) -> String {
+    format!(
+        "<{}>",
+        url.replace('\\', "\\\\")
+            .replace('<', "\\<")
+            .replace('>', "\\>")
+            .replace('\n', "%0A")
+    )
+}
+
+#[derive(Clone, Copy)]
+enum SiteHelperKind {
+    Navigation,
+    PostsByHashtag,
+    ReactionsCount,
+    RecentPosts,
+}
+
+struct SiteHelper<'a> {
+    scope: &'a TemplateScope,
+    kind: SiteHelperKind,
+}
+
+impl SiteHelper<'_> {
+    fn posts(&self, helper: &Helper<'_>) -> Vec<Value> {
+        let core_context = &self.scope.core_context;
+        let website = &self.scope.website;
+        let page_params = CursorPageParams {
+            after: None,
+            first: param_limit(helper),
+        };
+
+        let posts = match self.kind {
+            SiteHelperKind::PostsByHashtag => {
+                let Some(name) = helper.param(0).and_then(|param| param.value().as_str()) else {
+                    return vec![];
+                };
+
+                block_on(async {
+                    let Ok(hashtag) = crate::commands::get_hashtag_by_name(name.trim_start_matches('#')).await else {
+                        return vec![];
+                    };
+
+                    crate::commands::paginate_posts(
+                        core_context,
+                        &page_params,
+                        Some(website),
+                        None,
+                        Some(&hashtag),
+                        Some(true),
+                    )
+                    .await
+                    .nodes
+                })
+            }
+            _ => {
+                block_on(crate::commands::paginate_posts(
+                    core_context,
+                    &page_params,
+                    Some(website),
+                    None,
+                    None,
+                    Some(true),
+                ))
+                .nodes
+            }
+        };
+
+        posts.iter().map(|post| post_value(post, website)).collect()
+    }
+
+    fn navigation_items(&self) -> Vec<Value> {
+        block_on(crate::commands::all_navigation_items_by_website(&self.scope.website))
+            .iter()
+            .map(|item| json!({ "title": item.title, "url": item.url }))
+            .collect()
+    }
+
+    fn reactions_count(&self, helper: &Helper<'_>) -> i64 {
+        let scope = self.scope;
+
+        block_on(async {
+            if let Some(slug) = helper.param(0).and_then(|param| param.value().as_str()) {
+                match crate::commands::get_post_by_slug(&scope.core_context, slug, &scope.website, None).await {
+                    Ok(post) => crate::commands::get_post_reactions_count(&post).await,
+                    Err(_) => 0,
+                }
+            } else if let Some(post) = &scope.post {
+                crate::commands::get_post_reactions_count(post).await
+            } else {
+                0
+            }
+        })
+    }
+}
+
+impl HelperDef for SiteHelper<'_> {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        helper: &Helper<'rc>,
+        registry: &'reg Handlebars<'reg>,
+        context: &'rc Context,
+        render_context: &mut RenderContext<'reg, 'rc>,
+        output: &mut dyn Output,
+    ) -> HelperResult {
+        let items = match self.kind {
+            SiteHelperKind::ReactionsCount => {
+                output.write(&self.reactions_count(helper).to_string())?;
+
+                return Ok(());
+            }
+            SiteHelperKind::Navigation => self.navigation_items(),
+            SiteHelperKind::PostsByHashtag | SiteHelperKind::RecentPosts => self.posts(helper),
+        };
+
+        let Some(template) = helper.template() else {
+            // Without a block, the items are written as a Markdown list of links.
+            for item in items {
+                output.write(&format!(
+                    "- [{}]({})\n",
+                    escape_markdown_link_text(item["title"].as_str().unwrap_or_default()),
+                    escape_markdown_link_url(item["url"].as_str().unwrap_or_default())
+                ))?;
+            }
+
+            return Ok(());
+        };
+
+        if items.is_empty() {
+            if let Some(inverse) = helper.inverse() {
+                inverse.render(registry, context, render_context, output)?;
+            }
+
+            return Ok(());
+        }
+
+        for item in items {
+            render_context.push_block(create_block(&PathAndJson::new(None, ScopedJson::from(item))));
+
+            template.render(registry, context, render_context, output)?;
+
+            render_context.pop_block();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{insert_test_navigation_item, insert_test_post, insert_test_website, setup_core_context};
+    use crate::utils::render_handlebars;
+
+    use super::*;
+
+    async fn render(input: &str, scope: TemplateScope) -> String {
+        render_handlebars(input, &Value::Null, Some(scope))
+            .await
+            .unwrap()
+            .content
+    }
+
+    #[tokio::test]
+    async fn should_render_recent_posts_of_the_website() {
+        let core_context = setup_core_context().await;
+        let website = insert_test_website(&core_context, None).await;
+        let post = insert_test_post(&core_context, Some(&website), None).await;
+        let other_post = insert_test_post(&core_context, None, None).await;
+        let scope = TemplateScope {
+            core_context: core_context.clone(),
+            website,
+            post: None,
+        };
+
+        let content = render("{{#recent_posts limit=5}}{{title}};{{/recent_posts}}", scope).await;
+
+        assert!(content.contains(&format!("{};", post.title)));
+        assert!(!content.contains(&other_post.title));
+    }
+
+    #[tokio::test]
+    async fn should_render_navigation_as_markdown_list() {
+        let core_context = setup_core_context().await;
+        let website = insert_test_website(&core_context, None).await;
+        let navigation_item = insert_test_navigation_item(&core_context, Some(&website)).await;
+        let scope = TemplateScope {
+            core_context: core_context.clone(),
+            website,
+            post: None,
+        };
+
+        let content = render("{{navigation}}", scope).await;
+
+        assert_eq!(
+            content,
+            format!(
+                "- [{}]({})\n",
+                escape_markdown_link_text(&navigation_item.title),
+                escape_markdown_link_url(&navigation_item.url)
+            )
+        );
+    }
+
+    #[test]
+    fn should_escape_markdown_link() {
+        assert_eq!(escape_markdown_link_text("[Draft] *News*"), "\\[Draft\\] \\*News\\*");
+        assert_eq!(
+            escape_markdown_link_url("https://example.com/a (b)>c"),
+            "<https://example.com/a (b)\\>c>"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_render_inverse_when_hashtag_has_no_posts() {
+        let core_context = setup_core_context().await;
+        let website = insert_test_website(&core_context, None).await;
+        let scope = TemplateScope {
+            core_context: core_context.clone(),
+            website,
+            post: None,
+        };
+
+        let content = render(
+            r##"{{#posts_by_hashtag "#unknownhashtag"}}{{title}}{{else}}No posts{{/posts_by_hashtag}}"##,
+            scope,
+        )
+        .await;
+
+        assert_eq!(content, "No posts");
+    }
+
+    #[tokio::test]
+    async fn should_render_reactions_count_of_the_current_post() {
+        let core_context = setup_core_context().await;
+        let website = insert_test_website(&core_context, None).await;
+        let post = insert_test_post(&core_context, Some(&website), None).await;
+        let scope = TemplateScope {
+            core_context: core_context.clone(),
+            website,
+            post: Some(post.clone()),
+        };
+
+        let content = render(
+            &format!(r#"{{{{reactions_count}}}} {{{{reactions_count "{}"}}}}"#, post.slug),
+            scope,
+        )
+        .await;
+
+        assert_eq!(content, "0 0");
+    }
+
+    #[test]
+    fn should_detect_site_helpers() {
+        assert!(has_site_helpers("{{#recent_posts limit=5}}{{title}}{{/recent_posts}}"));
+        assert!(has_site_helpers("{{ navigation }}"));
+        assert!(!has_site_helpers("{{recent_posts_title}} navigation"));
+    }
+}
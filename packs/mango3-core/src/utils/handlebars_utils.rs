@@ -1,22 +1,40 @@
-use attohttpc::header::{HeaderName, HeaderValue};
-use attohttpc::RequestBuilder;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{LazyLock, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use cached::{Cached, SizedCache};
 use handlebars::{
-    BlockContext, Context, Handlebars, Helper, HelperResult, Output, PathAndJson, RenderContext, RenderError,
-    RenderErrorReason, Renderable, ScopedJson,
+    BlockContext, Context, Handlebars, Helper, HelperDef, HelperResult, Output, PathAndJson, RenderContext,
+    RenderError, RenderErrorReason, Renderable, ScopedJson,
 };
 use handlebars_misc_helpers::{assign_helpers, json_helpers, string_helpers};
+use regex::Regex;
+use reqwest::header::{HeaderName, HeaderValue};
+use serde::Deserialize;
 use serde_json::Value;
+use uuid::Uuid;
 
+use crate::config::HTTP_CONFIG;
 use crate::constants::REGEX_HANDLEBARS;
 
+use super::handlebars_site_helpers::{has_site_helpers, register_site_helpers};
+use super::{HttpClient, HttpError, HttpResponse, RateLimiter, TemplateScope};
+
+pub(crate) const DATA_SOURCES_KEY: &str = "data_sources";
+
+const HTTP_CACHE_SIZE: usize = 1000;
+
+static HTTP_HELPERS: LazyLock<HttpHelpers> = LazyLock::new(HttpHelpers::from_config);
+static REGEX_SHORTCODE_OPEN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(^|[^\\])\{\{<").unwrap());
+
 #[macro_export]
 macro_rules! render_handlebars {
-    ($input:expr, $data:expr) => {
-        $crate::utils::render_handlebars($input, $data)
+    ($input:expr, $data:expr, $scope:expr) => {
+        $crate::utils::render_handlebars($input, $data, $scope)
     };
 }
 
-fn create_block<'rc>(param: &PathAndJson<'rc>) -> BlockContext<'rc> {
+pub(super) fn create_block<'rc>(param: &PathAndJson<'rc>) -> BlockContext<'rc> {
     let mut block = BlockContext::new();
 
     if let Some(new_path) = param.context_path() {
@@ -29,6 +47,20 @@ fn create_block<'rc>(param: &PathAndJson<'rc>) -> BlockContext<'rc> {
     block
 }
 
+/// Responses are cached by website and by the headers sent, which may hold credentials, so they are never shared with
+/// templates of other websites or with requests made without the same headers.
+fn cache_key(website_id: Option<Uuid>, request: &str, headers: &[(HeaderName, HeaderValue)]) -> String {
+    let mut hasher = DefaultHasher::new();
+
+    headers.hash(&mut hasher);
+
+    format!(
+        "{}:{:x}:{request}",
+        website_id.map(|id| id.to_string()).unwrap_or_default(),
+        hasher.finish()
+    )
+}
+
 fn param_url<'rc>(helper: &'rc Helper<'rc>) -> Result<&'rc str, RenderErrorReason> {
     helper
         .param(0)
@@ -36,106 +68,322 @@ fn param_url<'rc>(helper: &'rc Helper<'rc>) -> Result<&'rc str, RenderErrorReaso
         .ok_or_else(|| RenderErrorReason::ParamNotFoundForIndex("fetch", 0))
 }
 
-fn append_headers<T>(helper: &Helper<'_>, mut request_builder: RequestBuilder<T>) -> RequestBuilder<T>
-where
-    T: attohttpc::body::Body,
-{
-    if let Some(headers) = helper.hash_get("headers").and_then(|h| h.value().as_array()) {
-        for header in headers {
-            if let Some(header_str) = header.as_str() {
-                if let Some((key, value)) = header_str.split_once(':') {
-                    let Ok(key) = key.parse::<HeaderName>() else {
-                        continue;
-                    };
+fn parse_header(header_str: &str) -> Option<(HeaderName, HeaderValue)> {
+    let (key, value) = header_str.split_once(':')?;
 
-                    let Ok(value) = value.parse::<HeaderValue>() else {
-                        continue;
-                    };
-
-                    request_builder = request_builder.header_append(key, value);
-                }
-            }
-        }
-    }
+    Some((key.trim().parse().ok()?, value.trim().parse().ok()?))
+}
 
-    request_builder
+fn param_headers(helper: &Helper<'_>) -> Vec<(HeaderName, HeaderValue)> {
+    helper
+        .hash_get("headers")
+        .and_then(|h| h.value().as_array())
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(Value::as_str)
+                .filter_map(parse_header)
+                .collect()
+        })
+        .unwrap_or_default()
 }
 
-fn push_response<'reg, 'rc, T>(
+fn push_response<'reg: 'rc, 'rc>(
     helper: &Helper<'rc>,
-    registry: &'reg Handlebars,
+    registry: &'reg Handlebars<'reg>,
     context: &'rc Context,
     render_context: &mut RenderContext<'reg, 'rc>,
     output: &mut dyn Output,
-    request_builder: RequestBuilder<T>,
-) -> HelperResult
-where
-    T: attohttpc::body::Body,
-{
-    if let Ok(text) = request_builder
-        .send()
-        .map_err(|err| RenderErrorReason::Other(err.to_string()))?
-        .text()
-    {
-        if let Some(template) = helper.template() {
-            let value = serde_json::from_str::<Value>(&text).unwrap_or(Value::String(text));
-            let block_context = create_block(&PathAndJson::new(None, ScopedJson::from(value)));
+    text: String,
+) -> HelperResult {
+    if let Some(template) = helper.template() {
+        let block_context = create_block(&PathAndJson::new(None, ScopedJson::from(parse_response(text))));
 
-            render_context.push_block(block_context);
+        render_context.push_block(block_context);
 
-            template.render(registry, context, render_context, output)?;
+        template.render(registry, context, render_context, output)?;
 
-            render_context.pop_block();
-        }
+        render_context.pop_block();
     }
 
     Ok(())
 }
 
-fn helper_http_get<'reg, 'rc>(
-    helper: &Helper<'rc>,
-    registry: &'reg Handlebars,
-    context: &'rc Context,
-    render_context: &mut RenderContext<'reg, 'rc>,
-    output: &mut dyn Output,
-) -> HelperResult {
-    let url = param_url(helper)?;
+fn parse_response(text: String) -> Value {
+    serde_json::from_str::<Value>(&text).unwrap_or(Value::String(text))
+}
 
-    let mut request_builder = attohttpc::get(url);
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum DataSourceMethod {
+    #[default]
+    Get,
+    Post,
+}
 
-    request_builder = append_headers(helper, request_builder);
+/// Remote data declared by a post in the `data_sources` object of its variables, for example:
+///
+/// ```json
+/// { "data_sources": { "weather": { "url": "https://api.example.com/weather", "ttl": 600 } } }
+/// ```
+///
+/// The response is available to the template under the same name as the source, `{{weather.temperature}}`.
+#[derive(Deserialize)]
+struct DataSource {
+    url: String,
+    #[serde(default)]
+    method: DataSourceMethod,
+    #[serde(default)]
+    data: Value,
+    #[serde(default)]
+    headers: Vec<String>,
+    ttl: Option<u64>,
+}
 
-    push_response(helper, registry, context, render_context, output, request_builder)
+fn has_data_sources(data: &Value) -> bool {
+    data.get(DATA_SOURCES_KEY)
+        .and_then(Value::as_object)
+        .is_some_and(|sources| !sources.is_empty())
 }
 
-fn helper_http_post<'reg, 'rc>(
-    helper: &Helper<'rc>,
-    registry: &'reg Handlebars,
-    context: &'rc Context,
-    render_context: &mut RenderContext<'reg, 'rc>,
-    output: &mut dyn Output,
-) -> HelperResult {
-    let url = param_url(helper)?;
+/// Returns `true` when the rendered template depends on data that changes independently of the post, such as data
+/// sources or the website's own posts, so its output should not be cached along with the post.
+pub fn is_dynamic_template(input: &str, data: &Value) -> bool {
+    REGEX_HANDLEBARS.is_match(input) && (has_data_sources(data) || has_site_helpers(input))
+}
 
-    let data = helper
-        .hash_get("data")
-        .map(|b| b.value())
-        .unwrap_or_else(|| &Value::Null);
+/// Returns how long the output of a dynamic template can be cached, which is the shortest TTL of its data sources.
+/// Templates using website helpers, or data sources without cache, are not cached at all.
+pub fn dynamic_template_ttl(input: &str, data: &Value) -> Option<Duration> {
+    if has_site_helpers(input) {
+        return None;
+    }
 
-    let mut request_builder = attohttpc::post(url)
-        .json(data)
-        .map_err(|err| RenderErrorReason::Other(err.to_string()))?;
+    data.get(DATA_SOURCES_KEY)
+        .and_then(Value::as_object)?
+        .values()
+        .map(|source| {
+            source
+                .get("ttl")
+                .and_then(Value::as_u64)
+                .unwrap_or(HTTP_CONFIG.cache_ttl)
+        })
+        .min()
+        .filter(|ttl| *ttl > 0)
+        .map(Duration::from_secs)
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RenderedTemplate {
+    pub content: String,
+    /// Data sources that could not be fetched, which are left empty so the rest of the template still renders.
+    pub errors: Vec<String>,
+}
+
+/// Shared state of the HTTP helpers and data sources: the guarded client, the per-website rate limiter and the cache
+/// of responses.
+struct HttpHelpers {
+    client: HttpClient,
+    rate_limiter: RateLimiter,
+    cache: Mutex<SizedCache<String, (Instant, String)>>,
+    cache_ttl: Duration,
+}
+
+impl HttpHelpers {
+    fn new(client: HttpClient, rate_limiter: RateLimiter, cache_ttl: Duration) -> Self {
+        Self {
+            client,
+            rate_limiter,
+            cache: Mutex::new(SizedCache::with_size(HTTP_CACHE_SIZE)),
+            cache_ttl,
+        }
+    }
+
+    fn from_config() -> Self {
+        Self::new(
+            HttpClient::from_config(),
+            RateLimiter::new(HTTP_CONFIG.rate_limit, Duration::from_secs(60)),
+            Duration::from_secs(HTTP_CONFIG.cache_ttl),
+        )
+    }
+
+    fn cached<F>(&self, key: String, ttl: Duration, website_id: Option<Uuid>, request: F) -> Result<String, HttpError>
+    where
+        F: FnOnce() -> Result<HttpResponse, HttpError>,
+    {
+        if let Some((fetched_at, text)) = self.lock_cache().cache_get(&key) {
+            if fetched_at.elapsed() < ttl {
+                return Ok(text.clone());
+            }
+        }
+
+        if let Some(website_id) = website_id {
+            self.rate_limiter.check(website_id)?;
+        }
+
+        let text = request()?.text();
+
+        if !ttl.is_zero() {
+            self.lock_cache().cache_set(key, (Instant::now(), text.clone()));
+        }
+
+        Ok(text)
+    }
+
+    fn get(
+        &self,
+        url: &str,
+        headers: &[(HeaderName, HeaderValue)],
+        website_id: Option<Uuid>,
+    ) -> Result<String, HttpError> {
+        self.cached(cache_key(website_id, url, headers), self.cache_ttl, website_id, || {
+            self.client.get(url, headers)
+        })
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(HeaderName, HeaderValue)],
+        data: &Value,
+        website_id: Option<Uuid>,
+    ) -> Result<String, HttpError> {
+        if let Some(website_id) = website_id {
+            self.rate_limiter.check(website_id)?;
+        }
 
-    request_builder = append_headers(helper, request_builder);
+        Ok(self.client.post(url, headers, data)?.text())
+    }
+
+    fn fetch_data_source(&self, source: &DataSource, website_id: Option<Uuid>) -> Result<String, HttpError> {
+        let headers = source
+            .headers
+            .iter()
+            .filter_map(|header| parse_header(header))
+            .collect::<Vec<_>>();
+        let ttl = source.ttl.map(Duration::from_secs).unwrap_or(self.cache_ttl);
+
+        match source.method {
+            DataSourceMethod::Get => self.cached(cache_key(website_id, &source.url, &headers), ttl, website_id, || {
+                self.client.get(&source.url, &headers)
+            }),
+            DataSourceMethod::Post => {
+                let request = format!("POST {} {}", source.url, source.data);
+
+                self.cached(cache_key(website_id, &request, &headers), ttl, website_id, || {
+                    self.client.post(&source.url, &headers, &source.data)
+                })
+            }
+        }
+    }
 
-    push_response(helper, registry, context, render_context, output, request_builder)
+    /// Returns the variables with the response of each data source added under its name.
+    fn resolve_data_sources(&self, data: &Value, website_id: Option<Uuid>, errors: &mut Vec<String>) -> Value {
+        let mut data = data.clone();
+        let Some(sources) = data.get(DATA_SOURCES_KEY).and_then(Value::as_object).cloned() else {
+            return data;
+        };
+        let Some(variables) = data.as_object_mut() else {
+            return data;
+        };
+
+        for (name, source) in sources {
+            let result = serde_json::from_value::<DataSource>(source)
+                .map_err(|error| error.to_string())
+                .and_then(|source| {
+                    self.fetch_data_source(&source, website_id)
+                        .map_err(|error| error.to_string())
+                });
+
+            let value = match result {
+                Ok(text) => parse_response(text),
+                Err(error) => {
+                    errors.push(format!("{name}: {error}"));
+                    Value::Null
+                }
+            };
+
+            variables.insert(name, value);
+        }
+
+        data
+    }
+
+    fn lock_cache(&self) -> MutexGuard<'_, SizedCache<String, (Instant, String)>> {
+        self.cache.lock().unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+struct HttpHelper<'a> {
+    http_helpers: &'a HttpHelpers,
+    is_post: bool,
+    website_id: Option<Uuid>,
 }
 
-pub fn render_handlebars(input: &str, data: &Value) -> Result<String, RenderError> {
+impl HelperDef for HttpHelper<'_> {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        helper: &Helper<'rc>,
+        registry: &'reg Handlebars<'reg>,
+        context: &'rc Context,
+        render_context: &mut RenderContext<'reg, 'rc>,
+        output: &mut dyn Output,
+    ) -> HelperResult {
+        let url = param_url(helper)?;
+        let headers = param_headers(helper);
+
+        let text = if self.is_post {
+            let data = helper
+                .hash_get("data")
+                .map(|b| b.value())
+                .unwrap_or_else(|| &Value::Null);
+
+            self.http_helpers.post(url, &headers, data, self.website_id)
+        } else {
+            self.http_helpers.get(url, &headers, self.website_id)
+        }
+        .map_err(|err| RenderErrorReason::Other(err.to_string()))?;
+
+        push_response(helper, registry, context, render_context, output, text)
+    }
+}
+
+/// Renders the template on the blocking thread pool, since data sources and HTTP helpers wait for remote servers.
+///
+/// Helpers for the website's own data, like `{{#recent_posts limit=5}}`, are only available with a scope.
+pub async fn render_handlebars(
+    input: &str,
+    data: &Value,
+    scope: Option<TemplateScope>,
+) -> Result<RenderedTemplate, RenderError> {
     if !REGEX_HANDLEBARS.is_match(input) {
-        return Ok(input.to_owned());
+        return Ok(RenderedTemplate {
+            content: input.to_owned(),
+            errors: vec![],
+        });
     }
 
+    let input = input.to_owned();
+    let data = data.clone();
+
+    tokio::task::spawn_blocking(move || {
+        let website_id = scope.as_ref().map(|scope| scope.website.id);
+
+        render_handlebars_with(&HTTP_HELPERS, &input, &data, website_id, scope.as_ref())
+    })
+    .await
+    .map_err(|error| RenderErrorReason::Other(error.to_string()))?
+}
+
+fn render_handlebars_with(
+    http_helpers: &HttpHelpers,
+    input: &str,
+    data: &Value,
+    website_id: Option<Uuid>,
+    scope: Option<&TemplateScope>,
+) -> Result<RenderedTemplate, RenderError> {
+    let mut errors = Vec::new();
+    let data = http_helpers.resolve_data_sources(data, website_id, &mut errors);
     let mut registry = Handlebars::new();
 
     registry.set_prevent_indent(true);
@@ -144,8 +392,473 @@ pub fn render_handlebars(input: &str, data: &Value) -> Result<String, RenderErro
     json_helpers::register(&mut registry);
     string_helpers::register(&mut registry);
 
-    registry.register_helper("http_get", Box::new(helper_http_get));
-    registry.register_helper("http_post", Box::new(helper_http_post));
+    registry.register_helper(
+        "http_get",
+        Box::new(HttpHelper {
+            http_helpers,
+            is_post: false,
+            website_id,
+        }),
+    );
+    registry.register_helper(
+        "http_post",
+        Box::new(HttpHelper {
+            http_helpers,
+            is_post: true,
+            website_id,
+        }),
+    );
+
+    if let Some(scope) = scope {
+        register_site_helpers(&mut registry, scope);
+    }
+
+    // Shortcodes are expanded later by the Markdown parser, so they are escaped to be kept as they are.
+    let input = REGEX_SHORTCODE_OPEN.replace_all(input, "${1}\\{{<");
+    let content = registry.render_template(&input, &data)?;
 
-    registry.render_template(input, data)
+    Ok(RenderedTemplate { content, errors })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use crate::utils::HttpRules;
+
+    use super::*;
+
+    struct MockServer {
+        url: String,
+        hits: Arc<AtomicUsize>,
+    }
+
+    impl MockServer {
+        fn start() -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let port = listener.local_addr().unwrap().port();
+            let hits = Arc::new(AtomicUsize::new(0));
+            let server_hits = hits.clone();
+
+            std::thread::spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    server_hits.fetch_add(1, Ordering::SeqCst);
+
+                    respond(stream, port);
+                }
+            });
+
+            Self {
+                url: format!("http://127.0.0.1:{port}"),
+                hits,
+            }
+        }
+
+        fn hits(&self) -> usize {
+            self.hits.load(Ordering::SeqCst)
+        }
+    }
+
+    fn respond(mut stream: TcpStream, port: u16) {
+        let mut request = Vec::new();
+        let mut buffer = [0; 1024];
+
+        while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+            match stream.read(&mut buffer) {
+                Ok(0) | Err(_) => return,
+                Ok(size) => request.extend_from_slice(&buffer[..size]),
+            }
+        }
+
+        let request = String::from_utf8_lossy(&request).into_owned();
+        let (head, body) = request.split_once("\r\n\r\n").unwrap_or_default();
+        let content_length = head
+            .lines()
+            .find_map(|line| line.to_lowercase().strip_prefix("content-length:")?.trim().parse().ok())
+            .unwrap_or(0_usize);
+        let mut remaining = content_length.saturating_sub(body.len());
+
+        while remaining > 0 {
+            match stream.read(&mut buffer) {
+                Ok(0) | Err(_) => return,
+                Ok(size) => remaining = remaining.saturating_sub(size),
+            }
+        }
+
+        let path = request.split_whitespace().nth(1).unwrap_or("/");
+        let (status, headers, body) = match path {
+            "/large" => ("200 OK", String::new(), "a".repeat(2048)),
+            "/redirect" => (
+                "302 Found",
+                format!("Location: http://localhost:{port}/json\r\n"),
+                String::new(),
+            ),
+            _ => (
+                "200 OK",
+                "Content-Type: application/json\r\n".to_owned(),
+                r#"{"name":"Mango"}"#.to_owned(),
+            ),
+        };
+
+        let _ = write!(
+            stream,
+            "HTTP/1.1 {status}\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+    }
+
+    fn http_helpers(rules: HttpRules, rate_limit: u32) -> HttpHelpers {
+        HttpHelpers::new(
+            HttpClient::new(rules, Duration::from_secs(5), 1024),
+            RateLimiter::new(rate_limit, Duration::from_secs(60)),
+            Duration::from_secs(300),
+        )
+    }
+
+    fn private_rules() -> HttpRules {
+        HttpRules {
+            allow_private_ips: true,
+            ..Default::default()
+        }
+    }
+
+    fn render_get(http_helpers: &HttpHelpers, url: &str, website_id: Option<Uuid>) -> Result<String, RenderError> {
+        render_handlebars_with(
+            http_helpers,
+            &format!(r#"{{{{#http_get "{url}"}}}}{{{{name}}}}{{{{/http_get}}}}"#),
+            &Value::Null,
+            website_id,
+            None,
+        )
+        .map(|rendered| rendered.content)
+    }
+
+    #[test]
+    fn test_http_get_renders_response() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(private_rules(), 60);
+
+        let result = render_get(&http_helpers, &format!("{}/json", server.url), None);
+
+        assert_eq!(result.unwrap(), "Mango");
+    }
+
+    #[test]
+    fn test_http_post_renders_response() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(private_rules(), 60);
+
+        let result = render_handlebars_with(
+            &http_helpers,
+            &format!(
+                r#"{{{{#http_post "{}/json" data=payload}}}}{{{{name}}}}{{{{/http_post}}}}"#,
+                server.url
+            ),
+            &serde_json::json!({ "payload": { "title": "Hello" } }),
+            None,
+            None,
+        );
+
+        assert_eq!(result.unwrap().content, "Mango");
+    }
+
+    #[test]
+    fn test_http_get_blocks_private_addresses_by_default() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(HttpRules::default(), 60);
+
+        for url in [
+            format!("{}/json", server.url),
+            format!("http://localhost:{}/json", server.url.rsplit(':').next().unwrap()),
+            "http://169.254.169.254/latest/meta-data".to_owned(),
+            "http://[::1]/json".to_owned(),
+            "http://10.0.0.1/json".to_owned(),
+        ] {
+            assert!(
+                render_get(&http_helpers, &url, None).is_err(),
+                "{url} should be blocked"
+            );
+        }
+
+        assert_eq!(server.hits(), 0);
+    }
+
+    #[test]
+    fn test_http_get_blocks_other_schemes() {
+        let http_helpers = http_helpers(private_rules(), 60);
+
+        let result = render_get(&http_helpers, "file:///etc/passwd", None);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_http_get_blocks_denied_hosts() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(
+            HttpRules {
+                denied_hosts: vec!["127.0.0.1".to_owned()],
+                ..private_rules()
+            },
+            60,
+        );
+
+        let result = render_get(&http_helpers, &format!("{}/json", server.url), None);
+
+        assert!(result.is_err());
+        assert_eq!(server.hits(), 0);
+    }
+
+    #[test]
+    fn test_http_get_only_allows_allowed_hosts() {
+        let server = MockServer::start();
+        let url = format!("{}/json", server.url);
+        let denied_http_helpers = http_helpers(
+            HttpRules {
+                allowed_hosts: vec!["example.com".to_owned()],
+                ..private_rules()
+            },
+            60,
+        );
+        let allowed_http_helpers = http_helpers(
+            HttpRules {
+                allowed_hosts: vec!["example.com".to_owned(), "127.0.0.1".to_owned()],
+                ..private_rules()
+            },
+            60,
+        );
+
+        assert!(render_get(&denied_http_helpers, &url, None).is_err());
+        assert_eq!(server.hits(), 0);
+        assert_eq!(render_get(&allowed_http_helpers, &url, None).unwrap(), "Mango");
+    }
+
+    #[test]
+    fn test_http_get_checks_redirect_targets() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(
+            HttpRules {
+                denied_hosts: vec!["localhost".to_owned()],
+                ..private_rules()
+            },
+            60,
+        );
+
+        let result = render_get(&http_helpers, &format!("{}/redirect", server.url), None);
+
+        assert!(result.is_err());
+        assert_eq!(server.hits(), 1);
+    }
+
+    #[test]
+    fn test_http_get_limits_response_size() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(private_rules(), 60);
+
+        let result = render_get(&http_helpers, &format!("{}/large", server.url), None);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_http_get_limits_requests_per_website() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(private_rules(), 2);
+        let website_id = Uuid::new_v4();
+
+        for n in 0..2 {
+            let url = format!("{}/json?n={n}", server.url);
+
+            assert!(render_get(&http_helpers, &url, Some(website_id)).is_ok());
+        }
+
+        let url = format!("{}/json?n=2", server.url);
+
+        assert!(render_get(&http_helpers, &url, Some(website_id)).is_err());
+        assert!(render_get(&http_helpers, &url, Some(Uuid::new_v4())).is_ok());
+    }
+
+    #[test]
+    fn test_http_get_caches_responses_by_url() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(private_rules(), 1);
+        let website_id = Uuid::new_v4();
+        let url = format!("{}/json", server.url);
+
+        assert!(render_get(&http_helpers, &url, Some(website_id)).is_ok());
+        assert_eq!(render_get(&http_helpers, &url, Some(website_id)).unwrap(), "Mango");
+        assert_eq!(server.hits(), 1);
+    }
+
+    #[test]
+    fn test_http_get_caches_responses_by_website_and_headers() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(private_rules(), 60);
+        let website_id = Uuid::new_v4();
+        let url = format!("{}/json", server.url);
+        let render_get_with_token = |token: &str| {
+            render_handlebars_with(
+                &http_helpers,
+                &format!(r#"{{{{#http_get "{url}" headers=headers}}}}{{{{name}}}}{{{{/http_get}}}}"#),
+                &serde_json::json!({ "headers": [format!("Authorization: Bearer {token}")] }),
+                Some(website_id),
+                None,
+            )
+        };
+
+        assert!(render_get(&http_helpers, &url, Some(website_id)).is_ok());
+        assert!(render_get(&http_helpers, &url, Some(Uuid::new_v4())).is_ok());
+        assert!(render_get_with_token("first").is_ok());
+        assert!(render_get_with_token("second").is_ok());
+        assert_eq!(server.hits(), 4);
+
+        assert!(render_get_with_token("first").is_ok());
+        assert_eq!(server.hits(), 4);
+    }
+
+    #[test]
+    fn test_data_sources_are_added_to_variables() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(private_rules(), 60);
+        let variables = serde_json::json!({
+            "data_sources": {
+                "fruit": { "url": format!("{}/json", server.url) },
+                "order": { "url": format!("{}/json", server.url), "method": "post", "data": { "id": 1 } }
+            }
+        });
+
+        let rendered =
+            render_handlebars_with(&http_helpers, "{{fruit.name}} {{order.name}}", &variables, None, None).unwrap();
+
+        assert_eq!(rendered.content, "Mango Mango");
+        assert!(rendered.errors.is_empty());
+    }
+
+    #[test]
+    fn test_data_source_errors_are_reported() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(HttpRules::default(), 60);
+        let variables = serde_json::json!({
+            "data_sources": {
+                "fruit": { "url": format!("{}/json", server.url) },
+                "invalid": { "ttl": 60 }
+            }
+        });
+
+        let rendered = render_handlebars_with(
+            &http_helpers,
+            "{{#if fruit}}{{fruit.name}}{{else}}Unavailable{{/if}}",
+            &variables,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(rendered.content, "Unavailable");
+        assert_eq!(rendered.errors.len(), 2);
+        assert!(rendered.errors.iter().any(|error| error.starts_with("fruit: ")));
+        assert!(rendered.errors.iter().any(|error| error.starts_with("invalid: ")));
+        assert_eq!(server.hits(), 0);
+    }
+
+    #[test]
+    fn test_data_sources_are_cached_with_their_ttl() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(private_rules(), 60);
+        let cached_variables = serde_json::json!({
+            "data_sources": { "fruit": { "url": format!("{}/json?cached", server.url), "ttl": 600 } }
+        });
+        let uncached_variables = serde_json::json!({
+            "data_sources": { "fruit": { "url": format!("{}/json?uncached", server.url), "ttl": 0 } }
+        });
+
+        for _ in 0..2 {
+            render_handlebars_with(&http_helpers, "{{fruit.name}}", &cached_variables, None, None).unwrap();
+        }
+
+        assert_eq!(server.hits(), 1);
+
+        for _ in 0..2 {
+            render_handlebars_with(&http_helpers, "{{fruit.name}}", &uncached_variables, None, None).unwrap();
+        }
+
+        assert_eq!(server.hits(), 3);
+    }
+
+    #[test]
+    fn test_data_sources_are_cached_by_website_and_headers() {
+        let server = MockServer::start();
+        let http_helpers = http_helpers(private_rules(), 60);
+        let url = format!("{}/json", server.url);
+        let variables = serde_json::json!({ "data_sources": { "fruit": { "url": url } } });
+        let variables_with_headers = serde_json::json!({
+            "data_sources": { "fruit": { "url": url, "headers": ["Authorization: Bearer token"] } }
+        });
+        let website_id = Some(Uuid::new_v4());
+
+        for data in [&variables, &variables_with_headers] {
+            for _ in 0..2 {
+                render_handlebars_with(&http_helpers, "{{fruit.name}}", data, website_id, None).unwrap();
+            }
+        }
+
+        assert_eq!(server.hits(), 2);
+
+        render_handlebars_with(&http_helpers, "{{fruit.name}}", &variables, Some(Uuid::new_v4()), None).unwrap();
+
+        assert_eq!(server.hits(), 3);
+    }
+
+    #[test]
+    fn test_dynamic_template_ttl() {
+        let variables = serde_json::json!({
+            "data_sources": {
+                "fruit": { "url": "https://example.com/fruit", "ttl": 600 },
+                "order": { "url": "https://example.com/order", "ttl": 60 }
+            }
+        });
+        let uncached_variables = serde_json::json!({
+            "data_sources": { "fruit": { "url": "https://example.com/fruit", "ttl": 0 } }
+        });
+
+        assert_eq!(
+            dynamic_template_ttl("{{fruit.name}}", &variables),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(dynamic_template_ttl("{{fruit.name}}", &uncached_variables), None);
+        assert_eq!(dynamic_template_ttl("{{navigation}}", &variables), None);
+        assert_eq!(dynamic_template_ttl("{{name}}", &Value::Null), None);
+    }
+
+    #[tokio::test]
+    async fn test_render_handlebars() {
+        let rendered = render_handlebars("Hello {{name}}!", &serde_json::json!({ "name": "World" }), None)
+            .await
+            .unwrap();
+
+        assert_eq!(rendered.content, "Hello World!");
+
+        let rendered = render_handlebars("Hello World!", &Value::Null, None).await.unwrap();
+
+        assert_eq!(rendered.content, "Hello World!");
+    }
+
+    #[tokio::test]
+    async fn test_render_handlebars_keeps_shortcodes() {
+        let rendered = render_handlebars(
+            "{{name}}\n{{< callout tip >}}{{< youtube dQw4w9WgXcQ >}}{{< /callout >}}",
+            &serde_json::json!({ "name": "Video" }),
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            rendered.content,
+            "Video\n{{< callout tip >}}{{< youtube dQw4w9WgXcQ >}}{{< /callout >}}"
+        );
+    }
 }
@@ -0,0 +1,371 @@
+use std::io::Read;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::blocking::{Client, Response};
+use reqwest::header::{HeaderName, HeaderValue, CONTENT_TYPE, LOCATION};
+use reqwest::redirect::Policy;
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+use url::Url;
+use uuid::Uuid;
+
+use crate::config::{CACHE_CONFIG, HTTP_CONFIG};
+use crate::constants::PREFIX_HTTP_RATE_LIMIT;
+
+const MAX_REDIRECTS: u8 = 5;
+
+#[derive(Debug)]
+pub(crate) enum HttpError {
+    DeniedHost(String),
+    InvalidUrl(String),
+    PrivateAddress(String),
+    RateLimited,
+    Request(String),
+    ResponseTooLarge,
+    Status(StatusCode),
+    TooManyRedirects,
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DeniedHost(host) => write!(f, "host is not allowed: {host}"),
+            Self::InvalidUrl(url) => write!(f, "invalid URL: {url}"),
+            Self::PrivateAddress(host) => write!(f, "host resolves to a private address: {host}"),
+            Self::RateLimited => write!(f, "too many requests"),
+            Self::Request(message) => write!(f, "request failed: {message}"),
+            Self::ResponseTooLarge => write!(f, "response is too large"),
+            Self::Status(status) => write!(f, "unexpected response status: {status}"),
+            Self::TooManyRedirects => write!(f, "too many redirects"),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<redis::RedisError> for HttpError {
+    fn from(error: redis::RedisError) -> Self {
+        Self::Request(error.to_string())
+    }
+}
+
+impl From<reqwest::Error> for HttpError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Request(error.to_string())
+    }
+}
+
+pub(crate) struct HttpResponse {
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.data).into_owned()
+    }
+}
+
+/// Host rules applied to every URL requested on behalf of users, including each redirect target.
+#[derive(Clone, Default)]
+pub(crate) struct HttpRules {
+    /// When not empty, only these hosts and their subdomains can be requested.
+    pub allowed_hosts: Vec<String>,
+    /// These hosts and their subdomains can never be requested.
+    pub denied_hosts: Vec<String>,
+    pub allow_private_ips: bool,
+}
+
+impl HttpRules {
+    pub fn from_config() -> Self {
+        Self {
+            allowed_hosts: HTTP_CONFIG.allowed_hosts(),
+            denied_hosts: HTTP_CONFIG.denied_hosts(),
+            allow_private_ips: HTTP_CONFIG.allow_private_ips,
+        }
+    }
+
+    /// Checks the scheme and host of the URL, then resolves the host and rejects it if any of its addresses is not
+    /// public. The request connects to the returned addresses, so the host is not resolved a second time.
+    pub fn resolve(&self, url: &Url) -> Result<Vec<SocketAddr>, HttpError> {
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(HttpError::InvalidUrl(url.to_string()));
+        }
+
+        let host = url
+            .host_str()
+            .map(|host| {
+                host.trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .trim_end_matches('.')
+                    .to_lowercase()
+            })
+            .filter(|host| !host.is_empty())
+            .ok_or_else(|| HttpError::InvalidUrl(url.to_string()))?;
+
+        if self.denied_hosts.iter().any(|pattern| host_matches(&host, pattern))
+            || (!self.allowed_hosts.is_empty()
+                && !self.allowed_hosts.iter().any(|pattern| host_matches(&host, pattern)))
+        {
+            return Err(HttpError::DeniedHost(host));
+        }
+
+        let port = url
+            .port_or_known_default()
+            .ok_or_else(|| HttpError::InvalidUrl(url.to_string()))?;
+        let addresses = (host.as_str(), port)
+            .to_socket_addrs()
+            .map_err(|error| HttpError::Request(error.to_string()))?
+            .collect::<Vec<_>>();
+
+        if addresses.is_empty()
+            || (!self.allow_private_ips && addresses.iter().any(|address| !is_public_ip(address.ip())))
+        {
+            return Err(HttpError::PrivateAddress(host));
+        }
+
+        Ok(addresses)
+    }
+}
+
+fn host_matches(host: &str, pattern: &str) -> bool {
+    host == pattern
+        || host
+            .strip_suffix(pattern)
+            .is_some_and(|subdomain| subdomain.ends_with('.'))
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => is_public_ipv6(ip),
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        || a == 0
+        || a >= 240
+        || (a == 100 && (b & 0b1100_0000) == 64)
+        || (a == 192 && b == 0 && c == 0)
+        || (a == 198 && (b & 0b1111_1110) == 18))
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    if let Some(ipv4) = ip.to_ipv4_mapped() {
+        return is_public_ipv4(ipv4);
+    }
+
+    let segments = ip.segments();
+
+    // 6to4 addresses embed the IPv4 address they route to.
+    if segments[0] == 0x2002 {
+        let [a, b] = segments[1].to_be_bytes();
+        let [c, d] = segments[2].to_be_bytes();
+
+        return is_public_ipv4(Ipv4Addr::new(a, b, c, d));
+    }
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        || (segments[0] & 0xfe00) == 0xfc00
+        || (segments[0] & 0xffc0) == 0xfe80
+        || (segments[0] == 0x2001 && segments[1] == 0x0000)
+        || (segments[0] == 0x2001 && segments[1] == 0x0db8)
+        || (segments[0] == 0x0064 && segments[1] == 0xff9b))
+}
+
+/// Blocking HTTP client for requests made on behalf of users, which follows redirects by itself so every hop is
+/// checked against the rules.
+pub(crate) struct HttpClient {
+    rules: HttpRules,
+    timeout: Duration,
+    max_response_size: u64,
+}
+
+impl HttpClient {
+    pub fn new(rules: HttpRules, timeout: Duration, max_response_size: u64) -> Self {
+        Self {
+            rules,
+            timeout,
+            max_response_size,
+        }
+    }
+
+    pub fn from_config() -> Self {
+        Self::new(
+            HttpRules::from_config(),
+            HTTP_CONFIG.timeout(),
+            HTTP_CONFIG.max_response_size,
+        )
+    }
+
+    pub fn get(&self, url: &str, headers: &[(HeaderName, HeaderValue)]) -> Result<HttpResponse, HttpError> {
+        self.request(Method::GET, url, headers, None)
+    }
+
+    pub fn post(
+        &self,
+        url: &str,
+        headers: &[(HeaderName, HeaderValue)],
+        data: &Value,
+    ) -> Result<HttpResponse, HttpError> {
+        self.request(Method::POST, url, headers, Some(data))
+    }
+
+    fn request(
+        &self,
+        mut method: Method,
+        url: &str,
+        headers: &[(HeaderName, HeaderValue)],
+        mut data: Option<&Value>,
+    ) -> Result<HttpResponse, HttpError> {
+        let mut url = Url::parse(url).map_err(|_| HttpError::InvalidUrl(url.to_owned()))?;
+        let original_host = url.host_str().map(str::to_owned);
+
+        for _ in 0..=MAX_REDIRECTS {
+            let addresses = self.rules.resolve(&url)?;
+
+            // Custom headers may hold credentials, so they are only sent to the host the template asked for.
+            let headers: &[(HeaderName, HeaderValue)] = if url.host_str().map(str::to_owned) == original_host {
+                headers
+            } else {
+                &[]
+            };
+            let response = self.send(&method, &url, &addresses, headers, data)?;
+            let status = response.status();
+            let response_headers = response.headers().clone();
+
+            if status.is_redirection() {
+                let location = response_headers
+                    .get(LOCATION)
+                    .and_then(|value| value.to_str().ok())
+                    .ok_or(HttpError::Status(status))?;
+
+                url = url
+                    .join(location)
+                    .map_err(|_| HttpError::InvalidUrl(location.to_owned()))?;
+
+                if status != StatusCode::TEMPORARY_REDIRECT && status != StatusCode::PERMANENT_REDIRECT {
+                    method = Method::GET;
+                    data = None;
+                }
+
+                continue;
+            }
+
+            if !status.is_success() {
+                return Err(HttpError::Status(status));
+            }
+
+            let content_type = response_headers
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.split(';').next())
+                .map(|value| value.trim().to_owned());
+            let mut data = Vec::new();
+
+            response
+                .take(self.max_response_size + 1)
+                .read_to_end(&mut data)
+                .map_err(|error| HttpError::Request(error.to_string()))?;
+
+            if data.len() as u64 > self.max_response_size {
+                return Err(HttpError::ResponseTooLarge);
+            }
+
+            return Ok(HttpResponse { content_type, data });
+        }
+
+        Err(HttpError::TooManyRedirects)
+    }
+
+    fn send(
+        &self,
+        method: &Method,
+        url: &Url,
+        addresses: &[SocketAddr],
+        headers: &[(HeaderName, HeaderValue)],
+        data: Option<&Value>,
+    ) -> Result<Response, HttpError> {
+        let mut client_builder = Client::builder()
+            .no_proxy()
+            .redirect(Policy::none())
+            .timeout(self.timeout);
+
+        // Pins the addresses checked by the rules, so the host can't resolve to a different one for the request. Proxies
+        // are disabled above, since a proxy would resolve the host by itself.
+        if let Some(domain) = url.domain() {
+            client_builder = client_builder.resolve_to_addrs(domain, addresses);
+        }
+
+        let mut request_builder = client_builder.build()?.request(method.clone(), url.as_str());
+
+        for (key, value) in headers {
+            request_builder = request_builder.header(key.clone(), value.clone());
+        }
+
+        if let Some(data) = data {
+            request_builder = request_builder.json(data);
+        }
+
+        Ok(request_builder.send()?)
+    }
+}
+
+/// Fixed window rate limiter, shared by every process through Redis. A limit of zero disables it.
+pub(crate) struct RateLimiter {
+    limit: u32,
+    window: Duration,
+    client: Option<redis::Client>,
+}
+
+impl RateLimiter {
+    pub fn new(limit: u32, window: Duration) -> Self {
+        Self {
+            limit,
+            window,
+            client: redis::Client::open(CACHE_CONFIG.redis_url.as_str()).ok(),
+        }
+    }
+
+    pub fn check(&self, key: Uuid) -> Result<(), HttpError> {
+        if self.limit == 0 {
+            return Ok(());
+        }
+
+        let window = self.window.as_secs().max(1);
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let redis_key = format!("{PREFIX_HTTP_RATE_LIMIT}:{key}:{}", now / window);
+        let mut connection = self
+            .client
+            .as_ref()
+            .ok_or_else(|| HttpError::Request("rate limiter is not available".to_owned()))?
+            .get_connection()?;
+        let (count,): (u32,) = redis::pipe()
+            .atomic()
+            .incr(&redis_key, 1)
+            .expire(&redis_key, window as i64)
+            .ignore()
+            .query(&mut connection)?;
+
+        if count > self.limit {
+            return Err(HttpError::RateLimited);
+        }
+
+        Ok(())
+    }
+}
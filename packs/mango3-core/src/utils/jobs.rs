@@ -1,16 +1,23 @@
+use std::path::PathBuf;
+
 use apalis::prelude::Storage;
 use apalis_redis::RedisStorage;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-use crate::config::JOBS_CONFIG;
+use crate::config::{JOBS_CONFIG, MISC_CONFIG};
 use crate::enums::{AdminMailerJobCommand, GuestMailerJobCommand, MailerJobCommand};
 use crate::models::User;
 
 #[derive(Clone, Debug)]
 pub struct Jobs {
     pub storage_admin_mailer: RedisStorage<AdminMailerJob>,
+    pub storage_export_website: RedisStorage<ExportWebsiteJob>,
+    pub storage_generate_static_website: RedisStorage<GenerateStaticWebsiteJob>,
     pub storage_guest_mailer: RedisStorage<GuestMailerJob>,
+    pub storage_import_posts: RedisStorage<ImportPostsJob>,
     pub storage_mailer: RedisStorage<MailerJob>,
+    pub storage_render_post_diagrams: RedisStorage<RenderPostDiagramsJob>,
 }
 
 impl Jobs {
@@ -24,8 +31,12 @@ impl Jobs {
     pub async fn setup() -> Self {
         Self {
             storage_admin_mailer: Self::storage().await,
+            storage_export_website: Self::storage().await,
+            storage_generate_static_website: Self::storage().await,
             storage_guest_mailer: Self::storage().await,
+            storage_import_posts: Self::storage().await,
             storage_mailer: Self::storage().await,
+            storage_render_post_diagrams: Self::storage().await,
         }
     }
 
@@ -37,6 +48,25 @@ impl Jobs {
             .expect("Could not store job");
     }
 
+    pub async fn export_website(&self, website_id: Uuid, user: &User) {
+        self.storage_export_website
+            .clone()
+            .push(ExportWebsiteJob {
+                website_id,
+                user: user.clone(),
+            })
+            .await
+            .expect("Could not store job");
+    }
+
+    pub async fn generate_static_website(&self, website_id: Uuid) {
+        self.storage_generate_static_website
+            .clone()
+            .push(GenerateStaticWebsiteJob { website_id })
+            .await
+            .expect("Could not store job");
+    }
+
     pub async fn guest_mailer(&self, to: &str, command: GuestMailerJobCommand) {
         self.storage_guest_mailer
             .clone()
@@ -48,6 +78,18 @@ impl Jobs {
             .expect("Could not store job");
     }
 
+    pub async fn import_posts(&self, website_id: Uuid, user: &User, file_path: PathBuf) {
+        self.storage_import_posts
+            .clone()
+            .push(ImportPostsJob {
+                website_id,
+                user: user.clone(),
+                file_path,
+            })
+            .await
+            .expect("Could not store job");
+    }
+
     pub async fn mailer(&self, user: &User, command: MailerJobCommand) {
         self.storage_mailer
             .clone()
@@ -58,6 +100,26 @@ impl Jobs {
             .await
             .expect("Could not store job");
     }
+
+    pub async fn render_post_diagrams(&self, post_id: Uuid) {
+        self.storage_render_post_diagrams
+            .clone()
+            .push(RenderPostDiagramsJob { post_id })
+            .await
+            .expect("Could not store job");
+    }
+
+    /// Regenerates the static copy of a website, or removes it once the website is no longer published. Websites
+    /// without a static copy are left alone.
+    pub async fn refresh_static_website(&self, website_id: Uuid) {
+        if MISC_CONFIG
+            .storage_static_websites_path()
+            .join(website_id.to_string())
+            .exists()
+        {
+            self.generate_static_website(website_id).await;
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -65,14 +127,37 @@ pub struct AdminMailerJob {
     pub command: AdminMailerJobCommand,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExportWebsiteJob {
+    pub website_id: Uuid,
+    pub user: User,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GenerateStaticWebsiteJob {
+    pub website_id: Uuid,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GuestMailerJob {
     pub to: String,
     pub command: GuestMailerJobCommand,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ImportPostsJob {
+    pub website_id: Uuid,
+    pub user: User,
+    pub file_path: PathBuf,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MailerJob {
     pub user: User,
     pub command: MailerJobCommand,
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RenderPostDiagramsJob {
+    pub post_id: Uuid,
+}
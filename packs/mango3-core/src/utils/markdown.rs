@@ -1,10 +1,146 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use latex2mathml::{latex_to_mathml, DisplayStyle};
 use pulldown_cmark::html::push_html;
-use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
-use regex::Captures;
+use pulldown_cmark::{
+    CodeBlockKind, CowStr, Event, HeadingLevel, LinkType, Options, Parser, Tag, TagEnd, TextMergeStream,
+};
+use regex::{Captures, Regex};
+use syntect::html::{ClassStyle, ClassedHTMLGenerator};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
 
-use crate::constants::{BLACKLISTED_HASHTAGS, REGEX_FIND_HASHTAGS};
+use crate::constants::{BLACKLISTED_HASHTAGS, REGEX_FIND_HASHTAGS, REGEX_FIND_MENTIONS};
 
 use super::hashtag_has_lookaround;
+use super::shortcodes::{extract_shortcodes, restore_shortcodes, ExtractedShortcodes};
+
+const DIAGRAM_LANGUAGES: [&str; 3] = ["dot", "graphviz", "mermaid"];
+const HIGHLIGHT_CLASS_STYLE: ClassStyle = ClassStyle::SpacedPrefixed { prefix: "hl-" };
+const MATHML_ELEMENTS: &[&str] = &[
+    "annotation",
+    "math",
+    "menclose",
+    "merror",
+    "mfrac",
+    "mi",
+    "mmultiscripts",
+    "mn",
+    "mo",
+    "mover",
+    "mpadded",
+    "mphantom",
+    "mprescripts",
+    "mroot",
+    "mrow",
+    "ms",
+    "mspace",
+    "msqrt",
+    "mstyle",
+    "msub",
+    "msubsup",
+    "msup",
+    "mtable",
+    "mtd",
+    "mtext",
+    "mtr",
+    "munder",
+    "munderover",
+    "semantics",
+];
+#[cfg(any(feature = "diagrams", test))]
+const SVG_ELEMENTS: &[&str] = &[
+    "b",
+    "br",
+    "circle",
+    "clipPath",
+    "defs",
+    "desc",
+    "div",
+    "ellipse",
+    "em",
+    "feComposite",
+    "feDropShadow",
+    "feFlood",
+    "feGaussianBlur",
+    "feMerge",
+    "feMergeNode",
+    "feOffset",
+    "filter",
+    "foreignObject",
+    "g",
+    "i",
+    "line",
+    "linearGradient",
+    "marker",
+    "mask",
+    "p",
+    "path",
+    "pattern",
+    "polygon",
+    "polyline",
+    "radialGradient",
+    "rect",
+    "span",
+    "stop",
+    "strong",
+    "style",
+    "svg",
+    "symbol",
+    "text",
+    "title",
+    "tspan",
+];
+const TOC_MARKER: &str = "[toc]";
+
+static REGEX_HTML_HEADINGS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<h([2-6]) id="([^"]+)">((?s:.)*?)</h[2-6]>"#).unwrap());
+static REGEX_HTML_TAGS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+static REGEX_UNSAFE_ATTRIBUTES: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)\son[a-z]+\s*=|\s(?:href|src|xlink:href)\s*=|javascript:"#).unwrap());
+#[cfg(any(feature = "diagrams", test))]
+static REGEX_UNSAFE_CSS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)@import|expression\(|url\(\s*['"]?[^#'"\s)]"#).unwrap());
+static SYNTAX_SET: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+/// Sanitized SVG of the diagrams in fenced `mermaid`, `graphviz` and `dot` blocks, by their language and source.
+#[derive(Default)]
+pub struct RenderedDiagrams(HashMap<String, String>);
+
+impl RenderedDiagrams {
+    #[cfg(any(feature = "diagrams", test))]
+    pub(crate) fn insert(&mut self, language: &str, source: &str, svg: String) {
+        self.0.insert(format!("{language}:{source}"), svg);
+    }
+
+    fn get(&self, language: &str, source: &str) -> Option<&str> {
+        self.0.get(&format!("{language}:{source}")).map(String::as_str)
+    }
+}
+
+/// URLs of the users that can be mentioned with `@username`, by their username. Mentions of other usernames are kept
+/// as text.
+#[derive(Default)]
+pub struct Mentions(HashMap<String, String>);
+
+impl Mentions {
+    pub fn insert(&mut self, username: &str, url: String) {
+        self.0.insert(username.to_lowercase(), url);
+    }
+
+    fn get(&self, username: &str) -> Option<&str> {
+        self.0.get(&username.to_lowercase()).map(String::as_str)
+    }
+}
+
+struct TocHeading {
+    level: u8,
+    id: String,
+    /// Already escaped, so it can be written in the HTML as is.
+    title_html: String,
+}
 
 #[macro_export]
 macro_rules! parse_html {
@@ -13,7 +149,94 @@ macro_rules! parse_html {
     };
 }
 
+pub(crate) fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 pub fn parse_html(input: &str, enable_links: bool) -> String {
+    render_markdown(input, enable_links, false, None, None)
+}
+
+/// Same as `parse_html`, also linking the mentioned users to their profile.
+pub fn parse_html_with_mentions(input: &str, enable_links: bool, mentions: &Mentions) -> String {
+    render_markdown(input, enable_links, false, None, Some(mentions))
+}
+
+/// Same as `parse_html_with_mentions`, also expanding shortcodes like `{{< youtube id >}}` outside of code, rendering
+/// `$...$` math and replacing diagram blocks with their rendered SVG.
+pub fn parse_html_with_shortcodes(
+    input: &str,
+    enable_links: bool,
+    diagrams: &RenderedDiagrams,
+    mentions: &Mentions,
+) -> String {
+    render_markdown(input, enable_links, true, Some(diagrams), Some(mentions))
+}
+
+/// Returns the lowercase usernames mentioned with `@username` in the text of the content, leaving out code and links,
+/// the same way they are linked while rendering.
+pub fn find_mentions(input: &str) -> Vec<String> {
+    let mut usernames: Vec<String> = Vec::new();
+    let mut skip_depth = 0;
+
+    for event in TextMergeStream::new(Parser::new_ext(input, markdown_options())) {
+        match event {
+            Event::Start(Tag::CodeBlock(_) | Tag::Image { .. } | Tag::Link { .. }) => skip_depth += 1,
+            Event::End(TagEnd::CodeBlock | TagEnd::Image | TagEnd::Link) => skip_depth -= 1,
+            Event::Text(text) if skip_depth == 0 => {
+                for match_ in find_mention_matches(&text) {
+                    let username = match_.as_str().to_lowercase();
+
+                    if !usernames.contains(&username) {
+                        usernames.push(username);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    usernames
+}
+
+/// Returns the language and source of the fenced diagram blocks, as they will be looked up while rendering.
+#[cfg(feature = "diagrams")]
+pub(crate) fn find_diagrams(input: &str) -> Vec<(String, String)> {
+    let input = replace_hashtags(input);
+    let mut diagrams = Vec::new();
+    let mut current: Option<(String, String)> = None;
+
+    for event in Parser::new_ext(&input, markdown_options()) {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                current = code_block_language(&info)
+                    .filter(|language| DIAGRAM_LANGUAGES.contains(language))
+                    .map(|language| (language.to_owned(), String::new()));
+            }
+            Event::Text(text) => {
+                if let Some((_, source)) = current.as_mut() {
+                    source.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                if let Some(diagram) = current.take() {
+                    if !diagrams.contains(&diagram) {
+                        diagrams.push(diagram);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    diagrams
+}
+
+fn markdown_options() -> Options {
     let mut options = Options::empty();
 
     options.insert(Options::ENABLE_FOOTNOTES);
@@ -25,7 +248,11 @@ pub fn parse_html(input: &str, enable_links: bool) -> String {
     options.insert(Options::ENABLE_TABLES);
     options.insert(Options::ENABLE_YAML_STYLE_METADATA_BLOCKS);
 
-    let input = REGEX_FIND_HASHTAGS.replace_all(input, |captures: &Captures| {
+    options
+}
+
+fn replace_hashtags(input: &str) -> Cow<'_, str> {
+    REGEX_FIND_HASHTAGS.replace_all(input, |captures: &Captures| {
         let match_ = captures.name("name").expect("Could not get match");
         let name = match_.as_str();
 
@@ -34,9 +261,93 @@ pub fn parse_html(input: &str, enable_links: bool) -> String {
         } else {
             format!("#{name}")
         }
-    });
+    })
+}
+
+/// Returns the usernames of the mentions that are not part of a word, an email address or a URL.
+fn find_mention_matches(text: &str) -> impl Iterator<Item = regex::Match<'_>> {
+    REGEX_FIND_MENTIONS.captures_iter(text).filter_map(move |captures| {
+        let match_ = captures.name("username").expect("Could not get username");
+        let before = text[..match_.start() - 1].chars().next_back();
+        let after = text[match_.end()..].chars().next();
+
+        if before.is_some_and(|c| !c.is_whitespace() && !"([{\"'".contains(c))
+            || after.is_some_and(|c| c.is_alphanumeric() || "@/".contains(c))
+        {
+            None
+        } else {
+            Some(match_)
+        }
+    })
+}
 
-    let parser = Parser::new_ext(&input, options).filter(|event| match event {
+fn link_mentions<'a>(text: CowStr<'a>, mentions: &Mentions) -> Vec<Event<'a>> {
+    let mut events = Vec::new();
+    let mut last_end = 0;
+
+    for match_ in find_mention_matches(&text) {
+        let Some(url) = mentions.get(match_.as_str()) else {
+            continue;
+        };
+        let start = match_.start() - 1;
+
+        if start > last_end {
+            events.push(Event::Text(text[last_end..start].to_owned().into()));
+        }
+
+        events.push(Event::Start(Tag::Link {
+            link_type: LinkType::Inline,
+            dest_url: url.to_owned().into(),
+            title: "".into(),
+            id: "".into(),
+        }));
+        events.push(Event::Text(format!("@{}", match_.as_str()).into()));
+        events.push(Event::End(TagEnd::Link));
+
+        last_end = match_.end();
+    }
+
+    if events.is_empty() {
+        return vec![Event::Text(text)];
+    }
+
+    if last_end < text.len() {
+        events.push(Event::Text(text[last_end..].to_owned().into()));
+    }
+
+    events
+}
+
+fn render_markdown(
+    input: &str,
+    enable_links: bool,
+    enable_shortcodes: bool,
+    diagrams: Option<&RenderedDiagrams>,
+    mentions: Option<&Mentions>,
+) -> String {
+    let mut options = markdown_options();
+    let input = replace_hashtags(input);
+
+    // Math is only enabled for post content, as `$` is common in other texts, like prices in comments.
+    if enable_shortcodes {
+        options.insert(Options::ENABLE_MATH);
+    }
+
+    let (input, shortcodes) = if enable_shortcodes {
+        let code_ranges = Parser::new_ext(&input, options)
+            .into_offset_iter()
+            .filter_map(|(event, range)| match event {
+                Event::Code(_) | Event::Start(Tag::CodeBlock(_)) => Some(range),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        extract_shortcodes(&input, &code_ranges)
+    } else {
+        (input.into_owned(), ExtractedShortcodes::default())
+    };
+
+    let parser = TextMergeStream::new(Parser::new_ext(&input, options)).filter(|event| match event {
         Event::Start(Tag::Heading {
             level: HeadingLevel::H1,
             ..
@@ -47,9 +358,467 @@ pub fn parse_html(input: &str, enable_links: bool) -> String {
         _ => true,
     });
 
+    let mut image_caption: Option<CowStr> = None;
+
+    // Images with a title are rendered as a figure, using the title as its caption.
+    let parser = parser.flat_map(move |event| match event {
+        Event::Start(Tag::Image { ref title, .. }) if !title.is_empty() => {
+            image_caption = Some(title.clone());
+
+            vec![Event::Html("<figure>".into()), event]
+        }
+        Event::End(TagEnd::Image) => match image_caption.take() {
+            Some(caption) => vec![
+                event,
+                Event::Html(format!("<figcaption>{}</figcaption></figure>", escape_html(&caption)).into()),
+            ],
+            None => vec![event],
+        },
+        _ => vec![event],
+    });
+
+    let mut skip_depth = 0;
+
+    // Mentions are linked only in text, leaving out code and the text of images and links.
+    let parser = parser.flat_map(move |event| match event {
+        Event::Start(Tag::CodeBlock(_) | Tag::Image { .. } | Tag::Link { .. }) => {
+            skip_depth += 1;
+
+            vec![event]
+        }
+        Event::End(TagEnd::CodeBlock | TagEnd::Image | TagEnd::Link) => {
+            skip_depth -= 1;
+
+            vec![event]
+        }
+        Event::Text(text) if enable_links && skip_depth == 0 => match mentions {
+            Some(mentions) => link_mentions(text, mentions),
+            None => vec![Event::Text(text)],
+        },
+        _ => vec![event],
+    });
+
+    let parser = parser.map(|event| match event {
+        Event::InlineMath(latex) => Event::InlineHtml(render_math(&latex, DisplayStyle::Inline).into()),
+        Event::DisplayMath(latex) => Event::InlineHtml(render_math(&latex, DisplayStyle::Block).into()),
+        _ => event,
+    });
+
+    let events = process_blocks(parser, enable_links, diagrams);
     let mut html_output = String::new();
 
-    push_html(&mut html_output, parser);
+    push_html(&mut html_output, events.into_iter());
+
+    if shortcodes.is_empty() {
+        html_output
+    } else {
+        restore_shortcodes(html_output, &shortcodes)
+    }
+}
+
+/// Highlights fenced code blocks, gives every heading an id with an anchor link and replaces a `[toc]` paragraph with a
+/// table of contents.
+fn process_blocks<'a>(
+    mut events: impl Iterator<Item = Event<'a>>,
+    enable_links: bool,
+    diagrams: Option<&RenderedDiagrams>,
+) -> Vec<Event<'a>> {
+    let mut output = Vec::new();
+    let mut headings = Vec::new();
+    let mut heading_ids = HashMap::new();
+    let mut toc_positions = Vec::new();
+
+    while let Some(event) = events.next() {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let mut code = String::new();
+
+                for event in events.by_ref() {
+                    match event {
+                        Event::Text(text) => code.push_str(&text),
+                        Event::End(TagEnd::CodeBlock) => break,
+                        _ => {}
+                    }
+                }
+
+                let language = match &kind {
+                    CodeBlockKind::Fenced(info) => code_block_language(info),
+                    CodeBlockKind::Indented => None,
+                };
+
+                let html = match language
+                    .zip(diagrams)
+                    .and_then(|(language, diagrams)| diagrams.get(language, &code))
+                {
+                    Some(svg) => format!("<figure class=\"diagram\">{svg}</figure>\n"),
+                    None => highlight_code(&code, language),
+                };
+
+                output.push(Event::Html(html.into()));
+            }
+            Event::Start(Tag::Heading {
+                level, classes, attrs, ..
+            }) => {
+                let mut inner = Vec::new();
+                let mut title = String::new();
+
+                for event in events.by_ref() {
+                    match &event {
+                        Event::End(TagEnd::Heading(_)) => break,
+                        Event::Text(text) | Event::Code(text) => title.push_str(text),
+                        _ => {}
+                    }
+
+                    inner.push(event);
+                }
+
+                let id = unique_heading_id(&mut heading_ids, &title);
+
+                output.push(Event::Start(Tag::Heading {
+                    level,
+                    id: Some(id.clone().into()),
+                    classes,
+                    attrs,
+                }));
+                output.extend(inner);
+
+                if enable_links {
+                    output.push(Event::Html(
+                        format!(r##"<a class="heading-anchor" href="#{id}" aria-hidden="true"></a>"##).into(),
+                    ));
+                }
+
+                output.push(Event::End(TagEnd::Heading(level)));
+
+                headings.push(TocHeading {
+                    level: level as u8,
+                    id,
+                    title_html: escape_html(title.trim()),
+                });
+            }
+            Event::Start(Tag::Paragraph) => {
+                let mut inner = Vec::new();
+                let mut text = String::new();
+                let mut is_text_only = true;
+
+                for event in events.by_ref() {
+                    match &event {
+                        Event::End(TagEnd::Paragraph) => break,
+                        Event::Text(value) => text.push_str(value),
+                        _ => is_text_only = false,
+                    }
+
+                    inner.push(event);
+                }
+
+                if is_text_only && text.trim().eq_ignore_ascii_case(TOC_MARKER) {
+                    toc_positions.push(output.len());
+                    output.push(Event::Html("".into()));
+                } else {
+                    output.push(Event::Start(Tag::Paragraph));
+                    output.extend(inner);
+                    output.push(Event::End(TagEnd::Paragraph));
+                }
+            }
+            _ => output.push(event),
+        }
+    }
+
+    if enable_links && !toc_positions.is_empty() {
+        let toc_html = render_toc(&headings);
+
+        for position in toc_positions {
+            output[position] = Event::Html(toc_html.clone().into());
+        }
+    }
+
+    output
+}
+
+fn code_block_language(info: &str) -> Option<&str> {
+    info.split([' ', ',', '{']).next().filter(|value| !value.is_empty())
+}
+
+/// Converts LaTeX to MathML, keeping the source as code when it can't be converted or the output has elements that are
+/// not MathML.
+fn render_math(latex: &str, display_style: DisplayStyle) -> String {
+    match latex_to_mathml(latex, display_style) {
+        Ok(mathml) if is_safe_mathml(&mathml) => mathml,
+        _ => format!(r#"<code class="math-error">{}</code>"#, escape_html(latex)),
+    }
+}
+
+fn is_safe_mathml(mathml: &str) -> bool {
+    has_only_safe_tags(mathml, MATHML_ELEMENTS)
+}
+
+/// Checks the SVG of a diagram before it is inlined in the content: only drawing and label elements are allowed, without
+/// scripts, links or styles that load external resources.
+#[cfg(any(feature = "diagrams", test))]
+pub(crate) fn is_safe_svg(svg: &str) -> bool {
+    !REGEX_UNSAFE_CSS.is_match(svg) && has_only_safe_tags(svg, SVG_ELEMENTS)
+}
+
+fn has_only_safe_tags(html: &str, elements: &[&str]) -> bool {
+    REGEX_HTML_TAGS.find_iter(html).all(|tag| {
+        let tag = tag.as_str();
+        let name = tag
+            .trim_start_matches(['<', '/'])
+            .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .next()
+            .unwrap_or_default();
+
+        elements.contains(&name) && !REGEX_UNSAFE_ATTRIBUTES.is_match(tag)
+    })
+}
+
+fn highlight_code(code: &str, language: Option<&str>) -> String {
+    let class = language
+        .map(|language| format!(r#" class="language-{}""#, escape_html(language)))
+        .unwrap_or_default();
+    let plain_html = || format!("<pre><code{class}>{}</code></pre>\n", escape_html(code));
+
+    let Some(syntax) = language.and_then(|language| SYNTAX_SET.find_syntax_by_token(language)) else {
+        return plain_html();
+    };
+
+    let mut generator = ClassedHTMLGenerator::new_with_class_style(syntax, &SYNTAX_SET, HIGHLIGHT_CLASS_STYLE);
+
+    for line in LinesWithEndings::from(code) {
+        if generator.parse_html_for_line_which_includes_newline(line).is_err() {
+            return plain_html();
+        }
+    }
+
+    format!("<pre class=\"hl\"><code{class}>{}</code></pre>\n", generator.finalize())
+}
+
+fn unique_heading_id(heading_ids: &mut HashMap<String, usize>, title: &str) -> String {
+    let mut id = title
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    if id.is_empty() {
+        id = "section".to_owned();
+    }
+
+    let count = heading_ids.entry(id.clone()).or_insert(0);
+
+    *count += 1;
+
+    if *count > 1 {
+        format!("{id}-{}", *count - 1)
+    } else {
+        id
+    }
+}
+
+fn render_toc(headings: &[TocHeading]) -> String {
+    let Some(min_level) = headings.iter().map(|heading| heading.level).min() else {
+        return String::new();
+    };
+
+    let mut html = r#"<nav class="toc"><ul>"#.to_owned();
+    let mut depth = 0;
+
+    for (index, heading) in headings.iter().enumerate() {
+        let level = (heading.level - min_level) as usize;
+
+        if index > 0 {
+            if level > depth {
+                // Skipped levels are nested only once, so the list stays valid.
+                html.push_str("<ul>");
+                depth += 1;
+            } else {
+                html.push_str("</li>");
+
+                while depth > level {
+                    html.push_str("</ul></li>");
+                    depth -= 1;
+                }
+            }
+        }
+
+        html.push_str(&format!(
+            r##"<li><a href="#{}">{}</a>"##,
+            heading.id, heading.title_html
+        ));
+    }
+
+    html.push_str("</li>");
+
+    while depth > 0 {
+        html.push_str("</ul></li>");
+        depth -= 1;
+    }
+
+    html.push_str("</ul></nav>");
+
+    html
+}
+
+/// Returns a table of contents for HTML generated by `parse_html`, when it has at least `min_headings` headings.
+pub fn parse_toc_html(content_html: &str, min_headings: usize) -> Option<String> {
+    let headings = REGEX_HTML_HEADINGS
+        .captures_iter(content_html)
+        .map(|captures| TocHeading {
+            level: captures[1].parse().unwrap_or(2),
+            id: captures[2].to_owned(),
+            title_html: REGEX_HTML_TAGS.replace_all(&captures[3], "").trim().to_owned(),
+        })
+        .collect::<Vec<_>>();
+
+    if headings.is_empty() || headings.len() < min_headings {
+        return None;
+    }
+
+    Some(render_toc(&headings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_highlight_fenced_code_blocks() {
+        let html = parse_html("```rust\nfn main() {}\n```", true);
+
+        assert!(html.starts_with(r#"<pre class="hl"><code class="language-rust"><span class="hl-source hl-rust">"#));
+        assert!(html.contains("hl-keyword"));
+    }
+
+    #[test]
+    fn should_escape_code_blocks_without_known_language() {
+        assert_eq!(
+            parse_html("```unknown\n<script>\n```", true),
+            "<pre><code class=\"language-unknown\">&lt;script&gt;\n</code></pre>\n"
+        );
+        assert_eq!(parse_html("    a < b\n", true), "<pre><code>a &lt; b\n</code></pre>\n");
+    }
+
+    #[test]
+    fn should_add_unique_ids_and_anchors_to_headings() {
+        let html = parse_html("## Getting started\n\n## Getting started", true);
+
+        assert_eq!(
+            html,
+            "<h2 id=\"getting-started\">Getting started<a class=\"heading-anchor\" href=\"#getting-started\" \
+             aria-hidden=\"true\"></a></h2>\n<h2 id=\"getting-started-1\">Getting started<a class=\"heading-anchor\" \
+             href=\"#getting-started-1\" aria-hidden=\"true\"></a></h2>\n"
+        );
+    }
+
+    #[test]
+    fn should_replace_toc_marker_with_table_of_contents() {
+        let html = parse_html("[toc]\n\n## One\n\n### Two\n\n## Three", true);
+
+        assert!(html.starts_with(
+            r##"<nav class="toc"><ul><li><a href="#one">One</a><ul><li><a href="#two">Two</a></li></ul></li><li><a href="#three">Three</a></li></ul></nav>"##
+        ));
+
+        let html = parse_html("[toc]\n\n## One", false);
+
+        assert!(!html.contains("toc"));
+    }
+
+    #[test]
+    fn should_parse_toc_from_html() {
+        let html = parse_html("## One & two\n\n### `Three`", true);
+
+        assert_eq!(
+            parse_toc_html(&html, 2),
+            Some(
+                r##"<nav class="toc"><ul><li><a href="#one-two">One &amp; two</a><ul><li><a href="#three">Three</a></li></ul></li></ul></nav>"##
+                    .to_owned()
+            )
+        );
+        assert_eq!(parse_toc_html(&html, 3), None);
+    }
+
+    #[test]
+    fn should_render_math_as_mathml() {
+        let html = parse_html_with_shortcodes(
+            "Euler: $e^{i\\pi} + 1 = 0$\n\n$$x^2$$",
+            true,
+            &RenderedDiagrams::default(),
+            &Mentions::default(),
+        );
+
+        assert!(html.contains("<p>Euler: <math"));
+        assert!(html.contains(r#"display="block""#));
+        assert!(!html.contains('$'));
+    }
+
+    #[test]
+    fn should_not_render_math_outside_of_post_content() {
+        assert_eq!(parse_html("From $5 to $10", true), "<p>From $5 to $10</p>\n");
+    }
+
+    #[test]
+    fn should_only_allow_mathml_elements() {
+        assert!(is_safe_mathml(r#"<math display="inline"><mi>x</mi></math>"#));
+        assert!(!is_safe_mathml("<math><script>alert(1)</script></math>"));
+        assert!(!is_safe_mathml(r#"<math><mi onclick="alert(1)">x</mi></math>"#));
+    }
+
+    #[test]
+    fn should_only_allow_safe_svg() {
+        assert!(is_safe_svg(
+            r#"<svg viewBox="0 0 10 10"><style>.node { fill: #fff; }</style><g><rect width="10"/></g></svg>"#
+        ));
+        assert!(!is_safe_svg("<svg><script>alert(1)</script></svg>"));
+        assert!(!is_safe_svg(
+            r#"<svg><a xlink:href="https://example.com"><text>A</text></a></svg>"#
+        ));
+        assert!(!is_safe_svg(r#"<svg><rect onload="alert(1)"/></svg>"#));
+        assert!(!is_safe_svg(
+            r#"<svg><style>@import url("https://example.com/track.css");</style></svg>"#
+        ));
+        assert!(!is_safe_svg(
+            r#"<svg><rect style="fill: url(https://example.com/track)"/></svg>"#
+        ));
+        assert!(is_safe_svg(r#"<svg><rect style="fill: url(#gradient)"/></svg>"#));
+    }
+
+    #[test]
+    fn should_replace_diagram_blocks_with_svg() {
+        let input = "```mermaid\ngraph TD;\nA-->B;\n```\n\n```mermaid\ngraph LR;\n```";
+        let mut diagrams = RenderedDiagrams::default();
+
+        diagrams.insert("mermaid", "graph TD;\nA-->B;\n", "<svg></svg>".to_owned());
+
+        assert_eq!(
+            parse_html_with_shortcodes(input, true, &diagrams, &Mentions::default()),
+            "<figure class=\"diagram\"><svg></svg></figure>\n<pre><code class=\"language-mermaid\">graph LR;\n</code></pre>\n"
+        );
+    }
+
+    #[test]
+    fn should_find_mentions_outside_of_code_and_links() {
+        let mentions = find_mentions(
+            "Hi @John and @jane.doe, (@john) @_bot_.\n\n`@code` [@link](/) me@example.com https://example.com/@path",
+        );
+
+        assert_eq!(mentions, vec!["john", "jane.doe", "_bot_"]);
+    }
+
+    #[test]
+    fn should_link_only_known_mentions() {
+        let mut mentions = Mentions::default();
+
+        mentions.insert("John", "https://example.com/@john".to_owned());
 
-    html_output
+        assert_eq!(
+            parse_html_with_mentions("Hi @john and @unknown.", true, &mentions),
+            "<p>Hi <a href=\"https://example.com/@john\">@john</a> and @unknown.</p>\n"
+        );
+        assert_eq!(
+            parse_html_with_mentions("Hi @john", false, &mentions),
+            "<p>Hi @john</p>\n"
+        );
+        assert_eq!(parse_html("Hi @john", true), "<p>Hi @john</p>\n");
+    }
 }
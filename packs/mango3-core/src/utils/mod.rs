@@ -2,8 +2,14 @@ mod info;
 
 #[cfg(feature = "cache")]
 mod cache_utils;
+#[cfg(feature = "diagrams")]
+mod diagrams;
+#[cfg(feature = "handlebars")]
+mod handlebars_site_helpers;
 #[cfg(feature = "handlebars")]
 mod handlebars_utils;
+#[cfg(feature = "http-client")]
+mod http_client;
 #[cfg(feature = "jobs")]
 mod jobs;
 #[cfg(feature = "locales")]
@@ -14,6 +20,12 @@ mod markdown;
 mod mutation;
 #[cfg(feature = "pagination")]
 mod pagination;
+#[cfg(feature = "post-import")]
+mod post_import;
+#[cfg(feature = "markdown")]
+mod shortcodes;
+#[cfg(feature = "static-website")]
+mod static_website;
 #[cfg(feature = "text-icon")]
 mod text_icon;
 #[cfg(feature = "validator")]
@@ -24,18 +36,51 @@ pub use info::{Info, INFO};
 #[cfg(feature = "cache")]
 #[allow(unused_imports)]
 pub(crate) use cache_utils::{async_redis_cache, AsyncRedisCacheTrait};
+#[cfg(feature = "diagrams")]
+pub(crate) use diagrams::cached_diagrams;
+#[cfg(feature = "diagrams")]
+pub use diagrams::render_diagrams;
+#[cfg(feature = "handlebars")]
+pub use handlebars_site_helpers::TemplateScope;
 #[cfg(feature = "handlebars")]
-pub use handlebars_utils::render_handlebars;
+pub use handlebars_utils::{dynamic_template_ttl, is_dynamic_template, render_handlebars, RenderedTemplate};
+#[cfg(feature = "http-client")]
+#[allow(unused_imports)]
+pub(crate) use http_client::{HttpClient, HttpError, HttpResponse, HttpRules, RateLimiter};
 #[cfg(feature = "jobs")]
-pub use jobs::{AdminMailerJob, GuestMailerJob, Jobs, MailerJob};
+pub use jobs::{
+    AdminMailerJob, ExportWebsiteJob, GenerateStaticWebsiteJob, GuestMailerJob, ImportPostsJob, Jobs, MailerJob,
+    RenderPostDiagramsJob,
+};
 #[cfg(feature = "locales")]
 pub use locales::I18n;
+#[cfg(any(feature = "search-result", feature = "static-website"))]
+pub(crate) use markdown::escape_html;
 #[cfg(feature = "markdown")]
-pub use markdown::parse_html;
+pub use markdown::{
+    find_mentions, parse_html, parse_html_with_mentions, parse_html_with_shortcodes, parse_toc_html, Mentions,
+    RenderedDiagrams,
+};
 #[cfg(feature = "mutation")]
 pub use mutation::{MutError, MutResult, MutSuccess};
 #[cfg(feature = "pagination")]
 pub use pagination::{cursor_page, CursorPage, CursorPageParams};
+#[cfg(feature = "post-import")]
+pub use post_import::PostImportReport;
+#[cfg(feature = "post-import")]
+#[allow(unused_imports)]
+pub(crate) use post_import::{
+    parse_post_import, tag_to_hashtag, ArchiveNavigationItem, ArchiveWebsite, FrontMatter, FrontMatterTags,
+    ImportableImage, ImportablePost, ImportableWebsite, ARCHIVE_NAVIGATION_PATH, ARCHIVE_WEBSITE_PATH,
+};
+#[cfg(feature = "markdown")]
+pub use shortcodes::{register_shortcode, Shortcode};
+#[cfg(feature = "static-website")]
+pub use static_website::StaticWebsiteReport;
+#[cfg(feature = "static-website")]
+pub(crate) use static_website::{
+    render_static_feed, render_static_page, StaticFeedItem, StaticLayout, StaticWebsiteWriter, STATIC_POSTS_PER_PAGE,
+};
 #[cfg(feature = "text-icon")]
 pub use text_icon::text_icon;
 #[cfg(feature = "validator")]
@@ -81,6 +126,22 @@ pub fn parse_date(value: &str) -> Option<chrono::NaiveDate> {
     chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
 }
 
+#[cfg(feature = "parse-size")]
+pub fn parse_size(value: &str) -> Option<size::Size> {
+    use std::str::FromStr;
+
+    size::Size::from_str(value).ok().filter(|size| size.bytes() >= 0)
+}
+
+#[cfg(any(feature = "post", feature = "website"))]
+pub fn text_search_language_code(language: &str) -> &'static str {
+    crate::constants::TEXT_SEARCH_LANGUAGE_CODES
+        .iter()
+        .find(|(name, _)| *name == language)
+        .map(|(_, code)| *code)
+        .unwrap_or_default()
+}
+
 #[cfg(feature = "verify-password")]
 pub(crate) fn verify_password(password: &str, encrypted_password: &str) -> bool {
     use argon2::{Argon2, PasswordHash, PasswordVerifier};
@@ -0,0 +1,563 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::path::{Component, Path, PathBuf};
+use std::sync::LazyLock;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use regex::Regex;
+use roxmltree::{Document, Node};
+use serde::{Deserialize, Serialize};
+
+pub(crate) const ARCHIVE_NAVIGATION_PATH: &str = "navigation.yaml";
+pub(crate) const ARCHIVE_WEBSITE_PATH: &str = "website.yaml";
+
+const MAX_ARCHIVE_ENTRY_SIZE: u64 = 32 * 1024 * 1024;
+const MAX_ARCHIVE_SIZE: u64 = 512 * 1024 * 1024;
+const NAMESPACE_CONTENT: &str = "http://purl.org/rss/1.0/modules/content/";
+const NAMESPACE_WP: &str = "http://wordpress.org/export/";
+
+static REGEX_MARKDOWN_IMAGE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"!\[[^\]]*\]\(\s*<?(?<src>[^)\s>]+)").unwrap());
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PostImportReport {
+    pub imported_posts: Vec<String>,
+    pub skipped_items: Vec<PostImportSkippedItem>,
+}
+
+impl PostImportReport {
+    pub(crate) fn skip(&mut self, name: impl Into<String>, reason: impl Into<String>) {
+        self.skipped_items.push(PostImportSkippedItem {
+            name: name.into(),
+            reason: reason.into(),
+        });
+    }
+}
+
+impl std::fmt::Display for PostImportReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Imported posts: {}", self.imported_posts.len())?;
+
+        for title in &self.imported_posts {
+            writeln!(f, "  {title}")?;
+        }
+
+        write!(f, "Skipped items: {}", self.skipped_items.len())?;
+
+        for item in &self.skipped_items {
+            write!(f, "\n  {}: {}", item.name, item.reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PostImportSkippedItem {
+    pub name: String,
+    pub reason: String,
+}
+
+pub(crate) enum ImportableImage {
+    Embedded { path: String, data: Vec<u8> },
+    Remote(String),
+}
+
+impl ImportableImage {
+    pub fn file_name(&self) -> &str {
+        let path = match self {
+            Self::Embedded { path, .. } => path.as_str(),
+            Self::Remote(url) => url.split(['?', '#']).next().unwrap_or_default(),
+        };
+
+        path.rsplit('/').next().unwrap_or_default()
+    }
+
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Embedded { path, .. } => path,
+            Self::Remote(url) => url,
+        }
+    }
+}
+
+pub(crate) struct ImportablePost {
+    pub title: String,
+    pub slug: String,
+    pub content: String,
+    pub variables: String,
+    pub tags: Vec<String>,
+    pub is_published: bool,
+    pub published_at: Option<DateTime<Utc>>,
+    pub cover_image: Option<String>,
+    pub images: Vec<(String, ImportableImage)>,
+}
+
+pub(crate) struct ImportableWebsite {
+    pub description: String,
+    pub light_theme: String,
+    pub dark_theme: String,
+    pub icon_image: Option<ImportableImage>,
+    pub cover_image: Option<ImportableImage>,
+}
+
+pub(crate) struct PostImport {
+    pub posts: Vec<ImportablePost>,
+    pub website: Option<ImportableWebsite>,
+    pub navigation_items: Vec<ArchiveNavigationItem>,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+pub(crate) struct ArchiveNavigationItem {
+    pub title: String,
+    pub url: String,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct ArchiveWebsite {
+    pub name: String,
+    pub description: String,
+    pub language: String,
+    pub light_theme: String,
+    pub dark_theme: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_image: Option<String>,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct FrontMatter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    pub draft: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published: Option<bool>,
+    pub tags: FrontMatterTags,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<serde_json::Value>,
+    #[serde(alias = "cover", alias = "image", skip_serializing_if = "Option::is_none")]
+    pub cover_image: Option<String>,
+}
+
+impl FrontMatter {
+    pub fn to_markdown(&self, content: &str) -> String {
+        format!(
+            "---\n{}---\n\n{}\n",
+            serde_yaml::to_string(self).unwrap_or_default(),
+            content
+        )
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(untagged)]
+pub(crate) enum FrontMatterTags {
+    #[default]
+    None,
+    List(Vec<String>),
+    Text(String),
+}
+
+impl FrontMatterTags {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            Self::None => vec![],
+            Self::List(tags) => tags,
+            Self::Text(tags) => tags.split(',').map(|tag| tag.trim().to_owned()).collect(),
+        }
+    }
+}
+
+/// Parses a WordPress WXR export or a zip archive of Markdown files with YAML front matter. Returns `None` when
+/// the data is in neither format; items that can't be imported are recorded in the report.
+pub(crate) fn parse_post_import(data: &[u8], report: &mut PostImportReport) -> Option<PostImport> {
+    if data.starts_with(b"PK") {
+        parse_markdown_archive(data, report)
+    } else {
+        Some(PostImport {
+            posts: parse_wxr(std::str::from_utf8(data).ok()?, report)?,
+            website: None,
+            navigation_items: vec![],
+        })
+    }
+}
+
+/// Converts a tag into a hashtag name, returning `None` when nothing of it fits a hashtag.
+pub(crate) fn tag_to_hashtag(tag: &str) -> Option<String> {
+    let name = tag
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+fn find_child<'a, 'input>(node: Node<'a, 'input>, namespace: &str, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| {
+        child.tag_name().name() == name && child.tag_name().namespace().unwrap_or_default().starts_with(namespace)
+    })
+}
+
+fn child_text<'a>(node: Node<'a, '_>, namespace: &str, name: &str) -> Option<&'a str> {
+    find_child(node, namespace, name)?
+        .text()
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+}
+
+fn html_to_markdown(html: &str) -> String {
+    // WordPress stores paragraphs as blank line separated text unless the block editor was used.
+    let html = if html.contains("<p") {
+        html.to_owned()
+    } else {
+        html.split("\n\n")
+            .map(|paragraph| format!("<p>{}</p>", paragraph.trim().replace('\n', "<br>")))
+            .collect()
+    };
+
+    htmd::convert(&html).unwrap_or_default().trim().to_owned()
+}
+
+fn markdown_images(content: &str) -> impl Iterator<Item = &str> {
+    REGEX_MARKDOWN_IMAGE
+        .captures_iter(content)
+        .filter_map(|captures| captures.name("src"))
+        .map(|match_| match_.as_str())
+}
+
+fn is_remote_url(value: &str) -> bool {
+    value.starts_with("http://") || value.starts_with("https://")
+}
+
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_rfc2822(value))
+        .map(|date_time| date_time.to_utc())
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .or_else(|| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0))
+                .map(|date_time| date_time.and_utc())
+        })
+}
+
+fn slugify(value: &str) -> String {
+    value
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn split_front_matter(text: &str) -> (&str, &str) {
+    let Some(rest) = text.strip_prefix("---\n").or_else(|| text.strip_prefix("---\r\n")) else {
+        return ("", text);
+    };
+
+    let mut offset = 0;
+
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return (&rest[..offset], &rest[offset + line.len()..]);
+        }
+
+        offset += line.len();
+    }
+
+    ("", text)
+}
+
+fn resolve_archive_path(base: &Path, reference: &str) -> Option<String> {
+    let reference = reference.split(['?', '#']).next()?;
+    let mut path = PathBuf::new();
+
+    let components = if let Some(absolute) = reference.strip_prefix('/') {
+        Path::new(absolute).components().collect::<Vec<_>>()
+    } else {
+        base.components().chain(Path::new(reference).components()).collect()
+    };
+
+    for component in components {
+        match component {
+            Component::Normal(part) => path.push(part),
+            Component::ParentDir => {
+                path.pop();
+            }
+            _ => {}
+        }
+    }
+
+    Some(path.to_string_lossy().replace('\\', "/"))
+}
+
+fn archive_image(files: &HashMap<String, Vec<u8>>, base: &Path, reference: &str) -> Option<ImportableImage> {
+    if is_remote_url(reference) {
+        return Some(ImportableImage::Remote(reference.to_owned()));
+    }
+
+    let path = resolve_archive_path(base, reference)?;
+    let data = files.get(&path)?.clone();
+
+    Some(ImportableImage::Embedded { path, data })
+}
+
+fn parse_archive_website(files: &HashMap<String, Vec<u8>>, report: &mut PostImportReport) -> Option<ImportableWebsite> {
+    let website = serde_yaml::from_slice::<ArchiveWebsite>(files.get(ARCHIVE_WEBSITE_PATH)?)
+        .inspect_err(|error| report.skip(ARCHIVE_WEBSITE_PATH, format!("Invalid website settings: {error}")))
+        .ok()?;
+    let mut image = |reference: Option<&str>| {
+        let reference = reference?;
+        let image = archive_image(files, Path::new(""), reference);
+
+        if image.is_none() {
+            report.skip(
+                format!("{ARCHIVE_WEBSITE_PATH} ({reference})"),
+                "Image not found in the archive",
+            );
+        }
+
+        image
+    };
+
+    Some(ImportableWebsite {
+        icon_image: image(website.icon_image.as_deref()),
+        cover_image: image(website.cover_image.as_deref()),
+        description: website.description,
+        light_theme: website.light_theme,
+        dark_theme: website.dark_theme,
+    })
+}
+
+fn parse_markdown_archive(data: &[u8], report: &mut PostImportReport) -> Option<PostImport> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(data)).ok()?;
+    let mut files = HashMap::new();
+    let mut total_size = 0;
+
+    for index in 0..archive.len() {
+        let Ok(file) = archive.by_index(index) else {
+            continue;
+        };
+
+        if file.is_dir() {
+            continue;
+        }
+
+        let Some(path) = file.enclosed_name() else {
+            report.skip(file.name(), "Invalid path");
+            continue;
+        };
+
+        let path = path.to_string_lossy().replace('\\', "/");
+
+        if file.size() > MAX_ARCHIVE_ENTRY_SIZE {
+            report.skip(path, "File is too large");
+            continue;
+        }
+
+        if total_size + file.size() > MAX_ARCHIVE_SIZE {
+            report.skip(path, "Archive is too large");
+            continue;
+        }
+
+        let mut buffer = Vec::new();
+
+        if file.take(MAX_ARCHIVE_ENTRY_SIZE).read_to_end(&mut buffer).is_err() {
+            report.skip(path, "File could not be read");
+            continue;
+        }
+
+        total_size += buffer.len() as u64;
+
+        files.insert(path, buffer);
+    }
+
+    let mut markdown_paths = files
+        .keys()
+        .filter(|path| path.ends_with(".md") || path.ends_with(".markdown"))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    markdown_paths.sort();
+
+    let mut posts = Vec::new();
+
+    for path in markdown_paths {
+        let Ok(text) = std::str::from_utf8(&files[&path]) else {
+            report.skip(path, "File is not valid UTF-8");
+            continue;
+        };
+
+        let (yaml, body) = split_front_matter(text);
+        let front_matter = if yaml.trim().is_empty() {
+            FrontMatter::default()
+        } else {
+            match serde_yaml::from_str::<FrontMatter>(yaml) {
+                Ok(front_matter) => front_matter,
+                Err(error) => {
+                    report.skip(path, format!("Invalid front matter: {error}"));
+                    continue;
+                }
+            }
+        };
+
+        let file_path = Path::new(&path);
+        let base = file_path.parent().unwrap_or(Path::new(""));
+        let stem = file_path.file_stem().unwrap_or_default().to_string_lossy();
+        // Jekyll style file names are prefixed with the publication date.
+        let (stem_date, stem) = match stem.get(..11).and_then(|prefix| prefix.strip_suffix('-')) {
+            Some(prefix) if NaiveDate::parse_from_str(prefix, "%Y-%m-%d").is_ok() => {
+                (parse_date(prefix), stem[11..].to_owned())
+            }
+            _ => (None, stem.to_string()),
+        };
+
+        let content = body.trim().to_owned();
+        let mut images = Vec::new();
+
+        for reference in markdown_images(&content).chain(front_matter.cover_image.as_deref()) {
+            if images.iter().any(|(other, _)| other == reference) {
+                continue;
+            }
+
+            let Some(image) = archive_image(&files, base, reference) else {
+                report.skip(format!("{path} ({reference})"), "Image not found in the archive");
+                continue;
+            };
+
+            images.push((reference.to_owned(), image));
+        }
+
+        let title = front_matter.title.unwrap_or_else(|| stem.clone());
+
+        posts.push(ImportablePost {
+            slug: slugify(front_matter.slug.as_deref().unwrap_or(&stem)),
+            content,
+            variables: front_matter
+                .variables
+                .map(|variables| variables.to_string())
+                .unwrap_or_else(|| "{}".to_owned()),
+            tags: front_matter.tags.into_vec(),
+            is_published: front_matter.published.unwrap_or(!front_matter.draft),
+            published_at: front_matter.date.as_deref().and_then(parse_date).or(stem_date),
+            cover_image: front_matter.cover_image,
+            images,
+            title,
+        });
+    }
+
+    let navigation_items = match files.get(ARCHIVE_NAVIGATION_PATH) {
+        Some(data) => serde_yaml::from_slice(data)
+            .inspect_err(|error| report.skip(ARCHIVE_NAVIGATION_PATH, format!("Invalid navigation: {error}")))
+            .unwrap_or_default(),
+        None => vec![],
+    };
+
+    Some(PostImport {
+        website: parse_archive_website(&files, report),
+        navigation_items,
+        posts,
+    })
+}
+
+fn parse_wxr(xml: &str, report: &mut PostImportReport) -> Option<Vec<ImportablePost>> {
+    let document = Document::parse(xml).ok()?;
+    let channel = document
+        .root_element()
+        .children()
+        .find(|node| node.has_tag_name("channel"))?;
+    let items = channel
+        .children()
+        .filter(|node| node.has_tag_name("item"))
+        .collect::<Vec<_>>();
+    let attachment_urls = items
+        .iter()
+        .filter(|item| child_text(**item, NAMESPACE_WP, "post_type") == Some("attachment"))
+        .filter_map(|item| {
+            Some((
+                child_text(*item, NAMESPACE_WP, "post_id")?,
+                child_text(*item, NAMESPACE_WP, "attachment_url")?,
+            ))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut posts = Vec::new();
+
+    for item in items {
+        let title = child_text(item, "", "title").unwrap_or_default().to_owned();
+        let post_type = child_text(item, NAMESPACE_WP, "post_type").unwrap_or("post");
+
+        match post_type {
+            "attachment" | "nav_menu_item" => continue,
+            "post" => {}
+            _ => {
+                report.skip(title, format!("Unsupported post type: {post_type}"));
+                continue;
+            }
+        }
+
+        let status = child_text(item, NAMESPACE_WP, "status").unwrap_or("publish");
+
+        if matches!(status, "auto-draft" | "inherit" | "trash") {
+            report.skip(title, format!("Unsupported post status: {status}"));
+            continue;
+        }
+
+        let content = html_to_markdown(child_text(item, NAMESPACE_CONTENT, "encoded").unwrap_or_default());
+        let cover_image = item
+            .children()
+            .filter(|node| node.tag_name().name() == "postmeta")
+            .find(|meta| child_text(*meta, NAMESPACE_WP, "meta_key") == Some("_thumbnail_id"))
+            .and_then(|meta| attachment_urls.get(child_text(meta, NAMESPACE_WP, "meta_value")?))
+            .map(|url| url.to_string());
+        let mut images = Vec::new();
+
+        for reference in markdown_images(&content).chain(cover_image.as_deref()) {
+            if is_remote_url(reference) && !images.iter().any(|(other, _)| other == reference) {
+                images.push((reference.to_owned(), ImportableImage::Remote(reference.to_owned())));
+            }
+        }
+
+        let published_at = child_text(item, NAMESPACE_WP, "post_date_gmt")
+            .filter(|date| !date.starts_with("0000"))
+            .and_then(parse_date)
+            .or_else(|| child_text(item, "", "pubDate").and_then(parse_date));
+        let slug = child_text(item, NAMESPACE_WP, "post_name")
+            .and_then(|post_name| url::form_urlencoded::parse(post_name.as_bytes()).next())
+            .map(|(post_name, _)| slugify(&post_name))
+            .unwrap_or_else(|| slugify(&title));
+        let tags = item
+            .children()
+            .filter(|node| node.has_tag_name("category") && node.attribute("domain") == Some("post_tag"))
+            .filter_map(|node| node.text())
+            .map(|tag| tag.trim().to_owned())
+            .collect();
+
+        posts.push(ImportablePost {
+            title,
+            slug,
+            content,
+            variables: "{}".to_owned(),
+            tags,
+            is_published: status == "publish",
+            published_at,
+            cover_image,
+            images,
+        });
+    }
+
+    Some(posts)
+}
@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::{LazyLock, RwLock};
+
+use regex::{Captures, Regex};
+use uuid::Uuid;
+
+use crate::config::BASIC_CONFIG;
+
+use super::generate_random_string;
+use super::markdown::escape_html;
+
+static REGEX_SHORTCODE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\{\{<\s*(?P<closing>/)?(?P<name>[a-z][a-z0-9_-]*)(?P<args>(?:\s+[^\s<>]+)*)\s*>\}\}").unwrap()
+});
+static REGEX_YOUTUBE_ID: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:https://(?:www\.)?(?:youtube\.com/watch\?v=|youtu\.be/))?(?P<id>[A-Za-z0-9_-]{11})$").unwrap()
+});
+
+static SHORTCODES: LazyLock<RwLock<HashMap<String, Box<dyn Shortcode>>>> = LazyLock::new(|| {
+    let mut shortcodes: HashMap<String, Box<dyn Shortcode>> = HashMap::new();
+
+    shortcodes.insert("callout".to_owned(), Box::new(CalloutShortcode));
+    shortcodes.insert("gallery".to_owned(), Box::new(GalleryShortcode));
+    shortcodes.insert("youtube".to_owned(), Box::new(YoutubeShortcode));
+
+    RwLock::new(shortcodes)
+});
+
+const CALLOUT_KINDS: [&str; 4] = ["info", "tip", "warning", "danger"];
+const MAX_GALLERY_IMAGES: usize = 24;
+const PLACEHOLDER_NONCE_LENGTH: u8 = 16;
+
+/// Expands `{{< name args >}}` in post content into HTML. Raw HTML is removed from Markdown, so the returned HTML is
+/// inserted as is: arguments come from authors and must be validated or escaped by each implementation.
+pub trait Shortcode: Send + Sync {
+    /// Returns the HTML for the shortcode, or `None` to keep it as text when the arguments are not valid.
+    fn render(&self, args: &[&str]) -> Option<String>;
+
+    /// Returns the HTML for `{{< /name >}}` when the shortcode wraps Markdown content.
+    fn render_end(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Registers a shortcode, replacing any other one with the same name.
+pub fn register_shortcode<S: Shortcode + 'static>(name: &str, shortcode: S) {
+    SHORTCODES
+        .write()
+        .unwrap_or_else(|error| error.into_inner())
+        .insert(name.to_owned(), Box::new(shortcode));
+}
+
+/// Shortcodes taken out of the content by `extract_shortcodes`. Placeholders include a nonce generated on every render,
+/// so authors can't write one in their content to insert the HTML of a shortcode somewhere else.
+#[derive(Default)]
+pub(crate) struct ExtractedShortcodes {
+    nonce: String,
+    items: Vec<ExtractedShortcode>,
+}
+
+struct ExtractedShortcode {
+    source: String,
+    html: String,
+    /// Index of the other end of a block shortcode.
+    pair: Option<usize>,
+}
+
+impl ExtractedShortcodes {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("MANGO3SHORTCODE{}N{index}X", self.nonce)
+    }
+
+    fn push(&mut self, source: &str, html: String, pair: Option<usize>) -> String {
+        let index = self.items.len();
+
+        if let Some(pair) = pair {
+            self.items[pair].pair = Some(index);
+        }
+
+        self.items.push(ExtractedShortcode {
+            source: source.to_owned(),
+            html,
+            pair,
+        });
+
+        self.placeholder(index)
+    }
+}
+
+/// Replaces the shortcodes outside of the code ranges with placeholders that go through the Markdown parser as
+/// paragraphs, returning the new input and the extracted shortcodes.
+pub(crate) fn extract_shortcodes(input: &str, code_ranges: &[Range<usize>]) -> (String, ExtractedShortcodes) {
+    let shortcodes = SHORTCODES.read().unwrap_or_else(|error| error.into_inner());
+    let mut extracted = ExtractedShortcodes {
+        nonce: generate_random_string(PLACEHOLDER_NONCE_LENGTH),
+        items: Vec::new(),
+    };
+    let mut open_blocks: Vec<(&str, usize)> = Vec::new();
+
+    let mut output = REGEX_SHORTCODE
+        .replace_all(input, |captures: &Captures| {
+            let match_ = captures.get(0).expect("Could not get match");
+
+            if code_ranges.iter().any(|range| range.contains(&match_.start())) {
+                return match_.as_str().to_owned();
+            }
+
+            let name = captures.name("name").expect("Could not get name").as_str();
+            let Some((name, shortcode)) = shortcodes.get_key_value(name) else {
+                return match_.as_str().to_owned();
+            };
+
+            let (rendered, pair) = if captures.name("closing").is_some() {
+                // Only close the last block that was opened, so the HTML stays balanced.
+                if open_blocks.last().map(|(open_name, _)| *open_name) != Some(name.as_str()) {
+                    return match_.as_str().to_owned();
+                }
+
+                let (_, open_index) = open_blocks.pop().expect("Could not get open block");
+
+                (shortcode.render_end(), Some(open_index))
+            } else {
+                let args = captures
+                    .name("args")
+                    .map(|args| args.as_str().split_whitespace().collect::<Vec<_>>())
+                    .unwrap_or_default();
+                let rendered = shortcode.render(&args);
+
+                if rendered.is_some() && shortcode.render_end().is_some() {
+                    open_blocks.push((name, extracted.items.len()));
+                }
+
+                (rendered, None)
+            };
+
+            let Some(rendered) = rendered else {
+                return match_.as_str().to_owned();
+            };
+
+            format!("\n\n{}\n\n", extracted.push(match_.as_str(), rendered, pair))
+        })
+        .into_owned();
+
+    while let Some((name, open_index)) = open_blocks.pop() {
+        if let Some(rendered) = shortcodes.get(name).and_then(|shortcode| shortcode.render_end()) {
+            let placeholder = extracted.push("", rendered, Some(open_index));
+
+            output.push_str(&format!("\n\n{placeholder}\n"));
+        }
+    }
+
+    (output, extracted)
+}
+
+/// Replaces the placeholders left by `extract_shortcodes` in the generated HTML. Shortcodes are only expanded when their
+/// placeholder, and the one of the other end of a block, are paragraphs of their own; anywhere else they are kept as
+/// text.
+pub(crate) fn restore_shortcodes(mut output: String, extracted: &ExtractedShortcodes) -> String {
+    let is_standalone = (0..extracted.items.len())
+        .map(|index| output.contains(&format!("<p>{}</p>", extracted.placeholder(index))))
+        .collect::<Vec<_>>();
+
+    for (index, item) in extracted.items.iter().enumerate().rev() {
+        let placeholder = extracted.placeholder(index);
+
+        output = if is_standalone[index] && item.pair.is_none_or(|pair| is_standalone[pair]) {
+            output.replace(&format!("<p>{placeholder}</p>"), &item.html)
+        } else {
+            output.replace(&placeholder, &escape_html(&item.source))
+        };
+    }
+
+    output
+}
+
+struct CalloutShortcode;
+
+impl Shortcode for CalloutShortcode {
+    fn render(&self, args: &[&str]) -> Option<String> {
+        let kind = args
+            .first()
+            .filter(|kind| CALLOUT_KINDS.contains(kind))
+            .unwrap_or(&CALLOUT_KINDS[0]);
+
+        Some(format!(
+            r#"<div class="shortcode-callout shortcode-callout-{kind}" role="note">"#
+        ))
+    }
+
+    fn render_end(&self) -> Option<String> {
+        Some("</div>".to_owned())
+    }
+}
+
+struct GalleryShortcode;
+
+impl Shortcode for GalleryShortcode {
+    fn render(&self, args: &[&str]) -> Option<String> {
+        let images = args
+            .iter()
+            .flat_map(|arg| arg.split(','))
+            .filter_map(|id| Uuid::try_parse(id.trim()).ok())
+            .take(MAX_GALLERY_IMAGES)
+            .map(|id| {
+                let url = BASIC_CONFIG.blob_url(id);
+
+                format!(
+                    r#"<a href="{url}" target="_blank"><img src="{url}?width=400&amp;height=400&amp;fill=true" alt="" loading="lazy"></a>"#
+                )
+            })
+            .collect::<String>();
+
+        if images.is_empty() {
+            return None;
+        }
+
+        Some(format!(r#"<div class="shortcode-gallery">{images}</div>"#))
+    }
+}
+
+struct YoutubeShortcode;
+
+impl Shortcode for YoutubeShortcode {
+    fn render(&self, args: &[&str]) -> Option<String> {
+        let id = REGEX_YOUTUBE_ID.captures(args.first()?)?.name("id")?.as_str();
+
+        Some(format!(
+            r#"<div class="shortcode-embed"><iframe src="https://www.youtube-nocookie.com/embed/{id}" title="YouTube" loading="lazy" referrerpolicy="strict-origin-when-cross-origin" allow="encrypted-media; picture-in-picture; fullscreen" sandbox="allow-scripts allow-same-origin allow-presentation allow-popups" allowfullscreen></iframe></div>"#
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{parse_html_with_shortcodes, Mentions, RenderedDiagrams};
+
+    use super::*;
+
+    fn parse(input: &str) -> String {
+        parse_html_with_shortcodes(input, true, &RenderedDiagrams::default(), &Mentions::default())
+    }
+
+    #[test]
+    fn should_render_youtube_embed() {
+        let html = parse("Intro\n\n{{< youtube dQw4w9WgXcQ >}}");
+
+        assert!(html.contains(r#"src="https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ""#));
+        assert!(!html.contains("<p><div"));
+    }
+
+    #[test]
+    fn should_keep_invalid_shortcodes_as_text() {
+        let html = parse(r#"{{< youtube "><script> >}}"#);
+
+        assert!(!html.contains("<iframe"));
+        assert!(!html.contains("<script>"));
+
+        let html = parse("{{< unknown value >}}");
+
+        assert!(html.contains("{{&lt; unknown value &gt;}}"));
+    }
+
+    #[test]
+    fn should_not_expand_shortcodes_in_code() {
+        let html = parse("`{{< youtube dQw4w9WgXcQ >}}`");
+
+        assert!(!html.contains("<iframe"));
+
+        let html = parse("```\n{{< youtube dQw4w9WgXcQ >}}\n```");
+
+        assert!(!html.contains("<iframe"));
+    }
+
+    #[test]
+    fn should_wrap_markdown_in_callout() {
+        let html = parse("{{< callout warning >}}\n**Careful**\n{{< /callout >}}");
+
+        assert_eq!(
+            html,
+            "<div class=\"shortcode-callout shortcode-callout-warning\" role=\"note\">\n<p><strong>Careful</strong></p>\n</div>\n"
+        );
+    }
+
+    #[test]
+    fn should_close_unclosed_callouts() {
+        let html = parse("{{< /callout >}}{{< callout danger >}}Text");
+
+        assert_eq!(html.matches("<div").count(), html.matches("</div>").count());
+    }
+
+    #[test]
+    fn should_not_expand_placeholders_written_in_content() {
+        let html = parse("MANGO3SHORTCODEN0X\n\n{{< youtube dQw4w9WgXcQ >}}");
+
+        assert!(html.contains("<p>MANGO3SHORTCODEN0X</p>"));
+        assert_eq!(html.matches("<iframe").count(), 1);
+    }
+
+    #[test]
+    fn should_keep_shortcodes_as_text_outside_of_paragraphs() {
+        let (input, extracted) =
+            extract_shortcodes("{{< callout >}}Text{{< /callout >}}{{< youtube dQw4w9WgXcQ >}}", &[]);
+        let placeholders = (0..3).map(|index| extracted.placeholder(index)).collect::<Vec<_>>();
+
+        assert!(placeholders.iter().all(|placeholder| input.contains(placeholder)));
+
+        let html = restore_shortcodes(
+            format!(
+                "<p>{}</p>\n<p>Text</p>\n<h2>{}</h2>\n<p>{}</p>\n",
+                placeholders[0], placeholders[1], placeholders[2]
+            ),
+            &extracted,
+        );
+
+        assert_eq!(
+            html,
+            format!(
+                "<p>{{{{&lt; callout &gt;}}}}</p>\n<p>Text</p>\n<h2>{{{{&lt; /callout &gt;}}}}</h2>\n{}\n",
+                extracted.items[2].html
+            )
+        );
+    }
+
+    #[test]
+    fn should_render_gallery_of_blobs() {
+        let id = Uuid::new_v4();
+        let html = parse(&format!("{{{{< gallery {id} not-an-id >}}}}"));
+
+        assert!(html.contains(&format!("{}?width=400", BASIC_CONFIG.blob_url(id))));
+        assert_eq!(html.matches("<img").count(), 1);
+    }
+
+    #[test]
+    fn should_render_registered_shortcodes() {
+        struct BadgeShortcode;
+
+        impl Shortcode for BadgeShortcode {
+            fn render(&self, args: &[&str]) -> Option<String> {
+                Some(format!(r#"<span class="badge">{}</span>"#, args.len()))
+            }
+        }
+
+        register_shortcode("test_badge", BadgeShortcode);
+
+        let html = parse("{{< test_badge a b >}}");
+
+        assert!(html.contains(r#"<span class="badge">2</span>"#));
+    }
+}
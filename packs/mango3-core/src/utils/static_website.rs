@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::escape_html;
+
+pub(crate) const STATIC_POSTS_PER_PAGE: usize = 10;
+
+const MANIFEST_PATH: &str = ".mango3-static.json";
+const STYLE: &str = "body{font-family:system-ui,sans-serif;line-height:1.6;margin:0 auto;max-width:720px;padding:1rem}\
+header{align-items:center;display:flex;flex-wrap:wrap;gap:1rem;justify-content:space-between;margin-bottom:2rem}\
+header a{color:inherit;text-decoration:none}header img{border-radius:.25rem;vertical-align:middle}\
+nav{display:flex;flex-wrap:wrap;gap:1rem}img{height:auto;max-width:100%}article{margin-bottom:2rem}\
+.pagination{display:flex;justify-content:space-between}pre{background:#f6f8fa;overflow-x:auto;padding:1rem}\
+.hl-comment{color:#6a737d}.hl-constant,.hl-support{color:#005cc5}.hl-entity{color:#6f42c1}\
+.hl-keyword,.hl-storage{color:#d73a49}.hl-string{color:#032f62}.heading-anchor{margin-left:.5rem;text-decoration:none}\
+.heading-anchor::before{content:\"#\"}";
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StaticWebsiteReport {
+    pub written_files: usize,
+    pub unchanged_files: usize,
+    pub removed_files: usize,
+}
+
+impl std::fmt::Display for StaticWebsiteReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} files written, {} unchanged, {} removed",
+            self.written_files, self.unchanged_files, self.removed_files
+        )
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct StaticWebsiteManifest {
+    files: HashMap<String, String>,
+    dependencies: HashMap<String, Vec<String>>,
+}
+
+/// Keeps track of the files generated for a static website, so unchanged pages and assets are not rendered or
+/// written again and files that are no longer generated are removed.
+pub(crate) struct StaticWebsiteWriter {
+    root: PathBuf,
+    force: bool,
+    previous_manifest: StaticWebsiteManifest,
+    manifest: StaticWebsiteManifest,
+    report: StaticWebsiteReport,
+}
+
+impl StaticWebsiteWriter {
+    pub fn new(root: PathBuf, force: bool) -> Self {
+        let previous_manifest = std::fs::read(root.join(MANIFEST_PATH))
+            .ok()
+            .and_then(|data| serde_json::from_slice(&data).ok())
+            .unwrap_or_default();
+
+        Self {
+            root,
+            force,
+            previous_manifest,
+            manifest: StaticWebsiteManifest::default(),
+            report: StaticWebsiteReport::default(),
+        }
+    }
+
+    /// Returns `true` and keeps the file, along with the files it depends on, when it was generated with the same
+    /// fingerprint in a previous run.
+    pub fn keep_if_fresh(&mut self, path: &str, fingerprint: &str) -> bool {
+        if self.manifest.files.contains_key(path) {
+            return true;
+        }
+
+        if self.force || !self.is_fresh(path, Some(fingerprint)) {
+            return false;
+        }
+
+        self.keep(path);
+
+        let dependencies = self
+            .previous_manifest
+            .dependencies
+            .get(path)
+            .cloned()
+            .unwrap_or_default();
+
+        for dependency in &dependencies {
+            if !self.manifest.files.contains_key(dependency) && self.is_fresh(dependency, None) {
+                self.keep(dependency);
+            }
+        }
+
+        self.manifest.dependencies.insert(path.to_owned(), dependencies);
+
+        true
+    }
+
+    fn is_fresh(&self, path: &str, fingerprint: Option<&str>) -> bool {
+        self.previous_manifest
+            .files
+            .get(path)
+            .is_some_and(|previous| fingerprint.is_none_or(|fingerprint| previous == fingerprint))
+            && self.root.join(path).exists()
+    }
+
+    fn keep(&mut self, path: &str) {
+        let fingerprint = self.previous_manifest.files.get(path).cloned().unwrap_or_default();
+
+        self.manifest.files.insert(path.to_owned(), fingerprint);
+        self.report.unchanged_files += 1;
+    }
+
+    pub fn write(
+        &mut self,
+        path: &str,
+        fingerprint: &str,
+        data: &[u8],
+        dependencies: Vec<String>,
+    ) -> std::io::Result<()> {
+        let file_path = self.root.join(path);
+
+        if self.force || std::fs::read(&file_path).ok().as_deref() != Some(data) {
+            if let Some(parent) = file_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            std::fs::write(&file_path, data)?;
+
+            self.report.written_files += 1;
+        } else {
+            self.report.unchanged_files += 1;
+        }
+
+        self.manifest.files.insert(path.to_owned(), fingerprint.to_owned());
+
+        if !dependencies.is_empty() {
+            self.manifest.dependencies.insert(path.to_owned(), dependencies);
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> std::io::Result<StaticWebsiteReport> {
+        for path in self.previous_manifest.files.keys() {
+            if !self.manifest.files.contains_key(path) && std::fs::remove_file(self.root.join(path)).is_ok() {
+                self.report.removed_files += 1;
+
+                remove_empty_parents(&self.root, &self.root.join(path));
+            }
+        }
+
+        std::fs::create_dir_all(&self.root)?;
+        std::fs::write(
+            self.root.join(MANIFEST_PATH),
+            serde_json::to_vec(&self.manifest).unwrap_or_default(),
+        )?;
+
+        Ok(self.report)
+    }
+}
+
+fn remove_empty_parents(root: &Path, path: &Path) {
+    let mut current = path.parent();
+
+    while let Some(dir) = current.filter(|dir| *dir != root && dir.starts_with(root)) {
+        if std::fs::remove_dir(dir).is_err() {
+            break;
+        }
+
+        current = dir.parent();
+    }
+}
+
+pub(crate) struct StaticLayout<'a> {
+    pub website_name: &'a str,
+    pub language: &'a str,
+    pub icon_path: Option<&'a str>,
+    pub navigation_items: &'a [(String, String)],
+}
+
+pub(crate) fn render_static_page(layout: &StaticLayout, title: &str, body: &str) -> String {
+    let website_name = escape_html(layout.website_name);
+    let page_title = if title.is_empty() {
+        website_name.clone()
+    } else {
+        format!("{} | {website_name}", escape_html(title))
+    };
+    let icon = layout
+        .icon_path
+        .map(|path| format!(r#"<img src="{path}" alt="" width="32" height="32"> "#))
+        .unwrap_or_default();
+    let icon_link = layout
+        .icon_path
+        .map(|path| format!(r#"<link rel="icon" href="{path}">"#))
+        .unwrap_or_default();
+    let navigation = layout
+        .navigation_items
+        .iter()
+        .map(|(title, url)| format!(r#"<a href="{}">{}</a>"#, escape_html(url), escape_html(title)))
+        .collect::<String>();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="{language}">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{page_title}</title>
+{icon_link}
+<link rel="alternate" type="application/rss+xml" href="/feed.xml" title="{website_name}">
+<style>{STYLE}</style>
+</head>
+<body>
+<header><a href="/">{icon}<strong>{website_name}</strong></a><nav>{navigation}</nav></header>
+<main>
+{body}
+</main>
+</body>
+</html>
+"#,
+        language = escape_html(layout.language),
+    )
+}
+
+pub(crate) struct StaticFeedItem {
+    pub title: String,
+    pub url: String,
+    pub description_html: String,
+    pub published_at: DateTime<Utc>,
+}
+
+pub(crate) fn render_static_feed(layout: &StaticLayout, url: &str, items: &[StaticFeedItem]) -> String {
+    let items = items
+        .iter()
+        .map(|item| {
+            format!(
+                "<item><title>{}</title><link>{}</link><guid>{}</guid><pubDate>{}</pubDate><description>{}</description></item>\n",
+                escape_html(&item.title),
+                escape_html(&item.url),
+                escape_html(&item.url),
+                item.published_at.to_rfc2822(),
+                escape_html(&item.description_html),
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0">
+<channel>
+<title>{}</title>
+<link>{}</link>
+<description>{}</description>
+<language>{}</language>
+{items}</channel>
+</rss>
+"#,
+        escape_html(layout.website_name),
+        escape_html(url),
+        escape_html(layout.website_name),
+        escape_html(layout.language),
+    )
+}
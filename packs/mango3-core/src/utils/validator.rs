@@ -62,6 +62,20 @@ impl Validator {
 
         true
     }
+
+    #[cfg(feature = "all-text-search-languages")]
+    pub(crate) async fn validate_language(&mut self, value: &str) -> bool {
+        let languages = crate::commands::all_text_search_languages().await;
+
+        self.custom_validation(Input::Language, InputError::IsInvalid, &|| {
+            languages.iter().any(|language| language == value)
+        })
+    }
+
+    #[cfg(any(feature = "moderate-post", feature = "moderate-website"))]
+    pub(crate) fn validate_moderation_reason(&mut self, value: &str) -> bool {
+        self.validate_presence(Input::Reason, value) && self.validate_length(Input::Reason, value, None, Some(1024))
+    }
 }
 
 pub trait ValidatorTrait<T> {
@@ -1,3 +1,5 @@
+pub const KEY_TEXT_AUTHOR_MENTIONED_YOU_YOU_CAN_SEE_IT_AT_THE_FOLLOWING_URL: &str =
+    "author-mentioned-you-you-can-see-it-at-the-following-url";
 pub const KEY_TEXT_BY_DEFAULT_ALL_USER_ACCOUNTS_ARE_DISABLED_BUT_WE_WILL_LET_YOU_KNOW_WHEN_YOUR_ACCOUNT_IS_ENABLED:
     &str = "by-default-all-user-accounts-are-disabled-but-we-will-let-you-know-when-your-account-is-enabled";
 pub const KEY_TEXT_CONFIRM_YOUR_EMAIL: &str = "confirm-your-email";
@@ -11,23 +13,58 @@ pub const KEY_TEXT_IF_YOU_HAVE_ANY_QUESTIONS_PLEASE_CONTACT_US_AT_THE_FOLLOWING_
 pub const KEY_TEXT_IF_YOU_RECOGNIZE_THIS_ACTION_YOU_CAN_IGNORE_THIS_MESSAGE: &str =
     "if-you-recognize-this-action-you-can-ignore-this-message";
 pub const KEY_TEXT_INVITATION_CODE: &str = "invitation-code";
+pub const KEY_TEXT_NEW_ABUSE_REPORT_RECEIVED: &str = "new-abuse-report-received";
 pub const KEY_TEXT_NEW_USER_ACCOUNT_CREATED: &str = "new-user-account-created";
 pub const KEY_TEXT_NEW_USER_SESSION_STARTED: &str = "new-user-session-started";
+pub const KEY_TEXT_PLEASE_FREE_UP_SOME_SPACE_OR_CONTACT_US_TO_INCREASE_YOUR_QUOTA_AT_THE_FOLLOWING_EMAIL_ADDRESS: &str =
+    "please-free-up-some-space-or-contact-us-to-increase-your-quota-at-the-following-email-address";
 pub const KEY_TEXT_RESET_YOUR_PASSWORD: &str = "reset-your-password";
 pub const KEY_TEXT_SOMEONE_HAS_CREATED_A_NEW_USER_ACCOUNT_WITH_THE_FOLLOWING_USERNAME: &str =
     "someone-has-created-a-new-user-account-with-the-following-username";
+pub const KEY_TEXT_SOMEONE_HAS_REPORTED_TARGET_FOR_THE_FOLLOWING_REASON: &str =
+    "someone-has-reported-target-for-the-following-reason";
 pub const KEY_TEXT_SOMEONE_HAS_STARTED_A_USER_SESSION_WITH_YOUR_ACCOUNT: &str =
     "someone-has-started-a-user-session-with-your-account";
+pub const KEY_TEXT_THE_EXPORT_OF_TARGET_IS_READY_YOU_CAN_DOWNLOAD_IT_FROM_THE_FOLLOWING_URL: &str =
+    "the-export-of-target-is-ready-you-can-download-it-from-the-following-url";
+pub const KEY_TEXT_THE_FOLLOWING_ITEMS_WERE_SKIPPED: &str = "the-following-items-were-skipped";
+pub const KEY_TEXT_THIS_FILE_WILL_BE_AVAILABLE_FOR_SEVEN_DAYS: &str = "this-file-will-be-available-for-seven-days";
 pub const KEY_TEXT_THIS_USER_ACCOUNT_IS_DISABLED_BY_DEFAULT: &str = "this-user-account-is-disabled-by-default";
 pub const KEY_TEXT_USE_THIS_CODE_TO_ACTION: &str = "use-this-code-to-action";
 pub const KEY_TEXT_USE_THIS_CODE_TO_CREATE_YOUR_ACCOUNT: &str = "use-this-code-to-create-your-account";
 pub const KEY_TEXT_WE_ARE_GLAD_TO_INFORM_YOU_THAT_WE_HAVE_ENABLED_YOUR_USER_ACCOUNT: &str =
     "we-are-glad-to-inform-you-that-we-have-enabled-your-user-account";
+pub const KEY_TEXT_WE_ARE_GLAD_TO_INFORM_YOU_THAT_WE_HAVE_RESTORED_TARGET: &str =
+    "we-are-glad-to-inform-you-that-we-have-restored-target";
+pub const KEY_TEXT_WE_COULD_NOT_IMPORT_THE_FILE_UPLOADED_TO_TARGET: &str =
+    "we-could-not-import-the-file-uploaded-to-target";
+pub const KEY_TEXT_WE_HAVE_IMPORTED_COUNT_POSTS_INTO_TARGET: &str = "we-have-imported-count-posts-into-target";
+pub const KEY_TEXT_WE_HAVE_SUSPENDED_TARGET_FOR_THE_FOLLOWING_REASON: &str =
+    "we-have-suspended-target-for-the-following-reason";
+pub const KEY_TEXT_WE_HAVE_UNPUBLISHED_TARGET_FOR_THE_FOLLOWING_REASON: &str =
+    "we-have-unpublished-target-for-the-following-reason";
 pub const KEY_TEXT_WE_REGRET_TO_INFORM_YOU_THAT_WE_HAVE_DISABLED_YOUR_USER_ACCOUNT: &str =
     "we-regret-to-inform-you-that-we-have-disabled-your-user-account";
 pub const KEY_TEXT_WELCOME_TO_TITLE: &str = "welcome-to-title";
+pub const KEY_TEXT_YOU_CAN_REVIEW_IT_AT_THE_FOLLOWING_URL: &str = "you-can-review-it-at-the-following-url";
+pub const KEY_TEXT_YOU_HAVE_BEEN_MENTIONED: &str = "you-have-been-mentioned";
+pub const KEY_TEXT_YOU_HAVE_USED_USED_OF_THE_MAX_AVAILABLE_IN_TARGET: &str =
+    "you-have-used-used-of-the-max-available-in-target";
+pub const KEY_TEXT_YOUR_CONTENT_HAS_BEEN_RESTORED: &str = "your-content-has-been-restored";
+pub const KEY_TEXT_YOUR_CONTENT_HAS_BEEN_SUSPENDED: &str = "your-content-has-been-suspended";
+pub const KEY_TEXT_YOUR_CONTENT_HAS_BEEN_UNPUBLISHED: &str = "your-content-has-been-unpublished";
+pub const KEY_TEXT_YOUR_PERSONAL_FILES: &str = "your-personal-files";
+pub const KEY_TEXT_YOUR_POSTS_COULD_NOT_BE_IMPORTED: &str = "your-posts-could-not-be-imported";
+pub const KEY_TEXT_YOUR_POSTS_HAVE_BEEN_IMPORTED: &str = "your-posts-have-been-imported";
+pub const KEY_TEXT_YOUR_STORAGE_IS_ALMOST_FULL: &str = "your-storage-is-almost-full";
 pub const KEY_TEXT_YOUR_USER_ACCOUNT_HAS_BEEN_ENABLED: &str = "your-user-account-has-been-enabled";
 pub const KEY_TEXT_YOUR_USER_ACCOUNT_HAS_BEEN_DISABLED: &str = "your-user-account-has-been-disabled";
+pub const KEY_TEXT_YOUR_WEBSITE_EXPORT_IS_READY: &str = "your-website-export-is-ready";
 
 pub const KEY_TEXT_ARG_ACTION: &str = "action";
+pub const KEY_TEXT_ARG_AUTHOR: &str = "author";
+pub const KEY_TEXT_ARG_COUNT: &str = "count";
+pub const KEY_TEXT_ARG_MAX: &str = "max";
+pub const KEY_TEXT_ARG_TARGET: &str = "target";
 pub const KEY_TEXT_ARG_TITLE: &str = "title";
+pub const KEY_TEXT_ARG_USED: &str = "used";
@@ -14,7 +14,10 @@ use tokio::signal::unix::SignalKind;
 mod constants;
 mod workers;
 
-use crate::workers::{admin_mailer_worker, guest_mailer_worker, mailer_worker, scheduled_worker};
+use crate::workers::{
+    admin_mailer_worker, export_website_worker, generate_static_website_worker, guest_mailer_worker,
+    import_posts_worker, mailer_worker, render_post_diagrams_worker, scheduled_worker,
+};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -33,12 +36,33 @@ async fn main() -> anyhow::Result<()> {
         .backend(core_context.jobs.storage_admin_mailer.clone())
         .build_fn(admin_mailer_worker);
 
+    let export_website_worker = WorkerBuilder::new("export-website")
+        .layer(ErrorHandlingLayer::new())
+        .enable_tracing()
+        .concurrency(1)
+        .backend(core_context.jobs.storage_export_website.clone())
+        .build_fn(export_website_worker);
+
+    let generate_static_website_worker = WorkerBuilder::new("generate-static-website")
+        .layer(ErrorHandlingLayer::new())
+        .enable_tracing()
+        .concurrency(1)
+        .backend(core_context.jobs.storage_generate_static_website.clone())
+        .build_fn(generate_static_website_worker);
+
     let guest_mailer_worker = WorkerBuilder::new("guest-mailer")
         .layer(ErrorHandlingLayer::new())
         .enable_tracing()
         .backend(core_context.jobs.storage_guest_mailer.clone())
         .build_fn(guest_mailer_worker);
 
+    let import_posts_worker = WorkerBuilder::new("import-posts")
+        .layer(ErrorHandlingLayer::new())
+        .enable_tracing()
+        .concurrency(1)
+        .backend(core_context.jobs.storage_import_posts.clone())
+        .build_fn(import_posts_worker);
+
     let mailer_worker = WorkerBuilder::new("mailer")
         .layer(ErrorHandlingLayer::new())
         .enable_tracing()
@@ -46,6 +70,13 @@ async fn main() -> anyhow::Result<()> {
         .backend(core_context.jobs.storage_mailer.clone())
         .build_fn(mailer_worker);
 
+    let render_post_diagrams_worker = WorkerBuilder::new("render-post-diagrams")
+        .layer(ErrorHandlingLayer::new())
+        .enable_tracing()
+        .concurrency(1)
+        .backend(core_context.jobs.storage_render_post_diagrams.clone())
+        .build_fn(render_post_diagrams_worker);
+
     let scheduled_worker = WorkerBuilder::new("scheduled")
         .layer(ErrorHandlingLayer::new())
         .enable_tracing()
@@ -57,8 +88,12 @@ async fn main() -> anyhow::Result<()> {
 
     Monitor::new()
         .register(admin_mailer_worker)
+        .register(export_website_worker)
+        .register(generate_static_website_worker)
         .register(guest_mailer_worker)
+        .register(import_posts_worker)
         .register(mailer_worker)
+        .register(render_post_diagrams_worker)
         .register(scheduled_worker)
         .on_event(|e| {
             let worker_id = e.id();
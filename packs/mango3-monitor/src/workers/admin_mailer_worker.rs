@@ -1,26 +1,79 @@
+use std::collections::HashMap;
+
 use apalis::prelude::Error;
+use uuid::Uuid;
 
-use mango3_core::enums::AdminMailerJobCommand;
+use mango3_core::config::BASIC_CONFIG;
+use mango3_core::enums::{AbuseReportCategory, AbuseReportTargetType, AdminMailerJobCommand};
 use mango3_core::models::User;
 use mango3_core::utils::*;
 use mango3_core::CoreContext;
 
 use crate::constants::{
-    KEY_TEXT_HELLO, KEY_TEXT_NEW_USER_ACCOUNT_CREATED,
+    KEY_TEXT_ARG_TARGET, KEY_TEXT_HELLO, KEY_TEXT_NEW_ABUSE_REPORT_RECEIVED, KEY_TEXT_NEW_USER_ACCOUNT_CREATED,
     KEY_TEXT_SOMEONE_HAS_CREATED_A_NEW_USER_ACCOUNT_WITH_THE_FOLLOWING_USERNAME,
-    KEY_TEXT_THIS_USER_ACCOUNT_IS_DISABLED_BY_DEFAULT,
+    KEY_TEXT_SOMEONE_HAS_REPORTED_TARGET_FOR_THE_FOLLOWING_REASON, KEY_TEXT_THIS_USER_ACCOUNT_IS_DISABLED_BY_DEFAULT,
+    KEY_TEXT_YOU_CAN_REVIEW_IT_AT_THE_FOLLOWING_URL,
 };
 
 use super::send_email;
 
 pub async fn admin_mailer_worker(job: AdminMailerJob) -> Result<(), Error> {
     match job.command {
+        AdminMailerJobCommand::NewAbuseReport {
+            category,
+            target_type,
+            target_id,
+            note,
+        } => send_new_abuse_report_emails(category, target_type, target_id, &note).await,
         AdminMailerJobCommand::NewUser(new_user) => send_new_user_emails(&new_user).await,
     }
 
     Ok(())
 }
 
+async fn send_new_abuse_report_emails(
+    category: AbuseReportCategory,
+    target_type: AbuseReportTargetType,
+    target_id: Uuid,
+    note: &str,
+) {
+    let core_context = CoreContext::setup().await;
+
+    let users = mango3_core::commands::all_admin_users(&core_context).await;
+    let reports_url = BASIC_CONFIG.admin_url().join("reports").unwrap();
+
+    for user in users {
+        let i18n = user.i18n();
+        let title = i18n.text(KEY_TEXT_NEW_ABUSE_REPORT_RECEIVED);
+        let mut text_args = HashMap::new();
+        text_args.insert(KEY_TEXT_ARG_TARGET.into(), format!("{target_type} {target_id}").into());
+
+        let mut message = format!(
+            "{} @{},\n\n{}: {}",
+            i18n.text(KEY_TEXT_HELLO),
+            user.username,
+            i18n.text_with_args(
+                KEY_TEXT_SOMEONE_HAS_REPORTED_TARGET_FOR_THE_FOLLOWING_REASON,
+                &text_args
+            ),
+            category
+        );
+
+        if !note.is_empty() {
+            message += &format!("\n\n{note}");
+        }
+
+        message += &format!(
+            "\n\n{}: {}",
+            i18n.text(KEY_TEXT_YOU_CAN_REVIEW_IT_AT_THE_FOLLOWING_URL),
+            reports_url
+        );
+
+        let _ = send_email(&user.email, &title, &message).await;
+    }
+}
+
 async fn send_new_user_emails(new_user: &User) {
     let core_context = CoreContext::setup().await;
 
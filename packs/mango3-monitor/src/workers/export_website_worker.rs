@@ -0,0 +1,34 @@
+use apalis::prelude::Error;
+use log::error;
+
+use mango3_core::commands::{export_website, get_website_by_id};
+use mango3_core::config::BASIC_CONFIG;
+use mango3_core::enums::MailerJobCommand;
+use mango3_core::utils::*;
+use mango3_core::CoreContext;
+
+pub async fn export_website_worker(job: ExportWebsiteJob) -> Result<(), Error> {
+    let core_context = CoreContext::setup().await;
+
+    let Ok(website) = get_website_by_id(&core_context, job.website_id, Some(&job.user)).await else {
+        return Ok(());
+    };
+
+    if let Err(err) = export_website(&core_context, &website).await {
+        error!("Could not export website {}: {}", website.id, err.message);
+        return Ok(());
+    }
+
+    core_context
+        .jobs
+        .mailer(
+            &job.user,
+            MailerJobCommand::WebsiteExportReady {
+                website_name: website.name.clone(),
+                url: BASIC_CONFIG.website_export_url(website.id).to_string(),
+            },
+        )
+        .await;
+
+    Ok(())
+}
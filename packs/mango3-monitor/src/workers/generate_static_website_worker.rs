@@ -0,0 +1,28 @@
+use apalis::prelude::Error;
+use log::{error, info};
+
+use mango3_core::commands::{delete_static_website, generate_static_website, get_website_by_id};
+use mango3_core::utils::*;
+use mango3_core::CoreContext;
+
+pub async fn generate_static_website_worker(job: GenerateStaticWebsiteJob) -> Result<(), Error> {
+    let core_context = CoreContext::setup().await;
+
+    let website = match get_website_by_id(&core_context, job.website_id, None).await {
+        Ok(website) if website.is_published() => website,
+        _ => {
+            if let Err(err) = delete_static_website(job.website_id).await {
+                error!("Could not delete static website {}: {}", job.website_id, err.message);
+            }
+
+            return Ok(());
+        }
+    };
+
+    match generate_static_website(&core_context, &website, false).await {
+        Ok(success) => info!("Static website {} generated: {}", website.id, success.data),
+        Err(err) => error!("Could not generate static website {}: {}", website.id, err.message),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use apalis::prelude::Error;
+use log::error;
+
+use mango3_core::commands::{get_website_by_id, import_posts};
+use mango3_core::enums::MailerJobCommand;
+use mango3_core::utils::*;
+use mango3_core::CoreContext;
+
+pub async fn import_posts_worker(job: ImportPostsJob) -> Result<(), Error> {
+    let core_context = CoreContext::setup().await;
+    let data = std::fs::read(&job.file_path);
+
+    let _ = std::fs::remove_file(&job.file_path);
+
+    let Ok(website) = get_website_by_id(&core_context, job.website_id, Some(&job.user)).await else {
+        return Ok(());
+    };
+
+    let result = match data {
+        Ok(data) => import_posts(&core_context, &website, &job.user, &data).await,
+        Err(err) => Err(err.into()),
+    };
+
+    let command = match result {
+        Ok(success) => MailerJobCommand::PostsImported {
+            website_name: website.name.clone(),
+            imported_posts: success.data.imported_posts.clone(),
+            skipped_items: success
+                .data
+                .skipped_items
+                .iter()
+                .map(|item| format!("{}: {}", item.name, item.reason))
+                .collect(),
+        },
+        Err(err) => {
+            error!("Could not import posts into website {}: {}", website.id, err.message);
+
+            MailerJobCommand::PostImportFailed {
+                website_name: website.name.clone(),
+            }
+        }
+    };
+
+    core_context.jobs.mailer(&job.user, command).await;
+
+    Ok(())
+}
@@ -3,22 +3,34 @@ use std::collections::HashMap;
 use apalis::prelude::Error;
 
 use mango3_core::config::{BASIC_CONFIG, USER_CONFIG};
-use mango3_core::enums::{ConfirmationCodeAction, MailerJobCommand};
+use mango3_core::enums::{ConfirmationCodeAction, MailerJobCommand, ModerationAction};
 use mango3_core::models::User;
 use mango3_core::utils::*;
 
 use crate::constants::{
-    KEY_TEXT_ARG_ACTION, KEY_TEXT_ARG_TITLE,
+    KEY_TEXT_ARG_ACTION, KEY_TEXT_ARG_AUTHOR, KEY_TEXT_ARG_COUNT, KEY_TEXT_ARG_MAX, KEY_TEXT_ARG_TARGET,
+    KEY_TEXT_ARG_TITLE, KEY_TEXT_ARG_USED, KEY_TEXT_AUTHOR_MENTIONED_YOU_YOU_CAN_SEE_IT_AT_THE_FOLLOWING_URL,
     KEY_TEXT_BY_DEFAULT_ALL_USER_ACCOUNTS_ARE_DISABLED_BUT_WE_WILL_LET_YOU_KNOW_WHEN_YOUR_ACCOUNT_IS_ENABLED,
     KEY_TEXT_CONFIRMATION_CODE, KEY_TEXT_CONFIRM_YOUR_EMAIL, KEY_TEXT_CONFIRM_YOUR_LOGIN, KEY_TEXT_HELLO,
     KEY_TEXT_IF_NOT_PLEASE_CONTACT_US_AT_THE_FOLLOWING_EMAIL_ADDRESS,
     KEY_TEXT_IF_YOU_HAVE_ANY_QUESTIONS_PLEASE_CONTACT_US_AT_THE_FOLLOWING_EMAIL_ADDRESS,
     KEY_TEXT_IF_YOU_RECOGNIZE_THIS_ACTION_YOU_CAN_IGNORE_THIS_MESSAGE, KEY_TEXT_NEW_USER_SESSION_STARTED,
+    KEY_TEXT_PLEASE_FREE_UP_SOME_SPACE_OR_CONTACT_US_TO_INCREASE_YOUR_QUOTA_AT_THE_FOLLOWING_EMAIL_ADDRESS,
     KEY_TEXT_RESET_YOUR_PASSWORD, KEY_TEXT_SOMEONE_HAS_STARTED_A_USER_SESSION_WITH_YOUR_ACCOUNT,
+    KEY_TEXT_THE_EXPORT_OF_TARGET_IS_READY_YOU_CAN_DOWNLOAD_IT_FROM_THE_FOLLOWING_URL,
+    KEY_TEXT_THE_FOLLOWING_ITEMS_WERE_SKIPPED, KEY_TEXT_THIS_FILE_WILL_BE_AVAILABLE_FOR_SEVEN_DAYS,
     KEY_TEXT_USE_THIS_CODE_TO_ACTION, KEY_TEXT_WELCOME_TO_TITLE,
     KEY_TEXT_WE_ARE_GLAD_TO_INFORM_YOU_THAT_WE_HAVE_ENABLED_YOUR_USER_ACCOUNT,
-    KEY_TEXT_WE_REGRET_TO_INFORM_YOU_THAT_WE_HAVE_DISABLED_YOUR_USER_ACCOUNT,
-    KEY_TEXT_YOUR_USER_ACCOUNT_HAS_BEEN_DISABLED, KEY_TEXT_YOUR_USER_ACCOUNT_HAS_BEEN_ENABLED,
+    KEY_TEXT_WE_ARE_GLAD_TO_INFORM_YOU_THAT_WE_HAVE_RESTORED_TARGET,
+    KEY_TEXT_WE_COULD_NOT_IMPORT_THE_FILE_UPLOADED_TO_TARGET, KEY_TEXT_WE_HAVE_IMPORTED_COUNT_POSTS_INTO_TARGET,
+    KEY_TEXT_WE_HAVE_SUSPENDED_TARGET_FOR_THE_FOLLOWING_REASON,
+    KEY_TEXT_WE_HAVE_UNPUBLISHED_TARGET_FOR_THE_FOLLOWING_REASON,
+    KEY_TEXT_WE_REGRET_TO_INFORM_YOU_THAT_WE_HAVE_DISABLED_YOUR_USER_ACCOUNT, KEY_TEXT_YOUR_CONTENT_HAS_BEEN_RESTORED,
+    KEY_TEXT_YOUR_CONTENT_HAS_BEEN_SUSPENDED, KEY_TEXT_YOUR_CONTENT_HAS_BEEN_UNPUBLISHED, KEY_TEXT_YOUR_PERSONAL_FILES,
+    KEY_TEXT_YOUR_POSTS_COULD_NOT_BE_IMPORTED, KEY_TEXT_YOUR_POSTS_HAVE_BEEN_IMPORTED,
+    KEY_TEXT_YOUR_STORAGE_IS_ALMOST_FULL, KEY_TEXT_YOUR_USER_ACCOUNT_HAS_BEEN_DISABLED,
+    KEY_TEXT_YOUR_USER_ACCOUNT_HAS_BEEN_ENABLED, KEY_TEXT_YOUR_WEBSITE_EXPORT_IS_READY,
+    KEY_TEXT_YOU_HAVE_BEEN_MENTIONED, KEY_TEXT_YOU_HAVE_USED_USED_OF_THE_MAX_AVAILABLE_IN_TARGET,
 };
 
 use super::send_email;
@@ -32,7 +44,32 @@ pub async fn mailer_worker(job: MailerJob) -> Result<(), Error> {
         }
         MailerJobCommand::Disabled => send_disabled_email(&i18n, &job.user).await,
         MailerJobCommand::Enabled => send_enabled_email(&i18n, &job.user).await,
+        MailerJobCommand::Mention { author_username, url } => {
+            send_mention_email(&i18n, &job.user, &author_username, &url).await
+        }
+        MailerJobCommand::Moderated {
+            action,
+            target_name,
+            reason,
+        } => send_moderated_email(&i18n, &job.user, action, target_name, &reason).await,
         MailerJobCommand::NewUserSession => send_new_user_session_email(&i18n, &job.user).await,
+        MailerJobCommand::PostImportFailed { website_name } => {
+            send_post_import_failed_email(&i18n, &job.user, website_name).await
+        }
+        MailerJobCommand::PostsImported {
+            website_name,
+            imported_posts,
+            skipped_items,
+        } => send_posts_imported_email(&i18n, &job.user, website_name, &imported_posts, &skipped_items).await,
+        MailerJobCommand::Restored { target_name } => send_restored_email(&i18n, &job.user, target_name).await,
+        MailerJobCommand::StorageWarning {
+            website_name,
+            used_storage,
+            max_storage,
+        } => send_storage_warning_email(&i18n, &job.user, website_name, &used_storage, &max_storage).await,
+        MailerJobCommand::WebsiteExportReady { website_name, url } => {
+            send_website_export_ready_email(&i18n, &job.user, website_name, &url).await
+        }
         MailerJobCommand::Welcome => send_welcome_email(&i18n, &job.user).await,
     }
 
@@ -92,6 +129,146 @@ pub async fn send_enabled_email(i18n: &I18n, user: &User) {
     let _ = send_email(&user.email, &title, &message).await;
 }
 
+async fn send_mention_email(i18n: &I18n, user: &User, author_username: &str, url: &str) {
+    let title = i18n.text(KEY_TEXT_YOU_HAVE_BEEN_MENTIONED);
+    let mut text_args = HashMap::new();
+    text_args.insert(KEY_TEXT_ARG_AUTHOR.into(), author_username.to_owned().into());
+
+    let message = format!(
+        "{} @{},\n\n{}:\n\n{}",
+        i18n.text(KEY_TEXT_HELLO),
+        user.username,
+        i18n.text_with_args(
+            KEY_TEXT_AUTHOR_MENTIONED_YOU_YOU_CAN_SEE_IT_AT_THE_FOLLOWING_URL,
+            &text_args
+        ),
+        url,
+    );
+
+    let _ = send_email(&user.email, &title, &message).await;
+}
+
+async fn send_moderated_email(i18n: &I18n, user: &User, action: ModerationAction, target_name: String, reason: &str) {
+    let (title_key, text_key) = match action {
+        ModerationAction::Suspend => (
+            KEY_TEXT_YOUR_CONTENT_HAS_BEEN_SUSPENDED,
+            KEY_TEXT_WE_COULD_NOT_IMPORT_THE_FILE_UPLOADED_TO_TARGET,
+            KEY_TEXT_WE_HAVE_IMPORTED_COUNT_POSTS_INTO_TARGET,
+            KEY_TEXT_WE_HAVE_SUSPENDED_TARGET_FOR_THE_FOLLOWING_REASON,
+        ),
+        ModerationAction::Unpublish => (
+            KEY_TEXT_YOUR_CONTENT_HAS_BEEN_UNPUBLISHED,
+            KEY_TEXT_WE_HAVE_UNPUBLISHED_TARGET_FOR_THE_FOLLOWING_REASON,
+        ),
+    };
+    let title = i18n.text(title_key);
+    let mut text_args = HashMap::new();
+    text_args.insert(KEY_TEXT_ARG_TARGET.into(), target_name.into());
+
+    let message = format!(
+        "{} @{},\n\n{}:\n\n{}\n\n{}: {}",
+        i18n.text(KEY_TEXT_HELLO),
+        user.username,
+        i18n.text_with_args(text_key, &text_args),
+        reason,
+        i18n.text(KEY_TEXT_IF_YOU_HAVE_ANY_QUESTIONS_PLEASE_CONTACT_US_AT_THE_FOLLOWING_EMAIL_ADDRESS),
+        BASIC_CONFIG.support_email_address
+    );
+
+    let _ = send_email(&user.email, &title, &message).await;
+}
+
+async fn send_post_import_failed_email(i18n: &I18n, user: &User, website_name: String) {
+    let title = i18n.text(KEY_TEXT_YOUR_POSTS_COULD_NOT_BE_IMPORTED);
+    let mut text_args = HashMap::new();
+    text_args.insert(KEY_TEXT_ARG_TARGET.into(), website_name.into());
+
+    let message = format!(
+        "{} @{},\n\n{}.\n\n{}: {}",
+        i18n.text(KEY_TEXT_HELLO),
+        user.username,
+        i18n.text_with_args(KEY_TEXT_WE_COULD_NOT_IMPORT_THE_FILE_UPLOADED_TO_TARGET, &text_args),
+        i18n.text(KEY_TEXT_IF_YOU_HAVE_ANY_QUESTIONS_PLEASE_CONTACT_US_AT_THE_FOLLOWING_EMAIL_ADDRESS),
+        BASIC_CONFIG.support_email_address
+    );
+
+    let _ = send_email(&user.email, &title, &message).await;
+}
+
+async fn send_posts_imported_email(
+    i18n: &I18n,
+    user: &User,
+    website_name: String,
+    imported_posts: &[String],
+    skipped_items: &[String],
+) {
+    let title = i18n.text(KEY_TEXT_YOUR_POSTS_HAVE_BEEN_IMPORTED);
+    let mut text_args = HashMap::new();
+    text_args.insert(KEY_TEXT_ARG_COUNT.into(), imported_posts.len().into());
+    text_args.insert(KEY_TEXT_ARG_TARGET.into(), website_name.into());
+
+    let mut message = format!(
+        "{} @{},\n\n{}:\n\n",
+        i18n.text(KEY_TEXT_HELLO),
+        user.username,
+        i18n.text_with_args(KEY_TEXT_WE_HAVE_IMPORTED_COUNT_POSTS_INTO_TARGET, &text_args),
+    );
+
+    for title in imported_posts {
+        message += &format!("- {}\n", title);
+    }
+
+    if !skipped_items.is_empty() {
+        message += &format!("\n{}:\n\n", i18n.text(KEY_TEXT_THE_FOLLOWING_ITEMS_WERE_SKIPPED));
+
+        for item in skipped_items {
+            message += &format!("- {}\n", item);
+        }
+    }
+
+    let _ = send_email(&user.email, &title, &message).await;
+}
+
+async fn send_restored_email(i18n: &I18n, user: &User, target_name: String) {
+    let title = i18n.text(KEY_TEXT_YOUR_CONTENT_HAS_BEEN_RESTORED);
+    let mut text_args = HashMap::new();
+    text_args.insert(KEY_TEXT_ARG_TARGET.into(), target_name.into());
+
+    let message = format!(
+        "{} @{},\n\n{}.\n\n{}: {}",
+        i18n.text(KEY_TEXT_HELLO),
+        user.username,
+        i18n.text_with_args(
+            KEY_TEXT_WE_ARE_GLAD_TO_INFORM_YOU_THAT_WE_HAVE_RESTORED_TARGET,
+            &text_args
+        ),
+        i18n.text(KEY_TEXT_IF_YOU_HAVE_ANY_QUESTIONS_PLEASE_CONTACT_US_AT_THE_FOLLOWING_EMAIL_ADDRESS),
+        BASIC_CONFIG.support_email_address
+    );
+
+    let _ = send_email(&user.email, &title, &message).await;
+}
+
+async fn send_website_export_ready_email(i18n: &I18n, user: &User, website_name: String, url: &str) {
+    let title = i18n.text(KEY_TEXT_YOUR_WEBSITE_EXPORT_IS_READY);
+    let mut text_args = HashMap::new();
+    text_args.insert(KEY_TEXT_ARG_TARGET.into(), website_name.into());
+
+    let message = format!(
+        "{} @{},\n\n{}:\n\n{}\n\n{}.",
+        i18n.text(KEY_TEXT_HELLO),
+        user.username,
+        i18n.text_with_args(
+            KEY_TEXT_THE_EXPORT_OF_TARGET_IS_READY_YOU_CAN_DOWNLOAD_IT_FROM_THE_FOLLOWING_URL,
+            &text_args
+        ),
+        url,
+        i18n.text(KEY_TEXT_THIS_FILE_WILL_BE_AVAILABLE_FOR_SEVEN_DAYS),
+    );
+
+    let _ = send_email(&user.email, &title, &message).await;
+}
+
 async fn send_welcome_email(i18n: &I18n, user: &User) {
     let mut text_args = HashMap::new();
     text_args.insert(KEY_TEXT_ARG_TITLE.into(), BASIC_CONFIG.title.clone().into());
@@ -130,3 +307,35 @@ async fn send_new_user_session_email(i18n: &I18n, user: &User) {
 
     let _ = send_email(&user.email, &title, &message).await;
 }
+
+async fn send_storage_warning_email(
+    i18n: &I18n,
+    user: &User,
+    website_name: Option<String>,
+    used_storage: &str,
+    max_storage: &str,
+) {
+    let title = i18n.text(KEY_TEXT_YOUR_STORAGE_IS_ALMOST_FULL);
+    let mut text_args = HashMap::new();
+    text_args.insert(KEY_TEXT_ARG_USED.into(), used_storage.to_owned().into());
+    text_args.insert(KEY_TEXT_ARG_MAX.into(), max_storage.to_owned().into());
+    text_args.insert(
+        KEY_TEXT_ARG_TARGET.into(),
+        website_name
+            .unwrap_or_else(|| i18n.text(KEY_TEXT_YOUR_PERSONAL_FILES))
+            .into(),
+    );
+
+    let message = format!(
+        "{} @{},\n\n{}.\n\n{}: {}",
+        i18n.text(KEY_TEXT_HELLO),
+        user.username,
+        i18n.text_with_args(KEY_TEXT_YOU_HAVE_USED_USED_OF_THE_MAX_AVAILABLE_IN_TARGET, &text_args),
+        i18n.text(
+            KEY_TEXT_PLEASE_FREE_UP_SOME_SPACE_OR_CONTACT_US_TO_INCREASE_YOUR_QUOTA_AT_THE_FOLLOWING_EMAIL_ADDRESS
+        ),
+        BASIC_CONFIG.support_email_address
+    );
+
+    let _ = send_email(&user.email, &title, &message).await;
+}
@@ -5,13 +5,21 @@ use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
 use mango3_core::config::MAILER_CONFIG;
 
 mod admin_mailer_worker;
+mod export_website_worker;
+mod generate_static_website_worker;
 mod guest_mailer_worker;
+mod import_posts_worker;
 mod mailer_worker;
+mod render_post_diagrams_worker;
 mod scheduled_worker;
 
 pub use admin_mailer_worker::admin_mailer_worker;
+pub use export_website_worker::export_website_worker;
+pub use generate_static_website_worker::generate_static_website_worker;
 pub use guest_mailer_worker::guest_mailer_worker;
+pub use import_posts_worker::import_posts_worker;
 pub use mailer_worker::mailer_worker;
+pub use render_post_diagrams_worker::render_post_diagrams_worker;
 pub use scheduled_worker::scheduled_worker;
 
 async fn send_email(to: &str, subject: &str, body: &str) {
@@ -0,0 +1,20 @@
+use apalis::prelude::Error;
+use log::error;
+
+use mango3_core::commands::{get_post_by_id, render_post_diagrams};
+use mango3_core::utils::*;
+use mango3_core::CoreContext;
+
+pub async fn render_post_diagrams_worker(job: RenderPostDiagramsJob) -> Result<(), Error> {
+    let core_context = CoreContext::setup().await;
+
+    let Ok(post) = get_post_by_id(&core_context, job.post_id, None, None, None).await else {
+        return Ok(());
+    };
+
+    if let Err(err) = render_post_diagrams(&core_context, &post).await {
+        error!("Could not render diagrams of post {}: {}", post.id, err.message);
+    }
+
+    Ok(())
+}
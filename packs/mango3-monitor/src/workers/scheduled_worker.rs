@@ -1,7 +1,11 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Days, Timelike, Utc};
 use log::info;
 
-use mango3_core::commands::{delete_all_expired_confirmation_codes, delete_orphaned_blobs};
+use mango3_core::commands::{
+    collect_blob_garbage, delete_all_expired_blob_uploads, delete_all_expired_confirmation_codes,
+    delete_all_expired_post_views, delete_all_expired_website_exports, update_hashtag_trends, update_website_analytics,
+};
+use mango3_core::config::MISC_CONFIG;
 
 #[allow(dead_code)]
 #[derive(Debug, Default)]
@@ -19,8 +23,28 @@ pub async fn scheduled_worker(reminder: Reminder) {
     info!("Deleting all expired confirmation codes...");
     let _ = delete_all_expired_confirmation_codes().await;
 
-    info!("Deleting all orphaned blobs...");
-    let _ = delete_orphaned_blobs().await;
+    info!("Deleting all expired blob uploads...");
+    let _ = delete_all_expired_blob_uploads().await;
+
+    if reminder.0.minute() == 0 {
+        info!("Collecting blob garbage...");
+        let summary = collect_blob_garbage(MISC_CONFIG.blob_gc_grace_period(), false).await;
+        info!("Blob garbage collected: {summary}");
+
+        info!("Deleting all expired website exports...");
+        let _ = delete_all_expired_website_exports().await;
+
+        info!("Deleting all expired post views...");
+        let _ = delete_all_expired_post_views().await;
+
+        info!("Updating hashtag trends...");
+        let _ = update_hashtag_trends().await;
+
+        info!("Updating website analytics...");
+        if let Some(since) = reminder.0.date_naive().checked_sub_days(Days::new(1)) {
+            let _ = update_website_analytics(since).await;
+        }
+    }
 
     info!("Done!");
 }
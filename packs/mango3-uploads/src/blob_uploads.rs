@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE, LOCATION};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{AppendHeaders, IntoResponse};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use tower_sessions::Session;
+use uuid::Uuid;
+
+use mango3_core::commands::{
+    append_blob_upload_chunk, delete_blob_upload, finish_blob_upload, get_blob_upload_by_id, get_user_session_by_id,
+    get_website_by_id, insert_blob_upload,
+};
+use mango3_core::config::BASIC_CONFIG;
+use mango3_core::models::{User, Website};
+use mango3_core::CoreContext;
+use mango3_web_utils::constants::KEY_USER_SESSION_ID;
+
+pub const MANGO3_BLOB_ID: &str = "mango3-blob-id";
+pub const TUS_EXTENSION: &str = "tus-extension";
+pub const TUS_RESUMABLE: &str = "tus-resumable";
+pub const TUS_VERSION: &str = "tus-version";
+pub const UPLOAD_LENGTH: &str = "upload-length";
+pub const UPLOAD_METADATA: &str = "upload-metadata";
+pub const UPLOAD_OFFSET: &str = "upload-offset";
+
+const CONTENT_TYPE_OFFSET_OCTET_STREAM: &str = "application/offset+octet-stream";
+const TUS_PROTOCOL_VERSION: &str = "1.0.0";
+
+type TusHeaders = AppendHeaders<Vec<(&'static str, String)>>;
+
+type TusResult = Result<(StatusCode, TusHeaders), StatusCode>;
+
+fn tus_headers(headers: impl IntoIterator<Item = (&'static str, String)>) -> TusHeaders {
+    let mut tus_headers = vec![(TUS_RESUMABLE, TUS_PROTOCOL_VERSION.to_owned())];
+
+    tus_headers.extend(headers);
+
+    AppendHeaders(tus_headers)
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn header_i64(headers: &HeaderMap, name: &str) -> Option<i64> {
+    header_str(headers, name).and_then(|value| value.parse().ok())
+}
+
+fn parse_upload_metadata(value: &str) -> HashMap<&str, String> {
+    value
+        .split(',')
+        .filter_map(|pair| {
+            let mut parts = pair.trim().splitn(2, ' ');
+            let key = parts.next()?;
+            let value = BASE64_STANDARD.decode(parts.next().unwrap_or_default()).ok()?;
+
+            Some((key, String::from_utf8(value).ok()?))
+        })
+        .collect()
+}
+
+fn require_tus_resumable(headers: &HeaderMap) -> Result<(), StatusCode> {
+    if header_str(headers, TUS_RESUMABLE) != Some(TUS_PROTOCOL_VERSION) {
+        return Err(StatusCode::PRECONDITION_FAILED);
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn require_user(core_context: &CoreContext, session: &Session) -> Result<User, StatusCode> {
+    let Ok(Some(id)) = session.get::<Uuid>(KEY_USER_SESSION_ID).await else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    let user_session = get_user_session_by_id(core_context, id)
+        .await
+        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let user = user_session
+        .user(core_context)
+        .await
+        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    if user.is_disabled() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(user)
+}
+
+async fn website_by_id(
+    core_context: &CoreContext,
+    website_id: Option<Uuid>,
+    user: &User,
+) -> Result<Option<Website>, StatusCode> {
+    let Some(website_id) = website_id else {
+        return Ok(None);
+    };
+
+    get_website_by_id(core_context, website_id, Some(user))
+        .await
+        .map(Some)
+        .map_err(|_| StatusCode::NOT_FOUND)
+}
+
+pub async fn delete_blob_upload_by_id(
+    State(core_context): State<CoreContext>,
+    session: Session,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> TusResult {
+    require_tus_resumable(&headers)?;
+
+    let user = require_user(&core_context, &session).await?;
+    let blob_upload = get_blob_upload_by_id(id, &user)
+        .await
+        .map_err(|_| StatusCode::NOT_FOUND)?;
+
+    delete_blob_upload(&blob_upload)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok((StatusCode::NO_CONTENT, tus_headers([])))
+}
+
+pub async fn head_blob_upload(
+    State(core_context): State<CoreContext>,
+    session: Session,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> TusResult {
+    require_tus_resumable(&headers)?;
+
+    let user = require_user(&core_context, &session).await?;
+    let blob_upload = get_blob_upload_by_id(id, &user)
+        .await
+        .map_err(|_| StatusCode::NOT_FOUND)?;
+
+    Ok((
+        StatusCode::OK,
+        tus_headers([
+            (CACHE_CONTROL.as_str(), "no-store".to_owned()),
+            (UPLOAD_LENGTH, blob_upload.upload_length.to_string()),
+            (UPLOAD_OFFSET, blob_upload.upload_offset.to_string()),
+        ]),
+    ))
+}
+
+pub async fn options_blob_uploads() -> impl IntoResponse {
+    (
+        StatusCode::NO_CONTENT,
+        tus_headers([
+            (TUS_EXTENSION, "creation,termination".to_owned()),
+            (TUS_VERSION, TUS_PROTOCOL_VERSION.to_owned()),
+        ]),
+    )
+}
+
+pub async fn patch_blob_upload(
+    State(core_context): State<CoreContext>,
+    session: Session,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> TusResult {
+    require_tus_resumable(&headers)?;
+
+    if header_str(&headers, CONTENT_TYPE.as_str()) != Some(CONTENT_TYPE_OFFSET_OCTET_STREAM) {
+        return Err(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    let upload_offset = header_i64(&headers, UPLOAD_OFFSET).ok_or(StatusCode::BAD_REQUEST)?;
+    let user = require_user(&core_context, &session).await?;
+    let blob_upload = get_blob_upload_by_id(id, &user)
+        .await
+        .map_err(|_| StatusCode::NOT_FOUND)?;
+
+    if upload_offset != blob_upload.upload_offset {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    let blob_upload = append_blob_upload_chunk(&blob_upload, upload_offset, &body)
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?
+        .data;
+
+    let mut response_headers = vec![(UPLOAD_OFFSET, blob_upload.upload_offset.to_string())];
+
+    if blob_upload.is_complete() {
+        let website = website_by_id(&core_context, blob_upload.website_id, &user).await?;
+        let blob = finish_blob_upload(&blob_upload, &user, website.as_ref())
+            .await
+            .map_err(|_| StatusCode::UNPROCESSABLE_ENTITY)?
+            .data;
+
+        response_headers.push((MANGO3_BLOB_ID, blob.id.to_string()));
+    }
+
+    Ok((StatusCode::NO_CONTENT, tus_headers(response_headers)))
+}
+
+pub async fn post_blob_uploads(
+    State(core_context): State<CoreContext>,
+    session: Session,
+    headers: HeaderMap,
+) -> TusResult {
+    require_tus_resumable(&headers)?;
+
+    let upload_length = header_i64(&headers, UPLOAD_LENGTH).ok_or(StatusCode::BAD_REQUEST)?;
+    let metadata = parse_upload_metadata(header_str(&headers, UPLOAD_METADATA).unwrap_or_default());
+    let website_id = match metadata.get("website_id") {
+        Some(website_id) if !website_id.is_empty() => {
+            Some(Uuid::try_parse(website_id).map_err(|_| StatusCode::BAD_REQUEST)?)
+        }
+        _ => None,
+    };
+
+    let user = require_user(&core_context, &session).await?;
+    let website = website_by_id(&core_context, website_id, &user).await?;
+    let file_name = metadata.get("filename").map(String::as_str).unwrap_or_default();
+    let content_type = metadata
+        .get("filetype")
+        .map(String::as_str)
+        .unwrap_or("application/octet-stream");
+
+    let blob_upload = insert_blob_upload(&user, website.as_ref(), file_name, content_type, upload_length)
+        .await
+        .map_err(|_| StatusCode::BAD_REQUEST)?
+        .data;
+
+    Ok((
+        StatusCode::CREATED,
+        tus_headers([(
+            LOCATION.as_str(),
+            BASIC_CONFIG.blob_upload_url(blob_upload.id).to_string(),
+        )]),
+    ))
+}
@@ -1,18 +1,29 @@
 use axum::body::Body;
 use axum::extract::{Path, Query};
-use axum::http::header::{CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_TYPE};
-use axum::http::StatusCode;
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_TYPE, LOCATION};
+use axum::http::{HeaderName, HeaderValue, Method, StatusCode};
 use axum::response::IntoResponse;
-use axum::routing::get;
+use axum::routing::{get, head, post};
 use axum::Router;
 use serde::Deserialize;
 use tokio::net::TcpListener;
+use tower_http::cors::{AllowOrigin, CorsLayer};
 use uuid::Uuid;
 
 use mango3_core::commands::get_blob_by_id;
-use mango3_core::config::load_config;
+use mango3_core::config::{load_config, BASIC_CONFIG};
 use mango3_core::utils::text_icon;
 use mango3_core::CoreContext;
+use mango3_web_utils::ssr::session_layer;
+
+mod blob_uploads;
+mod website_exports;
+
+use blob_uploads::{
+    delete_blob_upload_by_id, head_blob_upload, options_blob_uploads, patch_blob_upload, post_blob_uploads,
+    MANGO3_BLOB_ID, TUS_EXTENSION, TUS_RESUMABLE, TUS_VERSION, UPLOAD_LENGTH, UPLOAD_METADATA, UPLOAD_OFFSET,
+};
+use website_exports::get_website_export;
 
 #[derive(Deserialize)]
 pub struct BlobQueryParams {
@@ -77,18 +88,80 @@ async fn get_text_icon(Path(text): Path<String>, Query(params): Query<TextIconQu
     Ok((headers, body))
 }
 
+fn is_allowed_origin(origin: &HeaderValue) -> bool {
+    let Ok(origin) = origin.to_str() else {
+        return false;
+    };
+
+    // Websites are user-controlled subdomains, so only the app hosts can send credentialed requests.
+    [
+        BASIC_CONFIG.home_url(),
+        BASIC_CONFIG.subdomain_url("accounts"),
+        BASIC_CONFIG.admin_url(),
+        BASIC_CONFIG.my_account_url(),
+        BASIC_CONFIG.studio_url(),
+    ]
+    .iter()
+    .any(|url| url.origin().ascii_serialization() == origin)
+}
+
 #[tokio::main]
 async fn main() {
     load_config();
 
     let core_context = CoreContext::setup().await;
+    let (session_layer, redis_conn) = session_layer().await;
+    let tus_headers = [
+        TUS_RESUMABLE,
+        TUS_VERSION,
+        TUS_EXTENSION,
+        UPLOAD_LENGTH,
+        UPLOAD_METADATA,
+        UPLOAD_OFFSET,
+    ]
+    .map(HeaderName::from_static);
+    let cors_layer = CorsLayer::new()
+        .allow_credentials(true)
+        .allow_headers(
+            [CONTENT_TYPE]
+                .into_iter()
+                .chain(tus_headers.clone())
+                .collect::<Vec<_>>(),
+        )
+        .allow_methods([
+            Method::DELETE,
+            Method::GET,
+            Method::HEAD,
+            Method::OPTIONS,
+            Method::PATCH,
+            Method::POST,
+        ])
+        .allow_origin(AllowOrigin::predicate(|origin, _| is_allowed_origin(origin)))
+        .expose_headers(
+            [LOCATION, HeaderName::from_static(MANGO3_BLOB_ID)]
+                .into_iter()
+                .chain(tus_headers)
+                .collect::<Vec<_>>(),
+        );
 
     let app = Router::new()
         .route("/blobs/:id", get(get_blob))
+        .route("/blob-uploads", post(post_blob_uploads).options(options_blob_uploads))
+        .route(
+            "/blob-uploads/:id",
+            head(head_blob_upload)
+                .patch(patch_blob_upload)
+                .delete(delete_blob_upload_by_id),
+        )
         .route("/text-icons/:text", get(get_text_icon))
+        .route("/website-exports/:website_id", get(get_website_export))
+        .layer(session_layer)
+        .layer(cors_layer)
         .with_state(core_context);
 
     let listener = TcpListener::bind("127.0.0.1:3050").await.unwrap();
 
     axum::serve(listener, app.into_make_service()).await.unwrap();
+
+    redis_conn.await.unwrap().unwrap();
 }
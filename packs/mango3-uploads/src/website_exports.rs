@@ -0,0 +1,46 @@
+use axum::body::Body;
+use axum::extract::{Path, State};
+use axum::http::header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_TYPE};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use tokio_util::io::ReaderStream;
+use tower_sessions::Session;
+use uuid::Uuid;
+
+use mango3_core::commands::get_website_by_id;
+use mango3_core::CoreContext;
+
+use crate::blob_uploads::require_user;
+
+pub async fn get_website_export(
+    State(core_context): State<CoreContext>,
+    session: Session,
+    Path(website_id): Path<Uuid>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let user = require_user(&core_context, &session).await?;
+    let website = get_website_by_id(&core_context, website_id, Some(&user))
+        .await
+        .map_err(|_| StatusCode::NOT_FOUND)?;
+
+    let file = tokio::fs::File::open(website.export_path())
+        .await
+        .map_err(|_| StatusCode::NOT_FOUND)?;
+    let content_length = file
+        .metadata()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .len();
+    let body = Body::from_stream(ReaderStream::new(file));
+
+    let headers = [
+        (CACHE_CONTROL, "no-store".to_owned()),
+        (CONTENT_TYPE, "application/zip".to_owned()),
+        (CONTENT_LENGTH, content_length.to_string()),
+        (
+            CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}.zip\"", website.subdomain),
+        ),
+    ];
+
+    Ok((headers, body))
+}
@@ -16,7 +16,8 @@ pub fn InvitationCodeModal(value: RwSignal<Option<Uuid>>) -> impl IntoView {
     let i18n = use_i18n();
     let server_action = ServerAction::<AttemptToGetInvitationCodeId>::new();
     let action_value = server_action.value();
-    let is_open = RwSignal::new(!basic_config.enable_register);
+    let enable_register = basic_config.enable_register;
+    let is_open = RwSignal::new(!enable_register);
     let support_email_address = basic_config.support_email_address;
 
     Effect::new(move || {
@@ -29,7 +30,13 @@ pub fn InvitationCodeModal(value: RwSignal<Option<Uuid>>) -> impl IntoView {
     });
 
     view! {
-        <Modal is_open=is_open is_closable=false>
+        <Show when=move || enable_register && value.get().is_none()>
+            <button class="btn btn-link px-0" on:click=move |_| is_open.set(true)>
+                {t!(i18n, accounts.invitation_code)}
+            </button>
+        </Show>
+
+        <Modal is_open=is_open is_closable=enable_register>
             <h4 class="text-lg font-bold">{t!(i18n, accounts.invitation_code)}</h4>
 
             <div role="alert" class="alert mt-4">
@@ -82,7 +82,7 @@ pub fn RegisterPage() -> impl IntoView {
             <ActionForm action=server_action attr:autocomplete="off" attr:novalidate="true" attr:class="form">
                 <FormErrorAlert action_value=action_value message=move || t!(i18n, accounts.failed_to_create_user) />
 
-                <Show when=move || !basic_config.enable_register>
+                <Show when=move || value_invitation_code_id.get().is_some()>
                     <input
                         type="hidden"
                         name="invitation_code_id"
@@ -8,9 +8,11 @@ use mango3_core::config::BASIC_CONFIG;
 #[cfg(feature = "ssr")]
 use mango3_core::enums::ConfirmationCodeAction;
 #[cfg(feature = "ssr")]
+use mango3_core::models::AuditActor;
+#[cfg(feature = "ssr")]
 use mango3_web_utils::ssr::{
-    expect_core_context, extract_confirmation_code, extract_i18n, finish_confirmation_code, require_no_authentication,
-    start_confirmation_code, start_user_session,
+    expect_core_context, extract_client_ip, extract_confirmation_code, extract_i18n, finish_confirmation_code,
+    require_no_authentication, start_confirmation_code, start_user_session,
 };
 
 #[server]
@@ -103,14 +105,18 @@ pub async fn attempt_to_register(
     let i18n = extract_i18n().await?;
     let core_context = expect_core_context();
 
-    let invitation_code = if !BASIC_CONFIG.enable_register {
-        let Some(id) = invitation_code_id else {
-            return mango3_web_utils::mut_presenter_error!();
-        };
+    let invitation_code = match invitation_code_id {
+        Some(id) => {
+            let invitation_code = mango3_core::commands::get_invitation_code_by_id(&core_context, id).await?;
 
-        Some(mango3_core::commands::get_invitation_code_by_id(&core_context, id).await?)
-    } else {
-        None
+            if !invitation_code.is_usable() {
+                return mango3_web_utils::mut_presenter_error!();
+            }
+
+            Some(invitation_code)
+        }
+        None if BASIC_CONFIG.enable_register => None,
+        None => return mango3_web_utils::mut_presenter_error!(),
     };
 
     let result = mango3_core::commands::insert_user(
@@ -122,6 +128,7 @@ pub async fn attempt_to_register(
         &birthdate,
         i18n.0.language.as_str(),
         &country_alpha2,
+        invitation_code.as_ref(),
     )
     .await;
 
@@ -129,10 +136,6 @@ pub async fn attempt_to_register(
         if let Ok(success) = mango3_core::commands::insert_user_session(&core_context, &success_insert.data).await {
             let _ = start_user_session(&core_context, &success.data).await?;
         }
-
-        if let Some(invitation_code) = invitation_code {
-            let _ = mango3_core::commands::delete_invitation_code(&core_context, &invitation_code).await;
-        }
     }
 
     mango3_web_utils::mut_presenter!(result)
@@ -172,6 +175,7 @@ pub async fn attempt_to_reset_password(code: String, new_password: String) -> Re
 
     let core_context = expect_core_context();
     let user = confirmation_code.user(&core_context).await?;
+    let client_ip = extract_client_ip().await?;
 
     let result = mango3_core::commands::confirm_confirmation_code(
         &confirmation_code,
@@ -181,8 +185,10 @@ pub async fn attempt_to_reset_password(code: String, new_password: String) -> Re
             let core_context = core_context.clone();
             let user = user.clone();
             let new_password = new_password.clone();
+            let actor = AuditActor::new(Some(&user), Some(&client_ip));
             async move {
-                let result = mango3_core::commands::reset_user_password(&core_context, &user, &new_password).await;
+                let result =
+                    mango3_core::commands::reset_user_password(&core_context, &user, &new_password, &actor).await;
 
                 if let Ok(_) = result {
                     let _ = finish_confirmation_code().await;
@@ -11,7 +11,10 @@ use mango3_web_utils::i18n::use_i18n;
 use mango3_web_utils::pages::NotFoundPage;
 use mango3_web_utils::utils::ToSignalTrait;
 
-use crate::pages::{IndexPage, IndexParentPage, UsersPage};
+use crate::pages::{
+    AuditLogsPage, IndexPage, IndexParentPage, InvitationsPage, PostsPage, ReportsPage, StoragePage, UsersPage,
+    WebsitesPage,
+};
 
 #[component]
 pub fn App() -> impl IntoView {
@@ -41,7 +44,13 @@ pub fn App() -> impl IntoView {
                             <Routes fallback=NotFoundPage>
                                 <ParentRoute path=StaticSegment("") view=IndexParentPage>
                                     <Route path=StaticSegment("") view=IndexPage />
+                                    <Route path=StaticSegment("invitations") view=InvitationsPage />
+                                    <Route path=StaticSegment("posts") view=PostsPage />
+                                    <Route path=StaticSegment("reports") view=ReportsPage />
+                                    <Route path=StaticSegment("audit-logs") view=AuditLogsPage />
+                                    <Route path=StaticSegment("storage") view=StoragePage />
                                     <Route path=StaticSegment("users") view=UsersPage />
+                                    <Route path=StaticSegment("websites") view=WebsitesPage />
                                 </ParentRoute>
                             </Routes>
                         </main>
@@ -1,10 +1,21 @@
+use leptos::either::{Either, EitherOf4};
 use leptos::prelude::*;
 use leptos_router::components::Redirect;
+use leptos_router::hooks::{use_navigate, use_query_map};
 
-use mango3_web_utils::context::use_basic_config;
+use mango3_web_utils::async_t_string;
+use mango3_web_utils::components::forms::{FormErrorAlert, FormField, SubmitButton, TextField};
+use mango3_web_utils::components::{
+    ConfirmationModal, InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollLocalResourceController, Modal,
+    UserTag,
+};
+use mango3_web_utils::context::{param_query, use_basic_config};
+use mango3_web_utils::i18n::{t, use_i18n};
+use mango3_web_utils::icons::MagnifyingGlassMini;
 use mango3_web_utils::pages::Page;
+use mango3_web_utils::presenters::{ModerationPresenter, MutPresenter};
 
-use crate::server_functions::is_admin;
+use crate::server_functions::{get_moderation_items, is_admin, AttemptToModerate, AttemptToRestore};
 
 #[component]
 pub fn AdminPageContainer(
@@ -31,3 +42,269 @@ pub fn AdminPageContainer(
         </Page>
     }
 }
+
+/// Path of an admin page filtered with the `q` param.
+pub fn search_path(path: &str, query: &str) -> String {
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("q", query.trim())
+        .finish();
+
+    format!("/{path}?{query}")
+}
+
+#[component]
+pub fn SearchForm(#[prop(into)] path: &'static str, query: RwSignal<String>) -> impl IntoView {
+    let i18n = use_i18n();
+    let navigate = use_navigate();
+
+    view! {
+        <form
+            class="mb-5"
+            on:submit=move |event| {
+                event.prevent_default();
+                navigate(&search_path(path, &query.get()), Default::default());
+            }
+        >
+            <label class="input flex items-center gap-2 w-full">
+                <input
+                    class="grow"
+                    type="search"
+                    placeholder=move || async_t_string!(i18n, admin.search).get()
+                    bind:value=query
+                />
+                <button class="btn btn-ghost p-0 min-h-7 h-7 w-7" type="submit">
+                    <MagnifyingGlassMini />
+                </button>
+            </label>
+        </form>
+    }
+}
+
+#[component]
+pub fn ModerationList(#[prop(into)] kind: &'static str) -> impl IntoView {
+    let i18n = use_i18n();
+    let query_map = use_query_map();
+    let query = RwSignal::new(String::new());
+    let controller = InfiniteScrollLocalResourceController::new(|after| {
+        LocalResource::new(move || {
+            let query = param_query(query_map);
+            let after = after.get();
+
+            async move { get_moderation_items(kind.to_owned(), query, after).await }
+        })
+    });
+    let server_action_moderate = ServerAction::<AttemptToModerate>::new();
+    let action_value_moderate = server_action_moderate.value();
+    let server_action_restore = ServerAction::<AttemptToRestore>::new();
+    let action_value_restore = server_action_restore.value();
+    let moderate_item = RwSignal::<Option<ModerationPresenter>>::new(None);
+    let restore_item = RwSignal::<Option<ModerationPresenter>>::new(None);
+    let show_moderate_form = RwSignal::new(false);
+    let show_restore_confirmation = RwSignal::new(false);
+    let value_reason = RwSignal::new(String::new());
+
+    Effect::new(move || query.set(param_query(query_map)));
+
+    Effect::new({
+        let controller = controller.clone();
+        move || {
+            query_map.track();
+            controller.clear_and_refetch();
+        }
+    });
+
+    Effect::new({
+        let controller = controller.clone();
+        move || {
+            let response = MutPresenter::from(action_value_moderate);
+
+            if response.is_success() {
+                show_moderate_form.set(false);
+                controller.clear_and_refetch();
+                moderate_item.set(None);
+            }
+        }
+    });
+
+    Effect::new({
+        let controller = controller.clone();
+        move || {
+            let response = MutPresenter::from(action_value_restore);
+
+            if response.is_success() {
+                controller.clear_and_refetch();
+                restore_item.set(None);
+            }
+        }
+    });
+
+    view! {
+        <SearchForm path=kind query=query />
+
+        <Modal is_open=show_moderate_form>
+            <h4 class="text-lg font-bold">{t!(i18n, admin.moderate)}</h4>
+
+            {move || {
+                moderate_item
+                    .get()
+                    .map(|item| {
+                        view! {
+                            <ActionForm
+                                action=server_action_moderate
+                                attr:autocomplete="off"
+                                attr:novalidate="true"
+                                attr:class="form"
+                            >
+                                <FormErrorAlert action_value=action_value_moderate />
+
+                                <input type="hidden" name="website_id" value=item.website_id.to_string() />
+
+                                {item
+                                    .post_id
+                                    .map(|post_id| {
+                                        view! { <input type="hidden" name="post_id" value=post_id.to_string() /> }
+                                    })}
+
+                                <div class="font-bold">{item.title.clone()}</div>
+
+                                <FormField
+                                    action_value=action_value_moderate
+                                    id="action"
+                                    label=move || t!(i18n, admin.action)
+                                    name="action"
+                                >
+                                    <select class="select w-full" id="action" name="action">
+                                        <option value="unpublish">{t!(i18n, admin.unpublish)}</option>
+                                        <option value="suspend">{t!(i18n, admin.suspend)}</option>
+                                    </select>
+                                </FormField>
+
+                                <TextField
+                                    action_value=action_value_moderate
+                                    id="reason"
+                                    label=move || t!(i18n, admin.reason)
+                                    name="reason"
+                                    value=value_reason
+                                />
+
+                                <SubmitButton is_loading=server_action_moderate.pending() />
+                            </ActionForm>
+                        }
+                    })
+            }}
+        </Modal>
+
+        <ConfirmationModal
+            is_open=show_restore_confirmation
+            on_accept=move || {
+                let item = restore_item.get().unwrap();
+                server_action_restore
+                    .dispatch(AttemptToRestore {
+                        website_id: item.website_id,
+                        post_id: item.post_id,
+                    });
+            }
+        >
+            <div>{t!(i18n, admin.are_you_sure_you_want_to_restore_this_content)}</div>
+
+            {move || restore_item.get().map(|item| view! { <div class="font-bold my-3">{item.title}</div> })}
+        </ConfirmationModal>
+
+        <InfiniteScroll controller=controller key=|item: &ModerationPresenter| item.key() let:item>
+            <div class="card card-sm bg-base-200 shadow-xl mb-4">
+                <div class="card-body">
+                    <div class="card-title">
+                        <a class="link" href=item.url.to_string() target="_blank">
+                            {item.title.clone()}
+                        </a>
+                    </div>
+
+                    {item
+                        .website_name
+                        .clone()
+                        .map(|website_name| view! { <div class="opacity-70">{website_name}</div> })}
+
+                    <UserTag user=item.user.clone() />
+
+                    <div>
+                        {if item.is_suspended {
+                            EitherOf4::A(
+                                view! {
+                                    <span class="btn btn-sm btn-outline btn-error no-animation">
+                                        {t!(i18n, admin.suspended)}
+                                    </span>
+                                },
+                            )
+                        } else if item.moderation_reason.is_some() {
+                            EitherOf4::B(
+                                view! {
+                                    <span class="btn btn-sm btn-outline btn-warning no-animation">
+                                        {t!(i18n, admin.unpublished_by_staff)}
+                                    </span>
+                                },
+                            )
+                        } else if !item.is_published {
+                            EitherOf4::C(
+                                view! {
+                                    <span class="btn btn-sm btn-outline btn-info no-animation">
+                                        {t!(i18n, shared.unpublished)}
+                                    </span>
+                                },
+                            )
+                        } else {
+                            EitherOf4::D(())
+                        }}
+                    </div>
+
+                    {item
+                        .moderation_reason
+                        .clone()
+                        .map(|reason| {
+                            view! {
+                                <div class="text-sm">
+                                    <span class="font-bold">{t!(i18n, admin.reason)} ": "</span>
+                                    {reason}
+                                </div>
+                            }
+                        })}
+
+                    <div class="card-actions justify-end">
+                        {
+                            let item = item.clone();
+                            if item.moderation_reason.is_some() {
+                                Either::Left(
+                                    view! {
+                                        <button
+                                            class="btn btn-ghost font-bold"
+                                            on:click=move |_| {
+                                                restore_item.set(Some(item.clone()));
+                                                show_restore_confirmation.set(true);
+                                            }
+                                        >
+                                            {t!(i18n, admin.restore)}
+                                        </button>
+                                    },
+                                )
+                            } else {
+                                Either::Right(
+                                    view! {
+                                        <button
+                                            class="btn btn-ghost font-bold"
+                                            on:click=move |_| {
+                                                value_reason.set(String::new());
+                                                moderate_item.set(Some(item.clone()));
+                                                show_moderate_form.set(true);
+                                            }
+                                        >
+                                            {t!(i18n, admin.moderate)}
+                                        </button>
+                                    },
+                                )
+                            }
+                        }
+                    </div>
+                </div>
+            </div>
+        </InfiniteScroll>
+    }
+}
@@ -0,0 +1,188 @@
+use leptos::either::Either;
+use leptos::prelude::*;
+
+use mango3_web_utils::async_t_string;
+use mango3_web_utils::components::{
+    InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollLocalResourceController, TimeAgo, UserTag,
+};
+use mango3_web_utils::i18n::{t, use_i18n};
+use mango3_web_utils::presenters::AuditLogPresenter;
+use mango3_web_utils::utils::ToSignalTrait;
+
+use crate::components::AdminPageContainer;
+use crate::server_functions::get_audit_logs;
+
+#[component]
+fn AuditLogLabels(audit_log: AuditLogPresenter) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let text_action = match audit_log.action.as_str() {
+        "delete_invitation_code" => async_t_string!(i18n, admin.delete_invitation_code),
+        "disable_user" => async_t_string!(i18n, admin.disable_user),
+        "enable_user" => async_t_string!(i18n, admin.enable_user),
+        "insert_invitation_code" => async_t_string!(i18n, admin.insert_invitation_code),
+        "moderate_post" => async_t_string!(i18n, admin.moderate_post),
+        "moderate_website" => async_t_string!(i18n, admin.moderate_website),
+        "reset_user_password" => async_t_string!(i18n, admin.reset_user_password),
+        "resolve_abuse_report" => async_t_string!(i18n, admin.resolve_abuse_report),
+        "restore_post" => async_t_string!(i18n, admin.restore_post),
+        "restore_website" => async_t_string!(i18n, admin.restore_website),
+        "update_user_email" => async_t_string!(i18n, admin.update_user_email),
+        "update_user_invitation_allowance" => async_t_string!(i18n, admin.update_user_invitation_allowance),
+        "update_user_password" => async_t_string!(i18n, admin.update_user_password),
+        "update_user_storage_quota" => async_t_string!(i18n, admin.update_user_storage_quota),
+        "update_website_storage_quota" => async_t_string!(i18n, admin.update_website_storage_quota),
+        _ => async_t_string!(i18n, admin.update_user_role),
+    }
+    .to_signal();
+
+    let text_target_type = match audit_log.target_type.as_str() {
+        "abuse_report" => async_t_string!(i18n, admin.abuse_report),
+        "invitation_code" => async_t_string!(i18n, admin.invitation_code),
+        "post" => async_t_string!(i18n, admin.post),
+        "website" => async_t_string!(i18n, admin.website),
+        _ => async_t_string!(i18n, admin.user),
+    }
+    .to_signal();
+
+    view! {
+        <div class="flex flex-wrap gap-2">
+            <span class="btn btn-sm btn-outline btn-primary no-animation">{move || text_action.get()}</span>
+            <span class="btn btn-sm btn-outline no-animation">{move || text_target_type.get()}</span>
+        </div>
+    }
+}
+
+#[component]
+pub fn AuditLogsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let action = RwSignal::new(String::new());
+    let username = RwSignal::new(String::new());
+    let filter_username = RwSignal::new(String::new());
+    let controller = InfiniteScrollLocalResourceController::new(move |after| {
+        LocalResource::new(move || async move {
+            get_audit_logs(action.get(), filter_username.get(), after.get()).await
+        })
+    });
+    let text_title = async_t_string!(i18n, admin.audit_log).to_signal();
+
+    Effect::new({
+        let controller = controller.clone();
+        move || {
+            action.track();
+            filter_username.track();
+            controller.clear_and_refetch();
+        }
+    });
+
+    view! {
+        <AdminPageContainer title=text_title>
+            <h1 class="h1">{move || text_title.get()}</h1>
+
+            <section class="max-w-[720px] w-full mx-auto">
+                <form
+                    class="flex flex-wrap gap-2 mb-5"
+                    on:submit=move |event| {
+                        event.prevent_default();
+                        filter_username.set(username.get().trim().to_owned());
+                    }
+                >
+                    <select
+                        class="select grow"
+                        on:change=move |event| action.set(event_target_value(&event))
+                        prop:value=move || action.get()
+                    >
+                        <option value="">{t!(i18n, admin.all_actions)}</option>
+                        <option value="delete_invitation_code">{t!(i18n, admin.delete_invitation_code)}</option>
+                        <option value="disable_user">{t!(i18n, admin.disable_user)}</option>
+                        <option value="enable_user">{t!(i18n, admin.enable_user)}</option>
+                        <option value="insert_invitation_code">{t!(i18n, admin.insert_invitation_code)}</option>
+                        <option value="moderate_post">{t!(i18n, admin.moderate_post)}</option>
+                        <option value="moderate_website">{t!(i18n, admin.moderate_website)}</option>
+                        <option value="reset_user_password">{t!(i18n, admin.reset_user_password)}</option>
+                        <option value="resolve_abuse_report">{t!(i18n, admin.resolve_abuse_report)}</option>
+                        <option value="restore_post">{t!(i18n, admin.restore_post)}</option>
+                        <option value="restore_website">{t!(i18n, admin.restore_website)}</option>
+                        <option value="update_user_email">{t!(i18n, admin.update_user_email)}</option>
+                        <option value="update_user_invitation_allowance">
+                            {t!(i18n, admin.update_user_invitation_allowance)}
+                        </option>
+                        <option value="update_user_password">{t!(i18n, admin.update_user_password)}</option>
+                        <option value="update_user_role">{t!(i18n, admin.update_user_role)}</option>
+                        <option value="update_user_storage_quota">
+                            {t!(i18n, admin.update_user_storage_quota)}
+                        </option>
+                        <option value="update_website_storage_quota">
+                            {t!(i18n, admin.update_website_storage_quota)}
+                        </option>
+                    </select>
+
+                    <input
+                        class="input grow"
+                        type="search"
+                        placeholder=move || async_t_string!(i18n, admin.actor_username).get()
+                        bind:value=username
+                    />
+
+                    <button class="btn btn-outline" type="submit">
+                        {t!(i18n, admin.search)}
+                    </button>
+                </form>
+
+                <InfiniteScroll
+                    controller=controller
+                    key=|audit_log: &AuditLogPresenter| audit_log.id
+                    let:audit_log
+                >
+                    <div class="card card-sm bg-base-200 shadow-xl mb-4">
+                        <div class="card-body">
+                            <AuditLogLabels audit_log=audit_log.clone() />
+
+                            <div class="text-sm opacity-70">{audit_log.target_id.to_string()}</div>
+
+                            {audit_log
+                                .before_value
+                                .clone()
+                                .map(|before_value| {
+                                    view! {
+                                        <div class="text-sm">
+                                            <span class="font-bold">{t!(i18n, admin.before)} ": "</span>
+                                            <code>{before_value}</code>
+                                        </div>
+                                    }
+                                })}
+
+                            {audit_log
+                                .after_value
+                                .clone()
+                                .map(|after_value| {
+                                    view! {
+                                        <div class="text-sm">
+                                            <span class="font-bold">{t!(i18n, admin.after)} ": "</span>
+                                            <code>{after_value}</code>
+                                        </div>
+                                    }
+                                })}
+
+                            <div class="flex items-center justify-between gap-2">
+                                {match audit_log.actor_user.clone() {
+                                    Some(actor_user) => Either::Left(view! { <UserTag user=actor_user /> }),
+                                    None => {
+                                        Either::Right(
+                                            view! { <span class="font-bold">{t!(i18n, admin.system)}</span> },
+                                        )
+                                    }
+                                }}
+
+                                <div class="flex items-center gap-2 opacity-70">
+                                    {audit_log.ip_address.clone()}
+                                    <TimeAgo value=audit_log.created_at />
+                                </div>
+                            </div>
+                        </div>
+                    </div>
+                </InfiniteScroll>
+            </section>
+        </AdminPageContainer>
+    }
+}
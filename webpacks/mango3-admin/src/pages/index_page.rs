@@ -1,19 +1,179 @@
 use leptos::prelude::*;
 
 use mango3_web_utils::async_t_string;
-use mango3_web_utils::i18n::use_i18n;
+use mango3_web_utils::components::LoadingSpinner;
+use mango3_web_utils::i18n::{t, use_i18n};
 use mango3_web_utils::utils::ToSignalTrait;
 
 use crate::components::AdminPageContainer;
+use crate::presenters::PlatformDailyStatPresenter;
+use crate::server_functions::get_dashboard;
+
+#[component]
+fn DailyChart(
+    #[prop(into)] title: AsyncDerived<&'static str>,
+    daily_stats: Vec<PlatformDailyStatPresenter>,
+    count: fn(&PlatformDailyStatPresenter) -> i64,
+) -> impl IntoView {
+    let total_count: i64 = daily_stats.iter().map(count).sum();
+    let max_count = daily_stats.iter().map(count).max().unwrap_or_default().max(1);
+
+    view! {
+        <section>
+            <h3 class="h3">{move || title.get()} " (" {total_count} ")"</h3>
+
+            <div class="flex items-end gap-px h-32 bg-base-200 rounded-box p-2">
+                {daily_stats
+                    .into_iter()
+                    .map(|stat| {
+                        view! {
+                            <div
+                                class="grow bg-primary rounded-t"
+                                style=format!("height: {}%", count(&stat) * 100 / max_count)
+                                title=format!("{}: {}", stat.day, count(&stat))
+                            />
+                        }
+                    })
+                    .collect_view()}
+            </div>
+        </section>
+    }
+}
 
 #[component]
 pub fn IndexPage() -> impl IntoView {
     let i18n = use_i18n();
     let text_title = async_t_string!(i18n, shared.home).to_signal();
+    let days = RwSignal::new(30);
+    let dashboard_resource = LocalResource::new(move || get_dashboard(days.get()));
 
     view! {
         <AdminPageContainer title=text_title>
             <h1 class="h1">{move || text_title.get()}</h1>
+
+            <div role="tablist" class="tabs tabs-border mb-5">
+                <a role="tab" class="tab" class:tab-active=move || days.get() == 7 on:click=move |_| days.set(7)>
+                    {t!(i18n, admin.last_7_days)}
+                </a>
+                <a role="tab" class="tab" class:tab-active=move || days.get() == 30 on:click=move |_| days.set(30)>
+                    {t!(i18n, admin.last_30_days)}
+                </a>
+                <a role="tab" class="tab" class:tab-active=move || days.get() == 90 on:click=move |_| days.set(90)>
+                    {t!(i18n, admin.last_90_days)}
+                </a>
+            </div>
+
+            <Transition fallback=LoadingSpinner>
+                {move || Suspend::new(async move {
+                    dashboard_resource
+                        .get()
+                        .and_then(|result| result.take().ok())
+                        .flatten()
+                        .map(|dashboard| {
+                            let stats = dashboard.stats;
+                            view! {
+                                <div class="stats stats-vertical md:stats-horizontal shadow w-full">
+                                    <div class="stat">
+                                        <div class="stat-title">{t!(i18n, admin.users)}</div>
+                                        <div class="stat-value">{stats.users_count}</div>
+                                        <div class="stat-desc">
+                                            {t!(i18n, admin.disabled)} ": " {stats.disabled_users_count} " · "
+                                            {t!(i18n, admin.unconfirmed)} ": " {stats.unconfirmed_users_count}
+                                        </div>
+                                    </div>
+                                    <div class="stat">
+                                        <div class="stat-title">{t!(i18n, admin.websites)}</div>
+                                        <div class="stat-value">{stats.websites_count}</div>
+                                        <div class="stat-desc">
+                                            {t!(i18n, admin.published)} ": " {stats.published_websites_count}
+                                        </div>
+                                    </div>
+                                    <div class="stat">
+                                        <div class="stat-title">{t!(i18n, admin.published_posts)}</div>
+                                        <div class="stat-value">{stats.published_posts_count}</div>
+                                    </div>
+                                    <div class="stat">
+                                        <div class="stat-title">{t!(i18n, admin.comments)}</div>
+                                        <div class="stat-value">{stats.comments_count}</div>
+                                    </div>
+                                    <div class="stat">
+                                        <div class="stat-title">{t!(i18n, admin.storage)}</div>
+                                        <div class="stat-value">{stats.used_storage_str}</div>
+                                    </div>
+                                </div>
+
+                                <div class="grid md:grid-cols-2 gap-4 mt-4">
+                                    <DailyChart
+                                        title=async_t_string!(i18n, admin.new_users)
+                                        daily_stats=dashboard.daily_stats.clone()
+                                        count=|stat| stat.users_count
+                                    />
+                                    <DailyChart
+                                        title=async_t_string!(i18n, admin.new_websites)
+                                        daily_stats=dashboard.daily_stats.clone()
+                                        count=|stat| stat.websites_count
+                                    />
+                                    <DailyChart
+                                        title=async_t_string!(i18n, admin.published_posts)
+                                        daily_stats=dashboard.daily_stats.clone()
+                                        count=|stat| stat.posts_count
+                                    />
+                                    <DailyChart
+                                        title=async_t_string!(i18n, admin.comments)
+                                        daily_stats=dashboard.daily_stats
+                                        count=|stat| stat.comments_count
+                                    />
+                                </div>
+
+                                <div class="grid md:grid-cols-2 gap-4 mt-4">
+                                    <section>
+                                        <h3 class="h3">{t!(i18n, admin.users_by_role)}</h3>
+
+                                        <table class="table">
+                                            <tbody>
+                                                {stats
+                                                    .users_by_role
+                                                    .into_iter()
+                                                    .map(|role_stat| {
+                                                        view! {
+                                                            <tr>
+                                                                <td class="p-2">{role_stat.role}</td>
+                                                                <td class="p-2 text-right">{role_stat.users_count}</td>
+                                                            </tr>
+                                                        }
+                                                    })
+                                                    .collect_view()}
+                                            </tbody>
+                                        </table>
+                                    </section>
+
+                                    <section>
+                                        <h3 class="h3">{t!(i18n, admin.job_queues)}</h3>
+
+                                        <table class="table">
+                                            <tbody>
+                                                {dashboard
+                                                    .job_queue_depths
+                                                    .into_iter()
+                                                    .map(|job_queue_depth| {
+                                                        view! {
+                                                            <tr>
+                                                                <td class="p-2">{job_queue_depth.name}</td>
+                                                                <td class="p-2 text-right">
+                                                                    {job_queue_depth.pending_count}
+                                                                </td>
+                                                            </tr>
+                                                        }
+                                                    })
+                                                    .collect_view()}
+                                            </tbody>
+                                        </table>
+                                    </section>
+                                </div>
+                            }
+                        })
+                })}
+            </Transition>
         </AdminPageContainer>
     }
 }
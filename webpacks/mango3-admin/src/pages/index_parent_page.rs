@@ -4,7 +4,10 @@ use leptos_router::components::Outlet;
 use mango3_web_utils::async_t_string;
 use mango3_web_utils::components::{Menu, MenuItem};
 use mango3_web_utils::i18n::use_i18n;
-use mango3_web_utils::icons::{HomeOutlined, UsersOutlined};
+use mango3_web_utils::icons::{
+    CircleStackOutlined, DocumentOutlined, DocumentTextOutlined, EnvelopeOutlined, ExclamationOutlined, HomeOutlined,
+    Squares2x2Mini, UsersOutlined,
+};
 
 #[component]
 pub fn IndexParentPage() -> impl IntoView {
@@ -15,6 +18,12 @@ pub fn IndexParentPage() -> impl IntoView {
             <Menu>
                 <MenuItem href="/" icon=HomeOutlined label=async_t_string!(i18n, shared.home) />
                 <MenuItem href="/users" icon=UsersOutlined label=async_t_string!(i18n, admin.users) />
+                <MenuItem href="/invitations" icon=EnvelopeOutlined label=async_t_string!(i18n, admin.invitations) />
+                <MenuItem href="/websites" icon=Squares2x2Mini label=async_t_string!(i18n, admin.websites) />
+                <MenuItem href="/posts" icon=DocumentTextOutlined label=async_t_string!(i18n, admin.posts) />
+                <MenuItem href="/reports" icon=ExclamationOutlined label=async_t_string!(i18n, admin.reports) />
+                <MenuItem href="/audit-logs" icon=DocumentOutlined label=async_t_string!(i18n, admin.audit_log) />
+                <MenuItem href="/storage" icon=CircleStackOutlined label=async_t_string!(i18n, admin.storage) />
             </Menu>
 
             <div class="grow ml-4">
@@ -0,0 +1,329 @@
+use leptos::either::EitherOf3;
+use leptos::ev::SubmitEvent;
+use leptos::html::Input;
+use leptos::prelude::*;
+use web_sys::FormData;
+
+use mango3_web_utils::async_t_string;
+use mango3_web_utils::components::forms::{FormErrorAlert, FormField, SubmitButton, TextField, TextareaField};
+use mango3_web_utils::components::{
+    ConfirmationModal, InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollLocalResourceController, TimeAgo,
+    UserTag,
+};
+use mango3_web_utils::i18n::{t, use_i18n};
+use mango3_web_utils::presenters::{InvitationCodePresenter, MutPresenter};
+use mango3_web_utils::utils::ToSignalTrait;
+
+use crate::components::AdminPageContainer;
+use crate::server_functions::{
+    attempt_to_bulk_invite, get_invitation_codes, AttemptToResendInvitationCode, AttemptToRevokeInvitationCode,
+};
+
+#[component]
+fn BulkInviteForm(#[prop(into)] on_success: Callback<()>) -> impl IntoView {
+    let i18n = use_i18n();
+    let bulk_invite_action = Action::new_local(|data: &FormData| attempt_to_bulk_invite(data.clone().into()));
+    let action_value = bulk_invite_action.value();
+    let file_input_ref = NodeRef::<Input>::new();
+    let value_emails = RwSignal::new(String::new());
+    let value_role = RwSignal::new("user".to_owned());
+    let value_expires_at = RwSignal::new(String::new());
+
+    Effect::new(move || {
+        let response = MutPresenter::from(action_value);
+
+        if response.is_success() {
+            value_emails.set(String::new());
+            on_success.run(());
+        }
+    });
+
+    let bulk_invite = move |event: SubmitEvent| {
+        event.prevent_default();
+
+        let Ok(form_data) = FormData::new() else {
+            return;
+        };
+
+        let _ = form_data.append_with_str("emails", &value_emails.get());
+        let _ = form_data.append_with_str("role", &value_role.get());
+        let _ = form_data.append_with_str("expires_at", &value_expires_at.get());
+
+        if let Some(file) = file_input_ref
+            .get()
+            .and_then(|input| input.files())
+            .and_then(|files| files.get(0))
+        {
+            let _ = form_data.append_with_blob_and_filename("file", &file, &file.name());
+        }
+
+        bulk_invite_action.dispatch_local(form_data);
+    };
+
+    view! {
+        <form class="form" on:submit=bulk_invite>
+            <FormErrorAlert action_value=action_value />
+
+            <TextareaField id="emails" label=move || t!(i18n, admin.emails) name="emails" value=value_emails />
+
+            <FormField action_value=action_value id="file" label=move || t!(i18n, admin.csv_file)>
+                <input class="file-input w-full" id="file" type="file" accept=".csv,.txt" node_ref=file_input_ref />
+            </FormField>
+
+            <FormField action_value=action_value id="role" label=move || t!(i18n, admin.role)>
+                <select
+                    class="select w-full"
+                    id="role"
+                    on:change=move |event| value_role.set(event_target_value(&event))
+                    prop:value=move || value_role.get()
+                >
+                    <option value="user">{t!(i18n, admin.user)}</option>
+                    <option value="creator">{t!(i18n, admin.creator)}</option>
+                    <option value="admin">{t!(i18n, admin.admin)}</option>
+                </select>
+            </FormField>
+
+            <TextField
+                action_value=action_value
+                id="expires_at"
+                input_type="date"
+                label=move || t!(i18n, admin.expires_at)
+                name="expires_at"
+                value=value_expires_at
+            />
+
+            <SubmitButton is_loading=bulk_invite_action.pending() />
+        </form>
+
+        {move || {
+            action_value
+                .get()
+                .and_then(|result| result.ok())
+                .and_then(|response| response.data)
+                .map(|report| {
+                    view! {
+                        <section class="mt-4">
+                            <h3 class="h3">{t!(i18n, admin.invited_emails)}</h3>
+
+                            <ul class="list-disc ml-5">
+                                {report
+                                    .invited_emails
+                                    .into_iter()
+                                    .map(|email| view! { <li>{email}</li> })
+                                    .collect_view()}
+                            </ul>
+
+                            <h3 class="h3 mt-4">{t!(i18n, admin.failed_emails)}</h3>
+
+                            <ul class="list-disc ml-5">
+                                {report
+                                    .failed_emails
+                                    .into_iter()
+                                    .map(|email| view! { <li>{email}</li> })
+                                    .collect_view()}
+                            </ul>
+                        </section>
+                    }
+                })
+        }}
+    }
+}
+
+#[component]
+fn InvitationCodeStatus(invitation_code: InvitationCodePresenter) -> impl IntoView {
+    let i18n = use_i18n();
+
+    if invitation_code.is_used {
+        EitherOf3::A(
+            view! { <span class="btn btn-sm btn-outline btn-success no-animation">{t!(i18n, admin.used)}</span> },
+        )
+    } else if invitation_code.is_expired {
+        EitherOf3::B(
+            view! { <span class="btn btn-sm btn-outline btn-error no-animation">{t!(i18n, admin.expired)}</span> },
+        )
+    } else {
+        EitherOf3::C(
+            view! { <span class="btn btn-sm btn-outline btn-info no-animation">{t!(i18n, admin.pending)}</span> },
+        )
+    }
+}
+
+#[component]
+pub fn InvitationsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let is_used = RwSignal::new(false);
+    let controller = InfiniteScrollLocalResourceController::new(move |after| {
+        LocalResource::new(move || async move { get_invitation_codes(is_used.get(), after.get()).await })
+    });
+    let text_title = async_t_string!(i18n, admin.invitations).to_signal();
+    let server_action_resend = ServerAction::<AttemptToResendInvitationCode>::new();
+    let server_action_revoke = ServerAction::<AttemptToRevokeInvitationCode>::new();
+    let action_value_revoke = server_action_revoke.value();
+    let resend_item = RwSignal::<Option<InvitationCodePresenter>>::new(None);
+    let revoke_item = RwSignal::<Option<InvitationCodePresenter>>::new(None);
+    let show_resend_confirmation = RwSignal::new(false);
+    let show_revoke_confirmation = RwSignal::new(false);
+
+    Effect::new({
+        let controller = controller.clone();
+        move || {
+            is_used.track();
+            controller.clear_and_refetch();
+        }
+    });
+
+    Effect::new({
+        let controller = controller.clone();
+        move || {
+            let response = MutPresenter::from(action_value_revoke);
+
+            if response.is_success() {
+                controller.clear_and_refetch();
+                revoke_item.set(None);
+            }
+        }
+    });
+
+    view! {
+        <AdminPageContainer title=text_title>
+            <h1 class="h1">{move || text_title.get()}</h1>
+
+            <section class="max-w-[720px] w-full mx-auto">
+                <h2 class="h2">{t!(i18n, admin.bulk_invite)}</h2>
+
+                <BulkInviteForm on_success={
+                    let controller = controller.clone();
+                    move |_| controller.clear_and_refetch()
+                } />
+
+                <div role="tablist" class="tabs tabs-border my-5">
+                    <a
+                        role="tab"
+                        class="tab"
+                        class:tab-active=move || !is_used.get()
+                        on:click=move |_| is_used.set(false)
+                    >
+                        {t!(i18n, admin.pending)}
+                    </a>
+                    <a
+                        role="tab"
+                        class="tab"
+                        class:tab-active=move || is_used.get()
+                        on:click=move |_| is_used.set(true)
+                    >
+                        {t!(i18n, admin.used)}
+                    </a>
+                </div>
+
+                <ConfirmationModal
+                    is_open=show_resend_confirmation
+                    on_accept=move || {
+                        let id = resend_item.get().unwrap().id;
+                        server_action_resend.dispatch(AttemptToResendInvitationCode { id });
+                    }
+                >
+                    <div>{t!(i18n, admin.are_you_sure_you_want_to_resend_this_invitation)}</div>
+
+                    {move || resend_item.get().map(|item| view! { <div class="font-bold my-3">{item.email}</div> })}
+                </ConfirmationModal>
+
+                <ConfirmationModal
+                    is_open=show_revoke_confirmation
+                    on_accept=move || {
+                        let id = revoke_item.get().unwrap().id;
+                        server_action_revoke.dispatch(AttemptToRevokeInvitationCode { id });
+                    }
+                >
+                    <div>{t!(i18n, admin.are_you_sure_you_want_to_revoke_this_invitation)}</div>
+
+                    {move || revoke_item.get().map(|item| view! { <div class="font-bold my-3">{item.email}</div> })}
+                </ConfirmationModal>
+
+                <InfiniteScroll
+                    controller=controller
+                    key=|invitation_code: &InvitationCodePresenter| invitation_code.id
+                    let:invitation_code
+                >
+                    <div class="card card-sm bg-base-200 shadow-xl mb-4">
+                        <div class="card-body">
+                            <div class="card-title">{invitation_code.email.clone()}</div>
+
+                            <div class="flex flex-wrap gap-2">
+                                <InvitationCodeStatus invitation_code=invitation_code.clone() />
+                                <span class="btn btn-sm btn-outline no-animation">{invitation_code.role.clone()}</span>
+                            </div>
+
+                            {invitation_code
+                                .expires_at
+                                .map(|expires_at| {
+                                    view! {
+                                        <div class="text-sm">
+                                            <span class="font-bold">{t!(i18n, admin.expires_at)} ": "</span>
+                                            {expires_at.format("%Y-%m-%d").to_string()}
+                                        </div>
+                                    }
+                                })}
+
+                            {invitation_code
+                                .used_by
+                                .clone()
+                                .map(|used_by| {
+                                    view! {
+                                        <div class="text-sm">
+                                            <span class="font-bold">{t!(i18n, admin.used_by)} ": "</span>
+                                            <UserTag user=used_by />
+                                        </div>
+                                    }
+                                })}
+
+                            <div class="flex items-center justify-between gap-2">
+                                {invitation_code
+                                    .invited_by
+                                    .clone()
+                                    .map(|invited_by| view! { <UserTag user=invited_by /> })}
+
+                                <div class="opacity-70">
+                                    <TimeAgo value=invitation_code.created_at />
+                                </div>
+                            </div>
+
+                            {(!invitation_code.is_used)
+                                .then(|| {
+                                    let resend_invitation_code = invitation_code.clone();
+                                    let revoke_invitation_code = invitation_code.clone();
+                                    view! {
+                                        <div class="card-actions justify-end">
+                                            {(!invitation_code.is_expired)
+                                                .then(|| {
+                                                    view! {
+                                                        <button
+                                                            class="btn btn-ghost font-bold"
+                                                            on:click=move |_| {
+                                                                resend_item.set(Some(resend_invitation_code.clone()));
+                                                                show_resend_confirmation.set(true);
+                                                            }
+                                                        >
+                                                            {t!(i18n, admin.resend)}
+                                                        </button>
+                                                    }
+                                                })}
+
+                                            <button
+                                                class="btn btn-ghost font-bold"
+                                                on:click=move |_| {
+                                                    revoke_item.set(Some(revoke_invitation_code.clone()));
+                                                    show_revoke_confirmation.set(true);
+                                                }
+                                            >
+                                                {t!(i18n, admin.revoke)}
+                                            </button>
+                                        </div>
+                                    }
+                                })}
+                        </div>
+                    </div>
+                </InfiniteScroll>
+            </section>
+        </AdminPageContainer>
+    }
+}
@@ -1,7 +1,19 @@
+mod audit_logs_page;
 mod index_page;
 mod index_parent_page;
+mod invitations_page;
+mod posts_page;
+mod reports_page;
+mod storage_page;
 mod users_page;
+mod websites_page;
 
+pub use audit_logs_page::AuditLogsPage;
 pub use index_page::IndexPage;
 pub use index_parent_page::IndexParentPage;
+pub use invitations_page::InvitationsPage;
+pub use posts_page::PostsPage;
+pub use reports_page::ReportsPage;
+pub use storage_page::StoragePage;
 pub use users_page::UsersPage;
+pub use websites_page::WebsitesPage;
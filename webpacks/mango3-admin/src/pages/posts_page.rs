@@ -0,0 +1,23 @@
+use leptos::prelude::*;
+
+use mango3_web_utils::async_t_string;
+use mango3_web_utils::i18n::use_i18n;
+use mango3_web_utils::utils::ToSignalTrait;
+
+use crate::components::{AdminPageContainer, ModerationList};
+
+#[component]
+pub fn PostsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let text_title = async_t_string!(i18n, admin.posts).to_signal();
+
+    view! {
+        <AdminPageContainer title=text_title>
+            <h1 class="h1">{move || text_title.get()}</h1>
+
+            <section class="max-w-[720px] w-full mx-auto">
+                <ModerationList kind="posts" />
+            </section>
+        </AdminPageContainer>
+    }
+}
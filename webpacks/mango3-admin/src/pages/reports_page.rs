@@ -0,0 +1,209 @@
+use leptos::either::Either;
+use leptos::prelude::*;
+
+use mango3_web_utils::async_t_string;
+use mango3_web_utils::components::{
+    ConfirmationModal, InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollLocalResourceController, TimeAgo,
+    UserTag,
+};
+use mango3_web_utils::i18n::{t, use_i18n};
+use mango3_web_utils::presenters::{AbuseReportPresenter, MutPresenter};
+use mango3_web_utils::utils::ToSignalTrait;
+
+use crate::components::{search_path, AdminPageContainer};
+use crate::server_functions::{get_abuse_reports, AttemptToResolveAbuseReport};
+
+/// Links to the target in its moderation list, filtered by its id. Comments are moderated through their post.
+fn moderation_href(abuse_report: &AbuseReportPresenter) -> String {
+    match abuse_report.target_type.as_str() {
+        "post" | "post_comment" => search_path(
+            "posts",
+            &abuse_report
+                .target_post_id
+                .unwrap_or(abuse_report.target_id)
+                .to_string(),
+        ),
+        "website" => search_path("websites", &abuse_report.target_id.to_string()),
+        _ => search_path("users", &abuse_report.target_id.to_string()),
+    }
+}
+
+#[component]
+fn AbuseReportLabels(abuse_report: AbuseReportPresenter) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let text_category = match abuse_report.category.as_str() {
+        "spam" => async_t_string!(i18n, shared.spam),
+        "harassment" => async_t_string!(i18n, shared.harassment),
+        "hate_speech" => async_t_string!(i18n, shared.hate_speech),
+        "violence" => async_t_string!(i18n, shared.violence),
+        "illegal_content" => async_t_string!(i18n, shared.illegal_content),
+        _ => async_t_string!(i18n, shared.other),
+    }
+    .to_signal();
+
+    let text_target_type = match abuse_report.target_type.as_str() {
+        "post" => async_t_string!(i18n, admin.post),
+        "post_comment" => async_t_string!(i18n, admin.comment),
+        "user" => async_t_string!(i18n, admin.user),
+        _ => async_t_string!(i18n, admin.website),
+    }
+    .to_signal();
+
+    view! {
+        <div class="flex flex-wrap gap-2">
+            <span class="btn btn-sm btn-outline btn-error no-animation">{move || text_category.get()}</span>
+            <span class="btn btn-sm btn-outline no-animation">{move || text_target_type.get()}</span>
+        </div>
+    }
+}
+
+#[component]
+pub fn ReportsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let is_resolved = RwSignal::new(false);
+    let controller = InfiniteScrollLocalResourceController::new(move |after| {
+        LocalResource::new(move || async move { get_abuse_reports(is_resolved.get(), after.get()).await })
+    });
+    let text_title = async_t_string!(i18n, admin.reports).to_signal();
+    let server_action_resolve = ServerAction::<AttemptToResolveAbuseReport>::new();
+    let action_value_resolve = server_action_resolve.value();
+    let resolve_item = RwSignal::<Option<AbuseReportPresenter>>::new(None);
+    let show_resolve_confirmation = RwSignal::new(false);
+
+    Effect::new({
+        let controller = controller.clone();
+        move || {
+            is_resolved.track();
+            controller.clear_and_refetch();
+        }
+    });
+
+    Effect::new({
+        let controller = controller.clone();
+        move || {
+            let response = MutPresenter::from(action_value_resolve);
+
+            if response.is_success() {
+                controller.clear_and_refetch();
+                resolve_item.set(None);
+            }
+        }
+    });
+
+    view! {
+        <AdminPageContainer title=text_title>
+            <h1 class="h1">{move || text_title.get()}</h1>
+
+            <section class="max-w-[720px] w-full mx-auto">
+                <div role="tablist" class="tabs tabs-border mb-5">
+                    <a
+                        role="tab"
+                        class="tab"
+                        class:tab-active=move || !is_resolved.get()
+                        on:click=move |_| is_resolved.set(false)
+                    >
+                        {t!(i18n, admin.open)}
+                    </a>
+                    <a
+                        role="tab"
+                        class="tab"
+                        class:tab-active=move || is_resolved.get()
+                        on:click=move |_| is_resolved.set(true)
+                    >
+                        {t!(i18n, admin.resolved)}
+                    </a>
+                </div>
+
+                <ConfirmationModal
+                    is_open=show_resolve_confirmation
+                    on_accept=move || {
+                        let id = resolve_item.get().unwrap().id;
+                        server_action_resolve.dispatch(AttemptToResolveAbuseReport { id });
+                    }
+                >
+                    <div>{t!(i18n, admin.are_you_sure_you_want_to_resolve_this_report)}</div>
+
+                    {move || {
+                        resolve_item
+                            .get()
+                            .and_then(|item| item.target_title)
+                            .map(|target_title| view! { <div class="font-bold my-3">{target_title}</div> })
+                    }}
+                </ConfirmationModal>
+
+                <InfiniteScroll
+                    controller=controller
+                    key=|abuse_report: &AbuseReportPresenter| abuse_report.id
+                    let:abuse_report
+                >
+                    <div class="card card-sm bg-base-200 shadow-xl mb-4">
+                        <div class="card-body">
+                            <div class="card-title">
+                                {match (abuse_report.target_title.clone(), abuse_report.target_url.clone()) {
+                                    (Some(target_title), Some(target_url)) => {
+                                        Either::Left(
+                                            view! {
+                                                <a class="link" href=target_url.to_string() target="_blank">
+                                                    {target_title}
+                                                </a>
+                                            },
+                                        )
+                                    }
+                                    _ => {
+                                        Either::Right(
+                                            view! {
+                                                <span class="opacity-70">{abuse_report.target_id.to_string()}</span>
+                                            },
+                                        )
+                                    }
+                                }}
+                            </div>
+
+                            <AbuseReportLabels abuse_report=abuse_report.clone() />
+
+                            {(!abuse_report.note.is_empty())
+                                .then(|| {
+                                    view! {
+                                        <div class="text-sm">
+                                            <span class="font-bold">{t!(i18n, shared.note)} ": "</span>
+                                            {abuse_report.note.clone()}
+                                        </div>
+                                    }
+                                })}
+
+                            <div class="flex items-center justify-between gap-2">
+                                <UserTag user=abuse_report.user.clone() />
+
+                                <div class="opacity-70">
+                                    <TimeAgo value=abuse_report.created_at />
+                                </div>
+                            </div>
+
+                            {(!abuse_report.is_resolved)
+                                .then(|| {
+                                    let abuse_report = abuse_report.clone();
+                                    view! {
+                                        <div class="card-actions justify-end">
+                                            <a class="btn btn-ghost font-bold" href=moderation_href(&abuse_report)>
+                                                {t!(i18n, admin.moderate)}
+                                            </a>
+                                            <button
+                                                class="btn btn-ghost font-bold"
+                                                on:click=move |_| {
+                                                    resolve_item.set(Some(abuse_report.clone()));
+                                                    show_resolve_confirmation.set(true);
+                                                }
+                                            >
+                                                {t!(i18n, admin.resolve)}
+                                            </button>
+                                        </div>
+                                    }
+                                })}
+                        </div>
+                    </div>
+                </InfiniteScroll>
+            </section>
+        </AdminPageContainer>
+    }
+}
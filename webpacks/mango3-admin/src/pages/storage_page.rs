@@ -0,0 +1,146 @@
+use leptos::either::Either;
+use leptos::prelude::*;
+
+use mango3_web_utils::async_t_string;
+use mango3_web_utils::components::forms::{FormErrorAlert, SubmitButton, TextField};
+use mango3_web_utils::components::{
+    InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollLocalResourceController, Modal, UserTag,
+};
+use mango3_web_utils::i18n::{t, use_i18n};
+use mango3_web_utils::presenters::{MutPresenter, StorageUsagePresenter};
+use mango3_web_utils::utils::ToSignalTrait;
+
+use crate::components::AdminPageContainer;
+use crate::server_functions::{get_storage_usages, AttemptToUpdateStorageQuota};
+
+#[component]
+pub fn StoragePage() -> impl IntoView {
+    let i18n = use_i18n();
+    let controller = InfiniteScrollLocalResourceController::new(|after| {
+        LocalResource::new(move || async move { get_storage_usages(after.get()).await })
+    });
+    let text_title = async_t_string!(i18n, admin.storage).to_signal();
+    let server_action = ServerAction::<AttemptToUpdateStorageQuota>::new();
+    let action_value = server_action.value();
+    let edit_storage_usage = RwSignal::<Option<StorageUsagePresenter>>::new(None);
+    let show_quota_form = RwSignal::new(false);
+    let value_storage_quota = RwSignal::new(String::new());
+
+    Effect::new({
+        let controller = controller.clone();
+        move || {
+            let response = MutPresenter::from(action_value);
+
+            if response.is_success() {
+                show_quota_form.set(false);
+                controller.clear_and_refetch();
+            }
+        }
+    });
+
+    view! {
+        <AdminPageContainer title=text_title>
+            <h1 class="h1">{move || text_title.get()}</h1>
+
+            <section class="max-w-[720px] w-full mx-auto">
+                <Modal is_open=show_quota_form>
+                    <h4 class="text-lg font-bold">{t!(i18n, admin.edit_storage_quota)}</h4>
+
+                    {move || {
+                        edit_storage_usage
+                            .get()
+                            .map(|storage_usage| {
+                                view! {
+                                    <ActionForm
+                                        action=server_action
+                                        attr:autocomplete="off"
+                                        attr:novalidate="true"
+                                        attr:class="form"
+                                    >
+                                        <FormErrorAlert action_value=action_value />
+
+                                        <input type="hidden" name="user_id" value=storage_usage.user.id.to_string() />
+
+                                        {storage_usage
+                                            .website
+                                            .map(|website| {
+                                                view! {
+                                                    <input type="hidden" name="website_id" value=website.id.to_string() />
+                                                }
+                                            })}
+
+                                        <TextField
+                                            action_value=action_value
+                                            id="storage_quota"
+                                            label=move || t!(i18n, admin.storage_quota)
+                                            name="storage_quota"
+                                            value=value_storage_quota
+                                        />
+
+                                        <div class="text-sm opacity-70">
+                                            {t!(i18n, admin.leave_blank_to_use_the_default_quota_for_the_user_role)}
+                                        </div>
+
+                                        <SubmitButton is_loading=server_action.pending() />
+                                    </ActionForm>
+                                }
+                            })
+                    }}
+                </Modal>
+
+                <InfiniteScroll
+                    controller=controller
+                    key=|storage_usage: &StorageUsagePresenter| storage_usage.key()
+                    let:storage_usage
+                >
+                    <div class="card card-sm bg-base-200 shadow-xl mb-4">
+                        <div class="card-body">
+                            <div class="card-title">
+                                {match storage_usage.website.as_ref() {
+                                    Some(website) => Either::Left(website.name.clone()),
+                                    None => Either::Right(t!(i18n, admin.personal_files)),
+                                }}
+                            </div>
+
+                            <UserTag user=storage_usage.user.clone() />
+
+                            <progress
+                                class="progress progress-primary"
+                                value=storage_usage.used_storage
+                                max=storage_usage.max_storage
+                            />
+
+                            <div>
+                                {storage_usage.used_storage_str.clone()} " / "
+                                {storage_usage.max_storage_str.clone()}
+                            </div>
+
+                            <div class="card-actions justify-end">
+                                <button
+                                    class="btn btn-ghost font-bold"
+                                    on:click={
+                                        let storage_usage = storage_usage.clone();
+                                        move |_| {
+                                            value_storage_quota
+                                                .set(
+                                                    if storage_usage.has_custom_quota {
+                                                        storage_usage.max_storage_str.clone()
+                                                    } else {
+                                                        String::new()
+                                                    },
+                                                );
+                                            edit_storage_usage.set(Some(storage_usage.clone()));
+                                            show_quota_form.set(true);
+                                        }
+                                    }
+                                >
+                                    {t!(i18n, admin.edit_storage_quota)}
+                                </button>
+                            </div>
+                        </div>
+                    </div>
+                </InfiniteScroll>
+            </section>
+        </AdminPageContainer>
+    }
+}
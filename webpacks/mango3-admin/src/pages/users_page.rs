@@ -1,25 +1,37 @@
 use leptos::either::EitherOf3;
 use leptos::prelude::*;
+use leptos_router::hooks::use_query_map;
 
 use mango3_web_utils::async_t_string;
+use mango3_web_utils::components::forms::{FormErrorAlert, SubmitButton, TextField};
 use mango3_web_utils::components::{
-    ConfirmationModal, InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollLocalResourceController, UserCard,
-    UserTag,
+    ConfirmationModal, InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollLocalResourceController, Modal,
+    UserCard, UserTag,
 };
-use mango3_web_utils::context::use_basic_config;
+use mango3_web_utils::context::{param_query, use_basic_config};
 use mango3_web_utils::i18n::{t, use_i18n};
 use mango3_web_utils::presenters::{MutPresenter, UserMinPresenter};
 use mango3_web_utils::utils::ToSignalTrait;
 
-use crate::components::AdminPageContainer;
-use crate::server_functions::{get_users, AttemptToDisableUser, AttemptToEnableUser};
+use crate::components::{AdminPageContainer, SearchForm};
+use crate::server_functions::{
+    get_user_invitation_allowance, get_users, AttemptToDisableUser, AttemptToEnableUser,
+    AttemptToUpdateUserInvitationAllowance,
+};
 
 #[component]
 pub fn UsersPage() -> impl IntoView {
     let i18n = use_i18n();
     let basic_config = use_basic_config();
+    let query_map = use_query_map();
+    let query = RwSignal::new(String::new());
     let controller = InfiniteScrollLocalResourceController::new(|after| {
-        LocalResource::new(move || async move { get_users(after.get()).await })
+        LocalResource::new(move || {
+            let query = param_query(query_map);
+            let after = after.get();
+
+            async move { get_users(query, after).await }
+        })
     });
     let text_title = async_t_string!(i18n, admin.users).to_signal();
     let server_action_disable_user = ServerAction::<AttemptToDisableUser>::new();
@@ -30,6 +42,42 @@ pub fn UsersPage() -> impl IntoView {
     let enable_user = RwSignal::<Option<UserMinPresenter>>::new(None);
     let show_disable_confirmation = RwSignal::new(false);
     let show_enable_confirmation = RwSignal::new(false);
+    let server_action_allowance = ServerAction::<AttemptToUpdateUserInvitationAllowance>::new();
+    let action_value_allowance = server_action_allowance.value();
+    let allowance_user = RwSignal::<Option<UserMinPresenter>>::new(None);
+    let show_allowance_form = RwSignal::new(false);
+    let value_invitation_allowance = RwSignal::new(String::new());
+    let allowance_resource = LocalResource::new(move || async move {
+        if let Some(user) = allowance_user.get() {
+            get_user_invitation_allowance(user.id).await.ok()
+        } else {
+            None
+        }
+    });
+
+    Effect::new(move || query.set(param_query(query_map)));
+
+    Effect::new({
+        let controller = controller.clone();
+        move || {
+            query_map.track();
+            controller.clear_and_refetch();
+        }
+    });
+
+    Effect::new(move || {
+        if let Some(invitation_allowance) = allowance_resource.get().and_then(|result| result.take()) {
+            value_invitation_allowance.set(invitation_allowance.to_string());
+        }
+    });
+
+    Effect::new(move || {
+        let response = MutPresenter::from(action_value_allowance);
+
+        if response.is_success() {
+            show_allowance_form.set(false);
+        }
+    });
 
     Effect::new({
         let controller = controller.clone();
@@ -60,6 +108,8 @@ pub fn UsersPage() -> impl IntoView {
             <h1 class="h1">{move || text_title.get()}</h1>
 
             <section class="max-w-[720px] w-full mx-auto">
+                <SearchForm path="users" query=query />
+
                 <ConfirmationModal
                     is_open=show_disable_confirmation
                     on_accept=move || {
@@ -87,13 +137,64 @@ pub fn UsersPage() -> impl IntoView {
                     {move || enable_user.get().map(|user| view! { <UserTag class="justify-center my-3" user=user /> })}
                 </ConfirmationModal>
 
+                <Modal is_open=show_allowance_form>
+                    <h4 class="text-lg font-bold">{t!(i18n, admin.edit_invitation_allowance)}</h4>
+
+                    {move || {
+                        allowance_user
+                            .get()
+                            .map(|user| {
+                                view! {
+                                    <ActionForm
+                                        action=server_action_allowance
+                                        attr:autocomplete="off"
+                                        attr:novalidate="true"
+                                        attr:class="form"
+                                    >
+                                        <FormErrorAlert action_value=action_value_allowance />
+
+                                        <input type="hidden" name="user_id" value=user.id.to_string() />
+
+                                        <UserTag class="my-3" user=user />
+
+                                        <TextField
+                                            action_value=action_value_allowance
+                                            id="invitation_allowance"
+                                            input_type="number"
+                                            label=move || t!(i18n, admin.invitation_allowance)
+                                            name="invitation_allowance"
+                                            value=value_invitation_allowance
+                                        />
+
+                                        <SubmitButton is_loading=server_action_allowance.pending() />
+                                    </ActionForm>
+                                }
+                            })
+                    }}
+                </Modal>
+
                 <InfiniteScroll controller=controller key=|user: &UserMinPresenter| user.id let:user>
                     <UserCard
                         user=user.clone()
                         hashtags_base_url=basic_config.home_url.to_string()
                         actions=move || {
+                            let allowance_button = view! {
+                                <button
+                                    class="btn btn-ghost font-bold"
+                                    on:click={
+                                        let user = user.clone();
+                                        move |_| {
+                                            value_invitation_allowance.set(String::new());
+                                            allowance_user.set(Some(user.clone()));
+                                            show_allowance_form.set(true);
+                                        }
+                                    }
+                                >
+                                    {t!(i18n, admin.invitations)}
+                                </button>
+                            };
                             let user = user.clone();
-                            if user.is_disabled {
+                            let toggle_button = if user.is_disabled {
                                 EitherOf3::A(
                                     view! {
                                         <button
@@ -123,6 +224,11 @@ pub fn UsersPage() -> impl IntoView {
                                 )
                             } else {
                                 EitherOf3::C(())
+                            };
+
+                            view! {
+                                {toggle_button}
+                                {allowance_button}
                             }
                         }
                     />
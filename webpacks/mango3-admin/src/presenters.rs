@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use mango3_core::models::{InvitationCodesReport, JobQueueDepth, PlatformDailyStat, PlatformRoleStat, PlatformStats};
+#[cfg(feature = "ssr")]
+use mango3_web_utils::presenters::FromModel;
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct DashboardPresenter {
+    pub stats: PlatformStatsPresenter,
+    pub daily_stats: Vec<PlatformDailyStatPresenter>,
+    pub job_queue_depths: Vec<JobQueueDepthPresenter>,
+}
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub struct InvitationCodesReportPresenter {
+    pub invited_emails: Vec<String>,
+    pub failed_emails: Vec<String>,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<InvitationCodesReport> for InvitationCodesReportPresenter {
+    async fn from_model(report: &InvitationCodesReport) -> Self {
+        Self {
+            invited_emails: report.invited_emails.clone(),
+            failed_emails: report.failed_emails.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct PlatformStatsPresenter {
+    pub users_count: i64,
+    pub disabled_users_count: i64,
+    pub unconfirmed_users_count: i64,
+    pub users_by_role: Vec<PlatformRoleStatPresenter>,
+    pub websites_count: i64,
+    pub published_websites_count: i64,
+    pub published_posts_count: i64,
+    pub comments_count: i64,
+    pub used_storage_str: String,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<PlatformStats> for PlatformStatsPresenter {
+    async fn from_model(stats: &PlatformStats) -> Self {
+        Self {
+            users_count: stats.users_count,
+            disabled_users_count: stats.disabled_users_count,
+            unconfirmed_users_count: stats.unconfirmed_users_count,
+            users_by_role: futures::future::join_all(
+                stats.users_by_role.iter().map(PlatformRoleStatPresenter::from_model),
+            )
+            .await,
+            websites_count: stats.websites_count,
+            published_websites_count: stats.published_websites_count,
+            published_posts_count: stats.published_posts_count,
+            comments_count: stats.comments_count,
+            used_storage_str: stats.used_storage().to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct PlatformRoleStatPresenter {
+    pub role: String,
+    pub users_count: i64,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<PlatformRoleStat> for PlatformRoleStatPresenter {
+    async fn from_model(role_stat: &PlatformRoleStat) -> Self {
+        Self {
+            role: role_stat.role.to_string(),
+            users_count: role_stat.users_count,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct PlatformDailyStatPresenter {
+    pub day: String,
+    pub users_count: i64,
+    pub websites_count: i64,
+    pub posts_count: i64,
+    pub comments_count: i64,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<PlatformDailyStat> for PlatformDailyStatPresenter {
+    async fn from_model(daily_stat: &PlatformDailyStat) -> Self {
+        Self {
+            day: daily_stat.day.format("%Y-%m-%d").to_string(),
+            users_count: daily_stat.users_count,
+            websites_count: daily_stat.websites_count,
+            posts_count: daily_stat.posts_count,
+            comments_count: daily_stat.comments_count,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct JobQueueDepthPresenter {
+    pub name: String,
+    pub pending_count: i64,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<JobQueueDepth> for JobQueueDepthPresenter {
+    async fn from_model(job_queue_depth: &JobQueueDepth) -> Self {
+        Self {
+            name: job_queue_depth.name.clone(),
+            pending_count: job_queue_depth.pending_count,
+        }
+    }
+}
@@ -1,7 +1,13 @@
 use leptos::prelude::*;
+use server_fn::codec::{MultipartData, MultipartFormData};
 use uuid::Uuid;
 
-use mango3_web_utils::presenters::{CursorPagePresenter, MutPresenter, UserMinPresenter};
+use mango3_web_utils::presenters::{
+    AbuseReportPresenter, AuditLogPresenter, CursorPagePresenter, InvitationCodePresenter, ModerationPresenter,
+    MutPresenter, StorageUsagePresenter, UserMinPresenter,
+};
+
+use crate::presenters::{DashboardPresenter, InvitationCodesReportPresenter};
 
 #[cfg(feature = "ssr")]
 use mango3_core::config::BASIC_CONFIG;
@@ -10,7 +16,9 @@ use mango3_core::enums::UserRole;
 #[cfg(feature = "ssr")]
 use mango3_core::utils::CursorPageParams;
 #[cfg(feature = "ssr")]
-use mango3_web_utils::ssr::{expect_core_context, extract_user};
+use mango3_web_utils::presenters::FromModel;
+#[cfg(feature = "ssr")]
+use mango3_web_utils::ssr::{expect_core_context, extract_audit_actor, extract_user};
 
 #[cfg(feature = "ssr")]
 const ALLOWED_ROLES: [UserRole; 2] = [UserRole::Admin, UserRole::Superuser];
@@ -26,6 +34,58 @@ pub async fn require_admin() -> Result<bool, ServerFnError> {
     Ok(true)
 }
 
+#[server(input = MultipartFormData)]
+pub async fn attempt_to_bulk_invite(
+    data: MultipartData,
+) -> Result<MutPresenter<InvitationCodesReportPresenter>, ServerFnError> {
+    use std::str::FromStr;
+
+    use chrono::NaiveDate;
+
+    if !require_admin().await? {
+        return mango3_web_utils::mut_presenter_error!();
+    }
+
+    let Some(mut data) = data.into_inner() else {
+        return mango3_web_utils::mut_presenter_error!();
+    };
+
+    let mut emails = String::new();
+    let mut role = UserRole::User;
+    let mut expires_at = None;
+
+    while let Some(field) = data.next_field().await? {
+        match field.name() {
+            Some("emails") | Some("file") => {
+                emails.push('\n');
+                emails.push_str(&field.text().await?);
+            }
+            Some("role") => {
+                role = UserRole::from_str(&field.text().await?).unwrap_or(UserRole::User);
+            }
+            Some("expires_at") => {
+                expires_at = NaiveDate::parse_from_str(field.text().await?.trim(), "%Y-%m-%d")
+                    .ok()
+                    .and_then(|date| date.and_hms_opt(23, 59, 59))
+                    .map(|date_time| date_time.and_utc());
+            }
+            _ => {}
+        }
+    }
+
+    // Invitations can't hand out the superuser role, as there can only be one superuser.
+    if role == UserRole::Superuser {
+        return mango3_web_utils::mut_presenter_error!();
+    }
+
+    let core_context = expect_core_context();
+    let actor = extract_audit_actor().await?;
+
+    let report = mango3_core::commands::insert_invitation_codes(&core_context, &emails, role, expires_at, &actor).await;
+
+    mango3_web_utils::mut_presenter!(mango3_core::mut_success!(report))
+}
+
 #[server]
 pub async fn attempt_to_disable_user(id: Uuid) -> Result<MutPresenter, ServerFnError> {
     if !require_admin().await? {
@@ -34,8 +94,9 @@ pub async fn attempt_to_disable_user(id: Uuid) -> Result<MutPresenter, ServerFnE
 
     let core_context = expect_core_context();
     let user = mango3_core::commands::get_user_by_id(&core_context, id).await?;
+    let actor = extract_audit_actor().await?;
 
-    let result = mango3_core::commands::disable_user(&core_context, &user).await;
+    let result = mango3_core::commands::disable_user(&core_context, &user, &actor).await;
 
     mango3_web_utils::mut_presenter!(result)
 }
@@ -48,8 +109,157 @@ pub async fn attempt_to_enable_user(id: Uuid) -> Result<MutPresenter, ServerFnEr
 
     let core_context = expect_core_context();
     let user = mango3_core::commands::get_user_by_id(&core_context, id).await?;
+    let actor = extract_audit_actor().await?;
+
+    let result = mango3_core::commands::enable_user(&core_context, &user, &actor).await;
+
+    mango3_web_utils::mut_presenter!(result)
+}
+
+#[server]
+pub async fn attempt_to_moderate(
+    website_id: Uuid,
+    post_id: Option<Uuid>,
+    action: String,
+    reason: String,
+) -> Result<MutPresenter, ServerFnError> {
+    if !require_admin().await? {
+        return mango3_web_utils::mut_presenter_error!();
+    }
+
+    let core_context = expect_core_context();
+    let actor = extract_audit_actor().await?;
+
+    if let Some(post_id) = post_id {
+        let post = mango3_core::commands::get_post_by_id(&core_context, post_id, None, None, None).await?;
+
+        let result =
+            mango3_core::commands::moderate_post(&core_context, &post, action.as_str().into(), &reason, &actor).await;
+
+        return mango3_web_utils::mut_presenter!(result);
+    }
+
+    let website = mango3_core::commands::get_website_by_id(&core_context, website_id, None).await?;
+
+    let result =
+        mango3_core::commands::moderate_website(&core_context, &website, action.as_str().into(), &reason, &actor).await;
+
+    mango3_web_utils::mut_presenter!(result)
+}
+
+#[server]
+pub async fn attempt_to_resend_invitation_code(id: Uuid) -> Result<MutPresenter, ServerFnError> {
+    if !require_admin().await? {
+        return mango3_web_utils::mut_presenter_error!();
+    }
+
+    let core_context = expect_core_context();
+    let invitation_code = mango3_core::commands::get_invitation_code_by_id(&core_context, id).await?;
+
+    let result = mango3_core::commands::resend_invitation_code(&core_context, &invitation_code).await;
+
+    mango3_web_utils::mut_presenter!(result)
+}
+
+#[server]
+pub async fn attempt_to_resolve_abuse_report(id: Uuid) -> Result<MutPresenter, ServerFnError> {
+    if !require_admin().await? {
+        return mango3_web_utils::mut_presenter_error!();
+    }
+
+    let core_context = expect_core_context();
+    let abuse_report = mango3_core::commands::get_abuse_report_by_id(&core_context, id).await?;
+    let actor = extract_audit_actor().await?;
+
+    let result = mango3_core::commands::resolve_abuse_report(&core_context, &abuse_report, &actor).await;
+
+    mango3_web_utils::mut_presenter!(result)
+}
+
+#[server]
+pub async fn attempt_to_restore(website_id: Uuid, post_id: Option<Uuid>) -> Result<MutPresenter, ServerFnError> {
+    if !require_admin().await? {
+        return mango3_web_utils::mut_presenter_error!();
+    }
+
+    let core_context = expect_core_context();
+    let actor = extract_audit_actor().await?;
+
+    if let Some(post_id) = post_id {
+        let post = mango3_core::commands::get_post_by_id(&core_context, post_id, None, None, None).await?;
+
+        let result = mango3_core::commands::restore_post(&core_context, &post, &actor).await;
+
+        return mango3_web_utils::mut_presenter!(result);
+    }
+
+    let website = mango3_core::commands::get_website_by_id(&core_context, website_id, None).await?;
+
+    let result = mango3_core::commands::restore_website(&core_context, &website, &actor).await;
+
+    mango3_web_utils::mut_presenter!(result)
+}
+
+#[server]
+pub async fn attempt_to_revoke_invitation_code(id: Uuid) -> Result<MutPresenter, ServerFnError> {
+    if !require_admin().await? {
+        return mango3_web_utils::mut_presenter_error!();
+    }
+
+    let core_context = expect_core_context();
+    let invitation_code = mango3_core::commands::get_invitation_code_by_id(&core_context, id).await?;
+    let actor = extract_audit_actor().await?;
+
+    let result = mango3_core::commands::delete_invitation_code(&core_context, &invitation_code, &actor).await;
+
+    mango3_web_utils::mut_presenter!(result)
+}
+
+#[server]
+pub async fn attempt_to_update_storage_quota(
+    user_id: Uuid,
+    website_id: Option<Uuid>,
+    storage_quota: String,
+) -> Result<MutPresenter, ServerFnError> {
+    if !require_admin().await? {
+        return mango3_web_utils::mut_presenter_error!();
+    }
+
+    let core_context = expect_core_context();
+    let actor = extract_audit_actor().await?;
 
-    let result = mango3_core::commands::enable_user(&core_context, &user).await;
+    if let Some(website_id) = website_id {
+        let website = mango3_core::commands::get_website_by_id(&core_context, website_id, None).await?;
+
+        let result =
+            mango3_core::commands::update_website_storage_quota(&core_context, &website, &storage_quota, &actor).await;
+
+        return mango3_web_utils::mut_presenter!(result);
+    }
+
+    let user = mango3_core::commands::get_user_by_id(&core_context, user_id).await?;
+
+    let result = mango3_core::commands::update_user_storage_quota(&core_context, &user, &storage_quota, &actor).await;
+
+    mango3_web_utils::mut_presenter!(result)
+}
+
+#[server]
+pub async fn attempt_to_update_user_invitation_allowance(
+    user_id: Uuid,
+    invitation_allowance: String,
+) -> Result<MutPresenter, ServerFnError> {
+    if !require_admin().await? {
+        return mango3_web_utils::mut_presenter_error!();
+    }
+
+    let core_context = expect_core_context();
+    let user = mango3_core::commands::get_user_by_id(&core_context, user_id).await?;
+    let actor = extract_audit_actor().await?;
+
+    let result =
+        mango3_core::commands::update_user_invitation_allowance(&core_context, &user, &invitation_allowance, &actor)
+            .await;
 
     mango3_web_utils::mut_presenter!(result)
 }
@@ -64,14 +274,160 @@ pub async fn is_admin() -> Result<bool, ServerFnError> {
 }
 
 #[server]
-pub async fn get_users(after: Option<Uuid>) -> Result<CursorPagePresenter<UserMinPresenter>, ServerFnError> {
+pub async fn get_abuse_reports(
+    is_resolved: bool,
+    after: Option<Uuid>,
+) -> Result<CursorPagePresenter<AbuseReportPresenter>, ServerFnError> {
+    if !require_admin().await? {
+        return mango3_web_utils::cursor_page_presenter!();
+    }
+
+    let core_context = expect_core_context();
+    let page_params = CursorPageParams { after, first: 10 };
+    let page = mango3_core::commands::paginate_abuse_reports(&core_context, &page_params, is_resolved).await;
+
+    mango3_web_utils::cursor_page_presenter!(&page)
+}
+
+#[server]
+pub async fn get_audit_logs(
+    action: String,
+    username: String,
+    after: Option<Uuid>,
+) -> Result<CursorPagePresenter<AuditLogPresenter>, ServerFnError> {
+    use std::str::FromStr;
+
+    use mango3_core::enums::AuditLogAction;
+
+    if !require_admin().await? {
+        return mango3_web_utils::cursor_page_presenter!();
+    }
+
+    let core_context = expect_core_context();
+    let action = AuditLogAction::from_str(&action).ok();
+    let actor_user_id = if !username.trim().is_empty() {
+        let Ok(user) = mango3_core::commands::get_user_by_username(&core_context, username.trim()).await else {
+            return mango3_web_utils::cursor_page_presenter!();
+        };
+
+        Some(user.id)
+    } else {
+        None
+    };
+    let page_params = CursorPageParams { after, first: 10 };
+    let page =
+        mango3_core::commands::paginate_audit_logs(&core_context, &page_params, action, actor_user_id, None).await;
+
+    mango3_web_utils::cursor_page_presenter!(&page)
+}
+
+#[server]
+pub async fn get_dashboard(days: u16) -> Result<Option<DashboardPresenter>, ServerFnError> {
+    use chrono::{Days, Utc};
+    use futures::future::join_all;
+
+    use crate::presenters::{JobQueueDepthPresenter, PlatformDailyStatPresenter, PlatformStatsPresenter};
+
+    if !require_admin().await? {
+        return Ok(None);
+    }
+
+    let core_context = expect_core_context();
+    let since = Utc::now()
+        .date_naive()
+        .checked_sub_days(Days::new(u64::from(days.clamp(1, 365)) - 1))
+        .unwrap_or_default();
+
+    let stats = mango3_core::commands::get_platform_stats(&core_context).await?;
+    let daily_stats = mango3_core::commands::all_platform_daily_stats(&core_context, since).await;
+    let job_queue_depths = mango3_core::commands::all_job_queue_depths(&core_context).await;
+
+    Ok(Some(DashboardPresenter {
+        stats: PlatformStatsPresenter::from_model(&stats).await,
+        daily_stats: join_all(daily_stats.iter().map(PlatformDailyStatPresenter::from_model)).await,
+        job_queue_depths: join_all(job_queue_depths.iter().map(JobQueueDepthPresenter::from_model)).await,
+    }))
+}
+
+#[server]
+pub async fn get_invitation_codes(
+    is_used: bool,
+    after: Option<Uuid>,
+) -> Result<CursorPagePresenter<InvitationCodePresenter>, ServerFnError> {
+    if !require_admin().await? {
+        return mango3_web_utils::cursor_page_presenter!();
+    }
+
+    let core_context = expect_core_context();
+    let page_params = CursorPageParams { after, first: 10 };
+    let page = mango3_core::commands::paginate_invitation_codes(&core_context, &page_params, Some(is_used), None).await;
+
+    mango3_web_utils::cursor_page_presenter!(&page)
+}
+
+#[server]
+pub async fn get_user_invitation_allowance(user_id: Uuid) -> Result<i32, ServerFnError> {
+    if !require_admin().await? {
+        return Ok(0);
+    }
+
+    let core_context = expect_core_context();
+    let user = mango3_core::commands::get_user_by_id(&core_context, user_id).await?;
+
+    Ok(mango3_core::commands::get_user_invitation_allowance(&core_context, &user).await)
+}
+
+#[server]
+pub async fn get_users(
+    query: String,
+    after: Option<Uuid>,
+) -> Result<CursorPagePresenter<UserMinPresenter>, ServerFnError> {
+    if !require_admin().await? {
+        return mango3_web_utils::cursor_page_presenter!();
+    }
+
+    let core_context = expect_core_context();
+    let page_params = CursorPageParams { after, first: 10 };
+    let page = mango3_core::commands::paginate_users(&core_context, &page_params, &query).await;
+
+    mango3_web_utils::cursor_page_presenter!(&page)
+}
+
+#[server]
+pub async fn get_moderation_items(
+    kind: String,
+    query: String,
+    after: Option<Uuid>,
+) -> Result<CursorPagePresenter<ModerationPresenter>, ServerFnError> {
+    if !require_admin().await? {
+        return mango3_web_utils::cursor_page_presenter!();
+    }
+
+    let core_context = expect_core_context();
+    let page_params = CursorPageParams { after, first: 10 };
+
+    if kind == "posts" {
+        let page = mango3_core::commands::paginate_posts_for_moderation(&core_context, &page_params, &query).await;
+
+        return mango3_web_utils::cursor_page_presenter!(&page);
+    }
+
+    let page = mango3_core::commands::paginate_websites_for_moderation(&core_context, &page_params, &query).await;
+
+    mango3_web_utils::cursor_page_presenter!(&page)
+}
+
+#[server]
+pub async fn get_storage_usages(
+    after: Option<Uuid>,
+) -> Result<CursorPagePresenter<StorageUsagePresenter>, ServerFnError> {
     if !require_admin().await? {
         return mango3_web_utils::cursor_page_presenter!();
     }
 
     let core_context = expect_core_context();
     let page_params = CursorPageParams { after, first: 10 };
-    let page = mango3_core::commands::paginate_users(&core_context, &page_params).await;
+    let page = mango3_core::commands::paginate_storage_usages(&core_context, &page_params).await;
 
     mango3_web_utils::cursor_page_presenter!(&page)
 }
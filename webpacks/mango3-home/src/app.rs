@@ -12,7 +12,7 @@ use mango3_web_utils::i18n::{t, use_i18n};
 use mango3_web_utils::pages::NotFoundPage;
 
 use crate::constants::KEY_PARAM_USERNAME;
-use crate::pages::{IndexPage, PostsPage, SearchPage, ShowHashtagPage, ShowUserPage, WebsitesPage};
+use crate::pages::{HashtagsPage, IndexPage, PostsPage, SearchPage, ShowHashtagPage, ShowUserPage, WebsitesPage};
 
 #[component]
 pub fn App() -> impl IntoView {
@@ -48,6 +48,10 @@ pub fn App() -> impl IntoView {
                                             <a href="/websites">{t!(i18n, home.websites)}</a>
                                         </li>
 
+                                        <li>
+                                            <a href="/hashtags">{t!(i18n, home.hashtags)}</a>
+                                        </li>
+
                                         <li class="shrink">
                                             <SearchBar />
                                         </li>
@@ -64,6 +68,7 @@ pub fn App() -> impl IntoView {
                                 <Route path=StaticSegment("posts") view=PostsPage />
                                 <Route path=StaticSegment("websites") view=WebsitesPage />
                                 <Route path=StaticSegment("search") view=SearchPage />
+                                <Route path=StaticSegment("hashtags") view=HashtagsPage />
                                 <Route
                                     path=(StaticSegment("hashtags"), ParamSegment(KEY_PARAM_NAME))
                                     view=ShowHashtagPage
@@ -1,50 +1,67 @@
 use leptos::prelude::*;
 
 use leptos_router::params::ParamsMap;
-use mango3_web_utils::components::{
-    InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollResourceController, PostCard, WebsiteCard,
-};
+use mango3_web_utils::components::{InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollResourceController};
 use mango3_web_utils::context::param_query;
-use mango3_web_utils::presenters::{PostMinPresenter, WebsiteMinPresenter};
 
-use crate::server_functions::{get_posts_search, get_websites_search};
+use crate::presenters::SearchResultPresenter;
+use crate::server_functions::get_search_results;
 
 #[component]
-pub fn SearchPostsTab(params_map: Memo<ParamsMap>) -> impl IntoView {
+pub fn SearchResultCard(search_result: SearchResultPresenter) -> impl IntoView {
+    let href = search_result.url.to_string();
+
+    view! {
+        <div class="card card-sm bg-base-200 shadow-xl mb-4">
+            <div class="card-body">
+                <h3 class="card-title text-xl">
+                    <a href=href.clone()>{search_result.title}</a>
+                </h3>
+
+                <a class="link link-hover text-sm opacity-70 break-all" href=href.clone()>
+                    {href}
+                </a>
+
+                <div class="break-words" inner_html=search_result.headline_html />
+            </div>
+        </div>
+    }
+}
+
+#[component]
+pub fn SearchResultsTab(params_map: Memo<ParamsMap>) -> impl IntoView {
+    let param = move |key: &str| params_map.with(|params| params.get(key));
     let controller = InfiniteScrollResourceController::new(move |after| {
         Resource::new_blocking(
-            move || (param_query(params_map), after.get()),
-            |(query, after)| async move { get_posts_search(query, after).await },
+            move || {
+                (
+                    param("tab").unwrap_or_default(),
+                    param_query(params_map),
+                    param("hashtag"),
+                    param("website"),
+                    param("author"),
+                    param("since"),
+                    param("until"),
+                    after.get(),
+                )
+            },
+            |(kind, query, hashtag, website, author, since, until, after)| async move {
+                get_search_results(kind, query, hashtag, website, author, since, until, after).await
+            },
         )
     });
 
     Effect::new({
         let controller = controller.clone();
         move || {
-            param_query(params_map);
+            params_map.track();
             controller.clear_and_refetch();
         }
     });
 
     view! {
-        <InfiniteScroll controller=controller key=|post: &PostMinPresenter| post.id let:post>
-            <PostCard post=post show_host=true />
-        </InfiniteScroll>
-    }
-}
-
-#[component]
-pub fn SearchWebsitesTab(params_map: Memo<ParamsMap>) -> impl IntoView {
-    let controller = InfiniteScrollResourceController::new(move |after| {
-        Resource::new_blocking(
-            move || (param_query(params_map), after.get()),
-            |(query, after)| async move { get_websites_search(query, after).await },
-        )
-    });
-
-    view! {
-        <InfiniteScroll controller=controller key=|website: &WebsiteMinPresenter| website.id let:website>
-            <WebsiteCard website=website />
+        <InfiniteScroll controller=controller key=|search_result: &SearchResultPresenter| search_result.id let:search_result>
+            <SearchResultCard search_result=search_result />
         </InfiniteScroll>
     }
 }
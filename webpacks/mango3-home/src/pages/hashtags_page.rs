@@ -0,0 +1,117 @@
+use leptos::either::Either;
+use leptos::prelude::*;
+use leptos_router::hooks::{use_navigate, use_query_map};
+
+use mango3_web_utils::async_t_string;
+use mango3_web_utils::components::{Hashtags, LoadingSpinner};
+use mango3_web_utils::context::param_query;
+use mango3_web_utils::i18n::{t, use_i18n};
+use mango3_web_utils::icons::MagnifyingGlassMini;
+use mango3_web_utils::pages::Page;
+use mango3_web_utils::server_functions::get_hashtag_suggestions;
+use mango3_web_utils::utils::ToSignalTrait;
+
+use crate::server_functions::{get_hashtags_search, get_trending_hashtags};
+
+#[component]
+pub fn HashtagsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let navigate = use_navigate();
+    let query_map = use_query_map();
+    let query = RwSignal::new(String::new());
+    let hashtags_resource = Resource::new_blocking(
+        move || param_query(query_map),
+        |query| async move {
+            if query.trim().is_empty() {
+                get_trending_hashtags().await
+            } else {
+                get_hashtags_search(query).await
+            }
+        },
+    );
+    let suggestions_resource = LocalResource::new(move || {
+        let query = query.get();
+
+        async move {
+            if query.trim().is_empty() {
+                vec![]
+            } else {
+                get_hashtag_suggestions(query).await.unwrap_or_default()
+            }
+        }
+    });
+
+    Effect::new(move || query.set(param_query(query_map)));
+
+    let text_title = async_t_string!(i18n, home.hashtags).to_signal();
+
+    view! {
+        <Page title=text_title>
+            <h1 class="h1">{move || text_title.get()}</h1>
+
+            <section class="max-w-[720px] w-full mx-auto">
+                <form
+                    class="mb-5"
+                    on:submit=move |event| {
+                        event.prevent_default();
+                        navigate(&format!("/hashtags?q={}", query.get().trim()), Default::default());
+                    }
+                >
+                    <label class="input flex items-center gap-2 w-full">
+                        <input
+                            class="grow"
+                            type="search"
+                            list="hashtag-suggestions"
+                            placeholder=move || async_t_string!(i18n, home.search_hashtags).get()
+                            bind:value=query
+                        />
+                        <button class="btn btn-ghost p-0 min-h-7 h-7 w-7" type="submit">
+                            <MagnifyingGlassMini />
+                        </button>
+                    </label>
+
+                    <datalist id="hashtag-suggestions">
+                        <For
+                            each=move || suggestions_resource.get().map(|hashtags| hashtags.take()).unwrap_or_default()
+                            key=|hashtag| hashtag.id
+                            let:hashtag
+                        >
+                            <option value=hashtag.name />
+                        </For>
+                    </datalist>
+                </form>
+
+                <h2 class="h2">
+                    {move || {
+                        if param_query(query_map).trim().is_empty() {
+                            async_t_string!(i18n, home.trending_hashtags).get()
+                        } else {
+                            async_t_string!(i18n, shared.search_results_for, query = param_query(query_map)).get()
+                        }
+                    }}
+                </h2>
+
+                <Transition fallback=LoadingSpinner>
+                    {move || Suspend::new(async move {
+                        hashtags_resource
+                            .get()
+                            .and_then(|result| result.ok())
+                            .map(|hashtags| {
+                                if hashtags.is_empty() {
+                                    Either::Left(view! { <p>{t!(i18n, home.no_hashtags_found)}</p> })
+                                } else {
+                                    Either::Right(
+                                        view! {
+                                            <div class="flex flex-wrap gap-2">
+                                                <Hashtags hashtags=hashtags />
+                                            </div>
+                                        },
+                                    )
+                                }
+                            })
+                    })}
+                </Transition>
+            </section>
+        </Page>
+    }
+}
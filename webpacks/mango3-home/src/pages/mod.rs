@@ -1,3 +1,4 @@
+mod hashtags_page;
 mod index_page;
 mod posts_page;
 mod search_page;
@@ -5,6 +6,7 @@ mod show_hashtag_page;
 mod show_user_page;
 mod websites_page;
 
+pub use hashtags_page::HashtagsPage;
 pub use index_page::IndexPage;
 pub use posts_page::PostsPage;
 pub use search_page::SearchPage;
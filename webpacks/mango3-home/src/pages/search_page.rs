@@ -1,6 +1,6 @@
-use leptos::either::Either;
 use leptos::prelude::*;
-use leptos_router::hooks::use_query_map;
+use leptos_router::hooks::{use_navigate, use_query_map};
+use leptos_router::params::ParamsMap;
 
 use mango3_web_utils::async_t_string;
 use mango3_web_utils::context::param_query;
@@ -8,49 +8,175 @@ use mango3_web_utils::i18n::{t, use_i18n};
 use mango3_web_utils::pages::Page;
 use mango3_web_utils::utils::ToSignalTrait;
 
-use crate::components::{SearchPostsTab, SearchWebsitesTab};
+use crate::components::SearchResultsTab;
+use crate::server_functions::get_search_suggestions;
+
+const SEARCH_FILTER_KEYS: [&str; 5] = ["hashtag", "website", "author", "since", "until"];
+
+fn search_path(params: Vec<(&str, String)>) -> String {
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(params.iter().filter(|(_, value)| !value.trim().is_empty()))
+        .finish();
+
+    format!("/search?{query}")
+}
+
+#[component]
+fn SearchFilterField(
+    value: RwSignal<String>,
+    name: &'static str,
+    #[prop(default = "text")] input_type: &'static str,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <label class="fieldset">
+            <span class="fieldset-legend">{children()}</span>
+            <input class="input w-full" type=input_type name=name bind:value=value />
+        </label>
+    }
+}
+
+#[component]
+fn SearchTab(
+    params_map: Memo<ParamsMap>,
+    active_tab: Memo<String>,
+    tab: &'static str,
+    #[prop(optional)] is_default: bool,
+    children: Children,
+) -> impl IntoView {
+    let href = move || {
+        let mut params = vec![("q", param_query(params_map)), ("tab", tab.to_owned())];
+
+        params_map.with(|params_map| {
+            for key in SEARCH_FILTER_KEYS {
+                params.push((key, params_map.get(key).unwrap_or_default()));
+            }
+        });
+
+        search_path(params)
+    };
+
+    view! {
+        <a
+            role="tab"
+            class="tab"
+            class:tab-active=move || active_tab.get() == tab || (is_default && active_tab.get().is_empty())
+            href=href
+        >
+            {children()}
+        </a>
+    }
+}
 
 #[component]
 pub fn SearchPage() -> impl IntoView {
     let query_map = use_query_map();
     let i18n = use_i18n();
+    let navigate = use_navigate();
     let active_tab = Memo::new(move |_| query_map.with(|params| params.get("tab")).unwrap_or_default());
+    let filters = SEARCH_FILTER_KEYS.map(|_| RwSignal::new(String::new()));
+    let [hashtag, website, author, since, until] = filters;
+    let suggestions_resource = Resource::new_blocking(
+        move || param_query(query_map),
+        |query| async move { get_search_suggestions(query).await.unwrap_or_default() },
+    );
     let text_title = async_t_string!(i18n, shared.search_results_for, query = param_query(query_map)).to_signal();
 
+    Effect::new(move || {
+        query_map.with(|params| {
+            for (key, value) in SEARCH_FILTER_KEYS.iter().zip(filters) {
+                value.set(params.get(key).unwrap_or_default());
+            }
+        })
+    });
+
     view! {
         <Page title=text_title>
             <h1 class="h1">{move || text_title.get()}</h1>
 
             <section class="max-w-[720px] w-full mx-auto">
-                <div role="tablist" class="tabs tabs-border mb-5">
-                    <a
-                        role="tab"
-                        class="tab"
-                        class:tab-active=move || active_tab.get() != "websites"
-                        href=move || format!("/search?q={}", param_query(query_map))
+                <Transition>
+                    {move || Suspend::new(async move {
+                        suggestions_resource
+                            .get()
+                            .filter(|suggestions| !suggestions.is_empty())
+                            .map(|suggestions| {
+                                view! {
+                                    <p class="mb-5">
+                                        {t!(i18n, home.did_you_mean)} " "
+                                        {suggestions
+                                            .into_iter()
+                                            .map(|suggestion| {
+                                                let href = search_path(
+                                                    vec![("q", suggestion.clone()), ("tab", active_tab.get_untracked())],
+                                                );
+                                                view! {
+                                                    <a class="link link-primary mr-2" href=href>
+                                                        {suggestion}
+                                                    </a>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </p>
+                                }
+                            })
+                    })}
+                </Transition>
+
+                <details class="collapse collapse-arrow bg-base-200 mb-5">
+                    <summary class="collapse-title font-bold">{t!(i18n, home.filter)}</summary>
+
+                    <form
+                        class="collapse-content grid grid-cols-1 md:grid-cols-2 gap-2"
+                        on:submit=move |event| {
+                            event.prevent_default();
+                            let mut params = vec![("q", param_query(query_map)), ("tab", active_tab.get())];
+                            for (key, value) in SEARCH_FILTER_KEYS.iter().zip(filters) {
+                                params.push((*key, value.get()));
+                            }
+                            navigate(&search_path(params), Default::default());
+                        }
                     >
+                        <SearchFilterField value=hashtag name="hashtag">
+                            {t!(i18n, home.hashtag)}
+                        </SearchFilterField>
+                        <SearchFilterField value=website name="website">
+                            {t!(i18n, home.website)}
+                        </SearchFilterField>
+                        <SearchFilterField value=author name="author">
+                            {t!(i18n, home.author)}
+                        </SearchFilterField>
+                        <SearchFilterField value=since name="since" input_type="date">
+                            {t!(i18n, home.since)}
+                        </SearchFilterField>
+                        <SearchFilterField value=until name="until" input_type="date">
+                            {t!(i18n, home.until)}
+                        </SearchFilterField>
+
+                        <div class="md:col-span-2 flex justify-end">
+                            <button class="btn btn-primary" type="submit">
+                                {t!(i18n, home.search)}
+                            </button>
+                        </div>
+                    </form>
+                </details>
+
+                <div role="tablist" class="tabs tabs-border mb-5">
+                    <SearchTab params_map=query_map active_tab=active_tab tab="posts" is_default=true>
                         {t!(i18n, shared.posts)}
-                    </a>
-                    <a
-                        role="tab"
-                        class="tab"
-                        class:tab-active=move || active_tab.get() == "websites"
-                        href=move || format!("/search?q={}&tab=websites", param_query(query_map))
-                    >
+                    </SearchTab>
+                    <SearchTab params_map=query_map active_tab=active_tab tab="websites">
                         {t!(i18n, home.websites)}
-                    </a>
-                </div>
-
-                <div>
-                    {move || {
-                        if active_tab.get() != "websites" {
-                            Either::Left(view! { <SearchPostsTab params_map=query_map /> })
-                        } else {
-                            Either::Right(view! { <SearchWebsitesTab params_map=query_map /> })
-                        }
-                    }}
+                    </SearchTab>
+                    <SearchTab params_map=query_map active_tab=active_tab tab="users">
+                        {t!(i18n, home.users)}
+                    </SearchTab>
+                    <SearchTab params_map=query_map active_tab=active_tab tab="comments">
+                        {t!(i18n, home.comments)}
+                    </SearchTab>
                 </div>
 
+                <SearchResultsTab params_map=query_map />
             </section>
         </Page>
     }
@@ -3,16 +3,19 @@ use leptos::prelude::*;
 use leptos_router::hooks::use_params_map;
 
 use mango3_web_utils::components::{
-    InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollResourceController, LoadingSpinner, PostCard,
+    Hashtags, InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollResourceController, LoadingSpinner,
+    PostCard,
 };
 use mango3_web_utils::context::param_name;
+use mango3_web_utils::i18n::{t, use_i18n};
 use mango3_web_utils::pages::{NotFoundPage, Page};
 use mango3_web_utils::presenters::PostMinPresenter;
 
-use crate::server_functions::{get_hashtag, get_hashtag_posts};
+use crate::server_functions::{get_hashtag, get_hashtag_posts, get_related_hashtags};
 
 #[component]
 pub fn ShowHashtagPage() -> impl IntoView {
+    let i18n = use_i18n();
     let params_map = use_params_map();
     let hashtag_resource = Resource::new_blocking(move || param_name(params_map), get_hashtag);
 
@@ -27,11 +30,32 @@ pub fn ShowHashtagPage() -> impl IntoView {
                                 move |(hashtag_id, after)| async move { get_hashtag_posts(hashtag_id, after).await },
                             )
                         });
+                        let related_hashtags_resource = Resource::new(move || hashtag.id, get_related_hashtags);
                         EitherOf3::A(
                             view! {
                                 <Page title=format!("#{}", hashtag.name)>
                                     <h1 class="h1">"#"{hashtag.name}</h1>
 
+                                    <Transition>
+                                        {move || Suspend::new(async move {
+                                            related_hashtags_resource
+                                                .get()
+                                                .and_then(|result| result.ok())
+                                                .filter(|hashtags| !hashtags.is_empty())
+                                                .map(|hashtags| {
+                                                    view! {
+                                                        <section class="max-w-[720px] w-full mx-auto mb-5">
+                                                            <h2 class="h2">{t!(i18n, home.related_hashtags)}</h2>
+
+                                                            <div class="flex flex-wrap gap-2">
+                                                                <Hashtags hashtags=hashtags />
+                                                            </div>
+                                                        </section>
+                                                    }
+                                                })
+                                        })}
+                                    </Transition>
+
                                     <section class="max-w-[720px] w-full mx-auto">
                                         <InfiniteScroll
                                             controller=controller
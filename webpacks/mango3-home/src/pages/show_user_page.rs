@@ -4,7 +4,7 @@ use leptos_router::hooks::use_params_map;
 
 use mango3_web_utils::components::{
     Hashtags, InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollResourceController, LoadingSpinner,
-    PostCard, UserAvatar, UserLabels,
+    PostCard, ReportButton, UserAvatar, UserLabels,
 };
 use mango3_web_utils::pages::{NotFoundPage, Page};
 use mango3_web_utils::presenters::{CursorPagePresenter, PostMinPresenter};
@@ -55,7 +55,7 @@ pub fn ShowUserPage() -> impl IntoView {
                                                 </div>
 
                                                 <div
-                                                    class="prose prose-pre:bg-transparent max-w-none break-words empty:hidden"
+                                                    class="prose max-w-none break-words empty:hidden"
                                                     inner_html=user.bio_html.clone()
                                                 />
 
@@ -64,6 +64,10 @@ pub fn ShowUserPage() -> impl IntoView {
 
                                                     <Hashtags hashtags=user.hashtags />
                                                 </div>
+
+                                                <div class="text-right">
+                                                    <ReportButton target_type="user" target_id=user.id />
+                                                </div>
                                             </div>
                                         </div>
 
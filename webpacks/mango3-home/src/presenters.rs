@@ -5,10 +5,30 @@ use uuid::Uuid;
 use mango3_web_utils::presenters::{BlobPresenter, HashtagPresenter, UserMinPresenter};
 
 #[cfg(feature = "ssr")]
-use mango3_core::models::User;
+use mango3_core::models::{SearchResult, User};
 #[cfg(feature = "ssr")]
 use mango3_web_utils::presenters::FromModel;
 
+#[derive(Clone, Deserialize, Serialize)]
+pub struct SearchResultPresenter {
+    pub id: Uuid,
+    pub title: String,
+    pub headline_html: String,
+    pub url: Url,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<SearchResult> for SearchResultPresenter {
+    async fn from_model(search_result: &SearchResult) -> Self {
+        Self {
+            id: search_result.id,
+            title: search_result.title.clone(),
+            headline_html: search_result.headline_html(),
+            url: search_result.url(),
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize)]
 pub struct UserProfilePresenter {
     pub id: Uuid,
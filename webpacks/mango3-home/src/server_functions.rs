@@ -3,6 +3,11 @@ use uuid::Uuid;
 
 use mango3_web_utils::presenters::{CursorPagePresenter, HashtagPresenter, PostMinPresenter, WebsiteMinPresenter};
 
+#[cfg(feature = "ssr")]
+use mango3_core::enums::SearchKind;
+#[cfg(feature = "ssr")]
+use mango3_core::models::SearchFilters;
+
 #[cfg(feature = "ssr")]
 use mango3_core::utils::CursorPageParams;
 #[cfg(feature = "ssr")]
@@ -10,7 +15,7 @@ use mango3_web_utils::presenters::FromModel;
 #[cfg(feature = "ssr")]
 use mango3_web_utils::ssr::expect_core_context;
 
-use crate::presenters::UserProfilePresenter;
+use crate::presenters::{SearchResultPresenter, UserProfilePresenter};
 
 #[server]
 pub async fn get_hashtag(name: String) -> Result<Option<HashtagPresenter>, ServerFnError> {
@@ -38,6 +43,13 @@ pub async fn get_hashtag_posts(
     mango3_web_utils::cursor_page_presenter!(&page)
 }
 
+#[server]
+pub async fn get_hashtags_search(query: String) -> Result<Vec<HashtagPresenter>, ServerFnError> {
+    let hashtags = mango3_core::commands::search_hashtags(&query, 50).await;
+
+    Ok(futures::future::join_all(hashtags.iter().map(HashtagPresenter::from_model)).await)
+}
+
 #[server]
 pub async fn get_posts(first: u8, after: Option<Uuid>) -> Result<CursorPagePresenter<PostMinPresenter>, ServerFnError> {
     let core_context = expect_core_context();
@@ -48,17 +60,84 @@ pub async fn get_posts(first: u8, after: Option<Uuid>) -> Result<CursorPagePrese
 }
 
 #[server]
-pub async fn get_posts_search(
+pub async fn get_related_hashtags(id: Uuid) -> Result<Vec<HashtagPresenter>, ServerFnError> {
+    let hashtag = mango3_core::commands::get_hashtag_by_id(id).await?;
+    let hashtags = mango3_core::commands::all_related_hashtags(&hashtag, 10).await;
+
+    Ok(futures::future::join_all(hashtags.iter().map(HashtagPresenter::from_model)).await)
+}
+
+#[server]
+pub async fn get_search_results(
+    kind: String,
     query: String,
+    hashtag: Option<String>,
+    website: Option<String>,
+    author: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
     after: Option<Uuid>,
-) -> Result<CursorPagePresenter<PostMinPresenter>, ServerFnError> {
+) -> Result<CursorPagePresenter<SearchResultPresenter>, ServerFnError> {
     let core_context = expect_core_context();
+
+    let hashtag = match hashtag.filter(|name| !name.trim().is_empty()) {
+        Some(name) => match mango3_core::commands::get_hashtag_by_name(name.trim().trim_start_matches('#')).await {
+            Ok(hashtag) => Some(hashtag),
+            Err(_) => return mango3_web_utils::cursor_page_presenter!(),
+        },
+        None => None,
+    };
+    let website = match website.filter(|subdomain| !subdomain.trim().is_empty()) {
+        Some(subdomain) => match mango3_core::commands::get_website_by_subdomain(&core_context, subdomain.trim()).await
+        {
+            Ok(website) => Some(website),
+            Err(_) => return mango3_web_utils::cursor_page_presenter!(),
+        },
+        None => None,
+    };
+    let author = match author.filter(|username| !username.trim().is_empty()) {
+        Some(username) => {
+            match mango3_core::commands::get_user_by_username(&core_context, username.trim().trim_start_matches('@'))
+                .await
+            {
+                Ok(user) => Some(user),
+                Err(_) => return mango3_web_utils::cursor_page_presenter!(),
+            }
+        }
+        None => None,
+    };
+    let filters = SearchFilters {
+        hashtag: hashtag.as_ref(),
+        website: website.as_ref(),
+        author: author.as_ref(),
+        since: since.and_then(|since| mango3_core::utils::parse_date(&since)),
+        until: until.and_then(|until| mango3_core::utils::parse_date(&until)),
+    };
     let page_params = CursorPageParams { after, first: 10 };
-    let page = mango3_core::commands::search_posts(&core_context, &page_params, None, None, Some(true), &query).await;
+    let page = mango3_core::commands::search_all(
+        &core_context,
+        &page_params,
+        SearchKind::from(kind.as_str()),
+        &filters,
+        &query,
+    )
+    .await;
 
     mango3_web_utils::cursor_page_presenter!(&page)
 }
 
+#[server]
+pub async fn get_search_suggestions(query: String) -> Result<Vec<String>, ServerFnError> {
+    Ok(mango3_core::commands::all_search_suggestions(&query, 3).await)
+}
+
+#[server]
+pub async fn get_trending_hashtags() -> Result<Vec<HashtagPresenter>, ServerFnError> {
+    let hashtags = mango3_core::commands::all_trending_hashtags(50).await;
+
+    Ok(futures::future::join_all(hashtags.iter().map(HashtagPresenter::from_model)).await)
+}
+
 #[server]
 pub async fn get_user(username: String) -> Result<Option<UserProfilePresenter>, ServerFnError> {
     let core_context = expect_core_context();
@@ -96,15 +175,3 @@ pub async fn get_websites(
 
     mango3_web_utils::cursor_page_presenter!(&page)
 }
-
-#[server]
-pub async fn get_websites_search(
-    query: String,
-    after: Option<Uuid>,
-) -> Result<CursorPagePresenter<WebsiteMinPresenter>, ServerFnError> {
-    let core_context = expect_core_context();
-    let page_params = CursorPageParams { after, first: 10 };
-    let page = mango3_core::commands::search_websites(&core_context, &page_params, None, Some(true), &query).await;
-
-    mango3_web_utils::cursor_page_presenter!(&page)
-}
@@ -11,7 +11,7 @@ use mango3_web_utils::i18n::use_i18n;
 use mango3_web_utils::pages::NotFoundPage;
 use mango3_web_utils::utils::ToSignalTrait;
 
-use crate::pages::{ChangePasswordPage, EditEmailPage, EditProfilePage, IndexPage, IndexParentPage};
+use crate::pages::{ChangePasswordPage, EditEmailPage, EditProfilePage, IndexPage, IndexParentPage, InvitationsPage};
 
 #[component]
 pub fn App() -> impl IntoView {
@@ -43,6 +43,7 @@ pub fn App() -> impl IntoView {
                                     <Route path=StaticSegment("edit-profile") view=EditProfilePage />
                                     <Route path=StaticSegment("edit-email") view=EditEmailPage />
                                     <Route path=StaticSegment("change-password") view=ChangePasswordPage />
+                                    <Route path=StaticSegment("invitations") view=InvitationsPage />
                                 </ParentRoute>
                             </Routes>
                         </main>
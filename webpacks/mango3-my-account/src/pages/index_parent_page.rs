@@ -31,6 +31,7 @@ pub fn IndexParentPage() -> impl IntoView {
             ("/edit-profile", async_t_string!(i18n, my_account.edit_profile)),
             ("/edit-email", async_t_string!(i18n, my_account.edit_email)),
             ("/change-password", async_t_string!(i18n, shared.change_password)),
+            ("/invitations", async_t_string!(i18n, my_account.invitations)),
         ]
     };
 
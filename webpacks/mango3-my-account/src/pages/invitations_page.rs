@@ -0,0 +1,133 @@
+use leptos::either::EitherOf3;
+use leptos::prelude::*;
+use web_sys::HtmlFormElement;
+
+use mango3_web_utils::async_t_string;
+use mango3_web_utils::components::forms::{FormErrorAlert, FormSuccessModal, SubmitButton, TextField};
+use mango3_web_utils::components::{
+    InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollLocalResourceController, TimeAgo,
+};
+use mango3_web_utils::i18n::{t, use_i18n};
+use mango3_web_utils::pages::AuthenticatedPage;
+use mango3_web_utils::presenters::{InvitationCodePresenter, MutPresenter};
+use mango3_web_utils::utils::ToSignalTrait;
+
+use crate::server_functions::{get_my_invitation_codes, get_remaining_invitations_count, AttemptToInvite};
+
+#[component]
+pub fn InvitationsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let form_node_ref = NodeRef::new();
+    let server_action = ServerAction::<AttemptToInvite>::new();
+    let action_value = server_action.value();
+    let remaining_count_resource = LocalResource::new(move || async move { get_remaining_invitations_count().await });
+    let controller = InfiniteScrollLocalResourceController::new(|after| {
+        LocalResource::new(move || async move { get_my_invitation_codes(after.get()).await })
+    });
+    let text_title = async_t_string!(i18n, my_account.invitations).to_signal();
+
+    Effect::new({
+        let controller = controller.clone();
+        move || {
+            let response = MutPresenter::from(action_value);
+
+            if response.is_success() {
+                form_node_ref.with(|form| form.as_ref().map(|f: &HtmlFormElement| f.reset()));
+                remaining_count_resource.refetch();
+                controller.clear_and_refetch();
+            }
+        }
+    });
+
+    view! {
+        <AuthenticatedPage title=text_title>
+            <h1 class="h1">{move || text_title.get()}</h1>
+
+            <section class="max-w-[640px] w-full mx-auto">
+                <Suspense>
+                    {move || Suspend::new(async move {
+                        remaining_count_resource
+                            .get()
+                            .and_then(|result| result.take().ok())
+                            .map(|remaining_count| {
+                                view! {
+                                    <p class="mb-4">
+                                        {t!(i18n, my_account.remaining_invitations)} ": "
+                                        <span class="font-bold">{remaining_count}</span>
+                                    </p>
+                                }
+                            })
+                    })}
+                </Suspense>
+
+                <ActionForm
+                    node_ref=form_node_ref
+                    action=server_action
+                    attr:autocomplete="off"
+                    attr:novalidate="true"
+                    attr:class="form"
+                >
+                    <FormErrorAlert
+                        action_value=action_value
+                        message=move || t!(i18n, my_account.failed_to_send_invitation)
+                    />
+
+                    <TextField action_value=action_value id="email" label=move || t!(i18n, shared.email) name="email" />
+
+                    <SubmitButton is_loading=server_action.pending() />
+                </ActionForm>
+
+                <FormSuccessModal
+                    action_value=action_value
+                    message=move || t!(i18n, my_account.invitation_sent_successfully)
+                />
+            </section>
+
+            <section class="max-w-[640px] w-full mx-auto mt-4">
+                <InfiniteScroll
+                    controller=controller
+                    key=|invitation_code: &InvitationCodePresenter| invitation_code.id
+                    let:invitation_code
+                >
+                    <div class="card card-sm bg-base-200 shadow-xl mb-4">
+                        <div class="card-body">
+                            <div class="flex items-center justify-between gap-2">
+                                <span class="font-bold">{invitation_code.email.clone()}</span>
+
+                                {if invitation_code.is_used {
+                                    EitherOf3::A(
+                                        view! {
+                                            <span class="badge badge-outline badge-success">
+                                                {t!(i18n, my_account.used)}
+                                            </span>
+                                        },
+                                    )
+                                } else if invitation_code.is_expired {
+                                    EitherOf3::B(
+                                        view! {
+                                            <span class="badge badge-outline badge-error">
+                                                {t!(i18n, my_account.expired)}
+                                            </span>
+                                        },
+                                    )
+                                } else {
+                                    EitherOf3::C(
+                                        view! {
+                                            <span class="badge badge-outline badge-info">
+                                                {t!(i18n, my_account.pending)}
+                                            </span>
+                                        },
+                                    )
+                                }}
+                            </div>
+
+                            <div class="opacity-70">
+                                <TimeAgo value=invitation_code.created_at />
+                            </div>
+                        </div>
+                    </div>
+                </InfiniteScroll>
+            </section>
+        </AuthenticatedPage>
+    }
+}
@@ -3,9 +3,11 @@ mod edit_email_page;
 mod edit_profile_page;
 mod index_page;
 mod index_parent_page;
+mod invitations_page;
 
 pub use change_password_page::ChangePasswordPage;
 pub use edit_email_page::EditEmailPage;
 pub use edit_profile_page::EditProfilePage;
 pub use index_page::IndexPage;
 pub use index_parent_page::IndexParentPage;
+pub use invitations_page::InvitationsPage;
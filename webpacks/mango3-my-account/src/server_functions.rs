@@ -1,15 +1,17 @@
 use leptos::prelude::*;
 use uuid::Uuid;
 
-use mango3_web_utils::presenters::MutPresenter;
+use mango3_web_utils::presenters::{CursorPagePresenter, InvitationCodePresenter, MutPresenter};
 
 #[cfg(feature = "ssr")]
 use mango3_core::enums::ConfirmationCodeAction;
 #[cfg(feature = "ssr")]
+use mango3_core::utils::CursorPageParams;
+#[cfg(feature = "ssr")]
 use mango3_web_utils::presenters::FromModel;
 #[cfg(feature = "ssr")]
 use mango3_web_utils::ssr::{
-    expect_core_context, extract_confirmation_code, extract_user, finish_and_delete_user_session,
+    expect_core_context, extract_audit_actor, extract_confirmation_code, extract_user, finish_and_delete_user_session,
     require_authentication, start_confirmation_code,
 };
 
@@ -43,6 +45,21 @@ pub async fn attempt_to_confirm_email(code: String) -> Result<MutPresenter, Serv
     mango3_web_utils::mut_presenter!(result)
 }
 
+#[server]
+pub async fn attempt_to_invite(email: String) -> Result<MutPresenter, ServerFnError> {
+    if !require_authentication().await? {
+        return mango3_web_utils::mut_presenter_error!();
+    };
+
+    let core_context = expect_core_context();
+    let user = extract_user().await?.unwrap();
+    let actor = extract_audit_actor().await?;
+
+    let result = mango3_core::commands::insert_user_invitation_code(&core_context, &user, &email, &actor).await;
+
+    mango3_web_utils::mut_presenter!(result)
+}
+
 #[server]
 pub async fn attempt_to_logout() -> Result<MutPresenter, ServerFnError> {
     if !require_authentication().await? {
@@ -81,8 +98,9 @@ pub async fn attempt_to_update_email(email: String, password: String) -> Result<
 
     let core_context = expect_core_context();
     let user = extract_user().await?.unwrap();
+    let actor = extract_audit_actor().await?;
 
-    let result = mango3_core::commands::update_user_email(&core_context, &user, &email, &password).await;
+    let result = mango3_core::commands::update_user_email(&core_context, &user, &email, &password, &actor).await;
 
     mango3_web_utils::mut_presenter!(result)
 }
@@ -98,9 +116,11 @@ pub async fn attempt_to_update_password(
 
     let core_context = expect_core_context();
     let user = extract_user().await?.unwrap();
+    let actor = extract_audit_actor().await?;
 
     let result =
-        mango3_core::commands::update_user_password(&core_context, &user, &current_password, &new_password).await;
+        mango3_core::commands::update_user_password(&core_context, &user, &current_password, &new_password, &actor)
+            .await;
 
     mango3_web_utils::mut_presenter!(result)
 }
@@ -142,6 +162,34 @@ pub async fn attempt_to_update_profile(
     mango3_web_utils::mut_presenter!(result)
 }
 
+#[server]
+pub async fn get_my_invitation_codes(
+    after: Option<Uuid>,
+) -> Result<CursorPagePresenter<InvitationCodePresenter>, ServerFnError> {
+    if !require_authentication().await? {
+        return mango3_web_utils::cursor_page_presenter!();
+    };
+
+    let core_context = expect_core_context();
+    let user = extract_user().await?.unwrap();
+    let page_params = CursorPageParams { after, first: 10 };
+    let page = mango3_core::commands::paginate_invitation_codes(&core_context, &page_params, None, Some(user.id)).await;
+
+    mango3_web_utils::cursor_page_presenter!(&page)
+}
+
+#[server]
+pub async fn get_remaining_invitations_count() -> Result<i64, ServerFnError> {
+    if !require_authentication().await? {
+        return Ok(0);
+    };
+
+    let core_context = expect_core_context();
+    let user = extract_user().await?.unwrap();
+
+    Ok(mango3_core::commands::get_remaining_invitations_count(&core_context, &user).await)
+}
+
 #[server]
 pub async fn get_user_profile() -> Result<Option<EditUserProfilePresenter>, ServerFnError> {
     if !require_authentication().await? {
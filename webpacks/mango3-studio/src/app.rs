@@ -43,12 +43,17 @@ pub fn App() -> impl IntoView {
                             <Route path=StaticSegment("") view=websites::ShowPage />
                             <Route path=StaticSegment("posts") view=websites::PostsPage />
                             <Route path=(StaticSegment("posts"), StaticSegment("new")) view=websites::NewPostPage />
+                            <Route
+                                path=(StaticSegment("posts"), StaticSegment("import"))
+                                view=websites::ImportPostsPage
+                            />
                             <Route
                                 path=(StaticSegment("posts"), ParamSegment(KEY_PARAM_POST_ID), StaticSegment("edit"))
                                 view=websites::EditPostPage
                             />
                             <Route path=StaticSegment("files") view=websites::FilesPage />
                             <Route path=StaticSegment("navigation") view=websites::NavigationPage />
+                            <Route path=StaticSegment("analytics") view=websites::AnalyticsPage />
                             <Route path=StaticSegment("edit") view=websites::EditPage />
                         </ParentRoute>
                     </Routes>
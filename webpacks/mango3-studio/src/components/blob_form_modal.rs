@@ -0,0 +1,145 @@
+use leptos::prelude::*;
+use uuid::Uuid;
+
+use mango3_web_utils::components::forms::{FormErrorAlert, SubmitButton, TextField, TextareaField};
+use mango3_web_utils::components::Modal;
+use mango3_web_utils::i18n::{t, use_i18n};
+use mango3_web_utils::presenters::{BlobPresenter, MutPresenter};
+
+use crate::server_functions::AttemptToUpdateBlob;
+
+#[component]
+pub fn BlobFormModal(
+    blob: RwSignal<Option<BlobPresenter>>,
+    is_open: RwSignal<bool>,
+    #[prop(into)] on_success: Callback<()>,
+    website_id: Uuid,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let server_action = ServerAction::<AttemptToUpdateBlob>::new();
+    let action_value = server_action.value();
+    let value_alt_text = RwSignal::new(String::new());
+    let value_caption = RwSignal::new(String::new());
+    let value_focal_point_x = RwSignal::new(50.0);
+    let value_focal_point_y = RwSignal::new(50.0);
+
+    Effect::new(move || {
+        if let Some(blob) = blob.get() {
+            value_alt_text.set(blob.alt_text.clone());
+            value_caption.set(blob.caption.clone());
+            value_focal_point_x.set(blob.focal_point_x * 100.0);
+            value_focal_point_y.set(blob.focal_point_y * 100.0);
+        }
+    });
+
+    Effect::new(move || {
+        let response = MutPresenter::from(action_value);
+
+        if response.is_success() {
+            is_open.set(false);
+            on_success.run(());
+        }
+    });
+
+    view! {
+        <Modal is_open=is_open>
+            <h4 class="text-lg font-bold">{t!(i18n, studio.edit_file)}</h4>
+
+            {move || {
+                blob.get()
+                    .map(|blob| {
+                        view! {
+                            <div class="flex justify-center my-4">
+                                <img
+                                    class="rounded object-cover"
+                                    style:width="288px"
+                                    style:height="288px"
+                                    alt=move || value_alt_text.get()
+                                    src=blob.variant_url(288, 288, false).to_string()
+                                    style:object-position=move || {
+                                        format!("{}% {}%", value_focal_point_x.get(), value_focal_point_y.get())
+                                    }
+                                />
+                            </div>
+
+                            <ActionForm
+                                action=server_action
+                                attr:autocomplete="off"
+                                attr:novalidate="true"
+                                attr:class="form"
+                            >
+                                <FormErrorAlert action_value=action_value />
+
+                                <input type="hidden" name="website_id" value=website_id.to_string() />
+                                <input type="hidden" name="id" value=blob.id.to_string() />
+
+                                <TextField
+                                    action_value=action_value
+                                    id="alt_text"
+                                    label=move || t!(i18n, shared.alternative_text)
+                                    name="alt_text"
+                                    value=value_alt_text
+                                />
+
+                                <TextareaField
+                                    action_value=action_value
+                                    id="caption"
+                                    label=move || t!(i18n, shared.caption)
+                                    name="caption"
+                                    value=value_caption
+                                />
+
+                                <fieldset class="fieldset w-full">
+                                    <label class="fieldset-label" for="focal_point_x">
+                                        {t!(i18n, studio.focal_point_x)}
+                                    </label>
+                                    <input
+                                        class="range w-full"
+                                        id="focal_point_x"
+                                        type="range"
+                                        min="0"
+                                        max="100"
+                                        prop:value=move || value_focal_point_x.get()
+                                        on:input=move |event| {
+                                            value_focal_point_x
+                                                .set(event_target_value(&event).parse().unwrap_or(50.0))
+                                        }
+                                    />
+                                    <input
+                                        type="hidden"
+                                        name="focal_point_x"
+                                        value=move || (value_focal_point_x.get() / 100.0).to_string()
+                                    />
+                                </fieldset>
+
+                                <fieldset class="fieldset w-full">
+                                    <label class="fieldset-label" for="focal_point_y">
+                                        {t!(i18n, studio.focal_point_y)}
+                                    </label>
+                                    <input
+                                        class="range w-full"
+                                        id="focal_point_y"
+                                        type="range"
+                                        min="0"
+                                        max="100"
+                                        prop:value=move || value_focal_point_y.get()
+                                        on:input=move |event| {
+                                            value_focal_point_y
+                                                .set(event_target_value(&event).parse().unwrap_or(50.0))
+                                        }
+                                    />
+                                    <input
+                                        type="hidden"
+                                        name="focal_point_y"
+                                        value=move || (value_focal_point_y.get() / 100.0).to_string()
+                                    />
+                                </fieldset>
+
+                                <SubmitButton is_loading=server_action.pending() />
+                            </ActionForm>
+                        }
+                    })
+            }}
+        </Modal>
+    }
+}
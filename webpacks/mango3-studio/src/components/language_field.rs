@@ -0,0 +1,52 @@
+use leptos::prelude::*;
+
+use mango3_web_utils::components::forms::FormField;
+use mango3_web_utils::presenters::MutPresenterActionValue;
+
+use crate::server_functions::get_language_options;
+
+#[component]
+pub fn LanguageField(
+    action_value: MutPresenterActionValue,
+    id: &'static str,
+    #[prop(into)] label: ViewFn,
+    name: &'static str,
+    value: RwSignal<String>,
+) -> impl IntoView {
+    let options_resource = Resource::new_blocking(|| (), |_| get_language_options());
+
+    view! {
+        <FormField action_value=action_value id=id label=label name=name>
+            <select class="select w-full" id=id name=name>
+                <Suspense>
+                    {move || Suspend::new(async move {
+                        options_resource
+                            .get()
+                            .and_then(|result| result.ok())
+                            .map(|options| {
+                                view! {
+                                    <For
+                                        each=move || options.clone()
+                                        key=|language| language.clone()
+                                        children=move |language| {
+                                            view! {
+                                                <option
+                                                    value=language.clone()
+                                                    selected={
+                                                        let language = language.clone();
+                                                        move || value.get() == language
+                                                    }
+                                                >
+                                                    {language}
+                                                </option>
+                                            }
+                                        }
+                                    />
+                                }
+                            })
+                    })}
+                </Suspense>
+            </select>
+        </FormField>
+    }
+}
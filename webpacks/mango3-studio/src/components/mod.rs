@@ -1,4 +1,6 @@
-mod highlight_code;
+mod blob_form_modal;
+mod language_field;
+mod moderation_alert;
 mod my_website;
 mod my_websites_infinite_scroll;
 mod post_form_fields;
@@ -7,7 +9,9 @@ mod select_website_dropdown;
 mod theme_selector_field;
 mod website_storage_progress;
 
-pub use highlight_code::HighLightCode;
+pub use blob_form_modal::BlobFormModal;
+pub use language_field::LanguageField;
+pub use moderation_alert::ModerationAlert;
 pub use my_website::{MyWebsite, MyWebsiteOpt, MyWebsitePageWrapper};
 pub use my_websites_infinite_scroll::MyWebsitesInfiniteScroll;
 pub use post_form_fields::PostFormFields;
@@ -0,0 +1,23 @@
+use leptos::either::Either;
+use leptos::prelude::*;
+
+use mango3_web_utils::i18n::{t, use_i18n};
+
+#[component]
+pub fn ModerationAlert(is_suspended: bool, reason: String) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div role="alert" class="alert alert-warning flex-col items-start mb-4">
+            <div class="font-bold">
+                {if is_suspended {
+                    Either::Left(t!(i18n, studio.this_content_has_been_suspended_by_the_staff))
+                } else {
+                    Either::Right(t!(i18n, studio.this_content_has_been_unpublished_by_the_staff))
+                }}
+            </div>
+
+            <div>{reason}</div>
+        </div>
+    }
+}
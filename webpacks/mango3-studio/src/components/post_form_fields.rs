@@ -11,7 +11,7 @@ use mango3_web_utils::components::forms::{
 use mango3_web_utils::i18n::{t, use_i18n};
 use mango3_web_utils::presenters::MutPresenter;
 
-use crate::components::PostPreviewModal;
+use crate::components::{LanguageField, PostPreviewModal};
 use crate::presenters::EditPostPresenter;
 
 #[component]
@@ -19,7 +19,8 @@ pub fn PostFormFields(
     action_value: RwSignal<Option<Result<MutPresenter, ServerFnError<NoCustomError>>>>,
     #[prop(into)] is_loading: Signal<bool>,
     #[prop(into)] website_id: TextProp,
-    #[prop(optional)] post: Option<EditPostPresenter>,
+    #[prop(optional_no_strip)] post: Option<EditPostPresenter>,
+    #[prop(into, optional)] website_language: String,
 ) -> impl IntoView {
     let i18n = use_i18n();
     let value_title = RwSignal::new(post.as_ref().map(|p| p.title.clone()).unwrap_or_default());
@@ -30,6 +31,7 @@ pub fn PostFormFields(
             .map(|p| p.variables.clone())
             .unwrap_or_else(|| "{}".to_owned()),
     );
+    let value_language = RwSignal::new(post.as_ref().map(|p| p.language.clone()).unwrap_or(website_language));
     let value_blobs = RwSignal::new(post.as_ref().map(|p| p.blobs.clone()).unwrap_or_default());
     let value_cover_image_blob = RwSignal::new(post.as_ref().and_then(|p| p.cover_image_blob.clone()));
     let value_publish = post.map(|p| p.is_published).unwrap_or_default();
@@ -75,6 +77,7 @@ pub fn PostFormFields(
 
         <MarkdownEditorField
             action_value=action_value
+            enable_shortcodes=true
             id="content"
             label=move || t!(i18n, studio.content)
             name="content"
@@ -99,6 +102,14 @@ pub fn PostFormFields(
             </div>
         </div>
 
+        <LanguageField
+            action_value=action_value
+            id="language"
+            label=move || t!(i18n, studio.language)
+            name="language"
+            value=value_language
+        />
+
         <MultipleImageUploadField
             id="blob_ids"
             label=move || t!(i18n, studio.attached_images)
@@ -114,7 +125,7 @@ pub fn PostFormFields(
             name="cover_image_blob_id"
             width=288
             value=value_cover_image_blob
-            website_id=website_id
+            website_id=website_id.clone()
         />
 
         <SwitchField
@@ -143,6 +154,7 @@ pub fn PostFormFields(
 
         <PostPreviewModal
             is_open=show_preview
+            website_id=website_id
             title=value_title
             content=value_content
             variables=value_variables
@@ -1,17 +1,19 @@
 use leptos::either::Either;
 use leptos::prelude::*;
+use leptos::text_prop::TextProp;
 use uuid::Uuid;
 
 use mango3_web_utils::components::{Hashtags, LoadingSpinner, Modal, UserTagLink};
 use mango3_web_utils::i18n::{t, use_i18n};
 use mango3_web_utils::presenters::BlobPresenter;
 
-use crate::components::{HighLightCode, MyWebsite};
+use crate::components::MyWebsite;
 use crate::server_functions::preview_post;
 
 #[component]
 pub fn PostPreviewModal(
     is_open: RwSignal<bool>,
+    #[prop(into)] website_id: TextProp,
     #[prop(into)] title: Signal<String>,
     #[prop(into)] content: Signal<String>,
     #[prop(into)] variables: Signal<String>,
@@ -19,13 +21,20 @@ pub fn PostPreviewModal(
 ) -> impl IntoView {
     let i18n = use_i18n();
     let preview_action = Action::new(
-        move |(title, content, variables, cover_image_blob_id): &(String, String, String, Option<Uuid>)| {
+        move |(website_id, title, content, variables, cover_image_blob_id): &(
+            Uuid,
+            String,
+            String,
+            String,
+            Option<Uuid>,
+        )| {
+            let website_id = website_id.to_owned();
             let title = title.to_owned();
             let content = content.to_owned();
             let variables = variables.to_owned();
             let cover_image_blob_id = cover_image_blob_id.to_owned();
 
-            async move { preview_post(title, content, variables, cover_image_blob_id).await }
+            async move { preview_post(website_id, title, content, variables, cover_image_blob_id).await }
         },
     );
     let preview_action_value = preview_action.value();
@@ -33,6 +42,7 @@ pub fn PostPreviewModal(
     Effect::new(move || {
         if is_open.get() {
             preview_action.dispatch((
+                Uuid::try_parse(&website_id.get()).unwrap_or_default(),
                 title.get(),
                 content.get(),
                 variables.get(),
@@ -49,9 +59,29 @@ pub fn PostPreviewModal(
         >
             <div class="card card-sm bg-base-200 shadow-xl">
                 {move || {
-                    if let Some(Ok(post)) = preview_action_value.get() {
+                    if let Some(Ok(preview)) = preview_action_value.get() {
+                        let post = preview.post;
+                        let template_errors = preview.template_errors;
                         Either::Left(
                             view! {
+                                {(!template_errors.is_empty())
+                                    .then(|| {
+                                        view! {
+                                            <div role="alert" class="alert alert-error m-4">
+                                                <div>
+                                                    <h3 class="font-bold">{t!(i18n, studio.template_errors)}</h3>
+
+                                                    <ul class="list-disc ml-5">
+                                                        {template_errors
+                                                            .into_iter()
+                                                            .map(|error| view! { <li>{error}</li> })
+                                                            .collect_view()}
+                                                    </ul>
+                                                </div>
+                                            </div>
+                                        }
+                                    })}
+
                                 {
                                     let post_title = post.title.clone();
                                     move || {
@@ -80,8 +110,8 @@ pub fn PostPreviewModal(
                                     </div>
 
                                     <div
-                                        class="prose prose-pre:bg-transparent prose-img:mx-auto max-w-none break-words"
-                                        inner_html=post.content_html.clone()
+                                        class="prose prose-img:mx-auto max-w-none break-words"
+                                        inner_html=post.content_html
                                     />
 
                                     <div class="empty:hidden my-4 flex flex-wrap gap-2">
@@ -90,8 +120,6 @@ pub fn PostPreviewModal(
                                         </MyWebsite>
                                     </div>
                                 </div>
-
-                                <HighLightCode content=post.content_html />
                             },
                         )
                     } else {
@@ -9,6 +9,7 @@ use mango3_web_utils::i18n::{t, use_i18n};
 use mango3_web_utils::pages::AuthenticatedPage;
 use mango3_web_utils::utils::ToSignalTrait;
 
+use crate::components::LanguageField;
 use crate::context::use_selected_website;
 use crate::server_functions::AttemptToCreateWebsite;
 
@@ -19,6 +20,7 @@ pub fn NewWebsitePage() -> impl IntoView {
     let server_action = ServerAction::<AttemptToCreateWebsite>::new();
     let action_value = server_action.value();
     let value_subdomain = RwSignal::new("".to_owned());
+    let value_language = RwSignal::new("english".to_owned());
     let title = async_t_string!(i18n, shared.new_website).to_signal();
 
     let selected_website = use_selected_website();
@@ -59,6 +61,14 @@ pub fn NewWebsitePage() -> impl IntoView {
                     name="description"
                 />
 
+                <LanguageField
+                    action_value=action_value
+                    id="language"
+                    label=move || t!(i18n, studio.language)
+                    name="language"
+                    value=value_language
+                />
+
                 <SubmitButton is_loading=server_action.pending() />
             </ActionForm>
 
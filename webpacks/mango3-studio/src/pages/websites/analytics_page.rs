@@ -0,0 +1,192 @@
+use leptos::either::Either;
+use leptos::prelude::*;
+
+use mango3_web_utils::components::LoadingSpinner;
+use mango3_web_utils::i18n::{t, use_i18n};
+
+use crate::components::MyWebsitePageWrapper;
+use crate::presenters::WebsiteViewsSourcePresenter;
+use crate::server_functions::get_my_website_analytics;
+
+#[component]
+fn ViewsSourcesTable(sources: Vec<WebsiteViewsSourcePresenter>) -> impl IntoView {
+    let i18n = use_i18n();
+
+    if sources.is_empty() {
+        return Either::Left(view! { <p>{t!(i18n, shared.no_results_found)}</p> });
+    }
+
+    Either::Right(view! {
+        <table class="table">
+            <tbody>
+                {sources
+                    .into_iter()
+                    .map(|source| {
+                        view! {
+                            <tr>
+                                <td class="p-2">{source.name}</td>
+                                <td class="p-2 text-right">{source.views_count}</td>
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+            </tbody>
+        </table>
+    })
+}
+
+#[component]
+pub fn AnalyticsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let days = RwSignal::new(30);
+
+    view! {
+        <MyWebsitePageWrapper children=move |website| {
+            let website_id = website.id;
+            let analytics_resource = LocalResource::new(move || get_my_website_analytics(website_id, days.get()));
+            view! {
+                <h1 class="h1">{t!(i18n, studio.analytics)}</h1>
+
+                <div role="tablist" class="tabs tabs-border mb-5">
+                    <a role="tab" class="tab" class:tab-active=move || days.get() == 7 on:click=move |_| days.set(7)>
+                        {t!(i18n, studio.last_7_days)}
+                    </a>
+                    <a role="tab" class="tab" class:tab-active=move || days.get() == 30 on:click=move |_| days.set(30)>
+                        {t!(i18n, studio.last_30_days)}
+                    </a>
+                    <a role="tab" class="tab" class:tab-active=move || days.get() == 90 on:click=move |_| days.set(90)>
+                        {t!(i18n, studio.last_90_days)}
+                    </a>
+                </div>
+
+                <Transition fallback=LoadingSpinner>
+                    {move || Suspend::new(async move {
+                        analytics_resource
+                            .get()
+                            .and_then(|result| result.take().ok())
+                            .flatten()
+                            .map(|analytics| {
+                                let daily_stats = &analytics.daily_stats;
+                                let views_count: i64 = daily_stats.iter().map(|stat| stat.views_count).sum();
+                                let visitors_count: i64 = daily_stats.iter().map(|stat| stat.visitors_count).sum();
+                                let reactions_count: i64 = daily_stats.iter().map(|stat| stat.reactions_count).sum();
+                                let comments_count: i64 = daily_stats.iter().map(|stat| stat.comments_count).sum();
+                                let max_views_count = analytics
+                                    .daily_stats
+                                    .iter()
+                                    .map(|stat| stat.views_count)
+                                    .max()
+                                    .unwrap_or_default()
+                                    .max(1);
+                                view! {
+                                    <div class="stats stats-vertical md:stats-horizontal shadow w-full">
+                                        <div class="stat">
+                                            <div class="stat-title">{t!(i18n, studio.views)}</div>
+                                            <div class="stat-value">{views_count}</div>
+                                        </div>
+                                        <div class="stat">
+                                            <div class="stat-title">{t!(i18n, studio.visitors)}</div>
+                                            <div class="stat-value">{visitors_count}</div>
+                                        </div>
+                                        <div class="stat">
+                                            <div class="stat-title">{t!(i18n, studio.reactions)}</div>
+                                            <div class="stat-value">{reactions_count}</div>
+                                        </div>
+                                        <div class="stat">
+                                            <div class="stat-title">{t!(i18n, studio.comments)}</div>
+                                            <div class="stat-value">{comments_count}</div>
+                                        </div>
+                                    </div>
+
+                                    <section class="mt-4">
+                                        <h3 class="h3">{t!(i18n, studio.views_per_day)}</h3>
+
+                                        <div class="flex items-end gap-px h-40 bg-base-200 rounded-box p-2">
+                                            {analytics
+                                                .daily_stats
+                                                .into_iter()
+                                                .map(|stat| {
+                                                    view! {
+                                                        <div
+                                                            class="grow bg-primary rounded-t"
+                                                            style=format!(
+                                                                "height: {}%",
+                                                                stat.views_count * 100 / max_views_count,
+                                                            )
+                                                            title=format!(
+                                                                "{}: {} / {} / {} / {}",
+                                                                stat.day,
+                                                                stat.views_count,
+                                                                stat.visitors_count,
+                                                                stat.reactions_count,
+                                                                stat.comments_count,
+                                                            )
+                                                        />
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </div>
+                                    </section>
+
+                                    <div class="grid md:grid-cols-3 gap-4 mt-4">
+                                        <section>
+                                            <h3 class="h3">{t!(i18n, studio.top_posts)}</h3>
+
+                                            <Show
+                                                when={
+                                                    let is_empty = analytics.top_posts.is_empty();
+                                                    move || !is_empty
+                                                }
+                                                fallback=move || view! { <p>{t!(i18n, shared.no_results_found)}</p> }
+                                            >
+                                                <table class="table">
+                                                    <tbody>
+                                                        <For
+                                                            each={
+                                                                let top_posts = analytics.top_posts.clone();
+                                                                move || top_posts.clone()
+                                                            }
+                                                            key=|post| post.id
+                                                            let:post
+                                                        >
+                                                            <tr>
+                                                                <td class="p-2">
+                                                                    <a
+                                                                        class="link"
+                                                                        href=format!(
+                                                                            "/websites/{}/posts/{}/edit",
+                                                                            website_id,
+                                                                            post.id,
+                                                                        )
+                                                                    >
+                                                                        {post.title}
+                                                                    </a>
+                                                                </td>
+                                                                <td class="p-2 text-right">{post.views_count}</td>
+                                                            </tr>
+                                                        </For>
+                                                    </tbody>
+                                                </table>
+                                            </Show>
+                                        </section>
+
+                                        <section>
+                                            <h3 class="h3">{t!(i18n, studio.referrers)}</h3>
+
+                                            <ViewsSourcesTable sources=analytics.referrers />
+                                        </section>
+
+                                        <section>
+                                            <h3 class="h3">{t!(i18n, studio.countries)}</h3>
+
+                                            <ViewsSourcesTable sources=analytics.countries />
+                                        </section>
+                                    </div>
+                                }
+                            })
+                    })}
+                </Transition>
+            }
+        } />
+    }
+}
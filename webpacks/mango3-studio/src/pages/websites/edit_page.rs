@@ -6,7 +6,7 @@ use mango3_web_utils::components::forms::{
 };
 use mango3_web_utils::i18n::{t, use_i18n};
 
-use crate::components::{MyWebsitePageWrapper, ThemeSelectorField};
+use crate::components::{LanguageField, MyWebsitePageWrapper, ThemeSelectorField};
 use crate::server_functions::AttemptToUpdateWebsite;
 
 const DARK_THEMES: [&str; 14] = [
@@ -65,6 +65,7 @@ pub fn EditPage() -> impl IntoView {
             let value_publish = RwSignal::new(website.is_published);
             let value_light_theme = RwSignal::new(website.light_theme.clone());
             let value_dark_theme = RwSignal::new(website.dark_theme.clone());
+            let value_language = RwSignal::new(website.language.clone());
 
             view! {
                 <h1 class="h1">{t!(i18n, studio.edit)}</h1>
@@ -129,6 +130,14 @@ pub fn EditPage() -> impl IntoView {
                         website=website.clone()
                     />
 
+                    <LanguageField
+                        action_value=action_value
+                        id="language"
+                        label=move || t!(i18n, studio.language)
+                        name="language"
+                        value=value_language
+                    />
+
                     <SwitchField
                         action_value=action_value
                         id="publish"
@@ -5,7 +5,7 @@ use leptos_router::hooks::{use_navigate, use_params_map};
 use mango3_web_utils::components::forms::{FormErrorAlert, FormSuccessModal};
 use mango3_web_utils::i18n::{t, use_i18n};
 
-use crate::components::{MyWebsitePageWrapper, PostFormFields};
+use crate::components::{ModerationAlert, MyWebsitePageWrapper, PostFormFields};
 use crate::constants::KEY_PARAM_POST_ID;
 use crate::server_functions::{get_my_post, AttemptToUpdatePost};
 
@@ -36,6 +36,13 @@ pub fn EditPostPage() -> impl IntoView {
                                     view! {
                                         <h1 class="h1">{t!(i18n, studio.edit_post)}</h1>
 
+                                        {post
+                                            .moderation_reason
+                                            .clone()
+                                            .map(|reason| {
+                                                view! { <ModerationAlert is_suspended=post.is_suspended reason=reason /> }
+                                            })}
+
                                         <ActionForm
                                             action=server_action
                                             attr:autocomplete="off"
@@ -50,7 +57,7 @@ pub fn EditPostPage() -> impl IntoView {
                                                 action_value=action_value
                                                 is_loading=server_action.pending()
                                                 website_id=website_id.to_string()
-                                                post=post
+                                                post=Some(post)
                                             />
                                         </ActionForm>
 
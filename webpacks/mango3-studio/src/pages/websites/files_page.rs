@@ -8,7 +8,7 @@ use mango3_web_utils::components::{
 use mango3_web_utils::i18n::{t, use_i18n};
 use mango3_web_utils::presenters::BlobPresenter;
 
-use crate::components::{MyWebsitePageWrapper, WebsiteStorageProgress};
+use crate::components::{BlobFormModal, MyWebsitePageWrapper, WebsiteStorageProgress};
 use crate::server_functions::{get_my_blobs, AttemptToDeleteBlob};
 
 #[component]
@@ -16,6 +16,8 @@ pub fn FilesPage() -> impl IntoView {
     let i18n = use_i18n();
     let server_action = ServerAction::<AttemptToDeleteBlob>::new();
     let delete_blob: RwSignal<Option<BlobPresenter>> = RwSignal::new(None);
+    let edit_blob: RwSignal<Option<BlobPresenter>> = RwSignal::new(None);
+    let show_blob_form = RwSignal::new(false);
     let show_delete_confirmation = RwSignal::new(false);
     let uploaded_files = RwSignal::new(vec![]);
 
@@ -60,7 +62,7 @@ pub fn FilesPage() -> impl IntoView {
                                             <div class="avatar">
                                                 <div class="rounded" style:width="82px" style:height="82px">
                                                     <img
-                                                        alt=blob.file_name.clone()
+                                                        alt=blob.alt_text.clone()
                                                         src=blob.variant_url(82, 82, true).to_string()
                                                     />
                                                 </div>
@@ -73,15 +75,28 @@ pub fn FilesPage() -> impl IntoView {
 
                                                 <CopyableText value=blob.url.clone() />
 
-                                                <Show when=move || {
-                                                    blob.is_removable
-                                                }>
-                                                    {
-                                                        let blob = blob.clone();
-                                                        move || {
+                                                <div class="card-actions justify-end">
+                                                    <button
+                                                        class="btn btn-ghost font-bold"
+                                                        on:click={
                                                             let blob = blob.clone();
-                                                            view! {
-                                                                <div class="card-actions justify-end">
+                                                            move |_| {
+                                                                edit_blob.set(Some(blob.clone()));
+                                                                show_blob_form.set(true);
+                                                            }
+                                                        }
+                                                    >
+                                                        {t!(i18n, studio.edit)}
+                                                    </button>
+
+                                                    <Show when=move || {
+                                                        blob.is_removable
+                                                    }>
+                                                        {
+                                                            let blob = blob.clone();
+                                                            move || {
+                                                                let blob = blob.clone();
+                                                                view! {
                                                                     <button
                                                                         class="btn btn-ghost font-bold"
                                                                         on:click=move |_| {
@@ -91,11 +106,11 @@ pub fn FilesPage() -> impl IntoView {
                                                                     >
                                                                         {t!(i18n, studio.delete)}
                                                                     </button>
-                                                                </div>
+                                                                }
                                                             }
                                                         }
-                                                    }
-                                                </Show>
+                                                    </Show>
+                                                </div>
                                             </div>
                                         </div>
                                     </div>
@@ -105,6 +120,16 @@ pub fn FilesPage() -> impl IntoView {
                     />
                 </section>
 
+                <BlobFormModal
+                    blob=edit_blob
+                    is_open=show_blob_form
+                    on_success={
+                        let controller = controller.clone();
+                        move |_| controller.clear_and_refetch()
+                    }
+                    website_id=website_id
+                />
+
                 <ConfirmationModal
                     is_open=show_delete_confirmation
                     on_accept=move || {
@@ -0,0 +1,62 @@
+use leptos::ev::SubmitEvent;
+use leptos::html::Input;
+use leptos::prelude::*;
+use web_sys::FormData;
+
+use mango3_web_utils::components::forms::{FormErrorAlert, FormSuccessModal, SubmitButton};
+use mango3_web_utils::i18n::{t, use_i18n};
+
+use crate::components::MyWebsitePageWrapper;
+use crate::server_functions::attempt_to_import_posts;
+
+#[component]
+pub fn ImportPostsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let import_action = Action::new_local(|data: &FormData| attempt_to_import_posts(data.clone().into()));
+    let action_value = import_action.value();
+    let file_input_ref = NodeRef::<Input>::new();
+
+    view! {
+        <MyWebsitePageWrapper children=move |website| {
+            let website_id = website.id;
+            let import = move |event: SubmitEvent| {
+                event.prevent_default();
+
+                let Some(file) = file_input_ref
+                    .get()
+                    .and_then(|input| input.files())
+                    .and_then(|files| files.get(0)) else {
+                    return;
+                };
+
+                let Ok(form_data) = FormData::new() else {
+                    return;
+                };
+
+                let _ = form_data.append_with_str("website_id", &website_id.to_string());
+
+                let _ = form_data.append_with_blob_and_filename("file", &file, &file.name());
+
+                import_action.dispatch_local(form_data);
+            };
+
+            view! {
+                <h1 class="h1">{t!(i18n, studio.import_posts)}</h1>
+
+                <form class="form" on:submit=import>
+                    <FormErrorAlert action_value=action_value />
+
+                    <p>{t!(i18n, studio.import_posts_description)}</p>
+
+                    <fieldset class="fieldset">
+                        <input class="file-input w-full" type="file" accept=".xml,.zip" node_ref=file_input_ref />
+                    </fieldset>
+
+                    <SubmitButton is_loading=import_action.pending() />
+                </form>
+
+                <FormSuccessModal action_value=action_value />
+            }
+        } />
+    }
+}
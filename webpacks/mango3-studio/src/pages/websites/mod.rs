@@ -1,15 +1,19 @@
+mod analytics_page;
 mod edit_page;
 mod edit_post_page;
 mod files_page;
+mod import_posts_page;
 mod navigation_page;
 mod new_post_page;
 mod posts_page;
 mod show_page;
 mod show_parent_page;
 
+pub use analytics_page::AnalyticsPage;
 pub use edit_page::EditPage;
 pub use edit_post_page::EditPostPage;
 pub use files_page::FilesPage;
+pub use import_posts_page::ImportPostsPage;
 pub use navigation_page::NavigationPage;
 pub use new_post_page::NewPostPage;
 pub use posts_page::PostsPage;
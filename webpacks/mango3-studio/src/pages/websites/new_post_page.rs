@@ -1,15 +1,18 @@
 use leptos::prelude::*;
-use leptos_router::hooks::use_navigate;
+use leptos_router::hooks::{use_navigate, use_query_map};
 
 use mango3_web_utils::components::forms::{FormErrorAlert, FormSuccessModal};
 use mango3_web_utils::i18n::{t, use_i18n};
 
 use crate::components::{MyWebsitePageWrapper, PostFormFields};
-use crate::server_functions::AttemptToCreatePost;
+use crate::constants::KEY_PARAM_TRANSLATION_OF;
+use crate::presenters::EditPostPresenter;
+use crate::server_functions::{get_my_post, AttemptToCreatePost};
 
 #[component]
 pub fn NewPostPage() -> impl IntoView {
     let i18n = use_i18n();
+    let query_map = use_query_map();
     let server_action = ServerAction::<AttemptToCreatePost>::new();
     let action_value = server_action.value();
 
@@ -17,6 +20,16 @@ pub fn NewPostPage() -> impl IntoView {
         <MyWebsitePageWrapper children=move |website| {
             let navigate = use_navigate();
             let website_id = website.id;
+            let translation_of_resource = LocalResource::new(move || {
+                let translation_of_id = query_map.with(|params| params.get(KEY_PARAM_TRANSLATION_OF));
+                async move {
+                    if let Some(id) = translation_of_id {
+                        get_my_post(website_id, id).await.ok().flatten()
+                    } else {
+                        None
+                    }
+                }
+            });
             view! {
                 <h1 class="h1">{t!(i18n, studio.new_post)}</h1>
 
@@ -28,11 +41,41 @@ pub fn NewPostPage() -> impl IntoView {
                 >
                     <FormErrorAlert action_value=action_value />
 
-                    <PostFormFields
-                        action_value=action_value
-                        is_loading=server_action.pending()
-                        website_id=website_id.to_string()
-                    />
+                    <Suspense>
+                        {
+                            let website_language = website.language.clone();
+                            move || {
+                                let website_language = website_language.clone();
+                                Suspend::new(async move {
+                                    let translation_of = translation_of_resource
+                                        .get()
+                                        .and_then(|resource| resource.take())
+                                        .map(EditPostPresenter::into_translation);
+                                    view! {
+                                        {translation_of
+                                            .as_ref()
+                                            .map(|post| {
+                                                view! {
+                                                    <input
+                                                        type="hidden"
+                                                        name="translation_of_id"
+                                                        value=post.id.to_string()
+                                                    />
+                                                }
+                                            })}
+
+                                        <PostFormFields
+                                            action_value=action_value
+                                            is_loading=server_action.pending()
+                                            website_id=website_id.to_string()
+                                            website_language=website_language
+                                            post=translation_of
+                                        />
+                                    }
+                                })
+                            }
+                        }
+                    </Suspense>
                 </ActionForm>
 
                 <FormSuccessModal
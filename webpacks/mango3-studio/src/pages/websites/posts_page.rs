@@ -4,10 +4,11 @@ use mango3_web_utils::components::{
     ConfirmationModal, InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollLocalResourceController, PostCard,
 };
 use mango3_web_utils::i18n::{t, use_i18n};
-use mango3_web_utils::icons::PlusOutlined;
+use mango3_web_utils::icons::{DocumentOutlined, PlusOutlined};
 use mango3_web_utils::presenters::PostMinPresenter;
 
 use crate::components::MyWebsitePageWrapper;
+use crate::constants::KEY_PARAM_TRANSLATION_OF;
 use crate::server_functions::{get_my_posts, AttemptToDeletePost};
 
 #[component]
@@ -50,7 +51,12 @@ pub fn PostsPage() -> impl IntoView {
 
                 <h1 class="h1">{t!(i18n, shared.posts)}</h1>
 
-                <section class="flex justify-end max-w-[720px] w-full mb-5 mx-auto">
+                <section class="flex justify-end gap-2 max-w-[720px] w-full mb-5 mx-auto">
+                    <a class="btn btn-outline" href=format!("/websites/{}/posts/import", website_id)>
+                        <DocumentOutlined />
+                        {t!(i18n, studio.import_posts)}
+                    </a>
+
                     <a class="btn btn-outline" href=format!("/websites/{}/posts/new", website_id.clone())>
 
                         <PlusOutlined />
@@ -79,6 +85,18 @@ pub fn PostsPage() -> impl IntoView {
                                                 {t!(i18n, studio.edit)}
                                             </a>
 
+                                            <a
+                                                class="btn btn-ghost font-bold"
+                                                href=format!(
+                                                    "/websites/{}/posts/new?{}={}",
+                                                    &website_id,
+                                                    KEY_PARAM_TRANSLATION_OF,
+                                                    &post_id,
+                                                )
+                                            >
+                                                {t!(i18n, studio.create_translation)}
+                                            </a>
+
                                             <button
                                                 class="btn btn-ghost font-bold"
                                                 on:click=move |_| {
@@ -1,18 +1,27 @@
 use leptos::prelude::*;
 
+use mango3_web_utils::components::forms::{FormErrorAlert, FormSuccessModal, SubmitButton};
 use mango3_web_utils::components::CopyableText;
 use mango3_web_utils::i18n::{t, use_i18n};
 
-use crate::components::{MyWebsitePageWrapper, WebsiteStorageProgress};
+use crate::components::{ModerationAlert, MyWebsitePageWrapper, WebsiteStorageProgress};
+use crate::server_functions::AttemptToExportWebsite;
 
 #[component]
 pub fn ShowPage() -> impl IntoView {
     let i18n = use_i18n();
+    let export_action = ServerAction::<AttemptToExportWebsite>::new();
+    let action_value = export_action.value();
 
     view! {
         <MyWebsitePageWrapper let:website>
             <h1 class="h1">{t!(i18n, shared.home)}</h1>
 
+            {website
+                .moderation_reason
+                .clone()
+                .map(|reason| view! { <ModerationAlert is_suspended=website.is_suspended reason=reason /> })}
+
             <WebsiteStorageProgress website=website.clone() />
 
             <section class="max-w-[720px] w-full mx-auto mt-4">
@@ -29,6 +38,24 @@ pub fn ShowPage() -> impl IntoView {
                     </a>
                 </div>
             </section>
+
+            <section class="max-w-[720px] w-full mx-auto mt-4">
+                <h3 class="h3">{t!(i18n, studio.export_website)}</h3>
+
+                <ActionForm action=export_action attr:class="form">
+                    <FormErrorAlert action_value=action_value />
+
+                    <p>{t!(i18n, studio.export_website_description)}</p>
+
+                    <input type="hidden" name="id" value=website.id.to_string() />
+
+                    <SubmitButton is_loading=export_action.pending()>
+                        {t!(i18n, studio.export_website)}
+                    </SubmitButton>
+                </ActionForm>
+
+                <FormSuccessModal action_value=action_value />
+            </section>
         </MyWebsitePageWrapper>
     }
 }
@@ -48,6 +48,7 @@ pub fn ShowParentPage() -> impl IntoView {
                 let posts_path = format!("{home_path}/posts");
                 let files_path = format!("{home_path}/files");
                 let navigation_path = format!("{home_path}/navigation");
+                let analytics_path = format!("{home_path}/analytics");
                 let edit_path = format!("{home_path}/edit");
                 Either::Left(
                     view! {
@@ -77,6 +78,12 @@ pub fn ShowParentPage() -> impl IntoView {
                                     label=async_t_string!(i18n, studio.navigation)
                                 />
 
+                                <MenuItem
+                                    href=analytics_path
+                                    icon=move || view! { <ChartBarOutlined /> }
+                                    label=async_t_string!(i18n, studio.analytics)
+                                />
+
                                 <MenuItem
                                     href=edit_path
                                     icon=move || view! { <PencilSquareOutlined /> }
@@ -7,10 +7,10 @@ use futures::future;
 #[cfg(feature = "ssr")]
 use serde_json::to_string_pretty;
 
-use mango3_web_utils::presenters::BlobPresenter;
+use mango3_web_utils::presenters::{BlobPresenter, PostPresenter};
 
 #[cfg(feature = "ssr")]
-use mango3_core::models::Post;
+use mango3_core::models::{Post, WebsiteDailyStat, WebsiteTopPost, WebsiteViewsSource};
 #[cfg(feature = "ssr")]
 use mango3_web_utils::presenters::FromModel;
 
@@ -21,12 +21,28 @@ pub struct EditPostPresenter {
     pub slug: String,
     pub content: String,
     pub variables: String,
+    pub language: String,
     pub cover_image_blob: Option<BlobPresenter>,
     pub blobs: Vec<BlobPresenter>,
     pub is_published: bool,
+    pub is_suspended: bool,
+    pub moderation_reason: Option<String>,
     pub url: Url,
 }
 
+impl EditPostPresenter {
+    /// Prefills a new post with the fields of this one, which is the source of the translation.
+    pub fn into_translation(self) -> Self {
+        Self {
+            slug: String::new(),
+            is_published: false,
+            is_suspended: false,
+            moderation_reason: None,
+            ..self
+        }
+    }
+}
+
 #[cfg(feature = "ssr")]
 impl FromModel<Post> for EditPostPresenter {
     async fn from_model(post: &Post) -> Self {
@@ -44,10 +60,83 @@ impl FromModel<Post> for EditPostPresenter {
             slug: post.slug.clone(),
             content: post.content.clone(),
             variables: to_string_pretty(&post.variables).unwrap_or_else(|_| "{}".to_owned()),
+            language: post.language.clone(),
             cover_image_blob,
             is_published: post.is_published(&core_context).await,
+            is_suspended: post.is_suspended(),
+            moderation_reason: post.moderation_reason.clone(),
             blobs,
             url: post.url(&core_context).await,
         }
     }
 }
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct PostPreviewPresenter {
+    pub post: PostPresenter,
+    pub template_errors: Vec<String>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct WebsiteAnalyticsPresenter {
+    pub daily_stats: Vec<WebsiteDailyStatPresenter>,
+    pub top_posts: Vec<WebsiteTopPostPresenter>,
+    pub referrers: Vec<WebsiteViewsSourcePresenter>,
+    pub countries: Vec<WebsiteViewsSourcePresenter>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct WebsiteDailyStatPresenter {
+    pub day: String,
+    pub views_count: i64,
+    pub visitors_count: i64,
+    pub reactions_count: i64,
+    pub comments_count: i64,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<WebsiteDailyStat> for WebsiteDailyStatPresenter {
+    async fn from_model(daily_stat: &WebsiteDailyStat) -> Self {
+        Self {
+            day: daily_stat.day.format("%Y-%m-%d").to_string(),
+            views_count: daily_stat.views_count,
+            visitors_count: daily_stat.visitors_count,
+            reactions_count: daily_stat.reactions_count,
+            comments_count: daily_stat.comments_count,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct WebsiteTopPostPresenter {
+    pub id: Uuid,
+    pub title: String,
+    pub views_count: i64,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<WebsiteTopPost> for WebsiteTopPostPresenter {
+    async fn from_model(top_post: &WebsiteTopPost) -> Self {
+        Self {
+            id: top_post.post_id,
+            title: top_post.title.clone(),
+            views_count: top_post.views_count,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct WebsiteViewsSourcePresenter {
+    pub name: String,
+    pub views_count: i64,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<WebsiteViewsSource> for WebsiteViewsSourcePresenter {
+    async fn from_model(source: &WebsiteViewsSource) -> Self {
+        Self {
+            name: source.name.clone(),
+            views_count: source.views_count,
+        }
+    }
+}
@@ -8,7 +8,7 @@ use mango3_core::models::{Blob, User, Website};
 #[cfg(feature = "ssr")]
 use mango3_core::utils::CursorPageParams;
 #[cfg(feature = "ssr")]
-use mango3_web_utils::ssr::{expect_core_context, extract_user};
+use mango3_web_utils::ssr::{expect_core_context, extract_i18n, extract_user};
 
 #[cfg(feature = "ssr")]
 use super::my_website;
@@ -24,6 +24,30 @@ pub async fn attempt_to_delete_blob(website_id: Uuid, id: Uuid) -> Result<MutPre
     mango3_web_utils::mut_presenter!(result)
 }
 
+#[server]
+pub async fn attempt_to_update_blob(
+    website_id: Uuid,
+    id: Uuid,
+    alt_text: String,
+    caption: String,
+    focal_point_x: f32,
+    focal_point_y: f32,
+) -> Result<MutPresenter, ServerFnError> {
+    use crate::constants::ssr::{KEY_TEXT_FAILED_TO_UPDATE_FILE, KEY_TEXT_FILE_UPDATED_SUCCESSFULLY};
+
+    let i18n = extract_i18n().await?;
+    let error_message = i18n.text(KEY_TEXT_FAILED_TO_UPDATE_FILE);
+
+    let Some(blob) = my_blob(website_id, id).await? else {
+        return mango3_web_utils::mut_presenter_error!(error_message);
+    };
+
+    let result = mango3_core::commands::update_blob(&blob, &alt_text, &caption, focal_point_x, focal_point_y).await;
+    let success_message = i18n.text(KEY_TEXT_FILE_UPDATED_SUCCESSFULLY);
+
+    mango3_web_utils::mut_presenter!(result, success_message, error_message)
+}
+
 #[cfg(feature = "ssr")]
 pub async fn get_blobs_by_ids<'a>(website: &Website, user: &User, ids: Option<Vec<Uuid>>) -> Vec<Blob<'a>> {
     let Some(ids) = ids else {
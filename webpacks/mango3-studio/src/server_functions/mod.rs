@@ -1,3 +1,5 @@
+use leptos::prelude::*;
+
 mod blobs;
 mod navigation_items;
 mod posts;
@@ -7,3 +9,8 @@ pub use blobs::*;
 pub use navigation_items::*;
 pub use posts::*;
 pub use websites::*;
+
+#[server]
+pub async fn get_language_options() -> Result<Vec<String>, ServerFnError> {
+    Ok(mango3_core::commands::all_text_search_languages().await)
+}
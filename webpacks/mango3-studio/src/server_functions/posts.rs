@@ -1,4 +1,5 @@
 use leptos::prelude::*;
+use server_fn::codec::{MultipartData, MultipartFormData};
 use uuid::Uuid;
 
 #[cfg(feature = "ssr")]
@@ -6,8 +7,10 @@ use chrono::Utc;
 #[cfg(feature = "ssr")]
 use serde_json::Value;
 
-use mango3_web_utils::presenters::{CursorPagePresenter, MutPresenter, PostMinPresenter, PostPresenter};
+use mango3_web_utils::presenters::{CursorPagePresenter, MutPresenter, PostMinPresenter};
 
+#[cfg(feature = "ssr")]
+use mango3_core::commands::{all_mentioned_users, PostInput};
 #[cfg(feature = "ssr")]
 use mango3_core::config::BASIC_CONFIG;
 #[cfg(feature = "ssr")]
@@ -17,32 +20,60 @@ use mango3_core::models::Post;
 #[cfg(feature = "ssr")]
 use mango3_core::utils::hashtag_has_lookaround;
 #[cfg(feature = "ssr")]
-use mango3_core::utils::{parse_html, render_handlebars, CursorPageParams};
+use mango3_core::utils::{
+    parse_html_with_shortcodes, render_diagrams, render_handlebars, CursorPageParams, Mentions, TemplateScope,
+};
 #[cfg(feature = "ssr")]
-use mango3_web_utils::presenters::FromModel;
+use mango3_web_utils::presenters::{FromModel, PostPresenter};
 #[cfg(feature = "ssr")]
 use mango3_web_utils::ssr::{expect_core_context, extract_i18n, extract_user};
 
-use crate::presenters::EditPostPresenter;
+use crate::presenters::{EditPostPresenter, PostPreviewPresenter};
 
 #[cfg(feature = "ssr")]
 use super::{get_blobs_by_ids, my_website};
 
 #[server]
 pub async fn preview_post(
+    website_id: Uuid,
     title: String,
     content: String,
     variables: String,
     cover_image_blob_id: Option<Uuid>,
-) -> Result<PostPresenter, ServerFnError> {
+) -> Result<PostPreviewPresenter, ServerFnError> {
     use mango3_web_utils::presenters::{BlobPresenter, HashtagPresenter, UserMinPresenter};
 
     let title = title.trim().to_owned();
     let content = content.trim();
     let variables = variables.parse::<Value>().unwrap_or_default();
 
+    let Some(website) = my_website(website_id).await? else {
+        return Err(ServerFnError::new("website not found"));
+    };
+
+    let core_context = expect_core_context();
     let user = extract_user().await?.unwrap();
-    let content_html = parse_html(&render_handlebars(content, &variables)?, true);
+    let mentions = all_mentioned_users(&core_context, content)
+        .await
+        .iter()
+        .collect::<Mentions>();
+    let language_code = website.language_code().to_owned();
+    let scope = TemplateScope {
+        core_context,
+        website,
+        post: None,
+    };
+    let (content_html, template_errors) = match render_handlebars(content, &variables, Some(scope)).await {
+        Ok(rendered) => {
+            let (diagrams, _) = render_diagrams(&rendered.content).await;
+
+            (
+                parse_html_with_shortcodes(&rendered.content, true, &diagrams, &mentions),
+                rendered.errors,
+            )
+        }
+        Err(error) => (String::new(), vec![error.to_string()]),
+    };
 
     let mut hashtag_names = REGEX_FIND_HASHTAGS
         .captures_iter(content)
@@ -78,24 +109,29 @@ pub async fn preview_post(
         None
     };
 
-    Ok(PostPresenter {
-        id: Uuid::new_v4(),
-        user: UserMinPresenter::from_model(&user).await,
-        title,
-        slug: String::new(),
-        content_html,
-        hashtags,
-        cover_image_blob,
-        blobs: vec![],
-        is_published: true,
-        url: BASIC_CONFIG.home_url(),
-        views_count: 0,
-        comments_count: 0,
-        reactions_count: 0,
-        published_at: None,
-        modified_at: None,
-        created_at: Utc::now(),
-        updated_at: None,
+    Ok(PostPreviewPresenter {
+        post: PostPresenter {
+            id: Uuid::new_v4(),
+            user: UserMinPresenter::from_model(&user).await,
+            language_code,
+            title,
+            slug: String::new(),
+            content_html,
+            toc_html: None,
+            hashtags,
+            cover_image_blob,
+            blobs: vec![],
+            is_published: true,
+            url: BASIC_CONFIG.home_url(),
+            views_count: 0,
+            comments_count: 0,
+            reactions_count: 0,
+            published_at: None,
+            modified_at: None,
+            created_at: Utc::now(),
+            updated_at: None,
+        },
+        template_errors,
     })
 }
 
@@ -106,9 +142,11 @@ pub async fn attempt_to_create_post(
     slug: String,
     content: String,
     variables: String,
+    language: String,
     blob_ids: Option<Vec<Uuid>>,
     cover_image_blob_id: Option<Uuid>,
     publish: Option<bool>,
+    translation_of_id: Option<Uuid>,
 ) -> Result<MutPresenter, ServerFnError> {
     use crate::constants::ssr::{KEY_TEXT_FAILED_TO_CREATE_POST, KEY_TEXT_POST_CREATED_SUCCESSFULLY};
 
@@ -129,18 +167,28 @@ pub async fn attempt_to_create_post(
     } else {
         None
     };
+    let translation_of = if let Some(id) = translation_of_id {
+        my_post(website_id, id).await?
+    } else {
+        None
+    };
 
     let result = mango3_core::commands::insert_post(
         &core_context,
         &website,
         &user,
-        &title,
-        &slug,
-        &content,
-        &variables,
-        blobs,
-        cover_image_blob.as_ref(),
-        publish.unwrap_or_default(),
+        PostInput {
+            title: &title,
+            slug: &slug,
+            content: &content,
+            variables: &variables,
+            language: &language,
+            blobs,
+            cover_image_blob: cover_image_blob.as_ref(),
+            publish: publish.unwrap_or_default(),
+        },
+        None,
+        translation_of.as_ref(),
     )
     .await;
     let success_message = i18n.text(KEY_TEXT_POST_CREATED_SUCCESSFULLY);
@@ -169,6 +217,7 @@ pub async fn attempt_to_update_post(
     slug: String,
     content: String,
     variables: String,
+    language: String,
     blob_ids: Option<Vec<Uuid>>,
     cover_image_blob_id: Option<Uuid>,
     publish: Option<bool>,
@@ -197,13 +246,16 @@ pub async fn attempt_to_update_post(
     let result = mango3_core::commands::update_post(
         &core_context,
         &post,
-        &title,
-        &slug,
-        &content,
-        &variables,
-        blobs,
-        cover_image_blob.as_ref(),
-        publish.unwrap_or_default(),
+        PostInput {
+            title: &title,
+            slug: &slug,
+            content: &content,
+            variables: &variables,
+            language: &language,
+            blobs,
+            cover_image_blob: cover_image_blob.as_ref(),
+            publish: publish.unwrap_or_default(),
+        },
     )
     .await;
     let success_message = i18n.text(KEY_TEXT_POST_UPDATED_SUCCESSFULLY);
@@ -211,6 +263,49 @@ pub async fn attempt_to_update_post(
     mango3_web_utils::mut_presenter!(result, success_message, error_message)
 }
 
+#[server(input = MultipartFormData)]
+pub async fn attempt_to_import_posts(data: MultipartData) -> Result<MutPresenter, ServerFnError> {
+    use crate::constants::ssr::{KEY_TEXT_FAILED_TO_IMPORT_POSTS, KEY_TEXT_POST_IMPORT_STARTED_SUCCESSFULLY};
+
+    let i18n = extract_i18n().await?;
+    let error_message = i18n.text(KEY_TEXT_FAILED_TO_IMPORT_POSTS);
+
+    let Some(user) = extract_user().await? else {
+        return mango3_web_utils::mut_presenter_error!(error_message);
+    };
+
+    let Some(mut data) = data.into_inner() else {
+        return mango3_web_utils::mut_presenter_error!(error_message);
+    };
+
+    let Some(field) = data.next_field().await? else {
+        return mango3_web_utils::mut_presenter_error!(error_message);
+    };
+
+    let Some("website_id") = field.name() else {
+        return mango3_web_utils::mut_presenter_error!(error_message);
+    };
+
+    let Some(website) = my_website(Uuid::try_parse(&field.text().await?)?).await? else {
+        return mango3_web_utils::mut_presenter_error!(error_message);
+    };
+
+    let Some(mut field) = data.next_field().await? else {
+        return mango3_web_utils::mut_presenter_error!(error_message);
+    };
+
+    let Some("file") = field.name() else {
+        return mango3_web_utils::mut_presenter_error!(error_message);
+    };
+
+    let core_context = expect_core_context();
+
+    let result = mango3_core::commands::enqueue_post_import(&core_context, &website, &user, &mut field).await;
+    let success_message = i18n.text(KEY_TEXT_POST_IMPORT_STARTED_SUCCESSFULLY);
+
+    mango3_web_utils::mut_presenter!(result, success_message, error_message)
+}
+
 #[server]
 pub async fn get_my_post(website_id: Uuid, id: String) -> Result<Option<EditPostPresenter>, ServerFnError> {
     if let Some(post) = my_post(website_id, Uuid::try_parse(&id)?).await? {
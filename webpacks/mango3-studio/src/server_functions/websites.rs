@@ -3,6 +3,8 @@ use uuid::Uuid;
 
 use mango3_web_utils::presenters::{CursorPagePresenter, MutPresenter, WebsiteMinPresenter, WebsitePresenter};
 
+#[cfg(feature = "ssr")]
+use mango3_core::commands::WebsiteInput;
 #[cfg(feature = "ssr")]
 use mango3_core::models::Website;
 #[cfg(feature = "ssr")]
@@ -12,11 +14,17 @@ use mango3_web_utils::presenters::FromModel;
 #[cfg(feature = "ssr")]
 use mango3_web_utils::ssr::{expect_core_context, extract_i18n, extract_user, require_authentication};
 
+use crate::presenters::WebsiteAnalyticsPresenter;
+
+#[cfg(feature = "ssr")]
+use crate::presenters::{WebsiteDailyStatPresenter, WebsiteTopPostPresenter, WebsiteViewsSourcePresenter};
+
 #[server]
 pub async fn attempt_to_create_website(
     name: String,
     subdomain: String,
     description: String,
+    language: String,
 ) -> Result<MutPresenter, ServerFnError> {
     use crate::constants::ssr::{KEY_TEXT_FAILED_TO_CREATE_WEBSITE, KEY_TEXT_WEBSITE_CREATED_SUCCESSFULLY};
 
@@ -34,12 +42,33 @@ pub async fn attempt_to_create_website(
         return mango3_web_utils::mut_presenter_error!(error_message);
     }
 
-    let result = mango3_core::commands::insert_website(&core_context, &user, &name, &subdomain, &description).await;
+    let result =
+        mango3_core::commands::insert_website(&core_context, &user, &name, &subdomain, &description, &language).await;
     let success_message = i18n.text(KEY_TEXT_WEBSITE_CREATED_SUCCESSFULLY);
 
     mango3_web_utils::mut_presenter!(result, success_message, error_message)
 }
 
+#[server]
+pub async fn attempt_to_export_website(id: Uuid) -> Result<MutPresenter, ServerFnError> {
+    use crate::constants::ssr::{KEY_TEXT_FAILED_TO_EXPORT_WEBSITE, KEY_TEXT_WEBSITE_EXPORT_STARTED_SUCCESSFULLY};
+
+    let i18n = extract_i18n().await?;
+    let error_message = i18n.text(KEY_TEXT_FAILED_TO_EXPORT_WEBSITE);
+
+    let Some(website) = my_website(id).await? else {
+        return mango3_web_utils::mut_presenter_error!(error_message);
+    };
+
+    let core_context = expect_core_context();
+    let user = extract_user().await?.unwrap();
+
+    let result = mango3_core::commands::enqueue_website_export(&core_context, &website, &user).await;
+    let success_message = i18n.text(KEY_TEXT_WEBSITE_EXPORT_STARTED_SUCCESSFULLY);
+
+    mango3_web_utils::mut_presenter!(result, success_message, error_message)
+}
+
 #[server]
 pub async fn attempt_to_update_website(
     id: Uuid,
@@ -49,6 +78,7 @@ pub async fn attempt_to_update_website(
     cover_image_blob_id: Option<Uuid>,
     light_theme: String,
     dark_theme: String,
+    language: String,
     publish: Option<bool>,
 ) -> Result<MutPresenter, ServerFnError> {
     use crate::constants::ssr::{KEY_TEXT_FAILED_TO_UPDATE_WEBSITE, KEY_TEXT_WEBSITE_UPDATED_SUCCESSFULLY};
@@ -82,13 +112,16 @@ pub async fn attempt_to_update_website(
     let result = mango3_core::commands::update_website(
         &core_context,
         &website,
-        &name,
-        &description,
-        icon_image_blob.as_ref(),
-        cover_image_blob.as_ref(),
-        &light_theme,
-        &dark_theme,
-        publish.unwrap_or_default(),
+        WebsiteInput {
+            name: &name,
+            description: &description,
+            icon_image_blob: icon_image_blob.as_ref(),
+            cover_image_blob: cover_image_blob.as_ref(),
+            light_theme: &light_theme,
+            dark_theme: &dark_theme,
+            language: &language,
+            publish: publish.unwrap_or_default(),
+        },
     )
     .await;
     let success_message = i18n.text(KEY_TEXT_WEBSITE_UPDATED_SUCCESSFULLY);
@@ -105,6 +138,43 @@ pub async fn get_my_website(id: String) -> Result<Option<WebsitePresenter>, Serv
     }
 }
 
+#[server]
+pub async fn get_my_website_analytics(id: Uuid, days: u16) -> Result<Option<WebsiteAnalyticsPresenter>, ServerFnError> {
+    use chrono::{Days, Utc};
+    use futures::future::join_all;
+
+    let Some(website) = my_website(id).await? else {
+        return Ok(None);
+    };
+
+    let core_context = expect_core_context();
+    let since = Utc::now()
+        .date_naive()
+        .checked_sub_days(Days::new(u64::from(days.clamp(1, 365)) - 1))
+        .unwrap_or_default();
+
+    let daily_stats = mango3_core::commands::all_website_daily_stats(&core_context, &website, since).await;
+    let top_posts = mango3_core::commands::all_website_top_posts(&core_context, &website, since, 10).await;
+    let referrers = mango3_core::commands::all_website_referrers(&core_context, &website, since, 10).await;
+    let countries = mango3_core::commands::all_website_countries(&core_context, &website, since, 10).await;
+
+    Ok(Some(WebsiteAnalyticsPresenter {
+        daily_stats: join_all(daily_stats.iter().map(WebsiteDailyStatPresenter::from_model)).await,
+        top_posts: join_all(top_posts.iter().map(WebsiteTopPostPresenter::from_model)).await,
+        referrers: join_all(referrers.iter().map(WebsiteViewsSourcePresenter::from_model)).await,
+        countries: join_all(countries.iter().map(|country| async move {
+            let mut presenter = WebsiteViewsSourcePresenter::from_model(country).await;
+
+            if let Some(country_code) = mango3_core::utils::find_country(&country.name) {
+                presenter.name = country_code.name.to_owned();
+            }
+
+            presenter
+        }))
+        .await,
+    }))
+}
+
 #[server]
 pub async fn get_my_websites(after: Option<Uuid>) -> Result<CursorPagePresenter<WebsiteMinPresenter>, ServerFnError> {
     if !require_authentication().await? {
@@ -5,8 +5,10 @@ use crate::constants::COOKIE_NAME_LANGUAGE;
 use crate::context::{provide_basic_config, provide_current_user_resource, provide_info, use_language_cookie_options};
 use crate::i18n::I18nContextProvider;
 
+/// Apps that set the `lang` attribute of `<html>` from their own content, instead of the user language, should pass
+/// `set_lang_attr_on_html=false`.
 #[component]
-pub fn AppProvider(children: Children) -> impl IntoView {
+pub fn AppProvider(children: Children, #[prop(default = true)] set_lang_attr_on_html: bool) -> impl IntoView {
     provide_basic_config();
     provide_current_user_resource();
     provide_info();
@@ -14,7 +16,11 @@ pub fn AppProvider(children: Children) -> impl IntoView {
     let language_cookie_options = use_language_cookie_options::<FromToStringCodec>();
 
     view! {
-        <I18nContextProvider cookie_name=COOKIE_NAME_LANGUAGE cookie_options=language_cookie_options>
+        <I18nContextProvider
+            cookie_name=COOKIE_NAME_LANGUAGE
+            cookie_options=language_cookie_options
+            set_lang_attr_on_html=set_lang_attr_on_html
+        >
             <div class="flex flex-col min-h-screen">{children()}</div>
         </I18nContextProvider>
     }
@@ -3,12 +3,14 @@ use leptos::ev::{Event, MouseEvent};
 use leptos::prelude::*;
 use leptos::text_prop::TextProp;
 use wasm_bindgen::JsCast;
-use web_sys::{FormData, HtmlInputElement};
+use web_sys::{File, HtmlInputElement};
 
 use crate::components::{CopyableText, LoadingSpinner};
+use crate::context::use_basic_config;
 use crate::icons::TrashOutlined;
 use crate::presenters::{BlobPresenter, MutPresenterActionValue};
-use crate::server_functions::attempt_to_upload_image;
+use crate::server_functions::get_uploaded_image;
+use crate::utils::tus::upload_file;
 
 use super::FormField;
 
@@ -24,13 +26,24 @@ pub fn ImageUploadField(
     #[prop(default = 48)] width: u16,
     #[prop(into, optional)] website_id: TextProp,
 ) -> impl IntoView {
-    let upload_action = Action::new_local(|data: &FormData| attempt_to_upload_image(data.clone().into()));
-    let upload_action_value = upload_action.value();
+    let basic_config = use_basic_config();
     let website_id_store = StoredValue::new(website_id);
+    let upload_action = Action::new_local(move |file: &File| {
+        let blob_uploads_url = basic_config.blob_uploads_url.clone();
+        let file = file.clone();
+        let website_id = website_id_store.read_value().get().to_string();
+
+        async move {
+            let blob_id = upload_file(&blob_uploads_url, &file, &website_id).await?;
+
+            get_uploaded_image(blob_id, website_id).await.ok().flatten()
+        }
+    });
+    let upload_action_value = upload_action.value();
 
     Effect::new(move || {
-        if let Some(Ok(success)) = upload_action_value.get() {
-            value.set(success.data)
+        if let Some(blob) = upload_action_value.get() {
+            value.set(blob)
         }
     });
 
@@ -48,17 +61,7 @@ pub fn ImageUploadField(
             return;
         };
 
-        let Ok(form_data) = FormData::new() else {
-            return;
-        };
-
-        let website_id = website_id_store.read_value().get();
-
-        let _ = form_data.append_with_str("website_id", &website_id);
-
-        let _ = form_data.append_with_blob_and_filename("file", &file, &file.name());
-
-        upload_action.dispatch_local(form_data);
+        upload_action.dispatch_local(file);
     };
 
     let remove = move |event: MouseEvent| {
@@ -79,6 +82,7 @@ pub fn ImageUploadField(
                             <div class="flex flex-wrap gap-3">
                                 <img
                                     class="rounded"
+                                    alt=blob.alt_text.clone()
                                     width=width
                                     height=height
                                     src=blob.variant_url(width, height, true).to_string()
@@ -0,0 +1,45 @@
+use leptos::ev::MouseEvent;
+use leptos::prelude::*;
+
+use crate::presenters::HashtagPresenter;
+
+#[component]
+pub fn HashtagSuggestions<SH>(
+    highlighted: RwSignal<usize>,
+    hashtags: Signal<Vec<HashtagPresenter>>,
+    select_hashtag: SH,
+) -> impl IntoView
+where
+    SH: Fn(String) + Copy + Send + Sync + 'static,
+{
+    view! {
+        <Show when=move || hashtags.with(|hashtags| !hashtags.is_empty())>
+            <ul class="menu bg-base-200 rounded-box shadow z-[1] w-56 absolute left-0 top-full mt-1">
+                {move || {
+                    hashtags
+                        .get()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, hashtag)| {
+                            let name = hashtag.name.clone();
+                            view! {
+                                <li>
+                                    <a
+                                        class:active=move || highlighted.get() == index
+                                        on:mousedown=move |event: MouseEvent| {
+                                            event.prevent_default();
+                                            select_hashtag(name.clone());
+                                        }
+                                    >
+                                        "#"
+                                        {hashtag.name}
+                                    </a>
+                                </li>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </ul>
+        </Show>
+    }
+}
@@ -16,17 +16,29 @@ where
     let dummy_action_value = MutPresenterActionValue::<()>::new(None);
     let value_url = RwSignal::new("".to_string());
     let value_alt_text = RwSignal::new("".to_string());
+    let value_caption = RwSignal::new("".to_string());
 
     Effect::new(move |_| {
         if is_open.get() {
             value_url.set(String::new());
             value_alt_text.set(selected_text());
+            value_caption.set(String::new());
         }
     });
 
     let on_click_accept = move |event: MouseEvent| {
         event.prevent_default();
-        let text = format!("![{}]({})", value_alt_text.get(), value_url.get());
+        let caption = value_caption.get();
+        let text = if caption.is_empty() {
+            format!("![{}]({})", value_alt_text.get(), value_url.get())
+        } else {
+            format!(
+                "![{}]({} \"{}\")",
+                value_alt_text.get(),
+                value_url.get(),
+                caption.replace('"', "\\\"")
+            )
+        };
         insert_text(&text, text.len());
         is_open.set(false);
     };
@@ -39,6 +51,7 @@ where
     let on_close = move || {
         value_url.set(String::new());
         value_alt_text.set(String::new());
+        value_caption.set(String::new());
     };
 
     view! {
@@ -51,6 +64,7 @@ where
                 label=move || t!(i18n, shared.alternative_text)
                 value=value_alt_text
             />
+            <TextField action_value=dummy_action_value label=move || t!(i18n, shared.caption) value=value_caption />
 
             <div class="modal-action">
                 <button class="btn" on:click=on_click_cancel>
@@ -6,18 +6,25 @@ use leptos_use::{
 use wasm_bindgen::JsCast;
 use web_sys::{HtmlDocument, HtmlTextAreaElement};
 
-use crate::constants::{KEY_CODE_5, KEY_CODE_B, KEY_CODE_I, KEY_CODE_K};
+use crate::constants::{
+    KEY_CODE_5, KEY_CODE_ARROW_DOWN, KEY_CODE_ARROW_UP, KEY_CODE_B, KEY_CODE_ESCAPE, KEY_CODE_I, KEY_CODE_K,
+    KEY_CODE_TAB,
+};
 use crate::i18n::use_i18n;
 use crate::icons::{
-    ArrowUturnLeftMini, ArrowUturnRightMini, BoldMini, ImageMini, ItalicMini, LinkMini, StrikethroughMini,
+    ArrowUturnLeftMini, ArrowUturnRightMini, BoldMini, ImageMini, InformationCircleMini, ItalicMini, LinkMini,
+    Squares2x2Mini, StrikethroughMini, VideoCameraMini,
 };
 use crate::presenters::MutPresenterActionValue;
+use crate::server_functions::get_hashtag_suggestions;
 
 use super::FormField;
 
+mod hashtag_suggestions;
 mod image_modal;
 mod link_modal;
 
+use hashtag_suggestions::HashtagSuggestions;
 use image_modal::ImageModal;
 use link_modal::LinkModal;
 
@@ -25,9 +32,25 @@ const BOLD: &str = "**";
 const ITALIC: &str = "_";
 const STRIKETHROUGH: &str = "~";
 
+/// The partial hashtag name being typed right before the cursor, if any.
+fn hashtag_prefix(text: &str, cursor_pos: usize) -> Option<String> {
+    let text_before = String::from_utf16_lossy(&text.encode_utf16().take(cursor_pos).collect::<Vec<u16>>());
+    let (start, prefix) = text_before.rsplit_once('#')?;
+
+    if !start.chars().last().is_none_or(char::is_whitespace)
+        || prefix.is_empty()
+        || !prefix.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return None;
+    }
+
+    Some(prefix.to_owned())
+}
+
 #[component]
 pub fn MarkdownEditorField(
     #[prop(optional)] action_value: MutPresenterActionValue,
+    #[prop(optional)] enable_shortcodes: bool,
     #[prop(into, optional)] error: RwSignal<Option<String>>,
     #[prop(into, optional)] id: &'static str,
     #[prop(into, optional)] label: ViewFn,
@@ -40,6 +63,8 @@ pub fn MarkdownEditorField(
     let hotkey_pressed = RwSignal::new(false);
     let image_modal_is_open = RwSignal::new(false);
     let link_modal_is_open = RwSignal::new(false);
+    let hashtag_query = RwSignal::new(None::<String>);
+    let highlighted_hashtag = RwSignal::new(0);
 
     let UseTextareaAutosizeReturn {
         content, set_content, ..
@@ -92,12 +117,101 @@ pub fn MarkdownEditorField(
         insert_text(&format!("{chars}{text}{chars}",), chars.len() + text.len());
     };
 
+    let hashtag_suggestions_resource = LocalResource::new(move || {
+        let query = hashtag_query.get();
+
+        async move {
+            if let Some(query) = query {
+                get_hashtag_suggestions(query).await.unwrap_or_default()
+            } else {
+                vec![]
+            }
+        }
+    });
+
+    let hashtag_suggestions = Signal::derive(move || {
+        if hashtag_query.with(Option::is_none) {
+            return vec![];
+        }
+
+        hashtag_suggestions_resource
+            .get()
+            .map(|hashtags| hashtags.take())
+            .unwrap_or_default()
+    });
+
+    let update_hashtag_query = move || {
+        let (sel_start, sel_end) = text_selection();
+        let query = if sel_start == sel_end {
+            hashtag_prefix(&textarea_element().value(), sel_end)
+        } else {
+            None
+        };
+
+        if query != hashtag_query.get_untracked() {
+            highlighted_hashtag.set(0);
+            hashtag_query.set(query);
+        }
+    };
+
+    let select_hashtag = move |name: String| {
+        let Some(query) = hashtag_query.get_untracked() else {
+            return;
+        };
+
+        let (_, sel_end) = text_selection();
+        let sel_start = sel_end.saturating_sub(query.len());
+        let text = format!("{name} ");
+
+        let _ = textarea_element().set_selection_range(sel_start as u32, sel_end as u32);
+
+        insert_text(&text, text.len());
+        hashtag_query.set(None);
+    };
+
     let _ = use_event_listener(node_ref, keydown, move |event| {
         if hotkey_pressed.get() {
             event.prevent_default();
             return;
         }
 
+        let hashtags_count = hashtag_suggestions.with_untracked(Vec::len);
+
+        if hashtags_count > 0 {
+            match event.key_code() {
+                KEY_CODE_ARROW_DOWN => {
+                    event.prevent_default();
+                    highlighted_hashtag.update(|index| *index = (*index + 1) % hashtags_count);
+                    return;
+                }
+                KEY_CODE_ARROW_UP => {
+                    event.prevent_default();
+                    highlighted_hashtag.update(|index| *index = (*index + hashtags_count - 1) % hashtags_count);
+                    return;
+                }
+                KEY_CODE_TAB => {
+                    event.prevent_default();
+
+                    let name = hashtag_suggestions.with_untracked(|hashtags| {
+                        hashtags
+                            .get(highlighted_hashtag.get_untracked())
+                            .map(|hashtag| hashtag.name.clone())
+                    });
+
+                    if let Some(name) = name {
+                        select_hashtag(name);
+                    }
+                    return;
+                }
+                KEY_CODE_ESCAPE => {
+                    event.prevent_default();
+                    hashtag_query.set(None);
+                    return;
+                }
+                _ => {}
+            }
+        }
+
         if event.ctrl_key() {
             match event.key_code() {
                 KEY_CODE_B => {
@@ -143,6 +257,10 @@ pub fn MarkdownEditorField(
     let _ = use_event_listener(node_ref, keyup, move |event| {
         event.prevent_default();
         hotkey_pressed.set(false);
+
+        if event.key_code() != KEY_CODE_ESCAPE {
+            update_hashtag_query();
+        }
     });
 
     let has_error = move || error.get().is_some();
@@ -186,6 +304,33 @@ pub fn MarkdownEditorField(
         image_modal_is_open.set(true);
     };
 
+    let insert_shortcode = move |name: &str, default_args: &str| {
+        let text = selected_text();
+        let args = if text.trim().is_empty() { default_args } else { text.trim() };
+        let shortcode = format!("{{{{< {name} {args} >}}}}");
+
+        insert_text(&shortcode, shortcode.len());
+    };
+
+    let on_click_youtube = move |event: MouseEvent| {
+        event.prevent_default();
+        insert_shortcode("youtube", "VIDEO_ID");
+    };
+
+    let on_click_gallery = move |event: MouseEvent| {
+        event.prevent_default();
+        insert_shortcode("gallery", "BLOB_ID");
+    };
+
+    let on_click_callout = move |event: MouseEvent| {
+        event.prevent_default();
+
+        let text = selected_text();
+        let start = "{{< callout info >}}\n";
+
+        insert_text(&format!("{start}{text}\n{{{{< /callout >}}}}"), start.len() + text.len());
+    };
+
     view! {
         <FormField action_value=action_value error=error id=id label=label name=name>
             <div class="join">
@@ -279,22 +424,61 @@ pub fn MarkdownEditorField(
                 >
                     <ImageMini />
                 </button>
+
+                <Show when=move || enable_shortcodes>
+                    <div class="divider divider-horizontal mx-1" />
+
+                    <button
+                        class="btn btn-sm btn-outline btn-accent px-2"
+                        on:click=on_click_youtube
+                        title=move || async_t_string!(i18n, shared.insert_youtube_video).get()
+                    >
+                        <VideoCameraMini />
+                    </button>
+                    <button
+                        class="btn btn-sm btn-outline btn-accent px-2"
+                        on:click=on_click_gallery
+                        title=move || async_t_string!(i18n, shared.insert_gallery).get()
+                    >
+                        <Squares2x2Mini />
+                    </button>
+                    <button
+                        class="btn btn-sm btn-outline btn-accent px-2"
+                        on:click=on_click_callout
+                        title=move || async_t_string!(i18n, shared.insert_callout).get()
+                    >
+                        <InformationCircleMini />
+                    </button>
+                </Show>
             </div>
 
             <LinkModal insert_text=insert_text is_open=link_modal_is_open selected_text=selected_text />
 
             <ImageModal insert_text=insert_text is_open=image_modal_is_open selected_text=selected_text />
 
-            <textarea
-                node_ref=node_ref
-                prop:value=content
-                on:input=move |event| set_content.set(event_target_value(&event))
-                class="textarea textarea-bordered font-mono w-full"
-                class:textarea-error=has_error
-                id=id
-                name=name
-                rows=rows
-            />
+            <div class="relative">
+                <textarea
+                    node_ref=node_ref
+                    prop:value=content
+                    on:blur=move |_| hashtag_query.set(None)
+                    on:click=move |_| update_hashtag_query()
+                    on:input=move |event| {
+                        set_content.set(event_target_value(&event));
+                        update_hashtag_query();
+                    }
+                    class="textarea textarea-bordered font-mono w-full"
+                    class:textarea-error=has_error
+                    id=id
+                    name=name
+                    rows=rows
+                />
+
+                <HashtagSuggestions
+                    highlighted=highlighted_hashtag
+                    hashtags=hashtag_suggestions
+                    select_hashtag=select_hashtag
+                />
+            </div>
         </FormField>
     }
 }
@@ -27,6 +27,8 @@ mod current_user;
 mod infinite_scroll;
 #[cfg(feature = "post-card")]
 mod post_card;
+#[cfg(feature = "report-button")]
+mod report_button;
 #[cfg(feature = "unconfirmed-email-alert")]
 mod unconfirmed_email_alert;
 #[cfg(feature = "user-card")]
@@ -65,6 +67,8 @@ pub use infinite_scroll::{
 };
 #[cfg(feature = "post-card")]
 pub use post_card::PostCard;
+#[cfg(feature = "report-button")]
+pub use report_button::ReportButton;
 #[cfg(feature = "unconfirmed-email-alert")]
 pub use unconfirmed_email_alert::UnconfirmedEmailAlert;
 #[cfg(feature = "user-card")]
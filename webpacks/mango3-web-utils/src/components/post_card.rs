@@ -1,4 +1,4 @@
-use leptos::either::Either;
+use leptos::either::EitherOf3;
 use leptos::prelude::*;
 
 use crate::components::{Hashtags, WebsiteIcon};
@@ -22,12 +22,16 @@ pub fn PostCard(
         None
     };
 
-    let unpublished_tag = if !post.is_published {
-        Either::Left(
+    let status_tag = if post.is_suspended {
+        EitherOf3::A(
+            view! { <a class="btn btn-sm btn-outline btn-warning no-animation">{t!(i18n, shared.suspended)}</a> },
+        )
+    } else if !post.is_published {
+        EitherOf3::B(
             view! { <a class="btn btn-sm btn-outline btn-info no-animation">{t!(i18n, shared.unpublished)}</a> },
         )
     } else {
-        Either::Right(())
+        EitherOf3::C(())
     };
 
     view! {
@@ -44,7 +48,7 @@ pub fn PostCard(
                                     <a href=href.clone() title=post_title.clone()>
                                         <img
                                             src=cover_image_blob.variant_url(1200, 200, true).to_string()
-                                            alt=post_title.clone()
+                                            alt=cover_image_blob.alt_text_or(&post_title)
                                         />
                                     </a>
                                 </figure>
@@ -84,7 +88,7 @@ pub fn PostCard(
                 </a>
 
                 <div class="empty:hidden my-1 flex gap-2 overflow-x-auto">
-                    {unpublished_tag} <Hashtags hashtags=post.hashtags base_url=hashtags_base_url />
+                    {status_tag} <Hashtags hashtags=post.hashtags base_url=hashtags_base_url />
                 </div>
 
                 <PostBottomBar
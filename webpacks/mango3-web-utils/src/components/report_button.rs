@@ -0,0 +1,100 @@
+use leptos::prelude::*;
+use uuid::Uuid;
+
+use crate::components::forms::{FormErrorAlert, FormField, FormSuccessModal, SubmitButton, TextareaField};
+use crate::components::{CurrentUser, Modal};
+use crate::i18n::{t, use_i18n};
+use crate::presenters::MutPresenter;
+use crate::server_functions::AttemptToReport;
+
+#[component]
+pub fn ReportButton(
+    #[prop(into, optional)] class: &'static str,
+    target_id: Uuid,
+    #[prop(into)] target_type: &'static str,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <CurrentUser children=move |_| {
+            let server_action = ServerAction::<AttemptToReport>::new();
+            let action_value = server_action.value();
+            let show_form = RwSignal::new(false);
+            let value_note = RwSignal::new(String::new());
+
+            Effect::new(move || {
+                let response = MutPresenter::from(action_value);
+
+                if response.is_success() {
+                    show_form.set(false);
+                }
+            });
+
+            view! {
+                <button
+                    class=format!("btn btn-ghost btn-sm {class}")
+                    on:click=move |event| {
+                        event.prevent_default();
+                        show_form.set(true);
+                    }
+                >
+                    {t!(i18n, shared.report)}
+                </button>
+
+                <Modal is_open=show_form>
+                    <h4 class="text-lg font-bold">{t!(i18n, shared.report)}</h4>
+
+                    <ActionForm
+                        action=server_action
+                        attr:autocomplete="off"
+                        attr:novalidate="true"
+                        attr:class="form"
+                    >
+                        <FormErrorAlert
+                            action_value=action_value
+                            message=move || t!(i18n, shared.failed_to_send_report)
+                        />
+
+                        <input type="hidden" name="target_type" value=target_type />
+                        <input type="hidden" name="target_id" value=target_id.to_string() />
+
+                        <FormField
+                            action_value=action_value
+                            id="category"
+                            label=move || t!(i18n, shared.category)
+                            name="category"
+                        >
+                            <select class="select w-full" id="category" name="category">
+                                <option value="spam">{t!(i18n, shared.spam)}</option>
+                                <option value="harassment">{t!(i18n, shared.harassment)}</option>
+                                <option value="hate_speech">{t!(i18n, shared.hate_speech)}</option>
+                                <option value="violence">{t!(i18n, shared.violence)}</option>
+                                <option value="illegal_content">{t!(i18n, shared.illegal_content)}</option>
+                                <option value="other">{t!(i18n, shared.other)}</option>
+                            </select>
+                        </FormField>
+
+                        <TextareaField
+                            action_value=action_value
+                            id="note"
+                            label=move || t!(i18n, shared.note)
+                            name="note"
+                            value=value_note
+                        />
+
+                        <SubmitButton is_loading=server_action.pending() />
+                    </ActionForm>
+                </Modal>
+
+                <FormSuccessModal
+                    action_value=action_value
+                    message=move || t!(i18n, shared.thanks_for_your_report)
+                    on_close=move || {
+                        action_value.set(None);
+                        value_note.set(String::new());
+                    }
+                />
+            }
+        } />
+    }
+}
@@ -1,4 +1,4 @@
-use leptos::either::Either;
+use leptos::either::EitherOf3;
 use leptos::prelude::*;
 
 use crate::components::Hashtags;
@@ -20,12 +20,16 @@ pub fn WebsiteCard(
         None
     };
 
-    let unpublished_tag = if !website.is_published {
-        Either::Left(
+    let status_tag = if website.is_suspended {
+        EitherOf3::A(
+            view! { <a class="btn btn-sm btn-outline btn-warning no-animation">{t!(i18n, shared.suspended)}</a> },
+        )
+    } else if !website.is_published {
+        EitherOf3::B(
             view! { <a class="btn btn-sm btn-outline btn-info no-animation">{t!(i18n, shared.unpublished)}</a> },
         )
     } else {
-        Either::Right(())
+        EitherOf3::C(())
     };
 
     view! {
@@ -50,7 +54,7 @@ pub fn WebsiteCard(
                 </a>
 
                 <div class="empty:hidden my-1 flex gap-2 overflow-x-auto">
-                    {unpublished_tag} <Hashtags hashtags=website.hashtags base_url=hashtags_base_url />
+                    {status_tag} <Hashtags hashtags=website.hashtags base_url=hashtags_base_url />
                 </div>
 
                 <div class="card-actions justify-end">{actions.run()}</div>
@@ -3,6 +3,14 @@ pub(crate) const COOKIE_NAME_LANGUAGE: &str = "_mango3_language";
 #[cfg(feature = "forms")]
 pub(crate) const KEY_CODE_ENTER: u32 = 13;
 #[cfg(feature = "markdown-editor")]
+pub(crate) const KEY_CODE_TAB: u32 = 9;
+#[cfg(feature = "markdown-editor")]
+pub(crate) const KEY_CODE_ESCAPE: u32 = 27;
+#[cfg(feature = "markdown-editor")]
+pub(crate) const KEY_CODE_ARROW_UP: u32 = 38;
+#[cfg(feature = "markdown-editor")]
+pub(crate) const KEY_CODE_ARROW_DOWN: u32 = 40;
+#[cfg(feature = "markdown-editor")]
 pub(crate) const KEY_CODE_5: u32 = 53;
 #[cfg(feature = "markdown-editor")]
 pub(crate) const KEY_CODE_B: u32 = 66;
@@ -2,8 +2,11 @@ use leptos::prelude::*;
 
 mod arrow;
 mod bars;
+mod chart;
 mod chevron;
+mod circle_stack;
 mod document;
+mod envelope;
 mod exclamation;
 mod eye;
 mod home;
@@ -14,27 +17,34 @@ mod magnifying_glass;
 mod paper_clip;
 mod pencil;
 mod plus;
+mod squares;
 mod text_editor;
 mod trash;
 mod users;
+mod video_camera;
 
 pub use arrow::{ArrowUturnLeftMini, ArrowUturnRightMini};
 pub use bars::Bars3Outlined;
+pub use chart::ChartBarOutlined;
 pub use chevron::{ChevronDownMini, ChevronUpMini};
+pub use circle_stack::CircleStackOutlined;
 pub use document::{DocumentOutlined, DocumentTextOutlined};
+pub use envelope::EnvelopeOutlined;
 pub use exclamation::ExclamationOutlined;
 pub use eye::{EyeMini, EyeSlashMini};
 pub use home::HomeOutlined;
 pub use image::ImageMini;
-pub use information_circle::InformationCircleOutlined;
+pub use information_circle::{InformationCircleMini, InformationCircleOutlined};
 pub use link::{LinkMini, LinkOutlined};
 pub use magnifying_glass::MagnifyingGlassMini;
 pub use paper_clip::PaperClipOutlined;
 pub use pencil::PencilSquareOutlined;
 pub use plus::PlusOutlined;
+pub use squares::Squares2x2Mini;
 pub use text_editor::{BoldMini, ItalicMini, StrikethroughMini};
 pub use trash::TrashOutlined;
 pub use users::UsersOutlined;
+pub use video_camera::VideoCameraMini;
 
 #[component]
 pub fn CheckMini() -> impl IntoView {
@@ -60,16 +60,11 @@ pub async fn leptos_http_server<F, IV1, IV2>(
 
     use axum::Router;
     use axum_client_ip::SecureClientIpSource;
-    use cookie::{Key, SameSite};
-    use fred::prelude::{ClientLike, Config, Pool};
     use leptos::logging::log;
     use leptos_axum::{file_and_error_handler, generate_route_list, LeptosRoutes};
-    use time::Duration;
     use tokio::net::TcpListener;
-    use tower_sessions::{Expiry, SessionManagerLayer};
-    use tower_sessions_redis_store::RedisStore;
 
-    use mango3_core::config::{load_config, BASIC_CONFIG, MISC_CONFIG, SESSIONS_CONFIG};
+    use mango3_core::config::{load_config, MISC_CONFIG};
     use mango3_core::CoreContext;
 
     load_config();
@@ -77,30 +72,7 @@ pub async fn leptos_http_server<F, IV1, IV2>(
     let core_context = CoreContext::setup().await;
     let addr = leptos_options.site_addr;
     let routes = generate_route_list(app_fn);
-    let redis_pool = Pool::new(
-        Config::from_url(&SESSIONS_CONFIG.redis_url).expect("Could not get Redis URL for session."),
-        None,
-        None,
-        None,
-        10,
-    )
-    .expect("Could not get Redis pool for session.");
-
-    let redis_conn = redis_pool.connect();
-    redis_pool
-        .wait_for_connect()
-        .await
-        .expect("Could not get Redis connection for session.");
-
-    let session_store = RedisStore::new(redis_pool);
-    let session_layer = SessionManagerLayer::new(session_store)
-        .with_domain(BASIC_CONFIG.domain.clone())
-        .with_expiry(Expiry::OnInactivity(Duration::days(30)))
-        .with_http_only(true)
-        .with_name("_mango3_session")
-        .with_private(Key::from(SESSIONS_CONFIG.key.as_bytes()))
-        .with_same_site(SameSite::Strict)
-        .with_secure(BASIC_CONFIG.secure);
+    let (session_layer, redis_conn) = crate::ssr::session_layer().await;
     let client_ip_source_layer = SecureClientIpSource::from_str(&MISC_CONFIG.client_ip_source)
         .expect("Could not get client IP source.")
         .into_extension();
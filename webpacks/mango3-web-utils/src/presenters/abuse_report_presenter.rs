@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
+
+#[cfg(feature = "ssr")]
+use mango3_core::enums::AbuseReportTargetType;
+#[cfg(feature = "ssr")]
+use mango3_core::models::AbuseReport;
+
+use super::UserMinPresenter;
+
+#[cfg(feature = "ssr")]
+use super::FromModel;
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct AbuseReportPresenter {
+    pub id: Uuid,
+    pub user: UserMinPresenter,
+    pub target_type: String,
+    pub target_id: Uuid,
+    pub target_title: Option<String>,
+    pub target_url: Option<Url>,
+    /// The reported post, or the post of the reported comment.
+    pub target_post_id: Option<Uuid>,
+    pub category: String,
+    pub note: String,
+    pub is_resolved: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<AbuseReport> for AbuseReportPresenter {
+    async fn from_model(abuse_report: &AbuseReport) -> Self {
+        let core_context = crate::ssr::expect_core_context();
+        let user = abuse_report.user(&core_context).await.expect("Could not get user");
+
+        let (target_title, target_url, target_post_id) = target_details(&core_context, abuse_report)
+            .await
+            .map(|(title, url, post_id)| (Some(title), Some(url), post_id))
+            .unwrap_or_default();
+
+        Self {
+            id: abuse_report.id,
+            user: UserMinPresenter::from_model(&user).await,
+            target_type: abuse_report.target_type.to_string(),
+            target_id: abuse_report.target_id,
+            target_title,
+            target_url,
+            target_post_id,
+            category: abuse_report.category.to_string(),
+            note: abuse_report.note.clone(),
+            is_resolved: abuse_report.is_resolved(),
+            created_at: abuse_report.created_at,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+async fn target_details(
+    core_context: &mango3_core::CoreContext,
+    abuse_report: &AbuseReport,
+) -> Option<(String, Url, Option<Uuid>)> {
+    use mango3_core::commands::{get_post_by_id, get_post_comment_by_id, get_user_by_id, get_website_by_id};
+
+    match abuse_report.target_type {
+        AbuseReportTargetType::Post => {
+            let post = get_post_by_id(core_context, abuse_report.target_id, None, None, None)
+                .await
+                .ok()?;
+
+            Some((post.title.clone(), post.url(core_context).await, Some(post.id)))
+        }
+        AbuseReportTargetType::PostComment => {
+            let post_comment = get_post_comment_by_id(abuse_report.target_id, None).await.ok()?;
+            let post = get_post_by_id(core_context, post_comment.post_id, None, None, None)
+                .await
+                .ok()?;
+
+            Some((post.title.clone(), post.url(core_context).await, Some(post.id)))
+        }
+        AbuseReportTargetType::User => {
+            let user = get_user_by_id(core_context, abuse_report.target_id).await.ok()?;
+
+            Some((user.display_name.clone(), user.url(), None))
+        }
+        AbuseReportTargetType::Website => {
+            let website = get_website_by_id(core_context, abuse_report.target_id, None)
+                .await
+                .ok()?;
+
+            Some((website.name.clone(), website.url(), None))
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<AbuseReport> for () {
+    async fn from_model(_: &AbuseReport) -> Self {
+        ()
+    }
+}
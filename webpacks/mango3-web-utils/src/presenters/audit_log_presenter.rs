@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "ssr")]
+use mango3_core::models::AuditLog;
+
+use super::UserMinPresenter;
+
+#[cfg(feature = "ssr")]
+use super::FromModel;
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct AuditLogPresenter {
+    pub id: Uuid,
+    pub actor_user: Option<UserMinPresenter>,
+    pub ip_address: Option<String>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Uuid,
+    pub before_value: Option<String>,
+    pub after_value: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<AuditLog> for AuditLogPresenter {
+    async fn from_model(audit_log: &AuditLog) -> Self {
+        let core_context = crate::ssr::expect_core_context();
+        let actor_user = if let Some(actor_user) = audit_log.actor_user(&core_context).await {
+            Some(UserMinPresenter::from_model(&actor_user).await)
+        } else {
+            None
+        };
+
+        Self {
+            id: audit_log.id,
+            actor_user,
+            ip_address: audit_log.ip_address.clone(),
+            action: audit_log.action.to_string(),
+            target_type: audit_log.target_type.to_string(),
+            target_id: audit_log.target_id,
+            before_value: audit_log.before_value.as_ref().map(|value| value.to_string()),
+            after_value: audit_log.after_value.as_ref().map(|value| value.to_string()),
+            created_at: audit_log.created_at,
+        }
+    }
+}
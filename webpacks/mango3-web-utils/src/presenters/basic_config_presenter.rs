@@ -8,6 +8,7 @@ use mango3_core::config::BasicConfig;
 pub struct BasicConfigPresenter {
     pub about_url: Option<Url>,
     assets_url: Url,
+    pub blob_uploads_url: Url,
     pub copyright: String,
     pub description: String,
     pub domain: String,
@@ -31,6 +32,7 @@ impl Default for BasicConfigPresenter {
         Self {
             about_url: None,
             assets_url: home_url.clone(),
+            blob_uploads_url: home_url.clone(),
             copyright: String::new(),
             description: String::new(),
             domain: String::new(),
@@ -55,6 +57,7 @@ impl From<BasicConfig> for BasicConfigPresenter {
         Self {
             about_url: basic_config.about_url.clone(),
             assets_url: basic_config.assets_url().clone(),
+            blob_uploads_url: basic_config.blob_uploads_url(),
             copyright: basic_config.copyright.clone(),
             description: basic_config.description.clone(),
             domain: basic_config.domain.clone(),
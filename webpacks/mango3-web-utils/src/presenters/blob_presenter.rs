@@ -12,6 +12,10 @@ use super::FromModel;
 pub struct BlobPresenter {
     pub id: Uuid,
     pub file_name: String,
+    pub alt_text: String,
+    pub caption: String,
+    pub focal_point_x: f32,
+    pub focal_point_y: f32,
     pub url: Url,
 
     #[cfg(feature = "blob-is-removable")]
@@ -27,6 +31,10 @@ impl FromModel<Blob<'_>> for BlobPresenter {
         Self {
             id: blob.id,
             file_name: blob.file_name.to_string(),
+            alt_text: blob.alt_text.to_string(),
+            caption: blob.caption.to_string(),
+            focal_point_x: blob.focal_point_x,
+            focal_point_y: blob.focal_point_y,
             url: blob.url(),
 
             #[cfg(feature = "blob-is-removable")]
@@ -36,6 +44,18 @@ impl FromModel<Blob<'_>> for BlobPresenter {
 }
 
 impl BlobPresenter {
+    pub fn alt_text_or(&self, fallback: &str) -> String {
+        if self.alt_text.is_empty() {
+            fallback.to_owned()
+        } else {
+            self.alt_text.clone()
+        }
+    }
+
+    pub fn object_position(&self) -> String {
+        format!("{}% {}%", self.focal_point_x * 100.0, self.focal_point_y * 100.0)
+    }
+
     pub fn variant_url(&self, width: u16, height: u16, fill: bool) -> Url {
         let mut variant_url = self.url.clone();
 
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "ssr")]
+use mango3_core::models::InvitationCode;
+
+use super::UserMinPresenter;
+
+#[cfg(feature = "ssr")]
+use super::FromModel;
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct InvitationCodePresenter {
+    pub id: Uuid,
+    pub email: String,
+    pub role: String,
+    pub invited_by: Option<UserMinPresenter>,
+    pub used_by: Option<UserMinPresenter>,
+    pub is_expired: bool,
+    pub is_used: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<InvitationCode> for InvitationCodePresenter {
+    async fn from_model(invitation_code: &InvitationCode) -> Self {
+        let core_context = crate::ssr::expect_core_context();
+        let invited_by = if let Some(user) = invitation_code.invited_by(&core_context).await {
+            Some(UserMinPresenter::from_model(&user).await)
+        } else {
+            None
+        };
+        let used_by = if let Some(user) = invitation_code.used_by(&core_context).await {
+            Some(UserMinPresenter::from_model(&user).await)
+        } else {
+            None
+        };
+
+        Self {
+            id: invitation_code.id,
+            email: invitation_code.email.clone(),
+            role: invitation_code.role.to_string(),
+            invited_by,
+            used_by,
+            is_expired: invitation_code.is_expired(),
+            is_used: invitation_code.is_used(),
+            expires_at: invitation_code.expires_at,
+            used_at: invitation_code.used_at,
+            created_at: invitation_code.created_at,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<InvitationCode> for () {
+    async fn from_model(_: &InvitationCode) -> Self {
+        ()
+    }
+}
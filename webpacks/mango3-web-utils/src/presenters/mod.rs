@@ -1,20 +1,34 @@
 mod basic_config_presenter;
 mod info_presenter;
 
+#[cfg(feature = "abuse-report-presenter")]
+mod abuse_report_presenter;
+#[cfg(feature = "audit-log-presenter")]
+mod audit_log_presenter;
 #[cfg(feature = "blob-presenter")]
 mod blob_presenter;
 #[cfg(feature = "cursor-page-presenter")]
 mod cursor_page_presenter;
 #[cfg(feature = "hashtag-presenter")]
 mod hashtag_presenter;
+#[cfg(feature = "invitation-code-presenter")]
+mod invitation_code_presenter;
+#[cfg(feature = "moderation-presenter")]
+mod moderation_presenter;
 #[cfg(feature = "mutation-presenter")]
 mod mutation_presenter;
 #[cfg(feature = "navigation-item-presenter")]
 mod navigation_item_presenter;
 #[cfg(feature = "post-comment-presenter")]
 mod post_comment_presenter;
-#[cfg(any(feature = "post-min-presenter", feature = "post-presenter"))]
+#[cfg(any(
+    feature = "post-min-presenter",
+    feature = "post-presenter",
+    feature = "post-translation-presenter"
+))]
 mod post_presenter;
+#[cfg(feature = "storage-usage-presenter")]
+mod storage_usage_presenter;
 #[cfg(feature = "user-presenter")]
 mod user_presenter;
 #[cfg(any(feature = "website-min-presenter", feature = "website-presenter"))]
@@ -23,12 +37,20 @@ mod website_presenter;
 pub use basic_config_presenter::BasicConfigPresenter;
 pub use info_presenter::InfoPresenter;
 
+#[cfg(feature = "abuse-report-presenter")]
+pub use abuse_report_presenter::AbuseReportPresenter;
+#[cfg(feature = "audit-log-presenter")]
+pub use audit_log_presenter::AuditLogPresenter;
 #[cfg(feature = "blob-presenter")]
 pub use blob_presenter::BlobPresenter;
 #[cfg(feature = "cursor-page-presenter")]
 pub use cursor_page_presenter::CursorPagePresenter;
 #[cfg(feature = "hashtag-presenter")]
 pub use hashtag_presenter::HashtagPresenter;
+#[cfg(feature = "invitation-code-presenter")]
+pub use invitation_code_presenter::InvitationCodePresenter;
+#[cfg(feature = "moderation-presenter")]
+pub use moderation_presenter::ModerationPresenter;
 #[cfg(feature = "mutation-presenter")]
 pub use mutation_presenter::{MutPresenter, MutPresenterActionValue};
 #[cfg(feature = "navigation-item-presenter")]
@@ -39,6 +61,10 @@ pub use post_comment_presenter::PostCommentPresenter;
 pub use post_presenter::PostMinPresenter;
 #[cfg(feature = "post-presenter")]
 pub use post_presenter::PostPresenter;
+#[cfg(feature = "post-translation-presenter")]
+pub use post_presenter::PostTranslationPresenter;
+#[cfg(feature = "storage-usage-presenter")]
+pub use storage_usage_presenter::StorageUsagePresenter;
 #[cfg(feature = "user-presenter")]
 pub use user_presenter::{UserMinPresenter, UserPresenter};
 #[cfg(feature = "website-min-presenter")]
@@ -72,6 +98,13 @@ impl FromModel<uuid::Uuid> for uuid::Uuid {
     }
 }
 
+#[cfg(all(feature = "ssr", feature = "blob-presenter"))]
+impl FromModel<mango3_core::models::Blob<'_>> for () {
+    async fn from_model(_: &mango3_core::models::Blob<'_>) -> Self {
+        ()
+    }
+}
+
 #[cfg(all(feature = "ssr", feature = "confirmation-code-presenter"))]
 impl FromModel<mango3_core::models::ConfirmationCode<'_>> for () {
     async fn from_model(_: &mango3_core::models::ConfirmationCode<'_>) -> Self {
@@ -79,6 +112,21 @@ impl FromModel<mango3_core::models::ConfirmationCode<'_>> for () {
     }
 }
 
+#[cfg(all(
+    feature = "ssr",
+    any(
+        feature = "moderation-presenter",
+        feature = "post-min-presenter",
+        feature = "post-presenter",
+        feature = "post-translation-presenter"
+    )
+))]
+impl FromModel<mango3_core::models::Post> for () {
+    async fn from_model(_: &mango3_core::models::Post) -> Self {
+        ()
+    }
+}
+
 #[cfg(all(feature = "ssr", feature = "post-reaction-presenter"))]
 impl FromModel<mango3_core::models::PostReaction<'_>> for () {
     async fn from_model(_: &mango3_core::models::PostReaction<'_>) -> Self {
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
+
+#[cfg(feature = "ssr")]
+use mango3_core::models::{Post, Website};
+
+use super::UserMinPresenter;
+
+#[cfg(feature = "ssr")]
+use super::FromModel;
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct ModerationPresenter {
+    pub website_id: Uuid,
+    pub post_id: Option<Uuid>,
+    pub title: String,
+    pub website_name: Option<String>,
+    pub user: UserMinPresenter,
+    pub is_published: bool,
+    pub is_suspended: bool,
+    pub moderation_reason: Option<String>,
+    pub url: Url,
+}
+
+impl ModerationPresenter {
+    pub fn key(&self) -> Uuid {
+        self.post_id.unwrap_or(self.website_id)
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<Post> for ModerationPresenter {
+    async fn from_model(post: &Post) -> Self {
+        let core_context = crate::ssr::expect_core_context();
+        let website = post.website(&core_context).await.expect("Could not get website");
+        let user = post.user(&core_context).await.expect("Could not get user");
+
+        Self {
+            website_id: post.website_id,
+            post_id: Some(post.id),
+            title: post.title.clone(),
+            website_name: Some(website.name.clone()),
+            user: UserMinPresenter::from_model(&user).await,
+            is_published: post.is_published(&core_context).await,
+            is_suspended: post.is_suspended(),
+            moderation_reason: post.moderation_reason.clone(),
+            url: post.url(&core_context).await,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<Website> for ModerationPresenter {
+    async fn from_model(website: &Website) -> Self {
+        let core_context = crate::ssr::expect_core_context();
+        let user = mango3_core::commands::get_user_by_id(&core_context, website.user_id)
+            .await
+            .expect("Could not get user");
+
+        Self {
+            website_id: website.id,
+            post_id: None,
+            title: website.name.clone(),
+            website_name: None,
+            user: UserMinPresenter::from_model(&user).await,
+            is_published: website.is_published(),
+            is_suspended: website.is_suspended(),
+            moderation_reason: website.moderation_reason.clone(),
+            url: website.url(),
+        }
+    }
+}
@@ -29,7 +29,7 @@ impl FromModel<PostComment<'_>> for PostCommentPresenter {
         Self {
             id: post_comment.id,
             user,
-            content_html: post_comment.content_html().await.to_string(),
+            content_html: post_comment.content_html(&core_context).await.to_string(),
             created_at: post_comment.created_at,
             updated_at: post_comment.updated_at,
         }
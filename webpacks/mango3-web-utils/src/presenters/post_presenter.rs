@@ -11,11 +11,16 @@ use super::{BlobPresenter, HashtagPresenter, UserMinPresenter, WebsiteMinPresent
 #[cfg(feature = "ssr")]
 use super::FromModel;
 
+/// Posts with fewer headings are short enough to be read without a table of contents.
+#[cfg(all(feature = "ssr", feature = "post-content-html"))]
+const TOC_MIN_HEADINGS: usize = 3;
+
 #[cfg(feature = "post-presenter")]
 #[derive(Clone, Deserialize, Serialize)]
 pub struct PostPresenter {
     pub id: Uuid,
     pub user: UserMinPresenter,
+    pub language_code: String,
     pub title: String,
     pub slug: String,
     pub hashtags: Vec<HashtagPresenter>,
@@ -33,6 +38,8 @@ pub struct PostPresenter {
 
     #[cfg(feature = "post-content-html")]
     pub content_html: String,
+    #[cfg(feature = "post-content-html")]
+    pub toc_html: Option<String>,
 }
 
 #[cfg(all(feature = "ssr", feature = "post-presenter"))]
@@ -54,10 +61,13 @@ impl FromModel<Post> for PostPresenter {
         };
         let blobs =
             futures::future::join_all(post.blobs().await.iter().map(|blob| BlobPresenter::from_model(&blob))).await;
+        #[cfg(feature = "post-content-html")]
+        let content_html = post.content_html(&core_context).await;
 
         Self {
             id: post.id,
             user,
+            language_code: post.language_code().to_owned(),
             title: post.title.clone(),
             slug: post.slug.clone(),
             hashtags,
@@ -74,7 +84,9 @@ impl FromModel<Post> for PostPresenter {
             updated_at: post.updated_at,
 
             #[cfg(feature = "post-content-html")]
-            content_html: post.content_html().await,
+            toc_html: mango3_core::utils::parse_toc_html(&content_html, TOC_MIN_HEADINGS),
+            #[cfg(feature = "post-content-html")]
+            content_html,
         }
     }
 }
@@ -91,6 +103,7 @@ pub struct PostMinPresenter {
     pub hashtags: Vec<HashtagPresenter>,
     pub cover_image_blob: Option<BlobPresenter>,
     pub is_published: bool,
+    pub is_suspended: bool,
     pub comments_count: i64,
     pub reactions_count: i64,
     pub views_count: i64,
@@ -130,6 +143,7 @@ impl FromModel<Post> for PostMinPresenter {
             hashtags,
             cover_image_blob,
             is_published: post.is_published(&core_context).await,
+            is_suspended: post.is_suspended(),
             comments_count: post.comments_count().await,
             reactions_count: post.reactions_count().await,
             views_count: post.views_count(&core_context).await,
@@ -141,9 +155,25 @@ impl FromModel<Post> for PostMinPresenter {
     }
 }
 
-#[cfg(feature = "ssr")]
-impl FromModel<Post> for () {
-    async fn from_model(_: &Post) -> Self {
-        ()
+#[cfg(feature = "post-translation-presenter")]
+#[derive(Clone, Deserialize, Serialize)]
+pub struct PostTranslationPresenter {
+    pub id: Uuid,
+    pub language_code: String,
+    pub title: String,
+    pub url: Url,
+}
+
+#[cfg(all(feature = "ssr", feature = "post-translation-presenter"))]
+impl FromModel<Post> for PostTranslationPresenter {
+    async fn from_model(post: &Post) -> Self {
+        let core_context = crate::ssr::expect_core_context();
+
+        Self {
+            id: post.id,
+            language_code: post.language_code().to_owned(),
+            title: post.title.clone(),
+            url: post.url(&core_context).await,
+        }
     }
 }
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use mango3_core::models::StorageUsage;
+
+use super::{UserMinPresenter, WebsiteMinPresenter};
+
+#[cfg(feature = "ssr")]
+use super::FromModel;
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct StorageUsagePresenter {
+    pub user: UserMinPresenter,
+    pub website: Option<WebsiteMinPresenter>,
+    pub has_custom_quota: bool,
+    pub max_storage_str: String,
+    pub used_storage_str: String,
+    pub max_storage: i64,
+    pub used_storage: i64,
+}
+
+impl StorageUsagePresenter {
+    pub fn key(&self) -> String {
+        format!(
+            "{}-{}",
+            self.user.id,
+            self.website.as_ref().map(|website| website.id.to_string()).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl FromModel<StorageUsage> for StorageUsagePresenter {
+    async fn from_model(storage_usage: &StorageUsage) -> Self {
+        let core_context = crate::ssr::expect_core_context();
+        let user = storage_usage.user(&core_context).await.expect("Could not get user");
+        let (website, has_custom_quota, max_storage) =
+            if let Some(Ok(website)) = storage_usage.website(&core_context).await {
+                (
+                    Some(WebsiteMinPresenter::from_model(&website).await),
+                    website.storage_quota.is_some(),
+                    website.max_storage().await,
+                )
+            } else {
+                (None, user.storage_quota.is_some(), user.max_storage())
+            };
+        let used_storage = storage_usage.used_storage();
+
+        Self {
+            user: UserMinPresenter::from_model(&user).await,
+            website,
+            has_custom_quota,
+            max_storage_str: max_storage.to_string(),
+            used_storage_str: used_storage.to_string(),
+            max_storage: max_storage.bytes(),
+            used_storage: used_storage.bytes(),
+        }
+    }
+}
@@ -24,7 +24,11 @@ pub struct WebsitePresenter {
     pub cover_image_blob: Option<BlobPresenter>,
     pub light_theme: String,
     pub dark_theme: String,
+    pub language: String,
+    pub language_code: String,
     pub is_published: bool,
+    pub is_suspended: bool,
+    pub moderation_reason: Option<String>,
     pub host: String,
     pub url: Url,
 
@@ -84,9 +88,9 @@ impl FromModel<Website> for WebsitePresenter {
 
         #[cfg(feature = "website-storage")]
         let (available_storage, max_storage, used_storage) = (
-            website.available_storage(&core_context).await,
-            website.max_storage(),
-            website.used_storage(&core_context).await,
+            website.available_storage().await,
+            website.max_storage().await,
+            website.used_storage().await,
         );
 
         Self {
@@ -101,7 +105,11 @@ impl FromModel<Website> for WebsitePresenter {
             cover_image_blob,
             light_theme: website.light_theme.clone(),
             dark_theme: website.dark_theme.clone(),
+            language: website.language.clone(),
+            language_code: website.language_code().to_owned(),
             is_published: website.is_published(),
+            is_suspended: website.is_suspended(),
+            moderation_reason: website.moderation_reason.clone(),
             host: website.host(),
             url: website.url(),
 
@@ -134,6 +142,7 @@ pub struct WebsiteMinPresenter {
     pub icon_image_blob: Option<BlobPresenter>,
     pub text_icon_url: Url,
     pub is_published: bool,
+    pub is_suspended: bool,
     pub host: String,
     pub url: Url,
 }
@@ -178,6 +187,7 @@ impl FromModel<Website> for WebsiteMinPresenter {
             icon_image_blob,
             text_icon_url: website.text_icon_url(),
             is_published: website.is_published(),
+            is_suspended: website.is_suspended(),
             host: website.host(),
             url: website.url(),
         }
@@ -196,6 +206,7 @@ impl From<&WebsitePresenter> for WebsiteMinPresenter {
             icon_image_blob: website.icon_image_blob.clone(),
             text_icon_url: website.text_icon_url.clone(),
             is_published: website.is_published,
+            is_suspended: website.is_suspended,
             host: website.host.clone(),
             url: website.url.clone(),
         }
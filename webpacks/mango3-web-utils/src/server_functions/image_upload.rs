@@ -1,55 +1,40 @@
 use leptos::prelude::*;
+use uuid::Uuid;
 
-use server_fn::codec::{MultipartData, MultipartFormData};
-
-use crate::presenters::{BlobPresenter, MutPresenter};
+use crate::presenters::BlobPresenter;
 
 #[cfg(feature = "ssr")]
-use crate::ssr::{expect_core_context, extract_user, require_authentication};
-
-#[server(input = MultipartFormData)]
-pub async fn attempt_to_upload_image(data: MultipartData) -> Result<MutPresenter<BlobPresenter>, ServerFnError> {
-    if !require_authentication().await? {
-        return crate::mut_presenter_error!();
-    }
-
-    let Some(mut data) = data.into_inner() else {
-        return crate::mut_presenter_error!();
-    };
-
-    let Some(field) = data.next_field().await? else {
-        return crate::mut_presenter_error!();
-    };
+use crate::presenters::FromModel;
+#[cfg(feature = "ssr")]
+use crate::ssr::{expect_core_context, extract_user};
 
-    let Some("website_id") = field.name() else {
-        return crate::mut_presenter_error!();
+#[server]
+pub async fn get_uploaded_image(id: Uuid, website_id: String) -> Result<Option<BlobPresenter>, ServerFnError> {
+    let Some(user) = extract_user().await? else {
+        return Ok(None);
     };
 
-    #[allow(unused_variables)]
-    let website_id = field.text().await?;
-
     #[allow(unused_variables)]
     let core_context = expect_core_context();
-    let user = extract_user().await?.unwrap();
 
     #[cfg(not(feature = "website-image-upload"))]
-    let website = None;
-
-    #[cfg(feature = "website-image-upload")]
-    let website = Some(
-        &mango3_core::commands::get_website_by_id(&core_context, uuid::Uuid::try_parse(&website_id)?, Some(&user))
-            .await?,
-    );
+    let _ = website_id;
 
-    let Some(mut field) = data.next_field().await? else {
-        return crate::mut_presenter_error!();
-    };
+    #[cfg(not(feature = "website-image-upload"))]
+    let website = None::<mango3_core::models::Website>;
 
-    let Some("file") = field.name() else {
-        return crate::mut_presenter_error!();
+    #[cfg(feature = "website-image-upload")]
+    let website = if website_id.is_empty() {
+        None
+    } else {
+        Some(mango3_core::commands::get_website_by_id(&core_context, Uuid::try_parse(&website_id)?, Some(&user)).await?)
     };
 
-    let result = mango3_core::commands::insert_blob(&user, website, &mut field).await;
+    let result = mango3_core::commands::get_blob_by_id(id, website.as_ref(), Some(&user)).await;
 
-    crate::mut_presenter!(result)
+    if let Ok(blob) = result {
+        Ok(Some(BlobPresenter::from_model(&blob).await))
+    } else {
+        Ok(None)
+    }
 }
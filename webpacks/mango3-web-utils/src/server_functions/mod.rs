@@ -4,7 +4,7 @@ use leptos::prelude::*;
 mod image_upload;
 
 #[cfg(feature = "image-upload")]
-pub use image_upload::attempt_to_upload_image;
+pub use image_upload::get_uploaded_image;
 
 #[cfg(feature = "current-user")]
 #[server]
@@ -18,6 +18,46 @@ pub async fn get_current_user() -> Result<Option<crate::presenters::UserPresente
     Ok(Some(crate::presenters::UserPresenter::from_model(&user).await))
 }
 
+#[cfg(feature = "hashtag-suggestions")]
+#[server]
+pub async fn get_hashtag_suggestions(query: String) -> Result<Vec<crate::presenters::HashtagPresenter>, ServerFnError> {
+    use crate::presenters::FromModel;
+
+    let hashtags = mango3_core::commands::search_hashtags(&query, 8).await;
+
+    Ok(futures::future::join_all(hashtags.iter().map(crate::presenters::HashtagPresenter::from_model)).await)
+}
+
+#[cfg(feature = "report-button")]
+#[server]
+pub async fn attempt_to_report(
+    target_type: String,
+    target_id: uuid::Uuid,
+    category: String,
+    note: String,
+) -> Result<crate::presenters::MutPresenter, ServerFnError> {
+    use std::str::FromStr;
+
+    use mango3_core::enums::AbuseReportTargetType;
+
+    if !crate::ssr::require_authentication().await? {
+        return crate::mut_presenter_error!();
+    }
+
+    let Ok(target_type) = AbuseReportTargetType::from_str(&target_type) else {
+        return crate::mut_presenter_error!();
+    };
+
+    let core_context = crate::ssr::expect_core_context();
+    let user = crate::ssr::extract_user().await?.unwrap();
+
+    let result =
+        mango3_core::commands::insert_abuse_report(&core_context, &user, target_type, target_id, &category, &note)
+            .await;
+
+    crate::mut_presenter!(result)
+}
+
 #[server]
 pub async fn is_authenticated() -> Result<bool, ServerFnError> {
     crate::ssr::is_authenticated().await
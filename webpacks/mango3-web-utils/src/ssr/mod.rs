@@ -5,6 +5,7 @@ use http::header::{HeaderMap, ACCEPT_LANGUAGE};
 use leptos::prelude::*;
 use tower_sessions::Session;
 
+use mango3_core::config::MISC_CONFIG;
 use mango3_core::utils::I18n;
 use mango3_core::CoreContext;
 
@@ -20,16 +21,50 @@ pub use user_sessions::*;
 #[cfg(feature = "confirmation-codes")]
 pub use confirmation_codes::*;
 
+/// Returns who is performing the current request, so the core commands can record it in the audit log.
+#[cfg(feature = "audit-actor")]
+pub async fn extract_audit_actor() -> Result<mango3_core::models::AuditActor, ServerFnError> {
+    let user = extract_user().await?;
+    let client_ip = extract_client_ip().await?;
+
+    Ok(mango3_core::models::AuditActor::new(user.as_ref(), Some(&client_ip)))
+}
+
+pub async fn extract_client_country() -> Result<Option<String>, ServerFnError> {
+    extract_header(&MISC_CONFIG.client_country_header).await
+}
+
 pub async fn extract_client_ip() -> Result<String, ServerFnError> {
     let InsecureClientIp(client_ip) = leptos_axum::extract::<InsecureClientIp>().await?;
 
     Ok(client_ip.to_string())
 }
 
+/// Returns `true` when the client asks not to be tracked, either with `DNT` or with `Sec-GPC`.
+pub async fn extract_do_not_track() -> Result<bool, ServerFnError> {
+    let header_map = leptos_axum::extract::<HeaderMap>().await?;
+
+    Ok(["dnt", "sec-gpc"].iter().any(|name| {
+        header_map
+            .get(*name)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.trim() == "1")
+    }))
+}
+
 pub fn expect_core_context() -> CoreContext {
     expect_context::<CoreContext>()
 }
 
+pub async fn extract_header(name: &str) -> Result<Option<String>, ServerFnError> {
+    let header_map = leptos_axum::extract::<HeaderMap>().await?;
+
+    Ok(header_map
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_owned()))
+}
+
 pub async fn extract_host() -> Result<String, ServerFnError> {
     let Host(host) = leptos_axum::extract::<Host>().await?;
 
@@ -73,6 +108,48 @@ async fn extract_session() -> Result<Session, ServerFnError> {
     leptos_axum::extract::<Session>().await
 }
 
+/// Builds the session layer shared by every server that reads the `_mango3_session` cookie. The returned handle must be
+/// awaited to keep the Redis connection alive.
+pub async fn session_layer() -> (
+    tower_sessions::SessionManagerLayer<tower_sessions_redis_store::RedisStore<fred::prelude::Pool>>,
+    fred::types::ConnectHandle,
+) {
+    use cookie::{Key, SameSite};
+    use fred::prelude::{ClientLike, Config, Pool};
+    use time::Duration;
+    use tower_sessions::{Expiry, SessionManagerLayer};
+    use tower_sessions_redis_store::RedisStore;
+
+    use mango3_core::config::{BASIC_CONFIG, SESSIONS_CONFIG};
+
+    let redis_pool = Pool::new(
+        Config::from_url(&SESSIONS_CONFIG.redis_url).expect("Could not get Redis URL for session."),
+        None,
+        None,
+        None,
+        10,
+    )
+    .expect("Could not get Redis pool for session.");
+
+    let redis_conn = redis_pool.connect();
+    redis_pool
+        .wait_for_connect()
+        .await
+        .expect("Could not get Redis connection for session.");
+
+    let session_store = RedisStore::new(redis_pool);
+    let session_layer = SessionManagerLayer::new(session_store)
+        .with_domain(BASIC_CONFIG.domain.clone())
+        .with_expiry(Expiry::OnInactivity(Duration::days(30)))
+        .with_http_only(true)
+        .with_name("_mango3_session")
+        .with_private(Key::from(SESSIONS_CONFIG.key.as_bytes()))
+        .with_same_site(SameSite::Strict)
+        .with_secure(BASIC_CONFIG.secure);
+
+    (session_layer, redis_conn)
+}
+
 fn try_core_context() -> Result<CoreContext, ServerFnError> {
     use_context::<CoreContext>().ok_or_else(|| ServerFnError::new("Could not find mango3_core Context"))
 }
@@ -15,3 +15,137 @@ where
         Signal::derive(move || self.get().unwrap_or_default())
     }
 }
+
+#[cfg(feature = "image-upload")]
+pub mod tus {
+    use base64::prelude::{Engine, BASE64_STANDARD};
+    use url::Url;
+    use uuid::Uuid;
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_futures::{js_sys, JsFuture};
+    use web_sys::{File, Headers, RequestCredentials, RequestInit, Response};
+
+    const CHUNK_SIZE: f64 = 1024.0 * 1024.0;
+    const MAX_RETRIES: u32 = 5;
+    const TUS_VERSION: &str = "1.0.0";
+
+    async fn fetch(method: &str, url: &str, headers: &[(&str, String)], body: Option<&JsValue>) -> Option<Response> {
+        let request_headers = Headers::new().ok()?;
+
+        request_headers.set("Tus-Resumable", TUS_VERSION).ok()?;
+
+        for (name, value) in headers {
+            request_headers.set(name, value).ok()?;
+        }
+
+        let init = RequestInit::new();
+
+        init.set_method(method);
+        init.set_credentials(RequestCredentials::Include);
+        init.set_headers(&request_headers);
+
+        if let Some(body) = body {
+            init.set_body(body);
+        }
+
+        let promise = web_sys::window()?.fetch_with_str_and_init(url, &init);
+
+        JsFuture::from(promise).await.ok()?.dyn_into::<Response>().ok()
+    }
+
+    fn header(response: &Response, name: &str) -> Option<String> {
+        response.headers().get(name).ok().flatten()
+    }
+
+    async fn sleep(millis: i32) {
+        let promise = js_sys::Promise::new(&mut |resolve, _| {
+            if let Some(window) = web_sys::window() {
+                let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, millis);
+            }
+        });
+
+        let _ = JsFuture::from(promise).await;
+    }
+
+    async fn current_offset(location: &str) -> Option<f64> {
+        let response = fetch("HEAD", location, &[], None).await?;
+
+        if !response.ok() {
+            return None;
+        }
+
+        header(&response, "Upload-Offset")?.parse().ok()
+    }
+
+    pub async fn upload_file(blob_uploads_url: &Url, file: &File, website_id: &str) -> Option<Uuid> {
+        let file_size = file.size();
+        let mut metadata = vec![
+            format!("filename {}", BASE64_STANDARD.encode(file.name())),
+            format!("filetype {}", BASE64_STANDARD.encode(file.type_())),
+        ];
+
+        if !website_id.is_empty() {
+            metadata.push(format!("website_id {}", BASE64_STANDARD.encode(website_id)));
+        }
+
+        let response = fetch(
+            "POST",
+            blob_uploads_url.as_str(),
+            &[
+                ("Upload-Length", file_size.to_string()),
+                ("Upload-Metadata", metadata.join(",")),
+            ],
+            None,
+        )
+        .await?;
+
+        if response.status() != 201 {
+            return None;
+        }
+
+        let location = header(&response, "Location")?;
+        let mut offset = 0.0;
+        let mut retries = 0;
+
+        loop {
+            let chunk = file
+                .slice_with_f64_and_f64(offset, (offset + CHUNK_SIZE).min(file_size))
+                .ok()?;
+            let response = fetch(
+                "PATCH",
+                &location,
+                &[
+                    ("Content-Type", "application/offset+octet-stream".to_owned()),
+                    ("Upload-Offset", offset.to_string()),
+                ],
+                Some(chunk.as_ref()),
+            )
+            .await;
+
+            match response {
+                Some(response) if response.ok() => {
+                    if let Some(blob_id) = header(&response, "Mango3-Blob-Id") {
+                        return Uuid::try_parse(&blob_id).ok();
+                    }
+
+                    offset = header(&response, "Upload-Offset")?.parse().ok()?;
+                    retries = 0;
+                }
+                Some(response) if response.status() != 409 && response.status() < 500 => return None,
+                _ => {
+                    retries += 1;
+
+                    if retries > MAX_RETRIES {
+                        return None;
+                    }
+
+                    sleep(1000 * retries as i32).await;
+
+                    if let Some(current_offset) = current_offset(&location).await {
+                        offset = current_offset;
+                    }
+                }
+            }
+        }
+    }
+}
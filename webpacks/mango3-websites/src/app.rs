@@ -1,6 +1,6 @@
 use leptos::either::Either;
 use leptos::prelude::*;
-use leptos_meta::{provide_meta_context, Title};
+use leptos_meta::{provide_meta_context, Html, Title};
 use leptos_router::components::{Route, Router, Routes};
 use leptos_router::{ParamSegment, StaticSegment};
 
@@ -13,9 +13,9 @@ use mango3_web_utils::context::use_basic_config;
 use mango3_web_utils::i18n::{t, use_i18n};
 use mango3_web_utils::pages::NotFoundPage;
 
-use crate::components::{CurrentWebsiteOpt, WebsiteTopBar};
+use crate::components::{CurrentWebsite, CurrentWebsiteOpt, WebsiteTopBar};
 use crate::constants::KEY_PARAM_SLUG;
-use crate::context::provide_current_website_resource;
+use crate::context::{provide_current_website_resource, provide_page_language_code, use_page_language_code};
 use crate::pages::{IndexPage, SearchPage, ShowHashtagPage, ShowPostPage};
 
 #[component]
@@ -24,9 +24,10 @@ pub fn App() -> impl IntoView {
     provide_meta_context();
 
     provide_current_website_resource();
+    provide_page_language_code();
 
     view! {
-        <AppProvider>
+        <AppProvider set_lang_attr_on_html=false>
             {move || {
                 let i18n = use_i18n();
                 let basic_config = use_basic_config();
@@ -112,6 +113,21 @@ pub fn App() -> impl IntoView {
                         } />
                     </Router>
 
+                    // Pages showing content in another language, like post translations, set the page language code.
+                    <CurrentWebsite children=move |website| {
+                        let page_language_code = use_page_language_code();
+                        let website_language_code = website.language_code.clone();
+                        view! {
+                            <Html
+                                {..}
+                                lang=move || {
+                                    Some(page_language_code.get().unwrap_or_else(|| website_language_code.clone()))
+                                        .filter(|language_code| !language_code.is_empty())
+                                }
+                            />
+                        }
+                    } />
+
                     <CurrentWebsiteOpt children=move |website| {
                         match website {
                             Some(website) => {
@@ -8,14 +8,14 @@ use mango3_web_utils::presenters::WebsitePresenter;
 
 use crate::context::use_current_website_resource;
 
-mod highlight_code;
 mod post_comments;
 mod post_reactions;
+mod post_translations;
 mod website_top_bar;
 
-pub use highlight_code::HighLightCode;
 pub use post_comments::PostComments;
 pub use post_reactions::PostReactions;
+pub use post_translations::PostTranslations;
 pub use website_top_bar::WebsiteTopBar;
 
 #[component]
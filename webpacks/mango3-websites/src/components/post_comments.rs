@@ -3,8 +3,8 @@ use uuid::Uuid;
 
 use mango3_web_utils::components::forms::{FormErrorAlert, FormSuccessModal, MarkdownEditorField, SubmitButton};
 use mango3_web_utils::components::{
-    CurrentUser, InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollLocalResourceController, TimeAgo,
-    UserTag, UserTagLink,
+    CurrentUser, InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollLocalResourceController, ReportButton,
+    TimeAgo, UserTag, UserTagLink,
 };
 use mango3_web_utils::i18n::{t, use_i18n};
 use mango3_web_utils::presenters::PostCommentPresenter;
@@ -87,12 +87,16 @@ pub fn PostComments(post_id: Uuid) -> impl IntoView {
                                             <UserTagLink user=post_comment.user />
 
                                             <div class="flex-1">
-                                                <div class="text-right opacity-70">
-                                                    <TimeAgo value=post_comment.created_at />
+                                                <div class="flex items-center justify-end gap-2">
+                                                    <div class="opacity-70">
+                                                        <TimeAgo value=post_comment.created_at />
+                                                    </div>
+
+                                                    <ReportButton target_type="post_comment" target_id=post_comment.id />
                                                 </div>
 
                                                 <div
-                                                    class="prose prose-pre:bg-transparent max-w-none break-words"
+                                                    class="prose max-w-none break-words"
                                                     inner_html=post_comment.content_html.clone()
                                                 />
                                             </div>
@@ -0,0 +1,72 @@
+use leptos::prelude::*;
+use leptos_meta::Link;
+use uuid::Uuid;
+
+use mango3_web_utils::i18n::{t, use_i18n};
+
+use crate::server_functions::get_post_translations;
+
+fn translation_href(url: &str, language_code: &str) -> String {
+    format!("{url}?lang={language_code}")
+}
+
+/// Links the translations of a post, both for readers and, with `hreflang` alternate links, for search engines.
+#[component]
+pub fn PostTranslations(post_id: Uuid, language_code: String, url: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let translations_resource = Resource::new_blocking(move || post_id, get_post_translations);
+
+    view! {
+        <Suspense>
+            {move || {
+                let language_code = language_code.clone();
+                let url = url.clone();
+                Suspend::new(async move {
+                    translations_resource
+                        .get()
+                        .and_then(|result| result.ok())
+                        .filter(|translations| !translations.is_empty())
+                        .map(|translations| {
+                            view! {
+                                <Link
+                                    rel="alternate"
+                                    hreflang=language_code.clone()
+                                    href=translation_href(&url, &language_code)
+                                />
+
+                                <div class="flex flex-wrap gap-2 items-center my-4">
+                                    <span class="opacity-70">{t!(i18n, websites.also_available_in)}</span>
+
+                                    {translations
+                                        .into_iter()
+                                        .map(|translation| {
+                                            let href = translation_href(
+                                                translation.url.as_str(),
+                                                &translation.language_code,
+                                            );
+                                            view! {
+                                                <Link
+                                                    rel="alternate"
+                                                    hreflang=translation.language_code.clone()
+                                                    href=href.clone()
+                                                />
+
+                                                <a
+                                                    class="badge badge-outline"
+                                                    href=href
+                                                    hreflang=translation.language_code.clone()
+                                                    title=translation.title
+                                                >
+                                                    {translation.language_code.to_uppercase()}
+                                                </a>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            }
+                        })
+                })
+            }}
+        </Suspense>
+    }
+}
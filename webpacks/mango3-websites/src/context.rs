@@ -10,6 +10,10 @@ pub fn param_slug(params_map: Memo<ParamsMap>) -> String {
     params_map.with(|params| params.get(KEY_PARAM_SLUG).unwrap_or_default())
 }
 
+pub fn provide_page_language_code() {
+    provide_context(RwSignal::<Option<String>>::new(None))
+}
+
 pub fn provide_current_website_resource() {
     provide_context(Resource::new_blocking(|| (), |_| get_current_website()))
 }
@@ -18,6 +22,10 @@ pub fn use_current_website_resource() -> Resource<Result<Option<WebsitePresenter
     use_context::<Resource<Result<Option<WebsitePresenter>, ServerFnError>>>().unwrap()
 }
 
+pub fn use_page_language_code() -> RwSignal<Option<String>> {
+    use_context::<RwSignal<Option<String>>>().unwrap()
+}
+
 pub fn use_current_post() -> PostPresenter {
     use_context::<PostPresenter>().unwrap()
 }
@@ -4,6 +4,7 @@ use leptos::prelude::*;
 use mango3_web_utils::async_t_string;
 use mango3_web_utils::components::{
     Hashtags, InfiniteScroll, InfiniteScrollControllerTrait, InfiniteScrollResourceController, PostCard,
+    ReportButton,
 };
 use mango3_web_utils::i18n::use_i18n;
 use mango3_web_utils::pages::{NotFoundPage, Page};
@@ -49,13 +50,17 @@ pub fn IndexPage() -> impl IntoView {
                                     <div class="card card-compact bg-base-200 shadow-xl flex-1 self-start min-w-[320px] max-w-[640px]">
                                         <div class="card-body">
                                             <div
-                                                class="prose prose-pre:bg-transparent max-w-none break-words"
+                                                class="prose max-w-none break-words"
                                                 inner_html=website.description_html.clone()
                                             />
 
                                             <div class="empty:hidden my-4 flex flex-wrap gap-2">
                                                 <Hashtags hashtags=website.hashtags />
                                             </div>
+
+                                            <div class="text-right">
+                                                <ReportButton target_type="website" target_id=website.id />
+                                            </div>
                                         </div>
                                     </div>
 
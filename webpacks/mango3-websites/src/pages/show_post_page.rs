@@ -1,26 +1,34 @@
 use leptos::either::EitherOf3;
 use leptos::prelude::*;
 use leptos_meta::Meta;
-use leptos_router::hooks::use_params_map;
+use leptos_router::hooks::{use_params_map, use_query_map};
 
-use mango3_web_utils::components::{Hashtags, LoadingSpinner, Modal, PostBottomBar, UserTagLink};
+use mango3_web_utils::components::{Hashtags, LoadingSpinner, Modal, PostBottomBar, ReportButton, UserTagLink};
 use mango3_web_utils::pages::NotFoundPage;
 use mango3_web_utils::pages::Page;
 
-use crate::components::{HighLightCode, MetaDateTime, PostComments, PostReactions};
-use crate::context::param_slug;
+use crate::components::{MetaDateTime, PostComments, PostReactions, PostTranslations};
+use crate::context::{param_slug, use_page_language_code};
 use crate::server_functions::get_post;
 
 #[component]
 pub fn ShowPostPage() -> impl IntoView {
     let params_map = use_params_map();
-    let post_resource = Resource::new_blocking(move || param_slug(params_map), get_post);
+    let query_map = use_query_map();
+    let post_resource = Resource::new_blocking(
+        move || (param_slug(params_map), query_map.with(|params| params.get("lang"))),
+        |(slug, language_code)| get_post(slug, language_code),
+    );
+    let page_language_code = use_page_language_code();
+
+    on_cleanup(move || page_language_code.set(None));
 
     view! {
         <Suspense fallback=LoadingSpinner>
             {move || Suspend::new(async move {
                 match post_resource.get() {
                     Some(Ok(Some(post))) => {
+                        page_language_code.set(Some(post.language_code.clone()));
                         let image_modal_url = RwSignal::new(None);
                         let image_modal_is_open = RwSignal::new(false);
                         Effect::new(move || {
@@ -57,86 +65,110 @@ pub fn ShowPostPage() -> impl IntoView {
                                             })
                                     }}
 
-                                    <div class="card card-compact bg-base-200 shadow-xl">
-                                        {
-                                            let post_title = post.title.clone();
-                                            move || {
-                                                post.cover_image_blob
-                                                    .clone()
-                                                    .map(|cover_image_blob| {
-                                                        let cover_image_url = cover_image_blob
-                                                            .variant_url(1200, 200, true);
-                                                        view! {
-                                                            <Meta
-                                                                property="og:image"
-                                                                content=cover_image_url.to_string()
-                                                            />
-                                                            <figure>
-                                                                <img
-                                                                    src=cover_image_url.to_string()
-                                                                    alt=post_title.clone()
+                                    <div class="flex gap-4 items-start">
+                                        <div class="card card-compact bg-base-200 shadow-xl flex-1 min-w-0">
+                                            {
+                                                let post_title = post.title.clone();
+                                                move || {
+                                                    post.cover_image_blob
+                                                        .clone()
+                                                        .map(|cover_image_blob| {
+                                                            let cover_image_url = cover_image_blob
+                                                                .variant_url(1200, 200, true);
+                                                            view! {
+                                                                <Meta
+                                                                    property="og:image"
+                                                                    content=cover_image_url.to_string()
                                                                 />
-                                                            </figure>
-                                                        }
-                                                    })
-                                            }
-                                        } <div class="card-body">
-                                            <h1 class="card-title h1 text-2xl">{post.title}</h1>
-
-                                            <div class="my-4">
-                                                <UserTagLink user=post.user />
-                                            </div>
-
-                                            <div
-                                                class="prose prose-pre:bg-transparent prose-img:mx-auto max-w-none break-words"
-                                                inner_html=post.content_html.clone()
-                                            />
-
-                                            <div class="empty:hidden flex flex-wrap gap-3 my-4">
-                                                <For each=move || post.blobs.clone() key=|blob| blob.id let:blob>
-                                                    <figure
-                                                        class="rounded"
-                                                        on:click=move |_| {
-                                                            image_modal_url.set(Some(blob.url.clone()));
-                                                        }
-                                                    >
-                                                        <img src=blob.variant_url(128, 128, true).to_string() />
+                                                                <figure>
+                                                                    <img
+                                                                        src=cover_image_url.to_string()
+                                                                        alt=cover_image_blob.alt_text_or(&post_title)
+                                                                    />
+                                                                </figure>
+                                                            }
+                                                        })
+                                                }
+                                            } <div class="card-body">
+                                                <h1 class="card-title h1 text-2xl">{post.title}</h1>
+
+                                                <div class="my-4">
+                                                    <UserTagLink user=post.user />
+                                                </div>
+
+                                                <PostTranslations
+                                                    post_id=post.id
+                                                    language_code=post.language_code.clone()
+                                                    url=post.url.to_string()
+                                                />
+
+                                                <div
+                                                    class="prose prose-img:mx-auto max-w-none break-words"
+                                                    inner_html=post.content_html
+                                                />
+
+                                                <div class="empty:hidden flex flex-wrap gap-3 my-4">
+                                                    <For each=move || post.blobs.clone() key=|blob| blob.id let:blob>
+                                                        <figure
+                                                            class="rounded"
+                                                            on:click=move |_| {
+                                                                image_modal_url.set(Some(blob.url.clone()));
+                                                            }
+                                                        >
+                                                            <img
+                                                                src=blob.variant_url(128, 128, true).to_string()
+                                                                alt=blob.alt_text.clone()
+                                                                title=blob.caption.clone()
+                                                            />
+                                                        </figure>
+                                                    </For>
+                                                </div>
+
+                                                <Modal
+                                                    class="overflow-y-visible"
+                                                    box_class="overflow-y-visible max-w-[max-content]"
+                                                    is_open=image_modal_is_open
+                                                >
+                                                    <figure class="max-w-full">
+                                                        <img
+                                                            class="max-w-[calc(100vw-120px)] max-h-[calc(100vh-120px)]"
+                                                            src=move || image_modal_url.get().map(|url| url.to_string())
+                                                        />
                                                     </figure>
-                                                </For>
-                                            </div>
+                                                </Modal>
 
-                                            <Modal
-                                                class="overflow-y-visible"
-                                                box_class="overflow-y-visible max-w-[max-content]"
-                                                is_open=image_modal_is_open
-                                            >
-                                                <figure class="max-w-full">
-                                                    <img
-                                                        class="max-w-[calc(100vw-120px)] max-h-[calc(100vh-120px)]"
-                                                        src=move || image_modal_url.get().map(|url| url.to_string())
-                                                    />
-                                                </figure>
-                                            </Modal>
+                                                <div class="empty:hidden my-4 flex flex-wrap gap-2">
+                                                    <Hashtags hashtags=post.hashtags />
+                                                </div>
 
-                                            <div class="empty:hidden my-4 flex flex-wrap gap-2">
-                                                <Hashtags hashtags=post.hashtags />
-                                            </div>
+                                                <PostBottomBar
+                                                    comments_count=post.comments_count
+                                                    reactions_count=post.reactions_count
+                                                    views_count=post.views_count
+                                                    created_at=post.created_at
+                                                    modified_at=post.modified_at
+                                                />
 
-                                            <PostBottomBar
-                                                comments_count=post.comments_count
-                                                reactions_count=post.reactions_count
-                                                views_count=post.views_count
-                                                created_at=post.created_at
-                                                modified_at=post.modified_at
-                                            />
+                                                <PostReactions post_id=post.id />
 
-                                            <PostReactions post_id=post.id />
+                                                <div class="text-right">
+                                                    <ReportButton target_type="post" target_id=post.id />
+                                                </div>
 
-                                            <PostComments post_id=post.id />
+                                                <PostComments post_id=post.id />
+                                            </div>
                                         </div>
-                                    </div>
 
-                                    <HighLightCode content=post.content_html />
+                                        {post
+                                            .toc_html
+                                            .map(|toc_html| {
+                                                view! {
+                                                    <aside class="hidden lg:block sticky top-4 w-64 shrink-0 card card-compact bg-base-200 shadow-xl max-h-[calc(100vh-2rem)] overflow-y-auto">
+                                                        <div class="card-body prose prose-sm" inner_html=toc_html />
+                                                    </aside>
+                                                }
+                                            })}
+                                    </div>
                                 </Page>
                             },
                         )
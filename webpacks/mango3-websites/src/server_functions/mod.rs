@@ -15,7 +15,7 @@ mod posts;
 
 pub use post_comments::{get_post_comments, AttemptToCreatePostComment};
 pub use post_reactions::*;
-pub use posts::{get_post, get_posts, get_posts_search};
+pub use posts::{get_post, get_post_translations, get_posts, get_posts_search};
 
 #[cfg(feature = "ssr")]
 async fn current_website() -> Result<Option<Website>, ServerFnError> {
@@ -1,7 +1,7 @@
 use leptos::prelude::*;
 use uuid::Uuid;
 
-use mango3_web_utils::presenters::{CursorPagePresenter, PostMinPresenter, PostPresenter};
+use mango3_web_utils::presenters::{CursorPagePresenter, PostMinPresenter, PostPresenter, PostTranslationPresenter};
 
 #[cfg(feature = "ssr")]
 use mango3_core::models::Post;
@@ -10,7 +10,10 @@ use mango3_core::utils::CursorPageParams;
 #[cfg(feature = "ssr")]
 use mango3_web_utils::presenters::FromModel;
 #[cfg(feature = "ssr")]
-use mango3_web_utils::ssr::{expect_core_context, extract_client_ip, extract_user};
+use mango3_web_utils::ssr::{
+    expect_core_context, extract_client_country, extract_client_ip, extract_do_not_track, extract_header, extract_i18n,
+    extract_user,
+};
 
 #[cfg(feature = "ssr")]
 use super::current_website;
@@ -79,23 +82,68 @@ pub async fn get_posts_search(
     mango3_web_utils::cursor_page_presenter!(&page)
 }
 
+/// Returns the post with the given slug, or its translation in the requested language, which defaults to the
+/// language of the reader.
 #[server]
-pub async fn get_post(slug: String) -> Result<Option<PostPresenter>, ServerFnError> {
+pub async fn get_post(slug: String, language_code: Option<String>) -> Result<Option<PostPresenter>, ServerFnError> {
     let Some(website) = current_website().await? else {
         return Ok(None);
     };
 
     let core_context = expect_core_context();
-    let result = mango3_core::commands::get_post_by_slug(&core_context, &slug, &website).await;
+    let language_code = if let Some(language_code) = language_code {
+        language_code
+    } else {
+        extract_i18n().await?.0.language.to_string()
+    };
+    let result = mango3_core::commands::get_post_by_slug(&core_context, &slug, &website, Some(&language_code)).await;
 
     if let Ok(post) = result {
-        let client_ip = extract_client_ip().await?;
-        let user = extract_user().await?;
+        if !extract_do_not_track().await? {
+            let client_ip = extract_client_ip().await?;
+            let user = extract_user().await?;
+            let user_agent = extract_header("user-agent").await?;
+            let referrer = extract_header("referer").await?;
+            let country_alpha2 = extract_client_country().await?;
+
+            let _ = mango3_core::commands::get_or_insert_post_view(
+                &core_context,
+                &post,
+                user.as_ref(),
+                &client_ip,
+                user_agent.as_deref(),
+            )
+            .await;
 
-        let _ = mango3_core::commands::get_or_insert_post_view(&core_context, &post, user.as_ref(), &client_ip).await;
+            let _ = mango3_core::commands::insert_post_view_event(
+                &core_context,
+                &post,
+                user.as_ref(),
+                &client_ip,
+                user_agent.as_deref(),
+                referrer.as_deref(),
+                country_alpha2.as_deref(),
+            )
+            .await;
+        }
 
         Ok(Some(PostPresenter::from_model(&post).await))
     } else {
         Ok(None)
     }
 }
+
+#[server]
+pub async fn get_post_translations(id: Uuid) -> Result<Vec<PostTranslationPresenter>, ServerFnError> {
+    let post = current_post(id).await?;
+    let core_context = expect_core_context();
+    let translations = mango3_core::commands::all_post_translations(&core_context, &post, Some(true)).await;
+
+    Ok(futures::future::join_all(
+        translations
+            .iter()
+            .filter(|translation| !translation.language_code().is_empty())
+            .map(PostTranslationPresenter::from_model),
+    )
+    .await)
+}